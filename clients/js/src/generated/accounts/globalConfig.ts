@@ -22,6 +22,7 @@ import {
 import {
   Serializer,
   array,
+  bool,
   mapSerializer,
   publicKey as publicKeySerializer,
   string,
@@ -54,6 +55,8 @@ export type GlobalConfigAccountData = {
   baseMintDecimals: number;
   stakedSupply: bigint;
   depositCap: bigint;
+  stakeActive: boolean;
+  unstakeActive: boolean;
   exchangeRateHistory: Array<ExchangeRatePhase>;
   pointsHistory: Array<PointsEarnedPhase>;
   baseYieldHistory: Array<BaseYieldPhase>;
@@ -69,6 +72,8 @@ export type GlobalConfigAccountDataArgs = {
   baseMintDecimals: number;
   stakedSupply: number | bigint;
   depositCap: number | bigint;
+  stakeActive: boolean;
+  unstakeActive: boolean;
   exchangeRateHistory: Array<ExchangeRatePhaseArgs>;
   pointsHistory: Array<PointsEarnedPhaseArgs>;
   baseYieldHistory: Array<BaseYieldPhaseArgs>;
@@ -95,6 +100,8 @@ export function getGlobalConfigAccountDataSerializer(): Serializer<
         ['baseMintDecimals', u8()],
         ['stakedSupply', u64()],
         ['depositCap', u64()],
+        ['stakeActive', bool()],
+        ['unstakeActive', bool()],
         ['exchangeRateHistory', array(getExchangeRatePhaseSerializer())],
         ['pointsHistory', array(getPointsEarnedPhaseSerializer())],
         ['baseYieldHistory', array(getBaseYieldPhaseSerializer())],
@@ -185,6 +192,8 @@ export function getGlobalConfigGpaBuilder(
       baseMintDecimals: number;
       stakedSupply: number | bigint;
       depositCap: number | bigint;
+      stakeActive: boolean;
+      unstakeActive: boolean;
       exchangeRateHistory: Array<ExchangeRatePhaseArgs>;
       pointsHistory: Array<PointsEarnedPhaseArgs>;
       baseYieldHistory: Array<BaseYieldPhaseArgs>;
@@ -199,7 +208,9 @@ export function getGlobalConfigGpaBuilder(
       baseMintDecimals: [169, u8()],
       stakedSupply: [170, u64()],
       depositCap: [178, u64()],
-      exchangeRateHistory: [186, array(getExchangeRatePhaseSerializer())],
+      stakeActive: [186, bool()],
+      unstakeActive: [187, bool()],
+      exchangeRateHistory: [188, array(getExchangeRatePhaseSerializer())],
       pointsHistory: [null, array(getPointsEarnedPhaseSerializer())],
       baseYieldHistory: [null, array(getBaseYieldPhaseSerializer())],
     })
@@ -0,0 +1,182 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Guardian = Account<GuardianAccountData>;
+
+export type GuardianAccountData = {
+  discriminator: Array<number>;
+  poolManager: PublicKey;
+  wallet: PublicKey;
+};
+
+export type GuardianAccountDataArgs = {
+  poolManager: PublicKey;
+  wallet: PublicKey;
+};
+
+export function getGuardianAccountDataSerializer(): Serializer<
+  GuardianAccountDataArgs,
+  GuardianAccountData
+> {
+  return mapSerializer<GuardianAccountDataArgs, any, GuardianAccountData>(
+    struct<GuardianAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['poolManager', publicKeySerializer()],
+        ['wallet', publicKeySerializer()],
+      ],
+      { description: 'GuardianAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [57, 234, 122, 214, 12, 246, 9, 45],
+    })
+  ) as Serializer<GuardianAccountDataArgs, GuardianAccountData>;
+}
+
+export function deserializeGuardian(rawAccount: RpcAccount): Guardian {
+  return deserializeAccount(rawAccount, getGuardianAccountDataSerializer());
+}
+
+export async function fetchGuardian(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Guardian> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'Guardian');
+  return deserializeGuardian(maybeAccount);
+}
+
+export async function safeFetchGuardian(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Guardian | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeGuardian(maybeAccount) : null;
+}
+
+export async function fetchAllGuardian(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Guardian[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'Guardian');
+    return deserializeGuardian(maybeAccount);
+  });
+}
+
+export async function safeFetchAllGuardian(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Guardian[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeGuardian(maybeAccount as RpcAccount));
+}
+
+export function getGuardianGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      poolManager: PublicKey;
+      wallet: PublicKey;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      poolManager: [8, publicKeySerializer()],
+      wallet: [40, publicKeySerializer()],
+    })
+    .deserializeUsing<Guardian>((account) => deserializeGuardian(account))
+    .whereField('discriminator', [57, 234, 122, 214, 12, 246, 9, 45]);
+}
+
+export function getGuardianSize(): number {
+  return 72;
+}
+
+export function findGuardianPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the pool manager */
+    poolManager: PublicKey;
+    /** The address of the guardian wallet */
+    wallet: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('guardian'),
+    publicKeySerializer().serialize(seeds.poolManager),
+    publicKeySerializer().serialize(seeds.wallet),
+  ]);
+}
+
+export async function fetchGuardianFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findGuardianPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<Guardian> {
+  return fetchGuardian(context, findGuardianPda(context, seeds), options);
+}
+
+export async function safeFetchGuardianFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findGuardianPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<Guardian | null> {
+  return safeFetchGuardian(context, findGuardianPda(context, seeds), options);
+}
@@ -8,6 +8,17 @@
 
 export * from './gatekeeper';
 export * from './globalConfig';
+export * from './guardian';
+export * from './minter';
 export * from './poolManager';
+export * from './ptGuardian';
+export * from './rateFeed';
+export * from './rateHistory';
+export * from './stakerEntry';
+export * from './strategy';
+export * from './termPosition';
 export * from './tokenManager';
+export * from './tranche';
+export * from './unstakeTicket';
 export * from './userStake';
+export * from './yieldSchedule';
@@ -0,0 +1,169 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Minter = Account<MinterAccountData>;
+
+export type MinterAccountData = {
+  discriminator: Array<number>;
+  wallet: PublicKey;
+};
+
+export type MinterAccountDataArgs = { wallet: PublicKey };
+
+export function getMinterAccountDataSerializer(): Serializer<
+  MinterAccountDataArgs,
+  MinterAccountData
+> {
+  return mapSerializer<MinterAccountDataArgs, any, MinterAccountData>(
+    struct<MinterAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['wallet', publicKeySerializer()],
+      ],
+      { description: 'MinterAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [28, 69, 107, 166, 41, 139, 205, 247],
+    })
+  ) as Serializer<MinterAccountDataArgs, MinterAccountData>;
+}
+
+export function deserializeMinter(rawAccount: RpcAccount): Minter {
+  return deserializeAccount(rawAccount, getMinterAccountDataSerializer());
+}
+
+export async function fetchMinter(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Minter> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'Minter');
+  return deserializeMinter(maybeAccount);
+}
+
+export async function safeFetchMinter(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Minter | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeMinter(maybeAccount) : null;
+}
+
+export async function fetchAllMinter(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Minter[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'Minter');
+    return deserializeMinter(maybeAccount);
+  });
+}
+
+export async function safeFetchAllMinter(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Minter[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeMinter(maybeAccount as RpcAccount));
+}
+
+export function getMinterGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'parityIssuance',
+    'ALukFrRp8cFkWCEZamFVsBiFtxKYPLUUGRxskFh1g5ZX'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{ discriminator: Array<number>; wallet: PublicKey }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      wallet: [8, publicKeySerializer()],
+    })
+    .deserializeUsing<Minter>((account) => deserializeMinter(account))
+    .whereField('discriminator', [28, 69, 107, 166, 41, 139, 205, 247]);
+}
+
+export function getMinterSize(): number {
+  return 40;
+}
+
+export function findMinterPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the minter wallet */
+    wallet: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'parityIssuance',
+    'ALukFrRp8cFkWCEZamFVsBiFtxKYPLUUGRxskFh1g5ZX'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('minter'),
+    publicKeySerializer().serialize(seeds.wallet),
+  ]);
+}
+
+export async function fetchMinterFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findMinterPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<Minter> {
+  return fetchMinter(context, findMinterPda(context, seeds), options);
+}
+
+export async function safeFetchMinterFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findMinterPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<Minter | null> {
+  return safeFetchMinter(context, findMinterPda(context, seeds), options);
+}
@@ -22,21 +22,32 @@ import {
 import {
   Serializer,
   array,
+  bool,
   i32,
   i64,
   mapSerializer,
   publicKey as publicKeySerializer,
   string,
   struct,
+  u16,
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  FeeDestination,
+  FeeDestinationArgs,
+  YieldMode,
+  YieldModeArgs,
+  getFeeDestinationSerializer,
+  getYieldModeSerializer,
+} from '../types';
 
 export type PoolManager = Account<PoolManagerAccountData>;
 
 export type PoolManagerAccountData = {
   discriminator: Array<number>;
   bump: number;
+  poolId: bigint;
   owner: PublicKey;
   pendingOwner: PublicKey;
   admin: PublicKey;
@@ -44,6 +55,7 @@ export type PoolManagerAccountData = {
   xMint: PublicKey;
   baseMintDecimals: number;
   xMintDecimals: number;
+  annualYieldBps: bigint;
   intervalAprRate: bigint;
   secondsPerInterval: number;
   initialExchangeRate: bigint;
@@ -52,10 +64,54 @@ export type PoolManagerAccountData = {
   lastYieldChangeTimestamp: bigint;
   baseBalance: bigint;
   depositCap: bigint;
+  unstakeCooldown: bigint;
+  pendingUnstakeAmount: bigint;
+  treasury: PublicKey;
+  instantUnstakeFeeBps: number;
+  instantUnstakeFeeDestination: FeeDestination;
+  liquidityBufferTarget: bigint;
+  liquidityBufferAmount: bigint;
+  minStakeAmount: bigint;
+  minUnstakeAmount: bigint;
+  issuanceProgram: PublicKey;
+  tokenManager: PublicKey;
+  pendingIssuanceProgram: PublicKey;
+  pendingTokenManager: PublicKey;
+  issuanceUpdateTimestamp: bigint;
+  principalAmount: bigint;
+  realizedYieldAmount: bigint;
+  totalYieldMinted: bigint;
+  totalBoostMinted: bigint;
+  minAccrualInterval: bigint;
+  lastAccrualTimestamp: bigint;
+  keeperReward: bigint;
+  totalKeeperRewards: bigint;
+  yieldMode: YieldMode;
+  xSupply: bigint;
+  rewarder: PublicKey;
+  vestingPeriod: bigint;
+  vestingAmount: bigint;
+  vestingStartTimestamp: bigint;
+  totalRewardsDeposited: bigint;
+  performanceFeeBps: number;
+  totalPerformanceFees: bigint;
+  entryFeeBps: number;
+  entryFeeDestination: FeeDestination;
+  exitFeeBps: number;
+  exitFeeDestination: FeeDestination;
+  stakeActive: boolean;
+  unstakeActive: boolean;
+  xTokenProgram: PublicKey;
+  checkpointInterval: bigint;
+  maxStrategyAllocationBps: number;
+  strategyValue: bigint;
+  allowlistEnabled: boolean;
+  walletDepositCap: bigint;
 };
 
 export type PoolManagerAccountDataArgs = {
   bump: number;
+  poolId: number | bigint;
   owner: PublicKey;
   pendingOwner: PublicKey;
   admin: PublicKey;
@@ -63,6 +119,7 @@ export type PoolManagerAccountDataArgs = {
   xMint: PublicKey;
   baseMintDecimals: number;
   xMintDecimals: number;
+  annualYieldBps: number | bigint;
   intervalAprRate: number | bigint;
   secondsPerInterval: number;
   initialExchangeRate: number | bigint;
@@ -71,6 +128,49 @@ export type PoolManagerAccountDataArgs = {
   lastYieldChangeTimestamp: number | bigint;
   baseBalance: number | bigint;
   depositCap: number | bigint;
+  unstakeCooldown: number | bigint;
+  pendingUnstakeAmount: number | bigint;
+  treasury: PublicKey;
+  instantUnstakeFeeBps: number;
+  instantUnstakeFeeDestination: FeeDestinationArgs;
+  liquidityBufferTarget: number | bigint;
+  liquidityBufferAmount: number | bigint;
+  minStakeAmount: number | bigint;
+  minUnstakeAmount: number | bigint;
+  issuanceProgram: PublicKey;
+  tokenManager: PublicKey;
+  pendingIssuanceProgram: PublicKey;
+  pendingTokenManager: PublicKey;
+  issuanceUpdateTimestamp: number | bigint;
+  principalAmount: number | bigint;
+  realizedYieldAmount: number | bigint;
+  totalYieldMinted: number | bigint;
+  totalBoostMinted: number | bigint;
+  minAccrualInterval: number | bigint;
+  lastAccrualTimestamp: number | bigint;
+  keeperReward: number | bigint;
+  totalKeeperRewards: number | bigint;
+  yieldMode: YieldModeArgs;
+  xSupply: number | bigint;
+  rewarder: PublicKey;
+  vestingPeriod: number | bigint;
+  vestingAmount: number | bigint;
+  vestingStartTimestamp: number | bigint;
+  totalRewardsDeposited: number | bigint;
+  performanceFeeBps: number;
+  totalPerformanceFees: number | bigint;
+  entryFeeBps: number;
+  entryFeeDestination: FeeDestinationArgs;
+  exitFeeBps: number;
+  exitFeeDestination: FeeDestinationArgs;
+  stakeActive: boolean;
+  unstakeActive: boolean;
+  xTokenProgram: PublicKey;
+  checkpointInterval: number | bigint;
+  maxStrategyAllocationBps: number;
+  strategyValue: number | bigint;
+  allowlistEnabled: boolean;
+  walletDepositCap: number | bigint;
 };
 
 export function getPoolManagerAccountDataSerializer(): Serializer<
@@ -82,6 +182,7 @@ export function getPoolManagerAccountDataSerializer(): Serializer<
       [
         ['discriminator', array(u8(), { size: 8 })],
         ['bump', u8()],
+        ['poolId', u64()],
         ['owner', publicKeySerializer()],
         ['pendingOwner', publicKeySerializer()],
         ['admin', publicKeySerializer()],
@@ -89,6 +190,7 @@ export function getPoolManagerAccountDataSerializer(): Serializer<
         ['xMint', publicKeySerializer()],
         ['baseMintDecimals', u8()],
         ['xMintDecimals', u8()],
+        ['annualYieldBps', u64()],
         ['intervalAprRate', u64()],
         ['secondsPerInterval', i32()],
         ['initialExchangeRate', u64()],
@@ -97,6 +199,49 @@ export function getPoolManagerAccountDataSerializer(): Serializer<
         ['lastYieldChangeTimestamp', i64()],
         ['baseBalance', u64()],
         ['depositCap', u64()],
+        ['unstakeCooldown', i64()],
+        ['pendingUnstakeAmount', u64()],
+        ['treasury', publicKeySerializer()],
+        ['instantUnstakeFeeBps', u16()],
+        ['instantUnstakeFeeDestination', getFeeDestinationSerializer()],
+        ['liquidityBufferTarget', u64()],
+        ['liquidityBufferAmount', u64()],
+        ['minStakeAmount', u64()],
+        ['minUnstakeAmount', u64()],
+        ['issuanceProgram', publicKeySerializer()],
+        ['tokenManager', publicKeySerializer()],
+        ['pendingIssuanceProgram', publicKeySerializer()],
+        ['pendingTokenManager', publicKeySerializer()],
+        ['issuanceUpdateTimestamp', i64()],
+        ['principalAmount', u64()],
+        ['realizedYieldAmount', u64()],
+        ['totalYieldMinted', u64()],
+        ['totalBoostMinted', u64()],
+        ['minAccrualInterval', i64()],
+        ['lastAccrualTimestamp', i64()],
+        ['keeperReward', u64()],
+        ['totalKeeperRewards', u64()],
+        ['yieldMode', getYieldModeSerializer()],
+        ['xSupply', u64()],
+        ['rewarder', publicKeySerializer()],
+        ['vestingPeriod', i64()],
+        ['vestingAmount', u64()],
+        ['vestingStartTimestamp', i64()],
+        ['totalRewardsDeposited', u64()],
+        ['performanceFeeBps', u16()],
+        ['totalPerformanceFees', u64()],
+        ['entryFeeBps', u16()],
+        ['entryFeeDestination', getFeeDestinationSerializer()],
+        ['exitFeeBps', u16()],
+        ['exitFeeDestination', getFeeDestinationSerializer()],
+        ['stakeActive', bool()],
+        ['unstakeActive', bool()],
+        ['xTokenProgram', publicKeySerializer()],
+        ['checkpointInterval', i64()],
+        ['maxStrategyAllocationBps', u16()],
+        ['strategyValue', u64()],
+        ['allowlistEnabled', bool()],
+        ['walletDepositCap', u64()],
       ],
       { description: 'PoolManagerAccountData' }
     ),
@@ -176,6 +321,7 @@ export function getPoolManagerGpaBuilder(
     .registerFields<{
       discriminator: Array<number>;
       bump: number;
+      poolId: number | bigint;
       owner: PublicKey;
       pendingOwner: PublicKey;
       admin: PublicKey;
@@ -183,6 +329,7 @@ export function getPoolManagerGpaBuilder(
       xMint: PublicKey;
       baseMintDecimals: number;
       xMintDecimals: number;
+      annualYieldBps: number | bigint;
       intervalAprRate: number | bigint;
       secondsPerInterval: number;
       initialExchangeRate: number | bigint;
@@ -191,35 +338,129 @@ export function getPoolManagerGpaBuilder(
       lastYieldChangeTimestamp: number | bigint;
       baseBalance: number | bigint;
       depositCap: number | bigint;
+      unstakeCooldown: number | bigint;
+      pendingUnstakeAmount: number | bigint;
+      treasury: PublicKey;
+      instantUnstakeFeeBps: number;
+      instantUnstakeFeeDestination: FeeDestinationArgs;
+      liquidityBufferTarget: number | bigint;
+      liquidityBufferAmount: number | bigint;
+      minStakeAmount: number | bigint;
+      minUnstakeAmount: number | bigint;
+      issuanceProgram: PublicKey;
+      tokenManager: PublicKey;
+      pendingIssuanceProgram: PublicKey;
+      pendingTokenManager: PublicKey;
+      issuanceUpdateTimestamp: number | bigint;
+      principalAmount: number | bigint;
+      realizedYieldAmount: number | bigint;
+      totalYieldMinted: number | bigint;
+      totalBoostMinted: number | bigint;
+      minAccrualInterval: number | bigint;
+      lastAccrualTimestamp: number | bigint;
+      keeperReward: number | bigint;
+      totalKeeperRewards: number | bigint;
+      yieldMode: YieldModeArgs;
+      xSupply: number | bigint;
+      rewarder: PublicKey;
+      vestingPeriod: number | bigint;
+      vestingAmount: number | bigint;
+      vestingStartTimestamp: number | bigint;
+      totalRewardsDeposited: number | bigint;
+      performanceFeeBps: number;
+      totalPerformanceFees: number | bigint;
+      entryFeeBps: number;
+      entryFeeDestination: FeeDestinationArgs;
+      exitFeeBps: number;
+      exitFeeDestination: FeeDestinationArgs;
+      stakeActive: boolean;
+      unstakeActive: boolean;
+      xTokenProgram: PublicKey;
+      checkpointInterval: number | bigint;
+      maxStrategyAllocationBps: number;
+      strategyValue: number | bigint;
+      allowlistEnabled: boolean;
+      walletDepositCap: number | bigint;
     }>({
       discriminator: [0, array(u8(), { size: 8 })],
       bump: [8, u8()],
-      owner: [9, publicKeySerializer()],
-      pendingOwner: [41, publicKeySerializer()],
-      admin: [73, publicKeySerializer()],
-      baseMint: [105, publicKeySerializer()],
-      xMint: [137, publicKeySerializer()],
-      baseMintDecimals: [169, u8()],
-      xMintDecimals: [170, u8()],
-      intervalAprRate: [171, u64()],
-      secondsPerInterval: [179, i32()],
-      initialExchangeRate: [183, u64()],
-      lastYieldChangeExchangeRate: [191, u64()],
-      inceptionTimestamp: [199, i64()],
-      lastYieldChangeTimestamp: [207, i64()],
-      baseBalance: [215, u64()],
-      depositCap: [223, u64()],
+      poolId: [9, u64()],
+      owner: [17, publicKeySerializer()],
+      pendingOwner: [49, publicKeySerializer()],
+      admin: [81, publicKeySerializer()],
+      baseMint: [113, publicKeySerializer()],
+      xMint: [145, publicKeySerializer()],
+      baseMintDecimals: [177, u8()],
+      xMintDecimals: [178, u8()],
+      annualYieldBps: [179, u64()],
+      intervalAprRate: [187, u64()],
+      secondsPerInterval: [195, i32()],
+      initialExchangeRate: [199, u64()],
+      lastYieldChangeExchangeRate: [207, u64()],
+      inceptionTimestamp: [215, i64()],
+      lastYieldChangeTimestamp: [223, i64()],
+      baseBalance: [231, u64()],
+      depositCap: [239, u64()],
+      unstakeCooldown: [247, i64()],
+      pendingUnstakeAmount: [255, u64()],
+      treasury: [263, publicKeySerializer()],
+      instantUnstakeFeeBps: [295, u16()],
+      instantUnstakeFeeDestination: [297, getFeeDestinationSerializer()],
+      liquidityBufferTarget: [298, u64()],
+      liquidityBufferAmount: [306, u64()],
+      minStakeAmount: [314, u64()],
+      minUnstakeAmount: [322, u64()],
+      issuanceProgram: [330, publicKeySerializer()],
+      tokenManager: [362, publicKeySerializer()],
+      pendingIssuanceProgram: [394, publicKeySerializer()],
+      pendingTokenManager: [426, publicKeySerializer()],
+      issuanceUpdateTimestamp: [458, i64()],
+      principalAmount: [466, u64()],
+      realizedYieldAmount: [474, u64()],
+      totalYieldMinted: [482, u64()],
+      totalBoostMinted: [490, u64()],
+      minAccrualInterval: [498, i64()],
+      lastAccrualTimestamp: [506, i64()],
+      keeperReward: [514, u64()],
+      totalKeeperRewards: [522, u64()],
+      yieldMode: [530, getYieldModeSerializer()],
+      xSupply: [531, u64()],
+      rewarder: [539, publicKeySerializer()],
+      vestingPeriod: [571, i64()],
+      vestingAmount: [579, u64()],
+      vestingStartTimestamp: [587, i64()],
+      totalRewardsDeposited: [595, u64()],
+      performanceFeeBps: [603, u16()],
+      totalPerformanceFees: [605, u64()],
+      entryFeeBps: [613, u16()],
+      entryFeeDestination: [615, getFeeDestinationSerializer()],
+      exitFeeBps: [616, u16()],
+      exitFeeDestination: [618, getFeeDestinationSerializer()],
+      stakeActive: [619, bool()],
+      unstakeActive: [620, bool()],
+      xTokenProgram: [621, publicKeySerializer()],
+      checkpointInterval: [653, i64()],
+      maxStrategyAllocationBps: [661, u16()],
+      strategyValue: [663, u64()],
+      allowlistEnabled: [671, bool()],
+      walletDepositCap: [672, u64()],
     })
     .deserializeUsing<PoolManager>((account) => deserializePoolManager(account))
     .whereField('discriminator', [54, 241, 200, 10, 177, 151, 78, 17]);
 }
 
 export function getPoolManagerSize(): number {
-  return 231;
+  return 680;
 }
 
 export function findPoolManagerPda(
-  context: Pick<Context, 'eddsa' | 'programs'>
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the base mint */
+    baseMint: PublicKey;
+    /** The id of the pool */
+    poolId: number | bigint;
+  }
 ): Pda {
   const programId = context.programs.getPublicKey(
     'parityStaking',
@@ -227,19 +468,27 @@ export function findPoolManagerPda(
   );
   return context.eddsa.findPda(programId, [
     string({ size: 'variable' }).serialize('pool-manager'),
+    publicKeySerializer().serialize(seeds.baseMint),
+    u64().serialize(seeds.poolId),
   ]);
 }
 
 export async function fetchPoolManagerFromSeeds(
   context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findPoolManagerPda>[1],
   options?: RpcGetAccountOptions
 ): Promise<PoolManager> {
-  return fetchPoolManager(context, findPoolManagerPda(context), options);
+  return fetchPoolManager(context, findPoolManagerPda(context, seeds), options);
 }
 
 export async function safeFetchPoolManagerFromSeeds(
   context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findPoolManagerPda>[1],
   options?: RpcGetAccountOptions
 ): Promise<PoolManager | null> {
-  return safeFetchPoolManager(context, findPoolManagerPda(context), options);
+  return safeFetchPoolManager(
+    context,
+    findPoolManagerPda(context, seeds),
+    options
+  );
 }
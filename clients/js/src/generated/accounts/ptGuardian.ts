@@ -0,0 +1,173 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type PtGuardian = Account<PtGuardianAccountData>;
+
+export type PtGuardianAccountData = {
+  discriminator: Array<number>;
+  wallet: PublicKey;
+};
+
+export type PtGuardianAccountDataArgs = { wallet: PublicKey };
+
+export function getPtGuardianAccountDataSerializer(): Serializer<
+  PtGuardianAccountDataArgs,
+  PtGuardianAccountData
+> {
+  return mapSerializer<PtGuardianAccountDataArgs, any, PtGuardianAccountData>(
+    struct<PtGuardianAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['wallet', publicKeySerializer()],
+      ],
+      { description: 'PtGuardianAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [57, 234, 122, 214, 12, 246, 9, 45],
+    })
+  ) as Serializer<PtGuardianAccountDataArgs, PtGuardianAccountData>;
+}
+
+export function deserializePtGuardian(rawAccount: RpcAccount): PtGuardian {
+  return deserializeAccount(rawAccount, getPtGuardianAccountDataSerializer());
+}
+
+export async function fetchPtGuardian(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<PtGuardian> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'PtGuardian');
+  return deserializePtGuardian(maybeAccount);
+}
+
+export async function safeFetchPtGuardian(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<PtGuardian | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializePtGuardian(maybeAccount) : null;
+}
+
+export async function fetchAllPtGuardian(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<PtGuardian[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'PtGuardian');
+    return deserializePtGuardian(maybeAccount);
+  });
+}
+
+export async function safeFetchAllPtGuardian(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<PtGuardian[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializePtGuardian(maybeAccount as RpcAccount));
+}
+
+export function getPtGuardianGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'ptStaking',
+    'AdXJ8Sr46ujd9DSLP5LRyF1BrqxT9azqmQqN2oTyV8cz'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{ discriminator: Array<number>; wallet: PublicKey }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      wallet: [8, publicKeySerializer()],
+    })
+    .deserializeUsing<PtGuardian>((account) => deserializePtGuardian(account))
+    .whereField('discriminator', [57, 234, 122, 214, 12, 246, 9, 45]);
+}
+
+export function getPtGuardianSize(): number {
+  return 40;
+}
+
+export function findPtGuardianPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the guardian wallet */
+    wallet: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'ptStaking',
+    'AdXJ8Sr46ujd9DSLP5LRyF1BrqxT9azqmQqN2oTyV8cz'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('guardian'),
+    publicKeySerializer().serialize(seeds.wallet),
+  ]);
+}
+
+export async function fetchPtGuardianFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findPtGuardianPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<PtGuardian> {
+  return fetchPtGuardian(context, findPtGuardianPda(context, seeds), options);
+}
+
+export async function safeFetchPtGuardianFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findPtGuardianPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<PtGuardian | null> {
+  return safeFetchPtGuardian(
+    context,
+    findPtGuardianPda(context, seeds),
+    options
+  );
+}
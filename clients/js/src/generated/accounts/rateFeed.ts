@@ -0,0 +1,267 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u128,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  RateObservation,
+  RateObservationArgs,
+  getRateObservationSerializer,
+} from '../types';
+
+/**
+ * Exchange rate of a pool published for other programs, at
+ * `[b"rate-feed", pool_manager]`. The layout is stable: fields are only ever
+ * appended, with `version` bumped, so readers can rely on these offsets after
+ * the 8 byte discriminator:
+ *
+ * | offset | field                      | type                 |
+ * |--------|----------------------------|----------------------|
+ * | 0      | `version`                  | u8                   |
+ * | 1      | `bump`                     | u8                   |
+ * | 2      | `pool_manager`             | Pubkey               |
+ * | 34     | `x_mint`                   | Pubkey               |
+ * | 66     | `base_mint`                | Pubkey               |
+ * | 98     | `base_mint_decimals`       | u8                   |
+ * | 99     | `exchange_rate`            | u64                  |
+ * | 107    | `last_update_timestamp`    | i64                  |
+ * | 115    | `twap_exchange_rate`       | u64                  |
+ * | 123    | `realized_apy_bps`         | u64                  |
+ * | 131    | `cumulative_exchange_rate` | u128                 |
+ * | 147    | `next_observation_index`   | u32                  |
+ * | 151    | `observations`             | Vec<RateObservation> |
+ *
+ * Integers are little-endian. Rates are base per x, in base mint decimals.
+ *
+ * `twap_exchange_rate` is the average rate over the last
+ * `RATE_FEED_TWAP_WINDOW`, taken from the cumulative rate against the
+ * observations around the start of the window.
+ */
+export type RateFeed = Account<RateFeedAccountData>;
+
+export type RateFeedAccountData = {
+  discriminator: Array<number>;
+  version: number;
+  bump: number;
+  poolManager: PublicKey;
+  xMint: PublicKey;
+  baseMint: PublicKey;
+  baseMintDecimals: number;
+  exchangeRate: bigint;
+  lastUpdateTimestamp: bigint;
+  twapExchangeRate: bigint;
+  realizedApyBps: bigint;
+  cumulativeExchangeRate: bigint;
+  nextObservationIndex: number;
+  observations: Array<RateObservation>;
+};
+
+export type RateFeedAccountDataArgs = {
+  version: number;
+  bump: number;
+  poolManager: PublicKey;
+  xMint: PublicKey;
+  baseMint: PublicKey;
+  baseMintDecimals: number;
+  exchangeRate: number | bigint;
+  lastUpdateTimestamp: number | bigint;
+  twapExchangeRate: number | bigint;
+  realizedApyBps: number | bigint;
+  cumulativeExchangeRate: number | bigint;
+  nextObservationIndex: number;
+  observations: Array<RateObservationArgs>;
+};
+
+export function getRateFeedAccountDataSerializer(): Serializer<
+  RateFeedAccountDataArgs,
+  RateFeedAccountData
+> {
+  return mapSerializer<RateFeedAccountDataArgs, any, RateFeedAccountData>(
+    struct<RateFeedAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['version', u8()],
+        ['bump', u8()],
+        ['poolManager', publicKeySerializer()],
+        ['xMint', publicKeySerializer()],
+        ['baseMint', publicKeySerializer()],
+        ['baseMintDecimals', u8()],
+        ['exchangeRate', u64()],
+        ['lastUpdateTimestamp', i64()],
+        ['twapExchangeRate', u64()],
+        ['realizedApyBps', u64()],
+        ['cumulativeExchangeRate', u128()],
+        ['nextObservationIndex', u32()],
+        ['observations', array(getRateObservationSerializer())],
+      ],
+      { description: 'RateFeedAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [192, 77, 209, 138, 50, 103, 37, 189],
+    })
+  ) as Serializer<RateFeedAccountDataArgs, RateFeedAccountData>;
+}
+
+export function deserializeRateFeed(rawAccount: RpcAccount): RateFeed {
+  return deserializeAccount(rawAccount, getRateFeedAccountDataSerializer());
+}
+
+export async function fetchRateFeed(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RateFeed> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'RateFeed');
+  return deserializeRateFeed(maybeAccount);
+}
+
+export async function safeFetchRateFeed(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RateFeed | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeRateFeed(maybeAccount) : null;
+}
+
+export async function fetchAllRateFeed(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RateFeed[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'RateFeed');
+    return deserializeRateFeed(maybeAccount);
+  });
+}
+
+export async function safeFetchAllRateFeed(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RateFeed[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeRateFeed(maybeAccount as RpcAccount));
+}
+
+export function getRateFeedGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      version: number;
+      bump: number;
+      poolManager: PublicKey;
+      xMint: PublicKey;
+      baseMint: PublicKey;
+      baseMintDecimals: number;
+      exchangeRate: number | bigint;
+      lastUpdateTimestamp: number | bigint;
+      twapExchangeRate: number | bigint;
+      realizedApyBps: number | bigint;
+      cumulativeExchangeRate: number | bigint;
+      nextObservationIndex: number;
+      observations: Array<RateObservationArgs>;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      version: [8, u8()],
+      bump: [9, u8()],
+      poolManager: [10, publicKeySerializer()],
+      xMint: [42, publicKeySerializer()],
+      baseMint: [74, publicKeySerializer()],
+      baseMintDecimals: [106, u8()],
+      exchangeRate: [107, u64()],
+      lastUpdateTimestamp: [115, i64()],
+      twapExchangeRate: [123, u64()],
+      realizedApyBps: [131, u64()],
+      cumulativeExchangeRate: [139, u128()],
+      nextObservationIndex: [155, u32()],
+      observations: [159, array(getRateObservationSerializer())],
+    })
+    .deserializeUsing<RateFeed>((account) => deserializeRateFeed(account))
+    .whereField('discriminator', [192, 77, 209, 138, 50, 103, 37, 189]);
+}
+
+export function findRateFeedPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the pool manager */
+    poolManager: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('rate-feed'),
+    publicKeySerializer().serialize(seeds.poolManager),
+  ]);
+}
+
+export async function fetchRateFeedFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findRateFeedPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<RateFeed> {
+  return fetchRateFeed(context, findRateFeedPda(context, seeds), options);
+}
+
+export async function safeFetchRateFeedFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findRateFeedPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<RateFeed | null> {
+  return safeFetchRateFeed(context, findRateFeedPda(context, seeds), options);
+}
@@ -0,0 +1,196 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  RateCheckpoint,
+  RateCheckpointArgs,
+  getRateCheckpointSerializer,
+} from '../types';
+
+/** Ring buffer of exchange rate checkpoints, at `[b"rate-history", pool_manager]`. */
+export type RateHistory = Account<RateHistoryAccountData>;
+
+export type RateHistoryAccountData = {
+  discriminator: Array<number>;
+  bump: number;
+  poolManager: PublicKey;
+  nextIndex: number;
+  checkpoints: Array<RateCheckpoint>;
+};
+
+export type RateHistoryAccountDataArgs = {
+  bump: number;
+  poolManager: PublicKey;
+  nextIndex: number;
+  checkpoints: Array<RateCheckpointArgs>;
+};
+
+export function getRateHistoryAccountDataSerializer(): Serializer<
+  RateHistoryAccountDataArgs,
+  RateHistoryAccountData
+> {
+  return mapSerializer<RateHistoryAccountDataArgs, any, RateHistoryAccountData>(
+    struct<RateHistoryAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['bump', u8()],
+        ['poolManager', publicKeySerializer()],
+        ['nextIndex', u32()],
+        ['checkpoints', array(getRateCheckpointSerializer())],
+      ],
+      { description: 'RateHistoryAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [33, 237, 238, 92, 237, 89, 98, 122],
+    })
+  ) as Serializer<RateHistoryAccountDataArgs, RateHistoryAccountData>;
+}
+
+export function deserializeRateHistory(rawAccount: RpcAccount): RateHistory {
+  return deserializeAccount(rawAccount, getRateHistoryAccountDataSerializer());
+}
+
+export async function fetchRateHistory(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RateHistory> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'RateHistory');
+  return deserializeRateHistory(maybeAccount);
+}
+
+export async function safeFetchRateHistory(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RateHistory | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeRateHistory(maybeAccount) : null;
+}
+
+export async function fetchAllRateHistory(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RateHistory[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'RateHistory');
+    return deserializeRateHistory(maybeAccount);
+  });
+}
+
+export async function safeFetchAllRateHistory(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RateHistory[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeRateHistory(maybeAccount as RpcAccount));
+}
+
+export function getRateHistoryGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      bump: number;
+      poolManager: PublicKey;
+      nextIndex: number;
+      checkpoints: Array<RateCheckpointArgs>;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      bump: [8, u8()],
+      poolManager: [9, publicKeySerializer()],
+      nextIndex: [41, u32()],
+      checkpoints: [45, array(getRateCheckpointSerializer())],
+    })
+    .deserializeUsing<RateHistory>((account) => deserializeRateHistory(account))
+    .whereField('discriminator', [33, 237, 238, 92, 237, 89, 98, 122]);
+}
+
+export function findRateHistoryPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the pool manager */
+    poolManager: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('rate-history'),
+    publicKeySerializer().serialize(seeds.poolManager),
+  ]);
+}
+
+export async function fetchRateHistoryFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findRateHistoryPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<RateHistory> {
+  return fetchRateHistory(context, findRateHistoryPda(context, seeds), options);
+}
+
+export async function safeFetchRateHistoryFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findRateHistoryPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<RateHistory | null> {
+  return safeFetchRateHistory(
+    context,
+    findRateHistoryPda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,209 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Staking allowance of a wallet, at `[b"staker-entry", pool_manager, wallet]`. */
+export type StakerEntry = Account<StakerEntryAccountData>;
+
+export type StakerEntryAccountData = {
+  discriminator: Array<number>;
+  bump: number;
+  poolManager: PublicKey;
+  wallet: PublicKey;
+  allowed: boolean;
+  depositCap: bigint;
+  stakedAmount: bigint;
+};
+
+export type StakerEntryAccountDataArgs = {
+  bump: number;
+  poolManager: PublicKey;
+  wallet: PublicKey;
+  allowed: boolean;
+  depositCap: number | bigint;
+  stakedAmount: number | bigint;
+};
+
+export function getStakerEntryAccountDataSerializer(): Serializer<
+  StakerEntryAccountDataArgs,
+  StakerEntryAccountData
+> {
+  return mapSerializer<StakerEntryAccountDataArgs, any, StakerEntryAccountData>(
+    struct<StakerEntryAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['bump', u8()],
+        ['poolManager', publicKeySerializer()],
+        ['wallet', publicKeySerializer()],
+        ['allowed', bool()],
+        ['depositCap', u64()],
+        ['stakedAmount', u64()],
+      ],
+      { description: 'StakerEntryAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [202, 59, 182, 197, 141, 42, 37, 148],
+    })
+  ) as Serializer<StakerEntryAccountDataArgs, StakerEntryAccountData>;
+}
+
+export function deserializeStakerEntry(rawAccount: RpcAccount): StakerEntry {
+  return deserializeAccount(rawAccount, getStakerEntryAccountDataSerializer());
+}
+
+export async function fetchStakerEntry(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<StakerEntry> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'StakerEntry');
+  return deserializeStakerEntry(maybeAccount);
+}
+
+export async function safeFetchStakerEntry(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<StakerEntry | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeStakerEntry(maybeAccount) : null;
+}
+
+export async function fetchAllStakerEntry(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<StakerEntry[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'StakerEntry');
+    return deserializeStakerEntry(maybeAccount);
+  });
+}
+
+export async function safeFetchAllStakerEntry(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<StakerEntry[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeStakerEntry(maybeAccount as RpcAccount));
+}
+
+export function getStakerEntryGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      bump: number;
+      poolManager: PublicKey;
+      wallet: PublicKey;
+      allowed: boolean;
+      depositCap: number | bigint;
+      stakedAmount: number | bigint;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      bump: [8, u8()],
+      poolManager: [9, publicKeySerializer()],
+      wallet: [41, publicKeySerializer()],
+      allowed: [73, bool()],
+      depositCap: [74, u64()],
+      stakedAmount: [82, u64()],
+    })
+    .deserializeUsing<StakerEntry>((account) => deserializeStakerEntry(account))
+    .whereField('discriminator', [202, 59, 182, 197, 141, 42, 37, 148]);
+}
+
+export function getStakerEntrySize(): number {
+  return 90;
+}
+
+export function findStakerEntryPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the pool manager */
+    poolManager: PublicKey;
+    /** The address of the staker wallet */
+    wallet: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('staker-entry'),
+    publicKeySerializer().serialize(seeds.poolManager),
+    publicKeySerializer().serialize(seeds.wallet),
+  ]);
+}
+
+export async function fetchStakerEntryFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findStakerEntryPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<StakerEntry> {
+  return fetchStakerEntry(context, findStakerEntryPda(context, seeds), options);
+}
+
+export async function safeFetchStakerEntryFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findStakerEntryPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<StakerEntry | null> {
+  return safeFetchStakerEntry(
+    context,
+    findStakerEntryPda(context, seeds),
+    options
+  );
+}
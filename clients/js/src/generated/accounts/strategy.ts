@@ -0,0 +1,219 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  i64,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Strategy program whitelisted to hold part of the vault, at
+ * `[b"strategy", pool_manager, strategy_program]`.
+ */
+export type Strategy = Account<StrategyAccountData>;
+
+export type StrategyAccountData = {
+  discriminator: Array<number>;
+  bump: number;
+  poolManager: PublicKey;
+  strategyProgram: PublicKey;
+  active: boolean;
+  cap: bigint;
+  allocatedAmount: bigint;
+  reportedValue: bigint;
+  lastReportTimestamp: bigint;
+};
+
+export type StrategyAccountDataArgs = {
+  bump: number;
+  poolManager: PublicKey;
+  strategyProgram: PublicKey;
+  active: boolean;
+  cap: number | bigint;
+  allocatedAmount: number | bigint;
+  reportedValue: number | bigint;
+  lastReportTimestamp: number | bigint;
+};
+
+export function getStrategyAccountDataSerializer(): Serializer<
+  StrategyAccountDataArgs,
+  StrategyAccountData
+> {
+  return mapSerializer<StrategyAccountDataArgs, any, StrategyAccountData>(
+    struct<StrategyAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['bump', u8()],
+        ['poolManager', publicKeySerializer()],
+        ['strategyProgram', publicKeySerializer()],
+        ['active', bool()],
+        ['cap', u64()],
+        ['allocatedAmount', u64()],
+        ['reportedValue', u64()],
+        ['lastReportTimestamp', i64()],
+      ],
+      { description: 'StrategyAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [174, 110, 39, 119, 82, 106, 169, 102],
+    })
+  ) as Serializer<StrategyAccountDataArgs, StrategyAccountData>;
+}
+
+export function deserializeStrategy(rawAccount: RpcAccount): Strategy {
+  return deserializeAccount(rawAccount, getStrategyAccountDataSerializer());
+}
+
+export async function fetchStrategy(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Strategy> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'Strategy');
+  return deserializeStrategy(maybeAccount);
+}
+
+export async function safeFetchStrategy(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Strategy | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeStrategy(maybeAccount) : null;
+}
+
+export async function fetchAllStrategy(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Strategy[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'Strategy');
+    return deserializeStrategy(maybeAccount);
+  });
+}
+
+export async function safeFetchAllStrategy(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Strategy[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeStrategy(maybeAccount as RpcAccount));
+}
+
+export function getStrategyGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      bump: number;
+      poolManager: PublicKey;
+      strategyProgram: PublicKey;
+      active: boolean;
+      cap: number | bigint;
+      allocatedAmount: number | bigint;
+      reportedValue: number | bigint;
+      lastReportTimestamp: number | bigint;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      bump: [8, u8()],
+      poolManager: [9, publicKeySerializer()],
+      strategyProgram: [41, publicKeySerializer()],
+      active: [73, bool()],
+      cap: [74, u64()],
+      allocatedAmount: [82, u64()],
+      reportedValue: [90, u64()],
+      lastReportTimestamp: [98, i64()],
+    })
+    .deserializeUsing<Strategy>((account) => deserializeStrategy(account))
+    .whereField('discriminator', [174, 110, 39, 119, 82, 106, 169, 102]);
+}
+
+export function getStrategySize(): number {
+  return 106;
+}
+
+export function findStrategyPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the pool manager */
+    poolManager: PublicKey;
+    /** The address of the strategy program */
+    strategyProgram: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('strategy'),
+    publicKeySerializer().serialize(seeds.poolManager),
+    publicKeySerializer().serialize(seeds.strategyProgram),
+  ]);
+}
+
+export async function fetchStrategyFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findStrategyPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<Strategy> {
+  return fetchStrategy(context, findStrategyPda(context, seeds), options);
+}
+
+export async function safeFetchStrategyFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findStrategyPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<Strategy | null> {
+  return safeFetchStrategy(context, findStrategyPda(context, seeds), options);
+}
@@ -0,0 +1,241 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TermPosition = Account<TermPositionAccountData>;
+
+export type TermPositionAccountData = {
+  discriminator: Array<number>;
+  bump: number;
+  owner: PublicKey;
+  poolManager: PublicKey;
+  tranche: PublicKey;
+  positionId: bigint;
+  principalAmount: bigint;
+  xAmount: bigint;
+  intervalAprRate: bigint;
+  startTimestamp: bigint;
+  maturityTimestamp: bigint;
+};
+
+export type TermPositionAccountDataArgs = {
+  bump: number;
+  owner: PublicKey;
+  poolManager: PublicKey;
+  tranche: PublicKey;
+  positionId: number | bigint;
+  principalAmount: number | bigint;
+  xAmount: number | bigint;
+  intervalAprRate: number | bigint;
+  startTimestamp: number | bigint;
+  maturityTimestamp: number | bigint;
+};
+
+export function getTermPositionAccountDataSerializer(): Serializer<
+  TermPositionAccountDataArgs,
+  TermPositionAccountData
+> {
+  return mapSerializer<
+    TermPositionAccountDataArgs,
+    any,
+    TermPositionAccountData
+  >(
+    struct<TermPositionAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['bump', u8()],
+        ['owner', publicKeySerializer()],
+        ['poolManager', publicKeySerializer()],
+        ['tranche', publicKeySerializer()],
+        ['positionId', u64()],
+        ['principalAmount', u64()],
+        ['xAmount', u64()],
+        ['intervalAprRate', u64()],
+        ['startTimestamp', i64()],
+        ['maturityTimestamp', i64()],
+      ],
+      { description: 'TermPositionAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [27, 82, 100, 162, 9, 135, 221, 90],
+    })
+  ) as Serializer<TermPositionAccountDataArgs, TermPositionAccountData>;
+}
+
+export function deserializeTermPosition(rawAccount: RpcAccount): TermPosition {
+  return deserializeAccount(rawAccount, getTermPositionAccountDataSerializer());
+}
+
+export async function fetchTermPosition(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TermPosition> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'TermPosition');
+  return deserializeTermPosition(maybeAccount);
+}
+
+export async function safeFetchTermPosition(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TermPosition | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeTermPosition(maybeAccount) : null;
+}
+
+export async function fetchAllTermPosition(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TermPosition[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'TermPosition');
+    return deserializeTermPosition(maybeAccount);
+  });
+}
+
+export async function safeFetchAllTermPosition(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TermPosition[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeTermPosition(maybeAccount as RpcAccount));
+}
+
+export function getTermPositionGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      bump: number;
+      owner: PublicKey;
+      poolManager: PublicKey;
+      tranche: PublicKey;
+      positionId: number | bigint;
+      principalAmount: number | bigint;
+      xAmount: number | bigint;
+      intervalAprRate: number | bigint;
+      startTimestamp: number | bigint;
+      maturityTimestamp: number | bigint;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      bump: [8, u8()],
+      owner: [9, publicKeySerializer()],
+      poolManager: [41, publicKeySerializer()],
+      tranche: [73, publicKeySerializer()],
+      positionId: [105, u64()],
+      principalAmount: [113, u64()],
+      xAmount: [121, u64()],
+      intervalAprRate: [129, u64()],
+      startTimestamp: [137, i64()],
+      maturityTimestamp: [145, i64()],
+    })
+    .deserializeUsing<TermPosition>((account) =>
+      deserializeTermPosition(account)
+    )
+    .whereField('discriminator', [27, 82, 100, 162, 9, 135, 221, 90]);
+}
+
+export function getTermPositionSize(): number {
+  return 153;
+}
+
+export function findTermPositionPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the pool manager */
+    poolManager: PublicKey;
+    /** The address of the position owner */
+    owner: PublicKey;
+    /** The id of the term position */
+    positionId: number | bigint;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('term-position'),
+    publicKeySerializer().serialize(seeds.poolManager),
+    publicKeySerializer().serialize(seeds.owner),
+    u64().serialize(seeds.positionId),
+  ]);
+}
+
+export async function fetchTermPositionFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTermPositionPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TermPosition> {
+  return fetchTermPosition(
+    context,
+    findTermPositionPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchTermPositionFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTermPositionPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TermPosition | null> {
+  return safeFetchTermPosition(
+    context,
+    findTermPositionPda(context, seeds),
+    options
+  );
+}
@@ -62,6 +62,12 @@ export type TokenManagerAccountData = {
   totalCollateral: bigint;
   mintFeeBps: number;
   redeemFeeBps: number;
+  maxExchangeRateChangeBps: number;
+  exchangeRateUpdateInterval: bigint;
+  exchangeRateRampDuration: bigint;
+  previousExchangeRate: bigint;
+  lastExchangeRateUpdate: bigint;
+  exchangeRateRampEnd: bigint;
 };
 
 export type TokenManagerAccountDataArgs = {
@@ -89,6 +95,12 @@ export type TokenManagerAccountDataArgs = {
   totalCollateral: number | bigint;
   mintFeeBps: number;
   redeemFeeBps: number;
+  maxExchangeRateChangeBps: number;
+  exchangeRateUpdateInterval: number | bigint;
+  exchangeRateRampDuration: number | bigint;
+  previousExchangeRate: number | bigint;
+  lastExchangeRateUpdate: number | bigint;
+  exchangeRateRampEnd: number | bigint;
 };
 
 export function getTokenManagerAccountDataSerializer(): Serializer<
@@ -127,6 +139,12 @@ export function getTokenManagerAccountDataSerializer(): Serializer<
         ['totalCollateral', u64()],
         ['mintFeeBps', u16()],
         ['redeemFeeBps', u16()],
+        ['maxExchangeRateChangeBps', u16()],
+        ['exchangeRateUpdateInterval', i64()],
+        ['exchangeRateRampDuration', i64()],
+        ['previousExchangeRate', u64()],
+        ['lastExchangeRateUpdate', i64()],
+        ['exchangeRateRampEnd', i64()],
       ],
       { description: 'TokenManagerAccountData' }
     ),
@@ -229,6 +247,12 @@ export function getTokenManagerGpaBuilder(
       totalCollateral: number | bigint;
       mintFeeBps: number;
       redeemFeeBps: number;
+      maxExchangeRateChangeBps: number;
+      exchangeRateUpdateInterval: number | bigint;
+      exchangeRateRampDuration: number | bigint;
+      previousExchangeRate: number | bigint;
+      lastExchangeRateUpdate: number | bigint;
+      exchangeRateRampEnd: number | bigint;
     }>({
       discriminator: [0, array(u8(), { size: 8 })],
       bump: [8, u8()],
@@ -255,6 +279,12 @@ export function getTokenManagerGpaBuilder(
       totalCollateral: [303, u64()],
       mintFeeBps: [311, u16()],
       redeemFeeBps: [313, u16()],
+      maxExchangeRateChangeBps: [315, u16()],
+      exchangeRateUpdateInterval: [317, i64()],
+      exchangeRateRampDuration: [325, i64()],
+      previousExchangeRate: [333, u64()],
+      lastExchangeRateUpdate: [341, i64()],
+      exchangeRateRampEnd: [349, i64()],
     })
     .deserializeUsing<TokenManager>((account) =>
       deserializeTokenManager(account)
@@ -263,7 +293,7 @@ export function getTokenManagerGpaBuilder(
 }
 
 export function getTokenManagerSize(): number {
-  return 315;
+  return 357;
 }
 
 export function findTokenManagerPda(
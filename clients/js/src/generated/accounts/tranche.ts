@@ -0,0 +1,220 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  i64,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Tranche = Account<TrancheAccountData>;
+
+export type TrancheAccountData = {
+  discriminator: Array<number>;
+  bump: number;
+  poolManager: PublicKey;
+  trancheId: bigint;
+  termDuration: bigint;
+  annualYieldBps: bigint;
+  intervalAprRate: bigint;
+  active: boolean;
+  totalPrincipalAmount: bigint;
+  totalBoostPaid: bigint;
+};
+
+export type TrancheAccountDataArgs = {
+  bump: number;
+  poolManager: PublicKey;
+  trancheId: number | bigint;
+  termDuration: number | bigint;
+  annualYieldBps: number | bigint;
+  intervalAprRate: number | bigint;
+  active: boolean;
+  totalPrincipalAmount: number | bigint;
+  totalBoostPaid: number | bigint;
+};
+
+export function getTrancheAccountDataSerializer(): Serializer<
+  TrancheAccountDataArgs,
+  TrancheAccountData
+> {
+  return mapSerializer<TrancheAccountDataArgs, any, TrancheAccountData>(
+    struct<TrancheAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['bump', u8()],
+        ['poolManager', publicKeySerializer()],
+        ['trancheId', u64()],
+        ['termDuration', i64()],
+        ['annualYieldBps', u64()],
+        ['intervalAprRate', u64()],
+        ['active', bool()],
+        ['totalPrincipalAmount', u64()],
+        ['totalBoostPaid', u64()],
+      ],
+      { description: 'TrancheAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [58, 171, 37, 93, 206, 204, 41, 22],
+    })
+  ) as Serializer<TrancheAccountDataArgs, TrancheAccountData>;
+}
+
+export function deserializeTranche(rawAccount: RpcAccount): Tranche {
+  return deserializeAccount(rawAccount, getTrancheAccountDataSerializer());
+}
+
+export async function fetchTranche(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Tranche> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'Tranche');
+  return deserializeTranche(maybeAccount);
+}
+
+export async function safeFetchTranche(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Tranche | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeTranche(maybeAccount) : null;
+}
+
+export async function fetchAllTranche(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Tranche[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'Tranche');
+    return deserializeTranche(maybeAccount);
+  });
+}
+
+export async function safeFetchAllTranche(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Tranche[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeTranche(maybeAccount as RpcAccount));
+}
+
+export function getTrancheGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      bump: number;
+      poolManager: PublicKey;
+      trancheId: number | bigint;
+      termDuration: number | bigint;
+      annualYieldBps: number | bigint;
+      intervalAprRate: number | bigint;
+      active: boolean;
+      totalPrincipalAmount: number | bigint;
+      totalBoostPaid: number | bigint;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      bump: [8, u8()],
+      poolManager: [9, publicKeySerializer()],
+      trancheId: [41, u64()],
+      termDuration: [49, i64()],
+      annualYieldBps: [57, u64()],
+      intervalAprRate: [65, u64()],
+      active: [73, bool()],
+      totalPrincipalAmount: [74, u64()],
+      totalBoostPaid: [82, u64()],
+    })
+    .deserializeUsing<Tranche>((account) => deserializeTranche(account))
+    .whereField('discriminator', [58, 171, 37, 93, 206, 204, 41, 22]);
+}
+
+export function getTrancheSize(): number {
+  return 90;
+}
+
+export function findTranchePda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the pool manager */
+    poolManager: PublicKey;
+    /** The id of the tranche */
+    trancheId: number | bigint;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('tranche'),
+    publicKeySerializer().serialize(seeds.poolManager),
+    u64().serialize(seeds.trancheId),
+  ]);
+}
+
+export async function fetchTrancheFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTranchePda>[1],
+  options?: RpcGetAccountOptions
+): Promise<Tranche> {
+  return fetchTranche(context, findTranchePda(context, seeds), options);
+}
+
+export async function safeFetchTrancheFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTranchePda>[1],
+  options?: RpcGetAccountOptions
+): Promise<Tranche | null> {
+  return safeFetchTranche(context, findTranchePda(context, seeds), options);
+}
@@ -0,0 +1,238 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type UnstakeTicket = Account<UnstakeTicketAccountData>;
+
+export type UnstakeTicketAccountData = {
+  discriminator: Array<number>;
+  bump: number;
+  owner: PublicKey;
+  poolManager: PublicKey;
+  ticketId: bigint;
+  xAmount: bigint;
+  baseAmount: bigint;
+  requestTimestamp: bigint;
+  claimableTimestamp: bigint;
+};
+
+export type UnstakeTicketAccountDataArgs = {
+  bump: number;
+  owner: PublicKey;
+  poolManager: PublicKey;
+  ticketId: number | bigint;
+  xAmount: number | bigint;
+  baseAmount: number | bigint;
+  requestTimestamp: number | bigint;
+  claimableTimestamp: number | bigint;
+};
+
+export function getUnstakeTicketAccountDataSerializer(): Serializer<
+  UnstakeTicketAccountDataArgs,
+  UnstakeTicketAccountData
+> {
+  return mapSerializer<
+    UnstakeTicketAccountDataArgs,
+    any,
+    UnstakeTicketAccountData
+  >(
+    struct<UnstakeTicketAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['bump', u8()],
+        ['owner', publicKeySerializer()],
+        ['poolManager', publicKeySerializer()],
+        ['ticketId', u64()],
+        ['xAmount', u64()],
+        ['baseAmount', u64()],
+        ['requestTimestamp', i64()],
+        ['claimableTimestamp', i64()],
+      ],
+      { description: 'UnstakeTicketAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [131, 84, 209, 38, 145, 157, 181, 127],
+    })
+  ) as Serializer<UnstakeTicketAccountDataArgs, UnstakeTicketAccountData>;
+}
+
+export function deserializeUnstakeTicket(
+  rawAccount: RpcAccount
+): UnstakeTicket {
+  return deserializeAccount(
+    rawAccount,
+    getUnstakeTicketAccountDataSerializer()
+  );
+}
+
+export async function fetchUnstakeTicket(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<UnstakeTicket> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'UnstakeTicket');
+  return deserializeUnstakeTicket(maybeAccount);
+}
+
+export async function safeFetchUnstakeTicket(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<UnstakeTicket | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeUnstakeTicket(maybeAccount) : null;
+}
+
+export async function fetchAllUnstakeTicket(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<UnstakeTicket[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'UnstakeTicket');
+    return deserializeUnstakeTicket(maybeAccount);
+  });
+}
+
+export async function safeFetchAllUnstakeTicket(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<UnstakeTicket[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeUnstakeTicket(maybeAccount as RpcAccount)
+    );
+}
+
+export function getUnstakeTicketGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      bump: number;
+      owner: PublicKey;
+      poolManager: PublicKey;
+      ticketId: number | bigint;
+      xAmount: number | bigint;
+      baseAmount: number | bigint;
+      requestTimestamp: number | bigint;
+      claimableTimestamp: number | bigint;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      bump: [8, u8()],
+      owner: [9, publicKeySerializer()],
+      poolManager: [41, publicKeySerializer()],
+      ticketId: [73, u64()],
+      xAmount: [81, u64()],
+      baseAmount: [89, u64()],
+      requestTimestamp: [97, i64()],
+      claimableTimestamp: [105, i64()],
+    })
+    .deserializeUsing<UnstakeTicket>((account) =>
+      deserializeUnstakeTicket(account)
+    )
+    .whereField('discriminator', [131, 84, 209, 38, 145, 157, 181, 127]);
+}
+
+export function getUnstakeTicketSize(): number {
+  return 113;
+}
+
+export function findUnstakeTicketPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the pool manager */
+    poolManager: PublicKey;
+    /** The address of the ticket owner */
+    owner: PublicKey;
+    /** The id of the unstake ticket */
+    ticketId: number | bigint;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('unstake-ticket'),
+    publicKeySerializer().serialize(seeds.poolManager),
+    publicKeySerializer().serialize(seeds.owner),
+    u64().serialize(seeds.ticketId),
+  ]);
+}
+
+export async function fetchUnstakeTicketFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findUnstakeTicketPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<UnstakeTicket> {
+  return fetchUnstakeTicket(
+    context,
+    findUnstakeTicketPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchUnstakeTicketFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findUnstakeTicketPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<UnstakeTicket | null> {
+  return safeFetchUnstakeTicket(
+    context,
+    findUnstakeTicketPda(context, seeds),
+    options
+  );
+}
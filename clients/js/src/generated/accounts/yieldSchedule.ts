@@ -0,0 +1,206 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ScheduledRate,
+  ScheduledRateArgs,
+  getScheduledRateSerializer,
+} from '../types';
+
+export type YieldSchedule = Account<YieldScheduleAccountData>;
+
+export type YieldScheduleAccountData = {
+  discriminator: Array<number>;
+  bump: number;
+  poolManager: PublicKey;
+  scheduledRates: Array<ScheduledRate>;
+};
+
+export type YieldScheduleAccountDataArgs = {
+  bump: number;
+  poolManager: PublicKey;
+  scheduledRates: Array<ScheduledRateArgs>;
+};
+
+export function getYieldScheduleAccountDataSerializer(): Serializer<
+  YieldScheduleAccountDataArgs,
+  YieldScheduleAccountData
+> {
+  return mapSerializer<
+    YieldScheduleAccountDataArgs,
+    any,
+    YieldScheduleAccountData
+  >(
+    struct<YieldScheduleAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['bump', u8()],
+        ['poolManager', publicKeySerializer()],
+        ['scheduledRates', array(getScheduledRateSerializer())],
+      ],
+      { description: 'YieldScheduleAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [177, 13, 46, 200, 62, 166, 105, 94],
+    })
+  ) as Serializer<YieldScheduleAccountDataArgs, YieldScheduleAccountData>;
+}
+
+export function deserializeYieldSchedule(
+  rawAccount: RpcAccount
+): YieldSchedule {
+  return deserializeAccount(
+    rawAccount,
+    getYieldScheduleAccountDataSerializer()
+  );
+}
+
+export async function fetchYieldSchedule(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<YieldSchedule> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'YieldSchedule');
+  return deserializeYieldSchedule(maybeAccount);
+}
+
+export async function safeFetchYieldSchedule(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<YieldSchedule | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeYieldSchedule(maybeAccount) : null;
+}
+
+export async function fetchAllYieldSchedule(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<YieldSchedule[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'YieldSchedule');
+    return deserializeYieldSchedule(maybeAccount);
+  });
+}
+
+export async function safeFetchAllYieldSchedule(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<YieldSchedule[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeYieldSchedule(maybeAccount as RpcAccount)
+    );
+}
+
+export function getYieldScheduleGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      bump: number;
+      poolManager: PublicKey;
+      scheduledRates: Array<ScheduledRateArgs>;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      bump: [8, u8()],
+      poolManager: [9, publicKeySerializer()],
+      scheduledRates: [41, array(getScheduledRateSerializer())],
+    })
+    .deserializeUsing<YieldSchedule>((account) =>
+      deserializeYieldSchedule(account)
+    )
+    .whereField('discriminator', [177, 13, 46, 200, 62, 166, 105, 94]);
+}
+
+export function findYieldSchedulePda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the pool manager */
+    poolManager: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('yield-schedule'),
+    publicKeySerializer().serialize(seeds.poolManager),
+  ]);
+}
+
+export async function fetchYieldScheduleFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findYieldSchedulePda>[1],
+  options?: RpcGetAccountOptions
+): Promise<YieldSchedule> {
+  return fetchYieldSchedule(
+    context,
+    findYieldSchedulePda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchYieldScheduleFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findYieldSchedulePda>[1],
+  options?: RpcGetAccountOptions
+): Promise<YieldSchedule | null> {
+  return safeFetchYieldSchedule(
+    context,
+    findYieldSchedulePda(context, seeds),
+    options
+  );
+}
@@ -292,6 +292,58 @@ export class SiInvalidParamError extends ProgramError {
 codeToErrorMap.set(0x1783, SiInvalidParamError);
 nameToErrorMap.set('InvalidParam', SiInvalidParamError);
 
+/** ExchangeRateChangeTooLarge: Exchange rate change exceeds the maximum per update */
+export class SiExchangeRateChangeTooLargeError extends ProgramError {
+  override readonly name: string = 'ExchangeRateChangeTooLarge';
+
+  readonly code: number = 0x1784; // 6020
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Exchange rate change exceeds the maximum per update',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1784, SiExchangeRateChangeTooLargeError);
+nameToErrorMap.set(
+  'ExchangeRateChangeTooLarge',
+  SiExchangeRateChangeTooLargeError
+);
+
+/** ExchangeRateUpdateTooSoon: Exchange rate updated too soon */
+export class SiExchangeRateUpdateTooSoonError extends ProgramError {
+  override readonly name: string = 'ExchangeRateUpdateTooSoon';
+
+  readonly code: number = 0x1785; // 6021
+
+  constructor(program: Program, cause?: Error) {
+    super('Exchange rate updated too soon', program, cause);
+  }
+}
+codeToErrorMap.set(0x1785, SiExchangeRateUpdateTooSoonError);
+nameToErrorMap.set(
+  'ExchangeRateUpdateTooSoon',
+  SiExchangeRateUpdateTooSoonError
+);
+
+/** TokenManagerAlreadyMigrated: Token manager already migrated */
+export class SiTokenManagerAlreadyMigratedError extends ProgramError {
+  override readonly name: string = 'TokenManagerAlreadyMigrated';
+
+  readonly code: number = 0x1786; // 6022
+
+  constructor(program: Program, cause?: Error) {
+    super('Token manager already migrated', program, cause);
+  }
+}
+codeToErrorMap.set(0x1786, SiTokenManagerAlreadyMigratedError);
+nameToErrorMap.set(
+  'TokenManagerAlreadyMigrated',
+  SiTokenManagerAlreadyMigratedError
+);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -161,6 +161,474 @@ export class SsInvalidParamError extends ProgramError {
 codeToErrorMap.set(0x177a, SsInvalidParamError);
 nameToErrorMap.set('InvalidParam', SsInvalidParamError);
 
+/** SlippageExceeded: Slippage exceeded */
+export class SsSlippageExceededError extends ProgramError {
+  override readonly name: string = 'SlippageExceeded';
+
+  readonly code: number = 0x177b; // 6011
+
+  constructor(program: Program, cause?: Error) {
+    super('Slippage exceeded', program, cause);
+  }
+}
+codeToErrorMap.set(0x177b, SsSlippageExceededError);
+nameToErrorMap.set('SlippageExceeded', SsSlippageExceededError);
+
+/** UnstakeCooldownNotElapsed: Unstake cooldown not elapsed */
+export class SsUnstakeCooldownNotElapsedError extends ProgramError {
+  override readonly name: string = 'UnstakeCooldownNotElapsed';
+
+  readonly code: number = 0x177c; // 6012
+
+  constructor(program: Program, cause?: Error) {
+    super('Unstake cooldown not elapsed', program, cause);
+  }
+}
+codeToErrorMap.set(0x177c, SsUnstakeCooldownNotElapsedError);
+nameToErrorMap.set(
+  'UnstakeCooldownNotElapsed',
+  SsUnstakeCooldownNotElapsedError
+);
+
+/** InsufficientLiquidityBuffer: Insufficient liquidity buffer, request an unstake ticket instead */
+export class SsInsufficientLiquidityBufferError extends ProgramError {
+  override readonly name: string = 'InsufficientLiquidityBuffer';
+
+  readonly code: number = 0x177d; // 6013
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Insufficient liquidity buffer, request an unstake ticket instead',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x177d, SsInsufficientLiquidityBufferError);
+nameToErrorMap.set(
+  'InsufficientLiquidityBuffer',
+  SsInsufficientLiquidityBufferError
+);
+
+/** InvalidTreasury: Invalid treasury */
+export class SsInvalidTreasuryError extends ProgramError {
+  override readonly name: string = 'InvalidTreasury';
+
+  readonly code: number = 0x177e; // 6014
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid treasury', program, cause);
+  }
+}
+codeToErrorMap.set(0x177e, SsInvalidTreasuryError);
+nameToErrorMap.set('InvalidTreasury', SsInvalidTreasuryError);
+
+/** InvalidYieldSchedule: Invalid yield schedule */
+export class SsInvalidYieldScheduleError extends ProgramError {
+  override readonly name: string = 'InvalidYieldSchedule';
+
+  readonly code: number = 0x177f; // 6015
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid yield schedule', program, cause);
+  }
+}
+codeToErrorMap.set(0x177f, SsInvalidYieldScheduleError);
+nameToErrorMap.set('InvalidYieldSchedule', SsInvalidYieldScheduleError);
+
+/** AmountBelowMinimum: Amount below minimum */
+export class SsAmountBelowMinimumError extends ProgramError {
+  override readonly name: string = 'AmountBelowMinimum';
+
+  readonly code: number = 0x1780; // 6016
+
+  constructor(program: Program, cause?: Error) {
+    super('Amount below minimum', program, cause);
+  }
+}
+codeToErrorMap.set(0x1780, SsAmountBelowMinimumError);
+nameToErrorMap.set('AmountBelowMinimum', SsAmountBelowMinimumError);
+
+/** InvalidIssuanceProgram: Invalid issuance program */
+export class SsInvalidIssuanceProgramError extends ProgramError {
+  override readonly name: string = 'InvalidIssuanceProgram';
+
+  readonly code: number = 0x1781; // 6017
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid issuance program', program, cause);
+  }
+}
+codeToErrorMap.set(0x1781, SsInvalidIssuanceProgramError);
+nameToErrorMap.set('InvalidIssuanceProgram', SsInvalidIssuanceProgramError);
+
+/** InvalidTokenManager: Invalid token manager */
+export class SsInvalidTokenManagerError extends ProgramError {
+  override readonly name: string = 'InvalidTokenManager';
+
+  readonly code: number = 0x1782; // 6018
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid token manager', program, cause);
+  }
+}
+codeToErrorMap.set(0x1782, SsInvalidTokenManagerError);
+nameToErrorMap.set('InvalidTokenManager', SsInvalidTokenManagerError);
+
+/** IssuanceUpdateTimelockActive: Issuance update timelock has not elapsed */
+export class SsIssuanceUpdateTimelockActiveError extends ProgramError {
+  override readonly name: string = 'IssuanceUpdateTimelockActive';
+
+  readonly code: number = 0x1783; // 6019
+
+  constructor(program: Program, cause?: Error) {
+    super('Issuance update timelock has not elapsed', program, cause);
+  }
+}
+codeToErrorMap.set(0x1783, SsIssuanceUpdateTimelockActiveError);
+nameToErrorMap.set(
+  'IssuanceUpdateTimelockActive',
+  SsIssuanceUpdateTimelockActiveError
+);
+
+/** AccrualIntervalNotElapsed: Accrual interval not elapsed */
+export class SsAccrualIntervalNotElapsedError extends ProgramError {
+  override readonly name: string = 'AccrualIntervalNotElapsed';
+
+  readonly code: number = 0x1784; // 6020
+
+  constructor(program: Program, cause?: Error) {
+    super('Accrual interval not elapsed', program, cause);
+  }
+}
+codeToErrorMap.set(0x1784, SsAccrualIntervalNotElapsedError);
+nameToErrorMap.set(
+  'AccrualIntervalNotElapsed',
+  SsAccrualIntervalNotElapsedError
+);
+
+/** InvalidYieldMode: Invalid yield mode for this operation */
+export class SsInvalidYieldModeError extends ProgramError {
+  override readonly name: string = 'InvalidYieldMode';
+
+  readonly code: number = 0x1785; // 6021
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid yield mode for this operation', program, cause);
+  }
+}
+codeToErrorMap.set(0x1785, SsInvalidYieldModeError);
+nameToErrorMap.set('InvalidYieldMode', SsInvalidYieldModeError);
+
+/** InvalidRewarder: Invalid rewarder */
+export class SsInvalidRewarderError extends ProgramError {
+  override readonly name: string = 'InvalidRewarder';
+
+  readonly code: number = 0x1786; // 6022
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid rewarder', program, cause);
+  }
+}
+codeToErrorMap.set(0x1786, SsInvalidRewarderError);
+nameToErrorMap.set('InvalidRewarder', SsInvalidRewarderError);
+
+/** StakingPaused: Staking is paused */
+export class SsStakingPausedError extends ProgramError {
+  override readonly name: string = 'StakingPaused';
+
+  readonly code: number = 0x1787; // 6023
+
+  constructor(program: Program, cause?: Error) {
+    super('Staking is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x1787, SsStakingPausedError);
+nameToErrorMap.set('StakingPaused', SsStakingPausedError);
+
+/** UnstakingPaused: Unstaking is paused */
+export class SsUnstakingPausedError extends ProgramError {
+  override readonly name: string = 'UnstakingPaused';
+
+  readonly code: number = 0x1788; // 6024
+
+  constructor(program: Program, cause?: Error) {
+    super('Unstaking is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x1788, SsUnstakingPausedError);
+nameToErrorMap.set('UnstakingPaused', SsUnstakingPausedError);
+
+/** PoolStatusUnchanged: Pool status unchanged */
+export class SsPoolStatusUnchangedError extends ProgramError {
+  override readonly name: string = 'PoolStatusUnchanged';
+
+  readonly code: number = 0x1789; // 6025
+
+  constructor(program: Program, cause?: Error) {
+    super('Pool status unchanged', program, cause);
+  }
+}
+codeToErrorMap.set(0x1789, SsPoolStatusUnchangedError);
+nameToErrorMap.set('PoolStatusUnchanged', SsPoolStatusUnchangedError);
+
+/** InvalidToggleActiveAuthority: Invalid toggle active authority */
+export class SsInvalidToggleActiveAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidToggleActiveAuthority';
+
+  readonly code: number = 0x178a; // 6026
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid toggle active authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x178a, SsInvalidToggleActiveAuthorityError);
+nameToErrorMap.set(
+  'InvalidToggleActiveAuthority',
+  SsInvalidToggleActiveAuthorityError
+);
+
+/** TrancheInactive: Tranche is not open to new positions */
+export class SsTrancheInactiveError extends ProgramError {
+  override readonly name: string = 'TrancheInactive';
+
+  readonly code: number = 0x178b; // 6027
+
+  constructor(program: Program, cause?: Error) {
+    super('Tranche is not open to new positions', program, cause);
+  }
+}
+codeToErrorMap.set(0x178b, SsTrancheInactiveError);
+nameToErrorMap.set('TrancheInactive', SsTrancheInactiveError);
+
+/** InvalidTranche: Invalid tranche */
+export class SsInvalidTrancheError extends ProgramError {
+  override readonly name: string = 'InvalidTranche';
+
+  readonly code: number = 0x178c; // 6028
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid tranche', program, cause);
+  }
+}
+codeToErrorMap.set(0x178c, SsInvalidTrancheError);
+nameToErrorMap.set('InvalidTranche', SsInvalidTrancheError);
+
+/** InvalidXTokenProgram: Invalid x token program */
+export class SsInvalidXTokenProgramError extends ProgramError {
+  override readonly name: string = 'InvalidXTokenProgram';
+
+  readonly code: number = 0x178d; // 6029
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid x token program', program, cause);
+  }
+}
+codeToErrorMap.set(0x178d, SsInvalidXTokenProgramError);
+nameToErrorMap.set('InvalidXTokenProgram', SsInvalidXTokenProgramError);
+
+/** InvalidInterestRate: Interest rate out of range for the x mint */
+export class SsInvalidInterestRateError extends ProgramError {
+  override readonly name: string = 'InvalidInterestRate';
+
+  readonly code: number = 0x178e; // 6030
+
+  constructor(program: Program, cause?: Error) {
+    super('Interest rate out of range for the x mint', program, cause);
+  }
+}
+codeToErrorMap.set(0x178e, SsInvalidInterestRateError);
+nameToErrorMap.set('InvalidInterestRate', SsInvalidInterestRateError);
+
+/** InsufficientRateHistory: Rate history does not cover the window */
+export class SsInsufficientRateHistoryError extends ProgramError {
+  override readonly name: string = 'InsufficientRateHistory';
+
+  readonly code: number = 0x178f; // 6031
+
+  constructor(program: Program, cause?: Error) {
+    super('Rate history does not cover the window', program, cause);
+  }
+}
+codeToErrorMap.set(0x178f, SsInsufficientRateHistoryError);
+nameToErrorMap.set('InsufficientRateHistory', SsInsufficientRateHistoryError);
+
+/** InvalidStrategy: Invalid strategy */
+export class SsInvalidStrategyError extends ProgramError {
+  override readonly name: string = 'InvalidStrategy';
+
+  readonly code: number = 0x1790; // 6032
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid strategy', program, cause);
+  }
+}
+codeToErrorMap.set(0x1790, SsInvalidStrategyError);
+nameToErrorMap.set('InvalidStrategy', SsInvalidStrategyError);
+
+/** StrategyInactive: Strategy is not open to new allocations */
+export class SsStrategyInactiveError extends ProgramError {
+  override readonly name: string = 'StrategyInactive';
+
+  readonly code: number = 0x1791; // 6033
+
+  constructor(program: Program, cause?: Error) {
+    super('Strategy is not open to new allocations', program, cause);
+  }
+}
+codeToErrorMap.set(0x1791, SsStrategyInactiveError);
+nameToErrorMap.set('StrategyInactive', SsStrategyInactiveError);
+
+/** StrategyCapExceeded: Strategy cap exceeded */
+export class SsStrategyCapExceededError extends ProgramError {
+  override readonly name: string = 'StrategyCapExceeded';
+
+  readonly code: number = 0x1792; // 6034
+
+  constructor(program: Program, cause?: Error) {
+    super('Strategy cap exceeded', program, cause);
+  }
+}
+codeToErrorMap.set(0x1792, SsStrategyCapExceededError);
+nameToErrorMap.set('StrategyCapExceeded', SsStrategyCapExceededError);
+
+/** StrategyAllocationExceeded: Strategies would exceed their share of the pool assets */
+export class SsStrategyAllocationExceededError extends ProgramError {
+  override readonly name: string = 'StrategyAllocationExceeded';
+
+  readonly code: number = 0x1793; // 6035
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Strategies would exceed their share of the pool assets',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1793, SsStrategyAllocationExceededError);
+nameToErrorMap.set(
+  'StrategyAllocationExceeded',
+  SsStrategyAllocationExceededError
+);
+
+/** InsufficientVaultLiquidity: Vault balance reserved for unstakes */
+export class SsInsufficientVaultLiquidityError extends ProgramError {
+  override readonly name: string = 'InsufficientVaultLiquidity';
+
+  readonly code: number = 0x1794; // 6036
+
+  constructor(program: Program, cause?: Error) {
+    super('Vault balance reserved for unstakes', program, cause);
+  }
+}
+codeToErrorMap.set(0x1794, SsInsufficientVaultLiquidityError);
+nameToErrorMap.set(
+  'InsufficientVaultLiquidity',
+  SsInsufficientVaultLiquidityError
+);
+
+/** InvalidStrategyAuthority: Invalid strategy authority */
+export class SsInvalidStrategyAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidStrategyAuthority';
+
+  readonly code: number = 0x1795; // 6037
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid strategy authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x1795, SsInvalidStrategyAuthorityError);
+nameToErrorMap.set('InvalidStrategyAuthority', SsInvalidStrategyAuthorityError);
+
+/** StakerNotAllowed: Wallet is not on the allowlist */
+export class SsStakerNotAllowedError extends ProgramError {
+  override readonly name: string = 'StakerNotAllowed';
+
+  readonly code: number = 0x1796; // 6038
+
+  constructor(program: Program, cause?: Error) {
+    super('Wallet is not on the allowlist', program, cause);
+  }
+}
+codeToErrorMap.set(0x1796, SsStakerNotAllowedError);
+nameToErrorMap.set('StakerNotAllowed', SsStakerNotAllowedError);
+
+/** StakerEntryRequired: Staker entry required */
+export class SsStakerEntryRequiredError extends ProgramError {
+  override readonly name: string = 'StakerEntryRequired';
+
+  readonly code: number = 0x1797; // 6039
+
+  constructor(program: Program, cause?: Error) {
+    super('Staker entry required', program, cause);
+  }
+}
+codeToErrorMap.set(0x1797, SsStakerEntryRequiredError);
+nameToErrorMap.set('StakerEntryRequired', SsStakerEntryRequiredError);
+
+/** WalletDepositCapExceeded: Wallet deposit cap exceeded */
+export class SsWalletDepositCapExceededError extends ProgramError {
+  override readonly name: string = 'WalletDepositCapExceeded';
+
+  readonly code: number = 0x1798; // 6040
+
+  constructor(program: Program, cause?: Error) {
+    super('Wallet deposit cap exceeded', program, cause);
+  }
+}
+codeToErrorMap.set(0x1798, SsWalletDepositCapExceededError);
+nameToErrorMap.set('WalletDepositCapExceeded', SsWalletDepositCapExceededError);
+
+/** InvalidLegacyPoolManager: Invalid legacy pool manager */
+export class SsInvalidLegacyPoolManagerError extends ProgramError {
+  override readonly name: string = 'InvalidLegacyPoolManager';
+
+  readonly code: number = 0x1799; // 6041
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid legacy pool manager', program, cause);
+  }
+}
+codeToErrorMap.set(0x1799, SsInvalidLegacyPoolManagerError);
+nameToErrorMap.set('InvalidLegacyPoolManager', SsInvalidLegacyPoolManagerError);
+
+/** KeeperRewardWithoutAccrualInterval: Keeper reward requires a minimum accrual interval */
+export class SsKeeperRewardWithoutAccrualIntervalError extends ProgramError {
+  override readonly name: string = 'KeeperRewardWithoutAccrualInterval';
+
+  readonly code: number = 0x179a; // 6042
+
+  constructor(program: Program, cause?: Error) {
+    super('Keeper reward requires a minimum accrual interval', program, cause);
+  }
+}
+codeToErrorMap.set(0x179a, SsKeeperRewardWithoutAccrualIntervalError);
+nameToErrorMap.set(
+  'KeeperRewardWithoutAccrualInterval',
+  SsKeeperRewardWithoutAccrualIntervalError
+);
+
+/** InterestBearingXMintUnsupported: Setting not supported with an interest-bearing x mint */
+export class SsInterestBearingXMintUnsupportedError extends ProgramError {
+  override readonly name: string = 'InterestBearingXMintUnsupported';
+
+  readonly code: number = 0x179b; // 6043
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Setting not supported with an interest-bearing x mint',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x179b, SsInterestBearingXMintUnsupportedError);
+nameToErrorMap.set(
+  'InterestBearingXMintUnsupported',
+  SsInterestBearingXMintUnsupportedError
+);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
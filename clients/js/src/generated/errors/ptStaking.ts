@@ -171,6 +171,77 @@ export class PsInvalidParamError extends ProgramError {
 codeToErrorMap.set(0x177b, PsInvalidParamError);
 nameToErrorMap.set('InvalidParam', PsInvalidParamError);
 
+/** StakingPaused: Staking is paused */
+export class PsStakingPausedError extends ProgramError {
+  override readonly name: string = 'StakingPaused';
+
+  readonly code: number = 0x177c; // 6012
+
+  constructor(program: Program, cause?: Error) {
+    super('Staking is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x177c, PsStakingPausedError);
+nameToErrorMap.set('StakingPaused', PsStakingPausedError);
+
+/** UnstakingPaused: Unstaking is paused */
+export class PsUnstakingPausedError extends ProgramError {
+  override readonly name: string = 'UnstakingPaused';
+
+  readonly code: number = 0x177d; // 6013
+
+  constructor(program: Program, cause?: Error) {
+    super('Unstaking is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x177d, PsUnstakingPausedError);
+nameToErrorMap.set('UnstakingPaused', PsUnstakingPausedError);
+
+/** StatusUnchanged: Status unchanged */
+export class PsStatusUnchangedError extends ProgramError {
+  override readonly name: string = 'StatusUnchanged';
+
+  readonly code: number = 0x177e; // 6014
+
+  constructor(program: Program, cause?: Error) {
+    super('Status unchanged', program, cause);
+  }
+}
+codeToErrorMap.set(0x177e, PsStatusUnchangedError);
+nameToErrorMap.set('StatusUnchanged', PsStatusUnchangedError);
+
+/** InvalidToggleActiveAuthority: Invalid toggle active authority */
+export class PsInvalidToggleActiveAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidToggleActiveAuthority';
+
+  readonly code: number = 0x177f; // 6015
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid toggle active authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x177f, PsInvalidToggleActiveAuthorityError);
+nameToErrorMap.set(
+  'InvalidToggleActiveAuthority',
+  PsInvalidToggleActiveAuthorityError
+);
+
+/** InvalidLegacyGlobalConfig: Invalid legacy global config */
+export class PsInvalidLegacyGlobalConfigError extends ProgramError {
+  override readonly name: string = 'InvalidLegacyGlobalConfig';
+
+  readonly code: number = 0x1780; // 6016
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid legacy global config', program, cause);
+  }
+}
+codeToErrorMap.set(0x1780, PsInvalidLegacyGlobalConfigError);
+nameToErrorMap.set(
+  'InvalidLegacyGlobalConfig',
+  PsInvalidLegacyGlobalConfigError
+);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
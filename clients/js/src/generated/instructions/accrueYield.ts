@@ -0,0 +1,214 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AccrueYieldInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  rateFeed: PublicKey | Pda;
+  rateHistory: PublicKey | Pda;
+  tokenManager: PublicKey | Pda;
+  baseMint: PublicKey | Pda;
+  xMint: PublicKey | Pda;
+  vault: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
+  /** Receives the keeper reward, none is paid without it */
+  keeperBaseMintAta?: PublicKey | Pda;
+  keeper: Signer;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  xTokenProgram: PublicKey | Pda;
+  associatedTokenProgram: PublicKey | Pda;
+  minterAuthorization?: PublicKey | Pda;
+  parityIssuanceProgram: PublicKey | Pda;
+};
+
+// Data.
+export type AccrueYieldInstructionData = { discriminator: Array<number> };
+
+export type AccrueYieldInstructionDataArgs = {};
+
+export function getAccrueYieldInstructionDataSerializer(): Serializer<
+  AccrueYieldInstructionDataArgs,
+  AccrueYieldInstructionData
+> {
+  return mapSerializer<
+    AccrueYieldInstructionDataArgs,
+    any,
+    AccrueYieldInstructionData
+  >(
+    struct<AccrueYieldInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'AccrueYieldInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [243, 28, 81, 65, 175, 178, 5, 112],
+    })
+  ) as Serializer<AccrueYieldInstructionDataArgs, AccrueYieldInstructionData>;
+}
+
+// Instruction.
+export function accrueYield(
+  context: Pick<Context, 'programs'>,
+  input: AccrueYieldInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    yieldSchedule: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    rateFeed: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.rateFeed ?? null,
+    },
+    rateHistory: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.rateHistory ?? null,
+    },
+    tokenManager: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.tokenManager ?? null,
+    },
+    baseMint: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.baseMint ?? null,
+    },
+    xMint: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.xMint ?? null,
+    },
+    vault: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    treasury: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    keeperBaseMintAta: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.keeperBaseMintAta ?? null,
+    },
+    keeper: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.keeper ?? null,
+    },
+    systemProgram: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    xTokenProgram: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.xTokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    minterAuthorization: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.minterAuthorization ?? null,
+    },
+    parityIssuanceProgram: {
+      index: 16,
+      isWritable: false as boolean,
+      value: input.parityIssuanceProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAccrueYieldInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,143 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddGuardianInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  guardian: PublicKey | Pda;
+  admin: Signer;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddGuardianInstructionData = {
+  discriminator: Array<number>;
+  newGuardian: PublicKey;
+};
+
+export type AddGuardianInstructionDataArgs = { newGuardian: PublicKey };
+
+export function getAddGuardianInstructionDataSerializer(): Serializer<
+  AddGuardianInstructionDataArgs,
+  AddGuardianInstructionData
+> {
+  return mapSerializer<
+    AddGuardianInstructionDataArgs,
+    any,
+    AddGuardianInstructionData
+  >(
+    struct<AddGuardianInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['newGuardian', publicKeySerializer()],
+      ],
+      { description: 'AddGuardianInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [167, 189, 170, 27, 74, 240, 201, 241],
+    })
+  ) as Serializer<AddGuardianInstructionDataArgs, AddGuardianInstructionData>;
+}
+
+// Args.
+export type AddGuardianInstructionArgs = AddGuardianInstructionDataArgs;
+
+// Instruction.
+export function addGuardian(
+  context: Pick<Context, 'programs'>,
+  input: AddGuardianInstructionAccounts & AddGuardianInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    guardian: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.guardian ?? null,
+    },
+    admin: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AddGuardianInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddGuardianInstructionDataSerializer().serialize(
+    resolvedArgs as AddGuardianInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
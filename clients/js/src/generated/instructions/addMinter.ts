@@ -0,0 +1,140 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddMinterInstructionAccounts = {
+  tokenManager: PublicKey | Pda;
+  minter: PublicKey | Pda;
+  admin: Signer;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddMinterInstructionData = {
+  discriminator: Array<number>;
+  newMinter: PublicKey;
+};
+
+export type AddMinterInstructionDataArgs = { newMinter: PublicKey };
+
+export function getAddMinterInstructionDataSerializer(): Serializer<
+  AddMinterInstructionDataArgs,
+  AddMinterInstructionData
+> {
+  return mapSerializer<
+    AddMinterInstructionDataArgs,
+    any,
+    AddMinterInstructionData
+  >(
+    struct<AddMinterInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['newMinter', publicKeySerializer()],
+      ],
+      { description: 'AddMinterInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [75, 86, 218, 40, 219, 6, 141, 29] })
+  ) as Serializer<AddMinterInstructionDataArgs, AddMinterInstructionData>;
+}
+
+// Args.
+export type AddMinterInstructionArgs = AddMinterInstructionDataArgs;
+
+// Instruction.
+export function addMinter(
+  context: Pick<Context, 'programs'>,
+  input: AddMinterInstructionAccounts & AddMinterInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityIssuance',
+    'ALukFrRp8cFkWCEZamFVsBiFtxKYPLUUGRxskFh1g5ZX'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    tokenManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.tokenManager ?? null,
+    },
+    minter: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.minter ?? null,
+    },
+    admin: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AddMinterInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddMinterInstructionDataSerializer().serialize(
+    resolvedArgs as AddMinterInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,146 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddPtGuardianInstructionAccounts = {
+  globalConfig: PublicKey | Pda;
+  guardian: PublicKey | Pda;
+  admin: Signer;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddPtGuardianInstructionData = {
+  discriminator: Array<number>;
+  newGuardian: PublicKey;
+};
+
+export type AddPtGuardianInstructionDataArgs = { newGuardian: PublicKey };
+
+export function getAddPtGuardianInstructionDataSerializer(): Serializer<
+  AddPtGuardianInstructionDataArgs,
+  AddPtGuardianInstructionData
+> {
+  return mapSerializer<
+    AddPtGuardianInstructionDataArgs,
+    any,
+    AddPtGuardianInstructionData
+  >(
+    struct<AddPtGuardianInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['newGuardian', publicKeySerializer()],
+      ],
+      { description: 'AddPtGuardianInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [167, 189, 170, 27, 74, 240, 201, 241],
+    })
+  ) as Serializer<
+    AddPtGuardianInstructionDataArgs,
+    AddPtGuardianInstructionData
+  >;
+}
+
+// Args.
+export type AddPtGuardianInstructionArgs = AddPtGuardianInstructionDataArgs;
+
+// Instruction.
+export function addPtGuardian(
+  context: Pick<Context, 'programs'>,
+  input: AddPtGuardianInstructionAccounts & AddPtGuardianInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'ptStaking',
+    'AdXJ8Sr46ujd9DSLP5LRyF1BrqxT9azqmQqN2oTyV8cz'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    globalConfig: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.globalConfig ?? null,
+    },
+    guardian: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.guardian ?? null,
+    },
+    admin: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AddPtGuardianInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddPtGuardianInstructionDataSerializer().serialize(
+    resolvedArgs as AddPtGuardianInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,156 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddStakerEntryInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  stakerEntry: PublicKey | Pda;
+  /** The admin, or the wallet itself while the allowlist is disabled */
+  authority?: Signer;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddStakerEntryInstructionData = {
+  discriminator: Array<number>;
+  wallet: PublicKey;
+  depositCap: bigint;
+};
+
+export type AddStakerEntryInstructionDataArgs = {
+  wallet: PublicKey;
+  depositCap: number | bigint;
+};
+
+export function getAddStakerEntryInstructionDataSerializer(): Serializer<
+  AddStakerEntryInstructionDataArgs,
+  AddStakerEntryInstructionData
+> {
+  return mapSerializer<
+    AddStakerEntryInstructionDataArgs,
+    any,
+    AddStakerEntryInstructionData
+  >(
+    struct<AddStakerEntryInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['wallet', publicKeySerializer()],
+        ['depositCap', u64()],
+      ],
+      { description: 'AddStakerEntryInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [189, 132, 255, 193, 115, 22, 230, 247],
+    })
+  ) as Serializer<
+    AddStakerEntryInstructionDataArgs,
+    AddStakerEntryInstructionData
+  >;
+}
+
+// Args.
+export type AddStakerEntryInstructionArgs = AddStakerEntryInstructionDataArgs;
+
+// Instruction.
+export function addStakerEntry(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: AddStakerEntryInstructionAccounts & AddStakerEntryInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    stakerEntry: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.stakerEntry ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AddStakerEntryInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddStakerEntryInstructionDataSerializer().serialize(
+    resolvedArgs as AddStakerEntryInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
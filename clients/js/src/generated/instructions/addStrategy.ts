@@ -0,0 +1,186 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddStrategyInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  strategy: PublicKey | Pda;
+  strategyProgram: PublicKey | Pda;
+  strategyAuthority: PublicKey | Pda;
+  baseMint: PublicKey | Pda;
+  strategyTokenAccount: PublicKey | Pda;
+  owner: Signer;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram: PublicKey | Pda;
+};
+
+// Data.
+export type AddStrategyInstructionData = {
+  discriminator: Array<number>;
+  cap: bigint;
+};
+
+export type AddStrategyInstructionDataArgs = { cap: number | bigint };
+
+export function getAddStrategyInstructionDataSerializer(): Serializer<
+  AddStrategyInstructionDataArgs,
+  AddStrategyInstructionData
+> {
+  return mapSerializer<
+    AddStrategyInstructionDataArgs,
+    any,
+    AddStrategyInstructionData
+  >(
+    struct<AddStrategyInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['cap', u64()],
+      ],
+      { description: 'AddStrategyInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [64, 123, 127, 227, 192, 234, 198, 20],
+    })
+  ) as Serializer<AddStrategyInstructionDataArgs, AddStrategyInstructionData>;
+}
+
+// Args.
+export type AddStrategyInstructionArgs = AddStrategyInstructionDataArgs;
+
+// Instruction.
+export function addStrategy(
+  context: Pick<Context, 'programs'>,
+  input: AddStrategyInstructionAccounts & AddStrategyInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    strategy: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.strategy ?? null,
+    },
+    strategyProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.strategyProgram ?? null,
+    },
+    strategyAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.strategyAuthority ?? null,
+    },
+    baseMint: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.baseMint ?? null,
+    },
+    strategyTokenAccount: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.strategyTokenAccount ?? null,
+    },
+    owner: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.owner ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AddStrategyInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddStrategyInstructionDataSerializer().serialize(
+    resolvedArgs as AddStrategyInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
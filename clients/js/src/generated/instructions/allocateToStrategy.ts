@@ -0,0 +1,180 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AllocateToStrategyInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  strategy: PublicKey | Pda;
+  baseMint: PublicKey | Pda;
+  vault: PublicKey | Pda;
+  strategyProgram: PublicKey | Pda;
+  strategyAuthority: PublicKey | Pda;
+  strategyTokenAccount: PublicKey | Pda;
+  owner: Signer;
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AllocateToStrategyInstructionData = {
+  discriminator: Array<number>;
+  quantity: bigint;
+};
+
+export type AllocateToStrategyInstructionDataArgs = {
+  quantity: number | bigint;
+};
+
+export function getAllocateToStrategyInstructionDataSerializer(): Serializer<
+  AllocateToStrategyInstructionDataArgs,
+  AllocateToStrategyInstructionData
+> {
+  return mapSerializer<
+    AllocateToStrategyInstructionDataArgs,
+    any,
+    AllocateToStrategyInstructionData
+  >(
+    struct<AllocateToStrategyInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['quantity', u64()],
+      ],
+      { description: 'AllocateToStrategyInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [167, 33, 255, 61, 211, 127, 50, 201],
+    })
+  ) as Serializer<
+    AllocateToStrategyInstructionDataArgs,
+    AllocateToStrategyInstructionData
+  >;
+}
+
+// Args.
+export type AllocateToStrategyInstructionArgs =
+  AllocateToStrategyInstructionDataArgs;
+
+// Instruction.
+export function allocateToStrategy(
+  context: Pick<Context, 'programs'>,
+  input: AllocateToStrategyInstructionAccounts &
+    AllocateToStrategyInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    strategy: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.strategy ?? null,
+    },
+    baseMint: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.baseMint ?? null,
+    },
+    vault: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    strategyProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.strategyProgram ?? null,
+    },
+    strategyAuthority: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.strategyAuthority ?? null,
+    },
+    strategyTokenAccount: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.strategyTokenAccount ?? null,
+    },
+    owner: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    tokenProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AllocateToStrategyInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAllocateToStrategyInstructionDataSerializer().serialize(
+    resolvedArgs as AllocateToStrategyInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
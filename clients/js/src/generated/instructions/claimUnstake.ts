@@ -0,0 +1,168 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ClaimUnstakeInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  unstakeTicket: PublicKey | Pda;
+  baseMint: PublicKey | Pda;
+  payerBaseMintAta: PublicKey | Pda;
+  vault: PublicKey | Pda;
+  payer?: Signer;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram: PublicKey | Pda;
+};
+
+// Data.
+export type ClaimUnstakeInstructionData = { discriminator: Array<number> };
+
+export type ClaimUnstakeInstructionDataArgs = {};
+
+export function getClaimUnstakeInstructionDataSerializer(): Serializer<
+  ClaimUnstakeInstructionDataArgs,
+  ClaimUnstakeInstructionData
+> {
+  return mapSerializer<
+    ClaimUnstakeInstructionDataArgs,
+    any,
+    ClaimUnstakeInstructionData
+  >(
+    struct<ClaimUnstakeInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'ClaimUnstakeInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [172, 113, 117, 178, 223, 245, 247, 118],
+    })
+  ) as Serializer<ClaimUnstakeInstructionDataArgs, ClaimUnstakeInstructionData>;
+}
+
+// Instruction.
+export function claimUnstake(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: ClaimUnstakeInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    unstakeTicket: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.unstakeTicket ?? null,
+    },
+    baseMint: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.baseMint ?? null,
+    },
+    payerBaseMintAta: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payerBaseMintAta ?? null,
+    },
+    vault: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    payer: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getClaimUnstakeInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,176 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type DepositRewardsInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  rateFeed: PublicKey | Pda;
+  baseMint: PublicKey | Pda;
+  vault: PublicKey | Pda;
+  rewarderBaseMintAta: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
+  rewarder: Signer;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram: PublicKey | Pda;
+};
+
+// Data.
+export type DepositRewardsInstructionData = {
+  discriminator: Array<number>;
+  quantity: bigint;
+};
+
+export type DepositRewardsInstructionDataArgs = { quantity: number | bigint };
+
+export function getDepositRewardsInstructionDataSerializer(): Serializer<
+  DepositRewardsInstructionDataArgs,
+  DepositRewardsInstructionData
+> {
+  return mapSerializer<
+    DepositRewardsInstructionDataArgs,
+    any,
+    DepositRewardsInstructionData
+  >(
+    struct<DepositRewardsInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['quantity', u64()],
+      ],
+      { description: 'DepositRewardsInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [52, 249, 112, 72, 206, 161, 196, 1],
+    })
+  ) as Serializer<
+    DepositRewardsInstructionDataArgs,
+    DepositRewardsInstructionData
+  >;
+}
+
+// Args.
+export type DepositRewardsInstructionArgs = DepositRewardsInstructionDataArgs;
+
+// Instruction.
+export function depositRewards(
+  context: Pick<Context, 'programs'>,
+  input: DepositRewardsInstructionAccounts & DepositRewardsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    rateFeed: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.rateFeed ?? null,
+    },
+    baseMint: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.baseMint ?? null,
+    },
+    vault: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    rewarderBaseMintAta: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.rewarderBaseMintAta ?? null,
+    },
+    treasury: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    rewarder: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.rewarder ?? null,
+    },
+    tokenProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: DepositRewardsInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getDepositRewardsInstructionDataSerializer().serialize(
+    resolvedArgs as DepositRewardsInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,178 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type EmergencyWithdrawAllInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  strategy: PublicKey | Pda;
+  baseMint: PublicKey | Pda;
+  vault: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
+  strategyProgram: PublicKey | Pda;
+  strategyAuthority: PublicKey | Pda;
+  strategyTokenAccount: PublicKey | Pda;
+  authority?: Signer;
+  guardian?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type EmergencyWithdrawAllInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type EmergencyWithdrawAllInstructionDataArgs = {};
+
+export function getEmergencyWithdrawAllInstructionDataSerializer(): Serializer<
+  EmergencyWithdrawAllInstructionDataArgs,
+  EmergencyWithdrawAllInstructionData
+> {
+  return mapSerializer<
+    EmergencyWithdrawAllInstructionDataArgs,
+    any,
+    EmergencyWithdrawAllInstructionData
+  >(
+    struct<EmergencyWithdrawAllInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'EmergencyWithdrawAllInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [154, 202, 116, 235, 157, 122, 25, 31],
+    })
+  ) as Serializer<
+    EmergencyWithdrawAllInstructionDataArgs,
+    EmergencyWithdrawAllInstructionData
+  >;
+}
+
+// Instruction.
+export function emergencyWithdrawAll(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: EmergencyWithdrawAllInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    strategy: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.strategy ?? null,
+    },
+    baseMint: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.baseMint ?? null,
+    },
+    vault: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    treasury: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    strategyProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.strategyProgram ?? null,
+    },
+    strategyAuthority: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.strategyAuthority ?? null,
+    },
+    strategyTokenAccount: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.strategyTokenAccount ?? null,
+    },
+    authority: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    guardian: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.guardian ?? null,
+    },
+    tokenProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getEmergencyWithdrawAllInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
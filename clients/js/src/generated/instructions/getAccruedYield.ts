@@ -0,0 +1,115 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type GetAccruedYieldInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  xMint: PublicKey | Pda;
+};
+
+// Data.
+export type GetAccruedYieldInstructionData = { discriminator: Array<number> };
+
+export type GetAccruedYieldInstructionDataArgs = {};
+
+export function getGetAccruedYieldInstructionDataSerializer(): Serializer<
+  GetAccruedYieldInstructionDataArgs,
+  GetAccruedYieldInstructionData
+> {
+  return mapSerializer<
+    GetAccruedYieldInstructionDataArgs,
+    any,
+    GetAccruedYieldInstructionData
+  >(
+    struct<GetAccruedYieldInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'GetAccruedYieldInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [165, 219, 101, 206, 149, 25, 47, 25],
+    })
+  ) as Serializer<
+    GetAccruedYieldInstructionDataArgs,
+    GetAccruedYieldInstructionData
+  >;
+}
+
+// Instruction.
+export function getAccruedYield(
+  context: Pick<Context, 'programs'>,
+  input: GetAccruedYieldInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    yieldSchedule: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    xMint: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.xMint ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getGetAccruedYieldInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
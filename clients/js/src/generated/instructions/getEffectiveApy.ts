@@ -0,0 +1,103 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type GetEffectiveApyInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+};
+
+// Data.
+export type GetEffectiveApyInstructionData = { discriminator: Array<number> };
+
+export type GetEffectiveApyInstructionDataArgs = {};
+
+export function getGetEffectiveApyInstructionDataSerializer(): Serializer<
+  GetEffectiveApyInstructionDataArgs,
+  GetEffectiveApyInstructionData
+> {
+  return mapSerializer<
+    GetEffectiveApyInstructionDataArgs,
+    any,
+    GetEffectiveApyInstructionData
+  >(
+    struct<GetEffectiveApyInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'GetEffectiveApyInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [85, 100, 108, 37, 53, 141, 51, 105],
+    })
+  ) as Serializer<
+    GetEffectiveApyInstructionDataArgs,
+    GetEffectiveApyInstructionData
+  >;
+}
+
+// Instruction.
+export function getEffectiveApy(
+  context: Pick<Context, 'programs'>,
+  input: GetEffectiveApyInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getGetEffectiveApyInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
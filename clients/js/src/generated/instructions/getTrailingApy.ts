@@ -0,0 +1,130 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type GetTrailingApyInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  rateHistory: PublicKey | Pda;
+};
+
+// Data.
+export type GetTrailingApyInstructionData = {
+  discriminator: Array<number>;
+  window: bigint;
+};
+
+export type GetTrailingApyInstructionDataArgs = { window: number | bigint };
+
+export function getGetTrailingApyInstructionDataSerializer(): Serializer<
+  GetTrailingApyInstructionDataArgs,
+  GetTrailingApyInstructionData
+> {
+  return mapSerializer<
+    GetTrailingApyInstructionDataArgs,
+    any,
+    GetTrailingApyInstructionData
+  >(
+    struct<GetTrailingApyInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['window', i64()],
+      ],
+      { description: 'GetTrailingApyInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [28, 140, 210, 33, 188, 82, 50, 109],
+    })
+  ) as Serializer<
+    GetTrailingApyInstructionDataArgs,
+    GetTrailingApyInstructionData
+  >;
+}
+
+// Args.
+export type GetTrailingApyInstructionArgs = GetTrailingApyInstructionDataArgs;
+
+// Instruction.
+export function getTrailingApy(
+  context: Pick<Context, 'programs'>,
+  input: GetTrailingApyInstructionAccounts & GetTrailingApyInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    yieldSchedule: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    rateHistory: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.rateHistory ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: GetTrailingApyInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getGetTrailingApyInstructionDataSerializer().serialize(
+    resolvedArgs as GetTrailingApyInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -6,36 +6,76 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './accrueYield';
 export * from './addGatekeeper';
+export * from './addGuardian';
+export * from './addMinter';
+export * from './addPtGuardian';
+export * from './addStakerEntry';
+export * from './addStrategy';
+export * from './allocateToStrategy';
+export * from './claimUnstake';
+export * from './closeTermPosition';
 export * from './depositFunds';
+export * from './depositRewards';
+export * from './emergencyWithdrawAll';
+export * from './getAccruedYield';
+export * from './getEffectiveApy';
+export * from './getTrailingApy';
 export * from './initializeGlobalConfig';
 export * from './initializePoolManager';
 export * from './initializeTokenManager';
+export * from './initializeTranche';
 export * from './initializeWithdrawFunds';
 export * from './initiateUpdateGlobalConfigOwner';
+export * from './initiateUpdateIssuance';
 export * from './initiateUpdateManagerOwner';
 export * from './initiateUpdatePoolOwner';
 export * from './initPtStake';
+export * from './migrateGlobalConfig';
+export * from './migratePoolManager';
+export * from './migrateTokenManager';
 export * from './mint';
 export * from './mintAdmin';
+export * from './mintAndStake';
+export * from './openTermPosition';
+export * from './previewMint';
+export * from './previewRedeem';
+export * from './previewStake';
+export * from './previewUnstake';
 export * from './ptStake';
 export * from './ptUnstake';
 export * from './redeem';
+export * from './refreshRateFeed';
 export * from './removeGatekeeper';
+export * from './removeGuardian';
+export * from './removeMinter';
+export * from './removePtGuardian';
+export * from './reportStrategyValue';
+export * from './requestUnstake';
 export * from './stake';
 export * from './toggleActive';
+export * from './togglePtStakingActive';
+export * from './toggleStakingActive';
 export * from './unstake';
+export * from './unstakeAndRedeem';
 export * from './updateAnnualYield';
 export * from './updateGlobalConfig';
 export * from './updateGlobalConfigOwner';
+export * from './updateIssuance';
 export * from './updateManagerOwner';
 export * from './updateMintMetadata';
 export * from './updatePoolManager';
 export * from './updatePoolOwner';
+export * from './updateStakerEntry';
+export * from './updateStrategy';
 export * from './updateTokenManagerAdmin';
 export * from './updateTokenManagerOwner';
+export * from './updateTranche';
 export * from './updateXmintMetadata';
+export * from './updateYieldSchedule';
 export * from './withdrawExcessIssuance';
 export * from './withdrawExcessParity';
 export * from './withdrawExcessPt';
+export * from './withdrawFromStrategy';
 export * from './withdrawFunds';
@@ -18,7 +18,9 @@ import {
 import {
   Serializer,
   array,
+  bool,
   i32,
+  i64,
   mapSerializer,
   publicKey as publicKeySerializer,
   string,
@@ -31,6 +33,7 @@ import {
   ResolvedAccountsWithIndices,
   getAccountMetasAndSigners,
 } from '../shared';
+import { YieldMode, YieldModeArgs, getYieldModeSerializer } from '../types';
 
 // Accounts.
 export type InitializePoolManagerInstructionAccounts = {
@@ -39,39 +42,66 @@ export type InitializePoolManagerInstructionAccounts = {
   xMint: PublicKey | Pda;
   metadata: PublicKey | Pda;
   poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  rateFeed: PublicKey | Pda;
+  rateHistory: PublicKey | Pda;
   vault: PublicKey | Pda;
+  ownerBaseMintAta: PublicKey | Pda;
+  /** shares, owned by itself so that nothing ever signs for it */
+  deadShares: PublicKey | Pda;
+  /** Token manager of the base mint, bound to the pool for yield minting */
+  tokenManager: PublicKey | Pda;
   owner: Signer;
   rent?: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
   tokenProgram?: PublicKey | Pda;
+  /** SPL Token, or Token-2022 for an interest-bearing x mint */
+  xTokenProgram: PublicKey | Pda;
   tokenMetadataProgram?: PublicKey | Pda;
   associatedTokenProgram: PublicKey | Pda;
+  parityIssuanceProgram: PublicKey | Pda;
 };
 
 // Data.
 export type InitializePoolManagerInstructionData = {
   discriminator: Array<number>;
+  poolId: bigint;
   name: string;
   symbol: string;
   uri: string;
   decimals: number;
-  intervalAprRate: bigint;
+  annualYieldBps: bigint;
   secondsPerInterval: number;
   initialExchangeRate: bigint;
   admin: PublicKey;
   depositCap: bigint;
+  unstakeCooldown: bigint;
+  minStakeAmount: bigint;
+  minUnstakeAmount: bigint;
+  yieldMode: YieldMode;
+  rewarder: PublicKey;
+  vestingPeriod: bigint;
+  interestBearingXMint: boolean;
 };
 
 export type InitializePoolManagerInstructionDataArgs = {
+  poolId: number | bigint;
   name: string;
   symbol: string;
   uri: string;
   decimals: number;
-  intervalAprRate: number | bigint;
+  annualYieldBps: number | bigint;
   secondsPerInterval: number;
   initialExchangeRate: number | bigint;
   admin: PublicKey;
   depositCap: number | bigint;
+  unstakeCooldown: number | bigint;
+  minStakeAmount: number | bigint;
+  minUnstakeAmount: number | bigint;
+  yieldMode: YieldModeArgs;
+  rewarder: PublicKey;
+  vestingPeriod: number | bigint;
+  interestBearingXMint: boolean;
 };
 
 export function getInitializePoolManagerInstructionDataSerializer(): Serializer<
@@ -86,15 +116,23 @@ export function getInitializePoolManagerInstructionDataSerializer(): Serializer<
     struct<InitializePoolManagerInstructionData>(
       [
         ['discriminator', array(u8(), { size: 8 })],
+        ['poolId', u64()],
         ['name', string()],
         ['symbol', string()],
         ['uri', string()],
         ['decimals', u8()],
-        ['intervalAprRate', u64()],
+        ['annualYieldBps', u64()],
         ['secondsPerInterval', i32()],
         ['initialExchangeRate', u64()],
         ['admin', publicKeySerializer()],
         ['depositCap', u64()],
+        ['unstakeCooldown', i64()],
+        ['minStakeAmount', u64()],
+        ['minUnstakeAmount', u64()],
+        ['yieldMode', getYieldModeSerializer()],
+        ['rewarder', publicKeySerializer()],
+        ['vestingPeriod', i64()],
+        ['interestBearingXMint', bool()],
       ],
       { description: 'InitializePoolManagerInstructionData' }
     ),
@@ -146,37 +184,81 @@ export function initializePoolManager(
       isWritable: true as boolean,
       value: input.poolManager ?? null,
     },
-    vault: {
+    yieldSchedule: {
       index: 4,
       isWritable: true as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    rateFeed: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.rateFeed ?? null,
+    },
+    rateHistory: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.rateHistory ?? null,
+    },
+    vault: {
+      index: 7,
+      isWritable: true as boolean,
       value: input.vault ?? null,
     },
+    ownerBaseMintAta: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.ownerBaseMintAta ?? null,
+    },
+    deadShares: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.deadShares ?? null,
+    },
+    tokenManager: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.tokenManager ?? null,
+    },
     owner: {
-      index: 5,
+      index: 11,
       isWritable: true as boolean,
       value: input.owner ?? null,
     },
-    rent: { index: 6, isWritable: false as boolean, value: input.rent ?? null },
+    rent: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.rent ?? null,
+    },
     systemProgram: {
-      index: 7,
+      index: 13,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     tokenProgram: {
-      index: 8,
+      index: 14,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
+    xTokenProgram: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.xTokenProgram ?? null,
+    },
     tokenMetadataProgram: {
-      index: 9,
+      index: 16,
       isWritable: false as boolean,
       value: input.tokenMetadataProgram ?? null,
     },
     associatedTokenProgram: {
-      index: 10,
+      index: 17,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
+    parityIssuanceProgram: {
+      index: 18,
+      isWritable: false as boolean,
+      value: input.parityIssuanceProgram ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
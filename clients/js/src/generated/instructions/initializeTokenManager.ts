@@ -66,6 +66,9 @@ export type InitializeTokenManagerInstructionData = {
   withdrawExecutionWindow: bigint;
   mintFeeBps: number;
   redeemFeeBps: number;
+  maxExchangeRateChangeBps: number;
+  exchangeRateUpdateInterval: bigint;
+  exchangeRateRampDuration: bigint;
 };
 
 export type InitializeTokenManagerInstructionDataArgs = {
@@ -83,6 +86,9 @@ export type InitializeTokenManagerInstructionDataArgs = {
   withdrawExecutionWindow: number | bigint;
   mintFeeBps: number;
   redeemFeeBps: number;
+  maxExchangeRateChangeBps: number;
+  exchangeRateUpdateInterval: number | bigint;
+  exchangeRateRampDuration: number | bigint;
 };
 
 export function getInitializeTokenManagerInstructionDataSerializer(): Serializer<
@@ -111,6 +117,9 @@ export function getInitializeTokenManagerInstructionDataSerializer(): Serializer
         ['withdrawExecutionWindow', i64()],
         ['mintFeeBps', u16()],
         ['redeemFeeBps', u16()],
+        ['maxExchangeRateChangeBps', u16()],
+        ['exchangeRateUpdateInterval', i64()],
+        ['exchangeRateRampDuration', i64()],
       ],
       { description: 'InitializeTokenManagerInstructionData' }
     ),
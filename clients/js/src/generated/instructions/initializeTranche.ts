@@ -0,0 +1,175 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitializeTrancheInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  tranche: PublicKey | Pda;
+  xMint: PublicKey | Pda;
+  /** Holds the x tokens of the tranche's open positions */
+  trancheVault: PublicKey | Pda;
+  admin: Signer;
+  systemProgram?: PublicKey | Pda;
+  xTokenProgram: PublicKey | Pda;
+};
+
+// Data.
+export type InitializeTrancheInstructionData = {
+  discriminator: Array<number>;
+  trancheId: bigint;
+  termDuration: bigint;
+  annualYieldBps: bigint;
+};
+
+export type InitializeTrancheInstructionDataArgs = {
+  trancheId: number | bigint;
+  termDuration: number | bigint;
+  annualYieldBps: number | bigint;
+};
+
+export function getInitializeTrancheInstructionDataSerializer(): Serializer<
+  InitializeTrancheInstructionDataArgs,
+  InitializeTrancheInstructionData
+> {
+  return mapSerializer<
+    InitializeTrancheInstructionDataArgs,
+    any,
+    InitializeTrancheInstructionData
+  >(
+    struct<InitializeTrancheInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['trancheId', u64()],
+        ['termDuration', i64()],
+        ['annualYieldBps', u64()],
+      ],
+      { description: 'InitializeTrancheInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [129, 162, 50, 217, 235, 134, 123, 252],
+    })
+  ) as Serializer<
+    InitializeTrancheInstructionDataArgs,
+    InitializeTrancheInstructionData
+  >;
+}
+
+// Args.
+export type InitializeTrancheInstructionArgs =
+  InitializeTrancheInstructionDataArgs;
+
+// Instruction.
+export function initializeTranche(
+  context: Pick<Context, 'programs'>,
+  input: InitializeTrancheInstructionAccounts & InitializeTrancheInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    tranche: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.tranche ?? null,
+    },
+    xMint: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.xMint ?? null,
+    },
+    trancheVault: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.trancheVault ?? null,
+    },
+    admin: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    xTokenProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.xTokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InitializeTrancheInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitializeTrancheInstructionDataSerializer().serialize(
+    resolvedArgs as InitializeTrancheInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
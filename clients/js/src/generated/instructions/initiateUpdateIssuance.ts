@@ -0,0 +1,126 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitiateUpdateIssuanceInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  newIssuanceProgram: PublicKey | Pda;
+  newTokenManager: PublicKey | Pda;
+  owner: Signer;
+};
+
+// Data.
+export type InitiateUpdateIssuanceInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type InitiateUpdateIssuanceInstructionDataArgs = {};
+
+export function getInitiateUpdateIssuanceInstructionDataSerializer(): Serializer<
+  InitiateUpdateIssuanceInstructionDataArgs,
+  InitiateUpdateIssuanceInstructionData
+> {
+  return mapSerializer<
+    InitiateUpdateIssuanceInstructionDataArgs,
+    any,
+    InitiateUpdateIssuanceInstructionData
+  >(
+    struct<InitiateUpdateIssuanceInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'InitiateUpdateIssuanceInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [61, 96, 241, 181, 223, 146, 112, 194],
+    })
+  ) as Serializer<
+    InitiateUpdateIssuanceInstructionDataArgs,
+    InitiateUpdateIssuanceInstructionData
+  >;
+}
+
+// Instruction.
+export function initiateUpdateIssuance(
+  context: Pick<Context, 'programs'>,
+  input: InitiateUpdateIssuanceInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    newIssuanceProgram: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.newIssuanceProgram ?? null,
+    },
+    newTokenManager: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.newTokenManager ?? null,
+    },
+    owner: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitiateUpdateIssuanceInstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
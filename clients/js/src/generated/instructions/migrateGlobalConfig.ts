@@ -0,0 +1,127 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigrateGlobalConfigInstructionAccounts = {
+  globalConfig: PublicKey | Pda;
+  owner: Signer;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MigrateGlobalConfigInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type MigrateGlobalConfigInstructionDataArgs = {};
+
+export function getMigrateGlobalConfigInstructionDataSerializer(): Serializer<
+  MigrateGlobalConfigInstructionDataArgs,
+  MigrateGlobalConfigInstructionData
+> {
+  return mapSerializer<
+    MigrateGlobalConfigInstructionDataArgs,
+    any,
+    MigrateGlobalConfigInstructionData
+  >(
+    struct<MigrateGlobalConfigInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'MigrateGlobalConfigInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [207, 52, 247, 7, 1, 230, 228, 147],
+    })
+  ) as Serializer<
+    MigrateGlobalConfigInstructionDataArgs,
+    MigrateGlobalConfigInstructionData
+  >;
+}
+
+// Instruction.
+export function migrateGlobalConfig(
+  context: Pick<Context, 'programs'>,
+  input: MigrateGlobalConfigInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'ptStaking',
+    'AdXJ8Sr46ujd9DSLP5LRyF1BrqxT9azqmQqN2oTyV8cz'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    globalConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.globalConfig ?? null,
+    },
+    owner: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.owner ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigrateGlobalConfigInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
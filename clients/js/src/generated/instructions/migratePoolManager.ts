@@ -0,0 +1,242 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigratePoolManagerInstructionAccounts = {
+  legacyPoolManager: PublicKey | Pda;
+  legacyVault: PublicKey | Pda;
+  baseMint: PublicKey | Pda;
+  /** The legacy x mint, handed over to the new pool manager */
+  xMint: PublicKey | Pda;
+  metadata: PublicKey | Pda;
+  poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  rateFeed: PublicKey | Pda;
+  rateHistory: PublicKey | Pda;
+  vault: PublicKey | Pda;
+  /** Token manager of the base mint, bound to the pool for yield minting */
+  tokenManager: PublicKey | Pda;
+  owner: Signer;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  tokenMetadataProgram?: PublicKey | Pda;
+  associatedTokenProgram: PublicKey | Pda;
+  parityIssuanceProgram: PublicKey | Pda;
+};
+
+// Data.
+export type MigratePoolManagerInstructionData = {
+  discriminator: Array<number>;
+  poolId: bigint;
+};
+
+export type MigratePoolManagerInstructionDataArgs = { poolId: number | bigint };
+
+export function getMigratePoolManagerInstructionDataSerializer(): Serializer<
+  MigratePoolManagerInstructionDataArgs,
+  MigratePoolManagerInstructionData
+> {
+  return mapSerializer<
+    MigratePoolManagerInstructionDataArgs,
+    any,
+    MigratePoolManagerInstructionData
+  >(
+    struct<MigratePoolManagerInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['poolId', u64()],
+      ],
+      { description: 'MigratePoolManagerInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [40, 181, 89, 155, 227, 240, 87, 5],
+    })
+  ) as Serializer<
+    MigratePoolManagerInstructionDataArgs,
+    MigratePoolManagerInstructionData
+  >;
+}
+
+// Args.
+export type MigratePoolManagerInstructionArgs =
+  MigratePoolManagerInstructionDataArgs;
+
+// Instruction.
+export function migratePoolManager(
+  context: Pick<Context, 'programs'>,
+  input: MigratePoolManagerInstructionAccounts &
+    MigratePoolManagerInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    legacyPoolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.legacyPoolManager ?? null,
+    },
+    legacyVault: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.legacyVault ?? null,
+    },
+    baseMint: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.baseMint ?? null,
+    },
+    xMint: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.xMint ?? null,
+    },
+    metadata: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.metadata ?? null,
+    },
+    poolManager: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    yieldSchedule: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    rateFeed: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.rateFeed ?? null,
+    },
+    rateHistory: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.rateHistory ?? null,
+    },
+    vault: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    tokenManager: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.tokenManager ?? null,
+    },
+    owner: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.owner ?? null,
+    },
+    systemProgram: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    tokenMetadataProgram: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.tokenMetadataProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    parityIssuanceProgram: {
+      index: 16,
+      isWritable: false as boolean,
+      value: input.parityIssuanceProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: MigratePoolManagerInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenMetadataProgram.value) {
+    resolvedAccounts.tokenMetadataProgram.value = context.programs.getPublicKey(
+      'mplTokenMetadata',
+      'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+    );
+    resolvedAccounts.tokenMetadataProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigratePoolManagerInstructionDataSerializer().serialize(
+    resolvedArgs as MigratePoolManagerInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,127 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigrateTokenManagerInstructionAccounts = {
+  tokenManager: PublicKey | Pda;
+  owner: Signer;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MigrateTokenManagerInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type MigrateTokenManagerInstructionDataArgs = {};
+
+export function getMigrateTokenManagerInstructionDataSerializer(): Serializer<
+  MigrateTokenManagerInstructionDataArgs,
+  MigrateTokenManagerInstructionData
+> {
+  return mapSerializer<
+    MigrateTokenManagerInstructionDataArgs,
+    any,
+    MigrateTokenManagerInstructionData
+  >(
+    struct<MigrateTokenManagerInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'MigrateTokenManagerInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [212, 209, 211, 49, 198, 71, 240, 15],
+    })
+  ) as Serializer<
+    MigrateTokenManagerInstructionDataArgs,
+    MigrateTokenManagerInstructionData
+  >;
+}
+
+// Instruction.
+export function migrateTokenManager(
+  context: Pick<Context, 'programs'>,
+  input: MigrateTokenManagerInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityIssuance',
+    'ALukFrRp8cFkWCEZamFVsBiFtxKYPLUUGRxskFh1g5ZX'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    tokenManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.tokenManager ?? null,
+    },
+    owner: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.owner ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigrateTokenManagerInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -34,6 +34,7 @@ export type MintAdminInstructionAccounts = {
   mint: PublicKey | Pda;
   minterMintAta: PublicKey | Pda;
   minter: Signer;
+  minterAuthorization?: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
   tokenProgram?: PublicKey | Pda;
   associatedTokenProgram: PublicKey | Pda;
@@ -102,18 +103,23 @@ export function mintAdmin(
       isWritable: false as boolean,
       value: input.minter ?? null,
     },
-    systemProgram: {
+    minterAuthorization: {
       index: 4,
       isWritable: false as boolean,
+      value: input.minterAuthorization ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     tokenProgram: {
-      index: 5,
+      index: 6,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
     associatedTokenProgram: {
-      index: 6,
+      index: 7,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
@@ -0,0 +1,263 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type OpenTermPositionInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  rateFeed: PublicKey | Pda;
+  rateHistory: PublicKey | Pda;
+  baseMint: PublicKey | Pda;
+  payerBaseMintAta: PublicKey | Pda;
+  xMint: PublicKey | Pda;
+  payerXMintAta: PublicKey | Pda;
+  vault: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
+  /** Required while the allowlist or a wallet deposit cap is enabled */
+  stakerEntry?: PublicKey | Pda;
+  payer?: Signer;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  xTokenProgram: PublicKey | Pda;
+  associatedTokenProgram: PublicKey | Pda;
+  tranche: PublicKey | Pda;
+  trancheVault: PublicKey | Pda;
+  termPosition: PublicKey | Pda;
+  systemProgram2?: PublicKey | Pda;
+};
+
+// Data.
+export type OpenTermPositionInstructionData = {
+  discriminator: Array<number>;
+  quantity: bigint;
+  positionId: bigint;
+};
+
+export type OpenTermPositionInstructionDataArgs = {
+  quantity: number | bigint;
+  positionId: number | bigint;
+};
+
+export function getOpenTermPositionInstructionDataSerializer(): Serializer<
+  OpenTermPositionInstructionDataArgs,
+  OpenTermPositionInstructionData
+> {
+  return mapSerializer<
+    OpenTermPositionInstructionDataArgs,
+    any,
+    OpenTermPositionInstructionData
+  >(
+    struct<OpenTermPositionInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['quantity', u64()],
+        ['positionId', u64()],
+      ],
+      { description: 'OpenTermPositionInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [9, 26, 208, 31, 33, 139, 92, 62] })
+  ) as Serializer<
+    OpenTermPositionInstructionDataArgs,
+    OpenTermPositionInstructionData
+  >;
+}
+
+// Args.
+export type OpenTermPositionInstructionArgs =
+  OpenTermPositionInstructionDataArgs;
+
+// Instruction.
+export function openTermPosition(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: OpenTermPositionInstructionAccounts & OpenTermPositionInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    yieldSchedule: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    rateFeed: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.rateFeed ?? null,
+    },
+    rateHistory: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.rateHistory ?? null,
+    },
+    baseMint: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.baseMint ?? null,
+    },
+    payerBaseMintAta: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.payerBaseMintAta ?? null,
+    },
+    xMint: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.xMint ?? null,
+    },
+    payerXMintAta: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.payerXMintAta ?? null,
+    },
+    vault: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    treasury: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    stakerEntry: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.stakerEntry ?? null,
+    },
+    payer: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    xTokenProgram: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.xTokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    tranche: {
+      index: 16,
+      isWritable: true as boolean,
+      value: input.tranche ?? null,
+    },
+    trancheVault: {
+      index: 17,
+      isWritable: true as boolean,
+      value: input.trancheVault ?? null,
+    },
+    termPosition: {
+      index: 18,
+      isWritable: true as boolean,
+      value: input.termPosition ?? null,
+    },
+    systemProgram2: {
+      index: 19,
+      isWritable: false as boolean,
+      value: input.systemProgram2 ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: OpenTermPositionInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram2.value) {
+    resolvedAccounts.systemProgram2.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram2.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getOpenTermPositionInstructionDataSerializer().serialize(
+    resolvedArgs as OpenTermPositionInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
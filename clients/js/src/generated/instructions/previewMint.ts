@@ -0,0 +1,113 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PreviewMintInstructionAccounts = { tokenManager: PublicKey | Pda };
+
+// Data.
+export type PreviewMintInstructionData = {
+  discriminator: Array<number>;
+  quantity: bigint;
+};
+
+export type PreviewMintInstructionDataArgs = { quantity: number | bigint };
+
+export function getPreviewMintInstructionDataSerializer(): Serializer<
+  PreviewMintInstructionDataArgs,
+  PreviewMintInstructionData
+> {
+  return mapSerializer<
+    PreviewMintInstructionDataArgs,
+    any,
+    PreviewMintInstructionData
+  >(
+    struct<PreviewMintInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['quantity', u64()],
+      ],
+      { description: 'PreviewMintInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [42, 71, 86, 125, 215, 190, 214, 81],
+    })
+  ) as Serializer<PreviewMintInstructionDataArgs, PreviewMintInstructionData>;
+}
+
+// Args.
+export type PreviewMintInstructionArgs = PreviewMintInstructionDataArgs;
+
+// Instruction.
+export function previewMint(
+  context: Pick<Context, 'programs'>,
+  input: PreviewMintInstructionAccounts & PreviewMintInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityIssuance',
+    'ALukFrRp8cFkWCEZamFVsBiFtxKYPLUUGRxskFh1g5ZX'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    tokenManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.tokenManager ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: PreviewMintInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPreviewMintInstructionDataSerializer().serialize(
+    resolvedArgs as PreviewMintInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
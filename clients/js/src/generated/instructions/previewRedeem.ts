@@ -0,0 +1,118 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PreviewRedeemInstructionAccounts = {
+  tokenManager: PublicKey | Pda;
+};
+
+// Data.
+export type PreviewRedeemInstructionData = {
+  discriminator: Array<number>;
+  quantity: bigint;
+};
+
+export type PreviewRedeemInstructionDataArgs = { quantity: number | bigint };
+
+export function getPreviewRedeemInstructionDataSerializer(): Serializer<
+  PreviewRedeemInstructionDataArgs,
+  PreviewRedeemInstructionData
+> {
+  return mapSerializer<
+    PreviewRedeemInstructionDataArgs,
+    any,
+    PreviewRedeemInstructionData
+  >(
+    struct<PreviewRedeemInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['quantity', u64()],
+      ],
+      { description: 'PreviewRedeemInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [122, 195, 125, 8, 168, 149, 50, 40],
+    })
+  ) as Serializer<
+    PreviewRedeemInstructionDataArgs,
+    PreviewRedeemInstructionData
+  >;
+}
+
+// Args.
+export type PreviewRedeemInstructionArgs = PreviewRedeemInstructionDataArgs;
+
+// Instruction.
+export function previewRedeem(
+  context: Pick<Context, 'programs'>,
+  input: PreviewRedeemInstructionAccounts & PreviewRedeemInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityIssuance',
+    'ALukFrRp8cFkWCEZamFVsBiFtxKYPLUUGRxskFh1g5ZX'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    tokenManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.tokenManager ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: PreviewRedeemInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPreviewRedeemInstructionDataSerializer().serialize(
+    resolvedArgs as PreviewRedeemInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,121 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PreviewStakeInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+};
+
+// Data.
+export type PreviewStakeInstructionData = {
+  discriminator: Array<number>;
+  quantity: bigint;
+};
+
+export type PreviewStakeInstructionDataArgs = { quantity: number | bigint };
+
+export function getPreviewStakeInstructionDataSerializer(): Serializer<
+  PreviewStakeInstructionDataArgs,
+  PreviewStakeInstructionData
+> {
+  return mapSerializer<
+    PreviewStakeInstructionDataArgs,
+    any,
+    PreviewStakeInstructionData
+  >(
+    struct<PreviewStakeInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['quantity', u64()],
+      ],
+      { description: 'PreviewStakeInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [224, 123, 211, 246, 112, 17, 216, 124],
+    })
+  ) as Serializer<PreviewStakeInstructionDataArgs, PreviewStakeInstructionData>;
+}
+
+// Args.
+export type PreviewStakeInstructionArgs = PreviewStakeInstructionDataArgs;
+
+// Instruction.
+export function previewStake(
+  context: Pick<Context, 'programs'>,
+  input: PreviewStakeInstructionAccounts & PreviewStakeInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    yieldSchedule: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: PreviewStakeInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPreviewStakeInstructionDataSerializer().serialize(
+    resolvedArgs as PreviewStakeInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,124 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PreviewUnstakeInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+};
+
+// Data.
+export type PreviewUnstakeInstructionData = {
+  discriminator: Array<number>;
+  quantity: bigint;
+};
+
+export type PreviewUnstakeInstructionDataArgs = { quantity: number | bigint };
+
+export function getPreviewUnstakeInstructionDataSerializer(): Serializer<
+  PreviewUnstakeInstructionDataArgs,
+  PreviewUnstakeInstructionData
+> {
+  return mapSerializer<
+    PreviewUnstakeInstructionDataArgs,
+    any,
+    PreviewUnstakeInstructionData
+  >(
+    struct<PreviewUnstakeInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['quantity', u64()],
+      ],
+      { description: 'PreviewUnstakeInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [68, 71, 23, 118, 65, 143, 232, 85],
+    })
+  ) as Serializer<
+    PreviewUnstakeInstructionDataArgs,
+    PreviewUnstakeInstructionData
+  >;
+}
+
+// Args.
+export type PreviewUnstakeInstructionArgs = PreviewUnstakeInstructionDataArgs;
+
+// Instruction.
+export function previewUnstake(
+  context: Pick<Context, 'programs'>,
+  input: PreviewUnstakeInstructionAccounts & PreviewUnstakeInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    yieldSchedule: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: PreviewUnstakeInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPreviewUnstakeInstructionDataSerializer().serialize(
+    resolvedArgs as PreviewUnstakeInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,112 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RefreshRateFeedInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  rateFeed: PublicKey | Pda;
+};
+
+// Data.
+export type RefreshRateFeedInstructionData = { discriminator: Array<number> };
+
+export type RefreshRateFeedInstructionDataArgs = {};
+
+export function getRefreshRateFeedInstructionDataSerializer(): Serializer<
+  RefreshRateFeedInstructionDataArgs,
+  RefreshRateFeedInstructionData
+> {
+  return mapSerializer<
+    RefreshRateFeedInstructionDataArgs,
+    any,
+    RefreshRateFeedInstructionData
+  >(
+    struct<RefreshRateFeedInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'RefreshRateFeedInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [40, 237, 69, 228, 26, 1, 240, 76] })
+  ) as Serializer<
+    RefreshRateFeedInstructionDataArgs,
+    RefreshRateFeedInstructionData
+  >;
+}
+
+// Instruction.
+export function refreshRateFeed(
+  context: Pick<Context, 'programs'>,
+  input: RefreshRateFeedInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    yieldSchedule: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    rateFeed: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.rateFeed ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRefreshRateFeedInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
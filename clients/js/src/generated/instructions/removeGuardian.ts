@@ -0,0 +1,116 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RemoveGuardianInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  guardian: PublicKey | Pda;
+  admin: Signer;
+};
+
+// Data.
+export type RemoveGuardianInstructionData = { discriminator: Array<number> };
+
+export type RemoveGuardianInstructionDataArgs = {};
+
+export function getRemoveGuardianInstructionDataSerializer(): Serializer<
+  RemoveGuardianInstructionDataArgs,
+  RemoveGuardianInstructionData
+> {
+  return mapSerializer<
+    RemoveGuardianInstructionDataArgs,
+    any,
+    RemoveGuardianInstructionData
+  >(
+    struct<RemoveGuardianInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'RemoveGuardianInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [72, 117, 160, 244, 155, 185, 71, 18],
+    })
+  ) as Serializer<
+    RemoveGuardianInstructionDataArgs,
+    RemoveGuardianInstructionData
+  >;
+}
+
+// Instruction.
+export function removeGuardian(
+  context: Pick<Context, 'programs'>,
+  input: RemoveGuardianInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    guardian: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.guardian ?? null,
+    },
+    admin: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRemoveGuardianInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,113 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RemoveMinterInstructionAccounts = {
+  tokenManager: PublicKey | Pda;
+  minter: PublicKey | Pda;
+  admin: Signer;
+};
+
+// Data.
+export type RemoveMinterInstructionData = { discriminator: Array<number> };
+
+export type RemoveMinterInstructionDataArgs = {};
+
+export function getRemoveMinterInstructionDataSerializer(): Serializer<
+  RemoveMinterInstructionDataArgs,
+  RemoveMinterInstructionData
+> {
+  return mapSerializer<
+    RemoveMinterInstructionDataArgs,
+    any,
+    RemoveMinterInstructionData
+  >(
+    struct<RemoveMinterInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'RemoveMinterInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [241, 69, 84, 16, 164, 232, 131, 79],
+    })
+  ) as Serializer<RemoveMinterInstructionDataArgs, RemoveMinterInstructionData>;
+}
+
+// Instruction.
+export function removeMinter(
+  context: Pick<Context, 'programs'>,
+  input: RemoveMinterInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityIssuance',
+    'ALukFrRp8cFkWCEZamFVsBiFtxKYPLUUGRxskFh1g5ZX'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    tokenManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.tokenManager ?? null,
+    },
+    minter: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.minter ?? null,
+    },
+    admin: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRemoveMinterInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
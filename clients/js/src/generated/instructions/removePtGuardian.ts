@@ -0,0 +1,116 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RemovePtGuardianInstructionAccounts = {
+  globalConfig: PublicKey | Pda;
+  guardian: PublicKey | Pda;
+  admin: Signer;
+};
+
+// Data.
+export type RemovePtGuardianInstructionData = { discriminator: Array<number> };
+
+export type RemovePtGuardianInstructionDataArgs = {};
+
+export function getRemovePtGuardianInstructionDataSerializer(): Serializer<
+  RemovePtGuardianInstructionDataArgs,
+  RemovePtGuardianInstructionData
+> {
+  return mapSerializer<
+    RemovePtGuardianInstructionDataArgs,
+    any,
+    RemovePtGuardianInstructionData
+  >(
+    struct<RemovePtGuardianInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'RemovePtGuardianInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [72, 117, 160, 244, 155, 185, 71, 18],
+    })
+  ) as Serializer<
+    RemovePtGuardianInstructionDataArgs,
+    RemovePtGuardianInstructionData
+  >;
+}
+
+// Instruction.
+export function removePtGuardian(
+  context: Pick<Context, 'programs'>,
+  input: RemovePtGuardianInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'ptStaking',
+    'AdXJ8Sr46ujd9DSLP5LRyF1BrqxT9azqmQqN2oTyV8cz'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    globalConfig: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.globalConfig ?? null,
+    },
+    guardian: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.guardian ?? null,
+    },
+    admin: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRemovePtGuardianInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,168 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ReportStrategyValueInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  strategy: PublicKey | Pda;
+  rateFeed: PublicKey | Pda;
+  baseMint: PublicKey | Pda;
+  vault: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
+  strategyProgram: PublicKey | Pda;
+  strategyAuthority: PublicKey | Pda;
+  strategyTokenAccount: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ReportStrategyValueInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type ReportStrategyValueInstructionDataArgs = {};
+
+export function getReportStrategyValueInstructionDataSerializer(): Serializer<
+  ReportStrategyValueInstructionDataArgs,
+  ReportStrategyValueInstructionData
+> {
+  return mapSerializer<
+    ReportStrategyValueInstructionDataArgs,
+    any,
+    ReportStrategyValueInstructionData
+  >(
+    struct<ReportStrategyValueInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'ReportStrategyValueInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [46, 242, 227, 214, 70, 121, 137, 6],
+    })
+  ) as Serializer<
+    ReportStrategyValueInstructionDataArgs,
+    ReportStrategyValueInstructionData
+  >;
+}
+
+// Instruction.
+export function reportStrategyValue(
+  context: Pick<Context, 'programs'>,
+  input: ReportStrategyValueInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    strategy: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.strategy ?? null,
+    },
+    rateFeed: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.rateFeed ?? null,
+    },
+    baseMint: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.baseMint ?? null,
+    },
+    vault: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    treasury: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    strategyProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.strategyProgram ?? null,
+    },
+    strategyAuthority: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.strategyAuthority ?? null,
+    },
+    strategyTokenAccount: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.strategyTokenAccount ?? null,
+    },
+    tokenProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getReportStrategyValueInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
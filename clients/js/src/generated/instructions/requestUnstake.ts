@@ -0,0 +1,271 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RequestUnstakeInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  rateFeed: PublicKey | Pda;
+  rateHistory: PublicKey | Pda;
+  tokenManager: PublicKey | Pda;
+  baseMint: PublicKey | Pda;
+  payerBaseMintAta: PublicKey | Pda;
+  xMint: PublicKey | Pda;
+  payerXMintAta: PublicKey | Pda;
+  vault: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
+  /** Releases the unstaked base from the wallet deposit cap when passed */
+  stakerEntry?: PublicKey | Pda;
+  payer?: Signer;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  xTokenProgram: PublicKey | Pda;
+  associatedTokenProgram: PublicKey | Pda;
+  minterAuthorization?: PublicKey | Pda;
+  parityIssuanceProgram: PublicKey | Pda;
+  unstakeTicket: PublicKey | Pda;
+  systemProgram2?: PublicKey | Pda;
+};
+
+// Data.
+export type RequestUnstakeInstructionData = {
+  discriminator: Array<number>;
+  quantity: bigint;
+  ticketId: bigint;
+};
+
+export type RequestUnstakeInstructionDataArgs = {
+  quantity: number | bigint;
+  ticketId: number | bigint;
+};
+
+export function getRequestUnstakeInstructionDataSerializer(): Serializer<
+  RequestUnstakeInstructionDataArgs,
+  RequestUnstakeInstructionData
+> {
+  return mapSerializer<
+    RequestUnstakeInstructionDataArgs,
+    any,
+    RequestUnstakeInstructionData
+  >(
+    struct<RequestUnstakeInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['quantity', u64()],
+        ['ticketId', u64()],
+      ],
+      { description: 'RequestUnstakeInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [44, 154, 110, 253, 160, 202, 54, 34],
+    })
+  ) as Serializer<
+    RequestUnstakeInstructionDataArgs,
+    RequestUnstakeInstructionData
+  >;
+}
+
+// Args.
+export type RequestUnstakeInstructionArgs = RequestUnstakeInstructionDataArgs;
+
+// Instruction.
+export function requestUnstake(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: RequestUnstakeInstructionAccounts & RequestUnstakeInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    yieldSchedule: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    rateFeed: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.rateFeed ?? null,
+    },
+    rateHistory: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.rateHistory ?? null,
+    },
+    tokenManager: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.tokenManager ?? null,
+    },
+    baseMint: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.baseMint ?? null,
+    },
+    payerBaseMintAta: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.payerBaseMintAta ?? null,
+    },
+    xMint: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.xMint ?? null,
+    },
+    payerXMintAta: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.payerXMintAta ?? null,
+    },
+    vault: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    treasury: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    stakerEntry: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.stakerEntry ?? null,
+    },
+    payer: {
+      index: 12,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    xTokenProgram: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.xTokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 16,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    minterAuthorization: {
+      index: 17,
+      isWritable: false as boolean,
+      value: input.minterAuthorization ?? null,
+    },
+    parityIssuanceProgram: {
+      index: 18,
+      isWritable: false as boolean,
+      value: input.parityIssuanceProgram ?? null,
+    },
+    unstakeTicket: {
+      index: 19,
+      isWritable: true as boolean,
+      value: input.unstakeTicket ?? null,
+    },
+    systemProgram2: {
+      index: 20,
+      isWritable: false as boolean,
+      value: input.systemProgram2 ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RequestUnstakeInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram2.value) {
+    resolvedAccounts.systemProgram2.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram2.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRequestUnstakeInstructionDataSerializer().serialize(
+    resolvedArgs as RequestUnstakeInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
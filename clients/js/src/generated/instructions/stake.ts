@@ -31,14 +31,21 @@ import {
 // Accounts.
 export type StakeInstructionAccounts = {
   poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  rateFeed: PublicKey | Pda;
+  rateHistory: PublicKey | Pda;
   baseMint: PublicKey | Pda;
   payerBaseMintAta: PublicKey | Pda;
   xMint: PublicKey | Pda;
   payerXMintAta: PublicKey | Pda;
   vault: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
+  /** Required while the allowlist or a wallet deposit cap is enabled */
+  stakerEntry?: PublicKey | Pda;
   payer?: Signer;
   systemProgram?: PublicKey | Pda;
   tokenProgram?: PublicKey | Pda;
+  xTokenProgram: PublicKey | Pda;
   associatedTokenProgram: PublicKey | Pda;
 };
 
@@ -90,48 +97,78 @@ export function stake(
       isWritable: true as boolean,
       value: input.poolManager ?? null,
     },
-    baseMint: {
+    yieldSchedule: {
       index: 1,
       isWritable: true as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    rateFeed: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.rateFeed ?? null,
+    },
+    rateHistory: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.rateHistory ?? null,
+    },
+    baseMint: {
+      index: 4,
+      isWritable: true as boolean,
       value: input.baseMint ?? null,
     },
     payerBaseMintAta: {
-      index: 2,
+      index: 5,
       isWritable: true as boolean,
       value: input.payerBaseMintAta ?? null,
     },
     xMint: {
-      index: 3,
+      index: 6,
       isWritable: true as boolean,
       value: input.xMint ?? null,
     },
     payerXMintAta: {
-      index: 4,
+      index: 7,
       isWritable: true as boolean,
       value: input.payerXMintAta ?? null,
     },
     vault: {
-      index: 5,
+      index: 8,
       isWritable: true as boolean,
       value: input.vault ?? null,
     },
+    treasury: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    stakerEntry: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.stakerEntry ?? null,
+    },
     payer: {
-      index: 6,
+      index: 11,
       isWritable: true as boolean,
       value: input.payer ?? null,
     },
     systemProgram: {
-      index: 7,
+      index: 12,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     tokenProgram: {
-      index: 8,
+      index: 13,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
+    xTokenProgram: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.xTokenProgram ?? null,
+    },
     associatedTokenProgram: {
-      index: 9,
+      index: 15,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
@@ -0,0 +1,143 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type TogglePtStakingActiveInstructionAccounts = {
+  globalConfig: PublicKey | Pda;
+  authority?: Signer;
+  guardian?: PublicKey | Pda;
+};
+
+// Data.
+export type TogglePtStakingActiveInstructionData = {
+  discriminator: Array<number>;
+  stakeActive: boolean;
+  unstakeActive: boolean;
+};
+
+export type TogglePtStakingActiveInstructionDataArgs = {
+  stakeActive: boolean;
+  unstakeActive: boolean;
+};
+
+export function getTogglePtStakingActiveInstructionDataSerializer(): Serializer<
+  TogglePtStakingActiveInstructionDataArgs,
+  TogglePtStakingActiveInstructionData
+> {
+  return mapSerializer<
+    TogglePtStakingActiveInstructionDataArgs,
+    any,
+    TogglePtStakingActiveInstructionData
+  >(
+    struct<TogglePtStakingActiveInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['stakeActive', bool()],
+        ['unstakeActive', bool()],
+      ],
+      { description: 'TogglePtStakingActiveInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [51, 157, 191, 23, 239, 202, 160, 93],
+    })
+  ) as Serializer<
+    TogglePtStakingActiveInstructionDataArgs,
+    TogglePtStakingActiveInstructionData
+  >;
+}
+
+// Args.
+export type TogglePtStakingActiveInstructionArgs =
+  TogglePtStakingActiveInstructionDataArgs;
+
+// Instruction.
+export function togglePtStakingActive(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: TogglePtStakingActiveInstructionAccounts &
+    TogglePtStakingActiveInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'ptStaking',
+    'AdXJ8Sr46ujd9DSLP5LRyF1BrqxT9azqmQqN2oTyV8cz'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    globalConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.globalConfig ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    guardian: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.guardian ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: TogglePtStakingActiveInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getTogglePtStakingActiveInstructionDataSerializer().serialize(
+    resolvedArgs as TogglePtStakingActiveInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
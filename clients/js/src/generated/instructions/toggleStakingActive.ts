@@ -0,0 +1,143 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ToggleStakingActiveInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  authority?: Signer;
+  guardian?: PublicKey | Pda;
+};
+
+// Data.
+export type ToggleStakingActiveInstructionData = {
+  discriminator: Array<number>;
+  stakeActive: boolean;
+  unstakeActive: boolean;
+};
+
+export type ToggleStakingActiveInstructionDataArgs = {
+  stakeActive: boolean;
+  unstakeActive: boolean;
+};
+
+export function getToggleStakingActiveInstructionDataSerializer(): Serializer<
+  ToggleStakingActiveInstructionDataArgs,
+  ToggleStakingActiveInstructionData
+> {
+  return mapSerializer<
+    ToggleStakingActiveInstructionDataArgs,
+    any,
+    ToggleStakingActiveInstructionData
+  >(
+    struct<ToggleStakingActiveInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['stakeActive', bool()],
+        ['unstakeActive', bool()],
+      ],
+      { description: 'ToggleStakingActiveInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [51, 157, 191, 23, 239, 202, 160, 93],
+    })
+  ) as Serializer<
+    ToggleStakingActiveInstructionDataArgs,
+    ToggleStakingActiveInstructionData
+  >;
+}
+
+// Args.
+export type ToggleStakingActiveInstructionArgs =
+  ToggleStakingActiveInstructionDataArgs;
+
+// Instruction.
+export function toggleStakingActive(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: ToggleStakingActiveInstructionAccounts &
+    ToggleStakingActiveInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    guardian: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.guardian ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ToggleStakingActiveInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getToggleStakingActiveInstructionDataSerializer().serialize(
+    resolvedArgs as ToggleStakingActiveInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
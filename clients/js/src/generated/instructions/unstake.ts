@@ -31,16 +31,24 @@ import {
 // Accounts.
 export type UnstakeInstructionAccounts = {
   poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  rateFeed: PublicKey | Pda;
+  rateHistory: PublicKey | Pda;
   tokenManager: PublicKey | Pda;
   baseMint: PublicKey | Pda;
   payerBaseMintAta: PublicKey | Pda;
   xMint: PublicKey | Pda;
   payerXMintAta: PublicKey | Pda;
   vault: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
+  /** Releases the unstaked base from the wallet deposit cap when passed */
+  stakerEntry?: PublicKey | Pda;
   payer?: Signer;
   systemProgram?: PublicKey | Pda;
   tokenProgram?: PublicKey | Pda;
+  xTokenProgram: PublicKey | Pda;
   associatedTokenProgram: PublicKey | Pda;
+  minterAuthorization?: PublicKey | Pda;
   parityIssuanceProgram: PublicKey | Pda;
 };
 
@@ -92,58 +100,93 @@ export function unstake(
       isWritable: true as boolean,
       value: input.poolManager ?? null,
     },
-    tokenManager: {
+    yieldSchedule: {
       index: 1,
       isWritable: true as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    rateFeed: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.rateFeed ?? null,
+    },
+    rateHistory: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.rateHistory ?? null,
+    },
+    tokenManager: {
+      index: 4,
+      isWritable: true as boolean,
       value: input.tokenManager ?? null,
     },
     baseMint: {
-      index: 2,
+      index: 5,
       isWritable: true as boolean,
       value: input.baseMint ?? null,
     },
     payerBaseMintAta: {
-      index: 3,
+      index: 6,
       isWritable: true as boolean,
       value: input.payerBaseMintAta ?? null,
     },
     xMint: {
-      index: 4,
+      index: 7,
       isWritable: true as boolean,
       value: input.xMint ?? null,
     },
     payerXMintAta: {
-      index: 5,
+      index: 8,
       isWritable: true as boolean,
       value: input.payerXMintAta ?? null,
     },
     vault: {
-      index: 6,
+      index: 9,
       isWritable: true as boolean,
       value: input.vault ?? null,
     },
+    treasury: {
+      index: 10,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    stakerEntry: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.stakerEntry ?? null,
+    },
     payer: {
-      index: 7,
+      index: 12,
       isWritable: true as boolean,
       value: input.payer ?? null,
     },
     systemProgram: {
-      index: 8,
+      index: 13,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     tokenProgram: {
-      index: 9,
+      index: 14,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
+    xTokenProgram: {
+      index: 15,
+      isWritable: false as boolean,
+      value: input.xTokenProgram ?? null,
+    },
     associatedTokenProgram: {
-      index: 10,
+      index: 16,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
+    minterAuthorization: {
+      index: 17,
+      isWritable: false as boolean,
+      value: input.minterAuthorization ?? null,
+    },
     parityIssuanceProgram: {
-      index: 11,
+      index: 18,
       isWritable: false as boolean,
       value: input.parityIssuanceProgram ?? null,
     },
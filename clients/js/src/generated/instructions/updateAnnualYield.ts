@@ -31,25 +31,30 @@ import {
 // Accounts.
 export type UpdateAnnualYieldInstructionAccounts = {
   poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  rateFeed: PublicKey | Pda;
   tokenManager: PublicKey | Pda;
   baseMint: PublicKey | Pda;
   xMint: PublicKey | Pda;
   vault: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
   admin: Signer;
   systemProgram?: PublicKey | Pda;
   tokenProgram?: PublicKey | Pda;
+  xTokenProgram: PublicKey | Pda;
   associatedTokenProgram: PublicKey | Pda;
+  minterAuthorization?: PublicKey | Pda;
   parityIssuanceProgram: PublicKey | Pda;
 };
 
 // Data.
 export type UpdateAnnualYieldInstructionData = {
   discriminator: Array<number>;
-  intervalAprRate: bigint;
+  annualYieldBps: bigint;
 };
 
 export type UpdateAnnualYieldInstructionDataArgs = {
-  intervalAprRate: number | bigint;
+  annualYieldBps: number | bigint;
 };
 
 export function getUpdateAnnualYieldInstructionDataSerializer(): Serializer<
@@ -64,7 +69,7 @@ export function getUpdateAnnualYieldInstructionDataSerializer(): Serializer<
     struct<UpdateAnnualYieldInstructionData>(
       [
         ['discriminator', array(u8(), { size: 8 })],
-        ['intervalAprRate', u64()],
+        ['annualYieldBps', u64()],
       ],
       { description: 'UpdateAnnualYieldInstructionData' }
     ),
@@ -100,48 +105,73 @@ export function updateAnnualYield(
       isWritable: true as boolean,
       value: input.poolManager ?? null,
     },
-    tokenManager: {
+    yieldSchedule: {
       index: 1,
       isWritable: true as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    rateFeed: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.rateFeed ?? null,
+    },
+    tokenManager: {
+      index: 3,
+      isWritable: true as boolean,
       value: input.tokenManager ?? null,
     },
     baseMint: {
-      index: 2,
+      index: 4,
       isWritable: true as boolean,
       value: input.baseMint ?? null,
     },
     xMint: {
-      index: 3,
-      isWritable: false as boolean,
+      index: 5,
+      isWritable: true as boolean,
       value: input.xMint ?? null,
     },
     vault: {
-      index: 4,
+      index: 6,
       isWritable: true as boolean,
       value: input.vault ?? null,
     },
+    treasury: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
     admin: {
-      index: 5,
+      index: 8,
       isWritable: true as boolean,
       value: input.admin ?? null,
     },
     systemProgram: {
-      index: 6,
+      index: 9,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     tokenProgram: {
-      index: 7,
+      index: 10,
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
+    xTokenProgram: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.xTokenProgram ?? null,
+    },
     associatedTokenProgram: {
-      index: 8,
+      index: 12,
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
+    minterAuthorization: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.minterAuthorization ?? null,
+    },
     parityIssuanceProgram: {
-      index: 9,
+      index: 14,
       isWritable: false as boolean,
       value: input.parityIssuanceProgram ?? null,
     },
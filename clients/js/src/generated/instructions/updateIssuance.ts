@@ -0,0 +1,107 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateIssuanceInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  owner: Signer;
+};
+
+// Data.
+export type UpdateIssuanceInstructionData = { discriminator: Array<number> };
+
+export type UpdateIssuanceInstructionDataArgs = {};
+
+export function getUpdateIssuanceInstructionDataSerializer(): Serializer<
+  UpdateIssuanceInstructionDataArgs,
+  UpdateIssuanceInstructionData
+> {
+  return mapSerializer<
+    UpdateIssuanceInstructionDataArgs,
+    any,
+    UpdateIssuanceInstructionData
+  >(
+    struct<UpdateIssuanceInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'UpdateIssuanceInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [23, 77, 68, 94, 25, 36, 66, 98] })
+  ) as Serializer<
+    UpdateIssuanceInstructionDataArgs,
+    UpdateIssuanceInstructionData
+  >;
+}
+
+// Instruction.
+export function updateIssuance(
+  context: Pick<Context, 'programs'>,
+  input: UpdateIssuanceInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    owner: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateIssuanceInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -19,10 +19,13 @@ import {
 import {
   Serializer,
   array,
+  bool,
+  i64,
   mapSerializer,
   option,
   publicKey as publicKeySerializer,
   struct,
+  u16,
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
@@ -31,6 +34,11 @@ import {
   ResolvedAccountsWithIndices,
   getAccountMetasAndSigners,
 } from '../shared';
+import {
+  FeeDestination,
+  FeeDestinationArgs,
+  getFeeDestinationSerializer,
+} from '../types';
 
 // Accounts.
 export type UpdatePoolManagerInstructionAccounts = {
@@ -44,11 +52,51 @@ export type UpdatePoolManagerInstructionData = {
   discriminator: Array<number>;
   newAdmin: Option<PublicKey>;
   newDepositCap: Option<bigint>;
+  newUnstakeCooldown: Option<bigint>;
+  newTreasury: Option<PublicKey>;
+  newInstantUnstakeFeeBps: Option<number>;
+  newInstantUnstakeFeeDestination: Option<FeeDestination>;
+  newLiquidityBufferTarget: Option<bigint>;
+  newMinStakeAmount: Option<bigint>;
+  newMinUnstakeAmount: Option<bigint>;
+  newMinAccrualInterval: Option<bigint>;
+  newKeeperReward: Option<bigint>;
+  newRewarder: Option<PublicKey>;
+  newVestingPeriod: Option<bigint>;
+  newPerformanceFeeBps: Option<number>;
+  newEntryFeeBps: Option<number>;
+  newEntryFeeDestination: Option<FeeDestination>;
+  newExitFeeBps: Option<number>;
+  newExitFeeDestination: Option<FeeDestination>;
+  newCheckpointInterval: Option<bigint>;
+  newMaxStrategyAllocationBps: Option<number>;
+  newAllowlistEnabled: Option<boolean>;
+  newWalletDepositCap: Option<bigint>;
 };
 
 export type UpdatePoolManagerInstructionDataArgs = {
   newAdmin: OptionOrNullable<PublicKey>;
   newDepositCap: OptionOrNullable<number | bigint>;
+  newUnstakeCooldown: OptionOrNullable<number | bigint>;
+  newTreasury: OptionOrNullable<PublicKey>;
+  newInstantUnstakeFeeBps: OptionOrNullable<number>;
+  newInstantUnstakeFeeDestination: OptionOrNullable<FeeDestinationArgs>;
+  newLiquidityBufferTarget: OptionOrNullable<number | bigint>;
+  newMinStakeAmount: OptionOrNullable<number | bigint>;
+  newMinUnstakeAmount: OptionOrNullable<number | bigint>;
+  newMinAccrualInterval: OptionOrNullable<number | bigint>;
+  newKeeperReward: OptionOrNullable<number | bigint>;
+  newRewarder: OptionOrNullable<PublicKey>;
+  newVestingPeriod: OptionOrNullable<number | bigint>;
+  newPerformanceFeeBps: OptionOrNullable<number>;
+  newEntryFeeBps: OptionOrNullable<number>;
+  newEntryFeeDestination: OptionOrNullable<FeeDestinationArgs>;
+  newExitFeeBps: OptionOrNullable<number>;
+  newExitFeeDestination: OptionOrNullable<FeeDestinationArgs>;
+  newCheckpointInterval: OptionOrNullable<number | bigint>;
+  newMaxStrategyAllocationBps: OptionOrNullable<number>;
+  newAllowlistEnabled: OptionOrNullable<boolean>;
+  newWalletDepositCap: OptionOrNullable<number | bigint>;
 };
 
 export function getUpdatePoolManagerInstructionDataSerializer(): Serializer<
@@ -65,6 +113,29 @@ export function getUpdatePoolManagerInstructionDataSerializer(): Serializer<
         ['discriminator', array(u8(), { size: 8 })],
         ['newAdmin', option(publicKeySerializer())],
         ['newDepositCap', option(u64())],
+        ['newUnstakeCooldown', option(i64())],
+        ['newTreasury', option(publicKeySerializer())],
+        ['newInstantUnstakeFeeBps', option(u16())],
+        [
+          'newInstantUnstakeFeeDestination',
+          option(getFeeDestinationSerializer()),
+        ],
+        ['newLiquidityBufferTarget', option(u64())],
+        ['newMinStakeAmount', option(u64())],
+        ['newMinUnstakeAmount', option(u64())],
+        ['newMinAccrualInterval', option(i64())],
+        ['newKeeperReward', option(u64())],
+        ['newRewarder', option(publicKeySerializer())],
+        ['newVestingPeriod', option(i64())],
+        ['newPerformanceFeeBps', option(u16())],
+        ['newEntryFeeBps', option(u16())],
+        ['newEntryFeeDestination', option(getFeeDestinationSerializer())],
+        ['newExitFeeBps', option(u16())],
+        ['newExitFeeDestination', option(getFeeDestinationSerializer())],
+        ['newCheckpointInterval', option(i64())],
+        ['newMaxStrategyAllocationBps', option(u16())],
+        ['newAllowlistEnabled', option(bool())],
+        ['newWalletDepositCap', option(u64())],
       ],
       { description: 'UpdatePoolManagerInstructionData' }
     ),
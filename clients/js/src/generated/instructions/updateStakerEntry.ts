@@ -0,0 +1,141 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  option,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateStakerEntryInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  stakerEntry: PublicKey | Pda;
+  admin: Signer;
+};
+
+// Data.
+export type UpdateStakerEntryInstructionData = {
+  discriminator: Array<number>;
+  newAllowed: Option<boolean>;
+  newDepositCap: Option<bigint>;
+};
+
+export type UpdateStakerEntryInstructionDataArgs = {
+  newAllowed: OptionOrNullable<boolean>;
+  newDepositCap: OptionOrNullable<number | bigint>;
+};
+
+export function getUpdateStakerEntryInstructionDataSerializer(): Serializer<
+  UpdateStakerEntryInstructionDataArgs,
+  UpdateStakerEntryInstructionData
+> {
+  return mapSerializer<
+    UpdateStakerEntryInstructionDataArgs,
+    any,
+    UpdateStakerEntryInstructionData
+  >(
+    struct<UpdateStakerEntryInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['newAllowed', option(bool())],
+        ['newDepositCap', option(u64())],
+      ],
+      { description: 'UpdateStakerEntryInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [202, 255, 12, 192, 164, 107, 156, 214],
+    })
+  ) as Serializer<
+    UpdateStakerEntryInstructionDataArgs,
+    UpdateStakerEntryInstructionData
+  >;
+}
+
+// Args.
+export type UpdateStakerEntryInstructionArgs =
+  UpdateStakerEntryInstructionDataArgs;
+
+// Instruction.
+export function updateStakerEntry(
+  context: Pick<Context, 'programs'>,
+  input: UpdateStakerEntryInstructionAccounts & UpdateStakerEntryInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    stakerEntry: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.stakerEntry ?? null,
+    },
+    admin: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateStakerEntryInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateStakerEntryInstructionDataSerializer().serialize(
+    resolvedArgs as UpdateStakerEntryInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,140 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  option,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateStrategyInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  strategy: PublicKey | Pda;
+  owner: Signer;
+};
+
+// Data.
+export type UpdateStrategyInstructionData = {
+  discriminator: Array<number>;
+  newCap: Option<bigint>;
+  newActive: Option<boolean>;
+};
+
+export type UpdateStrategyInstructionDataArgs = {
+  newCap: OptionOrNullable<number | bigint>;
+  newActive: OptionOrNullable<boolean>;
+};
+
+export function getUpdateStrategyInstructionDataSerializer(): Serializer<
+  UpdateStrategyInstructionDataArgs,
+  UpdateStrategyInstructionData
+> {
+  return mapSerializer<
+    UpdateStrategyInstructionDataArgs,
+    any,
+    UpdateStrategyInstructionData
+  >(
+    struct<UpdateStrategyInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['newCap', option(u64())],
+        ['newActive', option(bool())],
+      ],
+      { description: 'UpdateStrategyInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [16, 76, 138, 179, 171, 112, 196, 21],
+    })
+  ) as Serializer<
+    UpdateStrategyInstructionDataArgs,
+    UpdateStrategyInstructionData
+  >;
+}
+
+// Args.
+export type UpdateStrategyInstructionArgs = UpdateStrategyInstructionDataArgs;
+
+// Instruction.
+export function updateStrategy(
+  context: Pick<Context, 'programs'>,
+  input: UpdateStrategyInstructionAccounts & UpdateStrategyInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    strategy: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.strategy ?? null,
+    },
+    owner: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateStrategyInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateStrategyInstructionDataSerializer().serialize(
+    resolvedArgs as UpdateStrategyInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -51,6 +51,9 @@ export type UpdateTokenManagerOwnerInstructionData = {
   newMintFeeBps: Option<number>;
   newRedeemFeeBps: Option<number>;
   newExchangeRate: Option<bigint>;
+  newMaxExchangeRateChangeBps: Option<number>;
+  newExchangeRateUpdateInterval: Option<bigint>;
+  newExchangeRateRampDuration: Option<bigint>;
 };
 
 export type UpdateTokenManagerOwnerInstructionDataArgs = {
@@ -62,6 +65,9 @@ export type UpdateTokenManagerOwnerInstructionDataArgs = {
   newMintFeeBps: OptionOrNullable<number>;
   newRedeemFeeBps: OptionOrNullable<number>;
   newExchangeRate: OptionOrNullable<number | bigint>;
+  newMaxExchangeRateChangeBps: OptionOrNullable<number>;
+  newExchangeRateUpdateInterval: OptionOrNullable<number | bigint>;
+  newExchangeRateRampDuration: OptionOrNullable<number | bigint>;
 };
 
 export function getUpdateTokenManagerOwnerInstructionDataSerializer(): Serializer<
@@ -84,6 +90,9 @@ export function getUpdateTokenManagerOwnerInstructionDataSerializer(): Serialize
         ['newMintFeeBps', option(u16())],
         ['newRedeemFeeBps', option(u16())],
         ['newExchangeRate', option(u64())],
+        ['newMaxExchangeRateChangeBps', option(u16())],
+        ['newExchangeRateUpdateInterval', option(i64())],
+        ['newExchangeRateRampDuration', option(i64())],
       ],
       { description: 'UpdateTokenManagerOwnerInstructionData' }
     ),
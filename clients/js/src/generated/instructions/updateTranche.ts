@@ -0,0 +1,140 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  option,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateTrancheInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  tranche: PublicKey | Pda;
+  admin: Signer;
+};
+
+// Data.
+export type UpdateTrancheInstructionData = {
+  discriminator: Array<number>;
+  newAnnualYieldBps: Option<bigint>;
+  newActive: Option<boolean>;
+};
+
+export type UpdateTrancheInstructionDataArgs = {
+  newAnnualYieldBps: OptionOrNullable<number | bigint>;
+  newActive: OptionOrNullable<boolean>;
+};
+
+export function getUpdateTrancheInstructionDataSerializer(): Serializer<
+  UpdateTrancheInstructionDataArgs,
+  UpdateTrancheInstructionData
+> {
+  return mapSerializer<
+    UpdateTrancheInstructionDataArgs,
+    any,
+    UpdateTrancheInstructionData
+  >(
+    struct<UpdateTrancheInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['newAnnualYieldBps', option(u64())],
+        ['newActive', option(bool())],
+      ],
+      { description: 'UpdateTrancheInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [84, 15, 139, 146, 54, 173, 140, 248],
+    })
+  ) as Serializer<
+    UpdateTrancheInstructionDataArgs,
+    UpdateTrancheInstructionData
+  >;
+}
+
+// Args.
+export type UpdateTrancheInstructionArgs = UpdateTrancheInstructionDataArgs;
+
+// Instruction.
+export function updateTranche(
+  context: Pick<Context, 'programs'>,
+  input: UpdateTrancheInstructionAccounts & UpdateTrancheInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.poolManager ?? null,
+    },
+    tranche: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.tranche ?? null,
+    },
+    admin: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateTrancheInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateTrancheInstructionDataSerializer().serialize(
+    resolvedArgs as UpdateTrancheInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
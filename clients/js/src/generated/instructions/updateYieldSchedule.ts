@@ -0,0 +1,139 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  ScheduledRate,
+  ScheduledRateArgs,
+  getScheduledRateSerializer,
+} from '../types';
+
+// Accounts.
+export type UpdateYieldScheduleInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  yieldSchedule: PublicKey | Pda;
+  admin: Signer;
+};
+
+// Data.
+export type UpdateYieldScheduleInstructionData = {
+  discriminator: Array<number>;
+  scheduledRates: Array<ScheduledRate>;
+};
+
+export type UpdateYieldScheduleInstructionDataArgs = {
+  scheduledRates: Array<ScheduledRateArgs>;
+};
+
+export function getUpdateYieldScheduleInstructionDataSerializer(): Serializer<
+  UpdateYieldScheduleInstructionDataArgs,
+  UpdateYieldScheduleInstructionData
+> {
+  return mapSerializer<
+    UpdateYieldScheduleInstructionDataArgs,
+    any,
+    UpdateYieldScheduleInstructionData
+  >(
+    struct<UpdateYieldScheduleInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['scheduledRates', array(getScheduledRateSerializer())],
+      ],
+      { description: 'UpdateYieldScheduleInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [161, 102, 249, 54, 148, 155, 87, 171],
+    })
+  ) as Serializer<
+    UpdateYieldScheduleInstructionDataArgs,
+    UpdateYieldScheduleInstructionData
+  >;
+}
+
+// Args.
+export type UpdateYieldScheduleInstructionArgs =
+  UpdateYieldScheduleInstructionDataArgs;
+
+// Instruction.
+export function updateYieldSchedule(
+  context: Pick<Context, 'programs'>,
+  input: UpdateYieldScheduleInstructionAccounts &
+    UpdateYieldScheduleInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    yieldSchedule: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.yieldSchedule ?? null,
+    },
+    admin: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateYieldScheduleInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateYieldScheduleInstructionDataSerializer().serialize(
+    resolvedArgs as UpdateYieldScheduleInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,186 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type WithdrawFromStrategyInstructionAccounts = {
+  poolManager: PublicKey | Pda;
+  strategy: PublicKey | Pda;
+  baseMint: PublicKey | Pda;
+  vault: PublicKey | Pda;
+  treasury?: PublicKey | Pda;
+  strategyProgram: PublicKey | Pda;
+  strategyAuthority: PublicKey | Pda;
+  strategyTokenAccount: PublicKey | Pda;
+  owner: Signer;
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type WithdrawFromStrategyInstructionData = {
+  discriminator: Array<number>;
+  quantity: bigint;
+};
+
+export type WithdrawFromStrategyInstructionDataArgs = {
+  quantity: number | bigint;
+};
+
+export function getWithdrawFromStrategyInstructionDataSerializer(): Serializer<
+  WithdrawFromStrategyInstructionDataArgs,
+  WithdrawFromStrategyInstructionData
+> {
+  return mapSerializer<
+    WithdrawFromStrategyInstructionDataArgs,
+    any,
+    WithdrawFromStrategyInstructionData
+  >(
+    struct<WithdrawFromStrategyInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['quantity', u64()],
+      ],
+      { description: 'WithdrawFromStrategyInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [140, 239, 65, 54, 125, 128, 223, 125],
+    })
+  ) as Serializer<
+    WithdrawFromStrategyInstructionDataArgs,
+    WithdrawFromStrategyInstructionData
+  >;
+}
+
+// Args.
+export type WithdrawFromStrategyInstructionArgs =
+  WithdrawFromStrategyInstructionDataArgs;
+
+// Instruction.
+export function withdrawFromStrategy(
+  context: Pick<Context, 'programs'>,
+  input: WithdrawFromStrategyInstructionAccounts &
+    WithdrawFromStrategyInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'parityStaking',
+    'BZzrzzNm14rcF8edGVYY2NHyj9aQURFXubgEdRJoyzvH'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    poolManager: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.poolManager ?? null,
+    },
+    strategy: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.strategy ?? null,
+    },
+    baseMint: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.baseMint ?? null,
+    },
+    vault: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    treasury: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+    strategyProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.strategyProgram ?? null,
+    },
+    strategyAuthority: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.strategyAuthority ?? null,
+    },
+    strategyTokenAccount: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.strategyTokenAccount ?? null,
+    },
+    owner: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    tokenProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: WithdrawFromStrategyInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getWithdrawFromStrategyInstructionDataSerializer().serialize(
+    resolvedArgs as WithdrawFromStrategyInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
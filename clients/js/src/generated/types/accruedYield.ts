@@ -0,0 +1,48 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+/** Yield accounting returned by `get_accrued_yield`. */
+export type AccruedYield = {
+  accruedUnmintedYield: bigint;
+  principalAmount: bigint;
+  realizedYieldAmount: bigint;
+  totalYieldMinted: bigint;
+  totalBoostMinted: bigint;
+  totalKeeperRewards: bigint;
+  exchangeRate: bigint;
+};
+
+export type AccruedYieldArgs = {
+  accruedUnmintedYield: number | bigint;
+  principalAmount: number | bigint;
+  realizedYieldAmount: number | bigint;
+  totalYieldMinted: number | bigint;
+  totalBoostMinted: number | bigint;
+  totalKeeperRewards: number | bigint;
+  exchangeRate: number | bigint;
+};
+
+export function getAccruedYieldSerializer(): Serializer<
+  AccruedYieldArgs,
+  AccruedYield
+> {
+  return struct<AccruedYield>(
+    [
+      ['accruedUnmintedYield', u64()],
+      ['principalAmount', u64()],
+      ['realizedYieldAmount', u64()],
+      ['totalYieldMinted', u64()],
+      ['totalBoostMinted', u64()],
+      ['totalKeeperRewards', u64()],
+      ['exchangeRate', u64()],
+    ],
+    { description: 'AccruedYield' }
+  ) as Serializer<AccruedYieldArgs, AccruedYield>;
+}
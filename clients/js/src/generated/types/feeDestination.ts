@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum FeeDestination {
+  Stakers,
+  Treasury,
+}
+
+export type FeeDestinationArgs = FeeDestination;
+
+export function getFeeDestinationSerializer(): Serializer<
+  FeeDestinationArgs,
+  FeeDestination
+> {
+  return scalarEnum<FeeDestination>(FeeDestination, {
+    description: 'FeeDestination',
+  }) as Serializer<FeeDestinationArgs, FeeDestination>;
+}
@@ -6,6 +6,19 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './accruedYield';
 export * from './baseYieldPhase';
 export * from './exchangeRatePhase';
+export * from './feeDestination';
+export * from './legacyGlobalConfig';
+export * from './legacyPoolManager';
+export * from './mintPreview';
 export * from './pointsEarnedPhase';
+export * from './rateCheckpoint';
+export * from './rateObservation';
+export * from './redeemPreview';
+export * from './scheduledRate';
+export * from './stakePreview';
+export * from './unstakePath';
+export * from './unstakePreview';
+export * from './yieldMode';
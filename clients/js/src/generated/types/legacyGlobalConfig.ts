@@ -0,0 +1,85 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  BaseYieldPhase,
+  BaseYieldPhaseArgs,
+  ExchangeRatePhase,
+  ExchangeRatePhaseArgs,
+  PointsEarnedPhase,
+  PointsEarnedPhaseArgs,
+  getBaseYieldPhaseSerializer,
+  getExchangeRatePhaseSerializer,
+  getPointsEarnedPhaseSerializer,
+} from '.';
+
+/**
+ * Layout of the global config before the emergency controls were added,
+ * read by `migrate_global_config`.
+ */
+export type LegacyGlobalConfig = {
+  bump: number;
+  owner: PublicKey;
+  pendingOwner: PublicKey;
+  admin: PublicKey;
+  baseMint: PublicKey;
+  stakingVault: PublicKey;
+  baseMintDecimals: number;
+  stakedSupply: bigint;
+  depositCap: bigint;
+  exchangeRateHistory: Array<ExchangeRatePhase>;
+  pointsHistory: Array<PointsEarnedPhase>;
+  baseYieldHistory: Array<BaseYieldPhase>;
+};
+
+export type LegacyGlobalConfigArgs = {
+  bump: number;
+  owner: PublicKey;
+  pendingOwner: PublicKey;
+  admin: PublicKey;
+  baseMint: PublicKey;
+  stakingVault: PublicKey;
+  baseMintDecimals: number;
+  stakedSupply: number | bigint;
+  depositCap: number | bigint;
+  exchangeRateHistory: Array<ExchangeRatePhaseArgs>;
+  pointsHistory: Array<PointsEarnedPhaseArgs>;
+  baseYieldHistory: Array<BaseYieldPhaseArgs>;
+};
+
+export function getLegacyGlobalConfigSerializer(): Serializer<
+  LegacyGlobalConfigArgs,
+  LegacyGlobalConfig
+> {
+  return struct<LegacyGlobalConfig>(
+    [
+      ['bump', u8()],
+      ['owner', publicKeySerializer()],
+      ['pendingOwner', publicKeySerializer()],
+      ['admin', publicKeySerializer()],
+      ['baseMint', publicKeySerializer()],
+      ['stakingVault', publicKeySerializer()],
+      ['baseMintDecimals', u8()],
+      ['stakedSupply', u64()],
+      ['depositCap', u64()],
+      ['exchangeRateHistory', array(getExchangeRatePhaseSerializer())],
+      ['pointsHistory', array(getPointsEarnedPhaseSerializer())],
+      ['baseYieldHistory', array(getBaseYieldPhaseSerializer())],
+    ],
+    { description: 'LegacyGlobalConfig' }
+  ) as Serializer<LegacyGlobalConfigArgs, LegacyGlobalConfig>;
+}
@@ -0,0 +1,87 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i32,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Layout of the pool manager before pools were keyed by base mint and pool
+ * id, read by `migrate_pool_manager`.
+ */
+export type LegacyPoolManager = {
+  bump: number;
+  owner: PublicKey;
+  pendingOwner: PublicKey;
+  admin: PublicKey;
+  baseMint: PublicKey;
+  xMint: PublicKey;
+  baseMintDecimals: number;
+  xMintDecimals: number;
+  intervalAprRate: bigint;
+  secondsPerInterval: number;
+  initialExchangeRate: bigint;
+  lastYieldChangeExchangeRate: bigint;
+  inceptionTimestamp: bigint;
+  lastYieldChangeTimestamp: bigint;
+  baseBalance: bigint;
+  depositCap: bigint;
+};
+
+export type LegacyPoolManagerArgs = {
+  bump: number;
+  owner: PublicKey;
+  pendingOwner: PublicKey;
+  admin: PublicKey;
+  baseMint: PublicKey;
+  xMint: PublicKey;
+  baseMintDecimals: number;
+  xMintDecimals: number;
+  intervalAprRate: number | bigint;
+  secondsPerInterval: number;
+  initialExchangeRate: number | bigint;
+  lastYieldChangeExchangeRate: number | bigint;
+  inceptionTimestamp: number | bigint;
+  lastYieldChangeTimestamp: number | bigint;
+  baseBalance: number | bigint;
+  depositCap: number | bigint;
+};
+
+export function getLegacyPoolManagerSerializer(): Serializer<
+  LegacyPoolManagerArgs,
+  LegacyPoolManager
+> {
+  return struct<LegacyPoolManager>(
+    [
+      ['bump', u8()],
+      ['owner', publicKeySerializer()],
+      ['pendingOwner', publicKeySerializer()],
+      ['admin', publicKeySerializer()],
+      ['baseMint', publicKeySerializer()],
+      ['xMint', publicKeySerializer()],
+      ['baseMintDecimals', u8()],
+      ['xMintDecimals', u8()],
+      ['intervalAprRate', u64()],
+      ['secondsPerInterval', i32()],
+      ['initialExchangeRate', u64()],
+      ['lastYieldChangeExchangeRate', u64()],
+      ['inceptionTimestamp', i64()],
+      ['lastYieldChangeTimestamp', i64()],
+      ['baseBalance', u64()],
+      ['depositCap', u64()],
+    ],
+    { description: 'LegacyPoolManager' }
+  ) as Serializer<LegacyPoolManagerArgs, LegacyPoolManager>;
+}
@@ -0,0 +1,50 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  bool,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Quote returned by `preview_mint`. */
+export type MintPreview = {
+  mintAmount: bigint;
+  feeAmount: bigint;
+  quoteAmount: bigint;
+  exchangeRate: bigint;
+  remainingSlotVolume: bigint;
+  active: boolean;
+};
+
+export type MintPreviewArgs = {
+  mintAmount: number | bigint;
+  feeAmount: number | bigint;
+  quoteAmount: number | bigint;
+  exchangeRate: number | bigint;
+  remainingSlotVolume: number | bigint;
+  active: boolean;
+};
+
+export function getMintPreviewSerializer(): Serializer<
+  MintPreviewArgs,
+  MintPreview
+> {
+  return struct<MintPreview>(
+    [
+      ['mintAmount', u64()],
+      ['feeAmount', u64()],
+      ['quoteAmount', u64()],
+      ['exchangeRate', u64()],
+      ['remainingSlotVolume', u64()],
+      ['active', bool()],
+    ],
+    { description: 'MintPreview' }
+  ) as Serializer<MintPreviewArgs, MintPreview>;
+}
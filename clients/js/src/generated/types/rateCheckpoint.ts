@@ -0,0 +1,34 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  i64,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type RateCheckpoint = { timestamp: bigint; exchangeRate: bigint };
+
+export type RateCheckpointArgs = {
+  timestamp: number | bigint;
+  exchangeRate: number | bigint;
+};
+
+export function getRateCheckpointSerializer(): Serializer<
+  RateCheckpointArgs,
+  RateCheckpoint
+> {
+  return struct<RateCheckpoint>(
+    [
+      ['timestamp', i64()],
+      ['exchangeRate', u64()],
+    ],
+    { description: 'RateCheckpoint' }
+  ) as Serializer<RateCheckpointArgs, RateCheckpoint>;
+}
@@ -0,0 +1,41 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  i64,
+  struct,
+  u128,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type RateObservation = {
+  timestamp: bigint;
+  exchangeRate: bigint;
+  cumulativeExchangeRate: bigint;
+};
+
+export type RateObservationArgs = {
+  timestamp: number | bigint;
+  exchangeRate: number | bigint;
+  cumulativeExchangeRate: number | bigint;
+};
+
+export function getRateObservationSerializer(): Serializer<
+  RateObservationArgs,
+  RateObservation
+> {
+  return struct<RateObservation>(
+    [
+      ['timestamp', i64()],
+      ['exchangeRate', u64()],
+      ['cumulativeExchangeRate', u128()],
+    ],
+    { description: 'RateObservation' }
+  ) as Serializer<RateObservationArgs, RateObservation>;
+}
@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  bool,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Quote returned by `preview_redeem`. */
+export type RedeemPreview = {
+  quoteAmount: bigint;
+  feeAmount: bigint;
+  exchangeRate: bigint;
+  remainingSlotVolume: bigint;
+  active: boolean;
+};
+
+export type RedeemPreviewArgs = {
+  quoteAmount: number | bigint;
+  feeAmount: number | bigint;
+  exchangeRate: number | bigint;
+  remainingSlotVolume: number | bigint;
+  active: boolean;
+};
+
+export function getRedeemPreviewSerializer(): Serializer<
+  RedeemPreviewArgs,
+  RedeemPreview
+> {
+  return struct<RedeemPreview>(
+    [
+      ['quoteAmount', u64()],
+      ['feeAmount', u64()],
+      ['exchangeRate', u64()],
+      ['remainingSlotVolume', u64()],
+      ['active', bool()],
+    ],
+    { description: 'RedeemPreview' }
+  ) as Serializer<RedeemPreviewArgs, RedeemPreview>;
+}
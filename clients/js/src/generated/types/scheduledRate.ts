@@ -0,0 +1,37 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  i64,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type ScheduledRate = {
+  effectiveTimestamp: bigint;
+  annualYieldBps: bigint;
+};
+
+export type ScheduledRateArgs = {
+  effectiveTimestamp: number | bigint;
+  annualYieldBps: number | bigint;
+};
+
+export function getScheduledRateSerializer(): Serializer<
+  ScheduledRateArgs,
+  ScheduledRate
+> {
+  return struct<ScheduledRate>(
+    [
+      ['effectiveTimestamp', i64()],
+      ['annualYieldBps', u64()],
+    ],
+    { description: 'ScheduledRate' }
+  ) as Serializer<ScheduledRateArgs, ScheduledRate>;
+}
@@ -0,0 +1,42 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+/** Quote returned by `preview_stake`. */
+export type StakePreview = {
+  xAmount: bigint;
+  feeAmount: bigint;
+  exchangeRate: bigint;
+  minStakeAmount: bigint;
+  maxStakeAmount: bigint;
+};
+
+export type StakePreviewArgs = {
+  xAmount: number | bigint;
+  feeAmount: number | bigint;
+  exchangeRate: number | bigint;
+  minStakeAmount: number | bigint;
+  maxStakeAmount: number | bigint;
+};
+
+export function getStakePreviewSerializer(): Serializer<
+  StakePreviewArgs,
+  StakePreview
+> {
+  return struct<StakePreview>(
+    [
+      ['xAmount', u64()],
+      ['feeAmount', u64()],
+      ['exchangeRate', u64()],
+      ['minStakeAmount', u64()],
+      ['maxStakeAmount', u64()],
+    ],
+    { description: 'StakePreview' }
+  ) as Serializer<StakePreviewArgs, StakePreview>;
+}
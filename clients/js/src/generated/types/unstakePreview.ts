@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+import { UnstakePath, UnstakePathArgs, getUnstakePathSerializer } from '.';
+
+/** Quote returned by `preview_unstake`. */
+export type UnstakePreview = {
+  baseAmount: bigint;
+  feeAmount: bigint;
+  exchangeRate: bigint;
+  unstakePath: UnstakePath;
+  minUnstakeAmount: bigint;
+  maxBaseAmount: bigint;
+};
+
+export type UnstakePreviewArgs = {
+  baseAmount: number | bigint;
+  feeAmount: number | bigint;
+  exchangeRate: number | bigint;
+  unstakePath: UnstakePathArgs;
+  minUnstakeAmount: number | bigint;
+  maxBaseAmount: number | bigint;
+};
+
+export function getUnstakePreviewSerializer(): Serializer<
+  UnstakePreviewArgs,
+  UnstakePreview
+> {
+  return struct<UnstakePreview>(
+    [
+      ['baseAmount', u64()],
+      ['feeAmount', u64()],
+      ['exchangeRate', u64()],
+      ['unstakePath', getUnstakePathSerializer()],
+      ['minUnstakeAmount', u64()],
+      ['maxBaseAmount', u64()],
+    ],
+    { description: 'UnstakePreview' }
+  ) as Serializer<UnstakePreviewArgs, UnstakePreview>;
+}
@@ -0,0 +1,23 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/** How the exchange rate grows. */
+export enum YieldMode {
+  Compounding,
+  RewardStreaming,
+}
+
+export type YieldModeArgs = YieldMode;
+
+export function getYieldModeSerializer(): Serializer<YieldModeArgs, YieldMode> {
+  return scalarEnum<YieldMode>(YieldMode, {
+    description: 'YieldMode',
+  }) as Serializer<YieldModeArgs, YieldMode>;
+}
@@ -1,20 +1,51 @@
-import { publicKey, TransactionBuilder, Umi } from '@metaplex-foundation/umi';
+import {
+  defaultPublicKey,
+  publicKey,
+  publicKeyBytes,
+  TransactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
 import { findMetadataPda } from '@metaplex-foundation/mpl-token-metadata';
 import {
+  createAssociatedToken,
   findAssociatedTokenPda,
   SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+  SPL_TOKEN_PROGRAM_ID,
 } from '@metaplex-foundation/mpl-toolbox';
 import {
   findGlobalConfigPda,
   findPoolManagerPda,
+  findRateFeedPda,
+  findRateHistoryPda,
   findTokenManagerPda,
+  findYieldSchedulePda,
   initializeGlobalConfig,
   initializePoolManager,
   initializeTokenManager,
+  mint,
   PARITY_ISSUANCE_PROGRAM_ID,
   PARITY_STAKING_PROGRAM_ID,
+  YieldMode,
 } from '../generated';
-import { getMerkleRoot } from '../utils';
+import { getMerkleProof, getMerkleRoot } from '../utils';
+
+/** x tokens locked in every pool at initialization */
+export const DEAD_SHARES = 1000;
+
+/**
+ * Base tokens to mint so that, after the mint fee, the pool owner
+ * holds enough to back the dead shares at the initial exchange rate.
+ */
+export const getDeadSharesMintQuantity = (
+  initialExchangeRate: number,
+  xMintDecimals: number,
+  mintFeeBps: number
+): number => {
+  const baseAmount = Math.ceil(
+    (DEAD_SHARES * initialExchangeRate) / 10 ** xMintDecimals
+  );
+  return Math.ceil((baseAmount * 10000) / (10000 - mintFeeBps));
+};
 
 export type SetupOptions = {
   baseMintDecimals: number;
@@ -33,8 +64,15 @@ export type SetupOptions = {
   allowList: string[];
   withdrawExecutionWindow: number;
   withdrawTimeLock: number;
-  intervalAprRate: number;
+  maxExchangeRateChangeBps: number;
+  exchangeRateUpdateInterval: number;
+  exchangeRateRampDuration: number;
+  poolId: number;
+  annualYieldBps: number;
   secondsPerInterval: number;
+  unstakeCooldown: number;
+  minStakeAmount: number;
+  minUnstakeAmount: number;
   mintFeeBps: number;
   redeemFeeBps: number;
   depositCapParityStaking: number;
@@ -47,14 +85,26 @@ export async function setup(umi: Umi, setupOptions: SetupOptions) {
   const baseMint = umi.eddsa.findPda(PARITY_ISSUANCE_PROGRAM_ID, [
     Buffer.from('mint'),
   ])[0];
-  const xMint = umi.eddsa.findPda(PARITY_STAKING_PROGRAM_ID, [
-    Buffer.from('mint'),
-  ])[0];
 
   const tokenManager = findTokenManagerPda(umi)[0];
-  const poolManager = findPoolManagerPda(umi)[0];
+  const poolManager = findPoolManagerPda(umi, {
+    baseMint,
+    poolId: setupOptions.poolId,
+  })[0];
   const globalConfig = findGlobalConfigPda(umi)[0];
 
+  const xMint = umi.eddsa.findPda(PARITY_STAKING_PROGRAM_ID, [
+    Buffer.from('mint'),
+    publicKeyBytes(poolManager),
+  ])[0];
+  const deadShares = umi.eddsa.findPda(PARITY_STAKING_PROGRAM_ID, [
+    Buffer.from('dead-shares'),
+    publicKeyBytes(poolManager),
+  ])[0];
+  const yieldSchedule = findYieldSchedulePda(umi, { poolManager });
+  const rateFeed = findRateFeedPda(umi, { poolManager });
+  const rateHistory = findRateHistoryPda(umi, { poolManager });
+
   const vaultIssuance = findAssociatedTokenPda(umi, {
     owner: tokenManager,
     mint: publicKey(setupOptions.quoteMint),
@@ -63,7 +113,14 @@ export async function setup(umi: Umi, setupOptions: SetupOptions) {
     owner: poolManager,
     mint: baseMint,
   });
-
+  const ownerBaseMintAta = findAssociatedTokenPda(umi, {
+    owner: umi.identity.publicKey,
+    mint: baseMint,
+  });
+  const ownerQuoteMintAta = findAssociatedTokenPda(umi, {
+    owner: umi.identity.publicKey,
+    mint: publicKey(setupOptions.quoteMint),
+  });
 
   const vaultPtStaking = findAssociatedTokenPda(umi, {
     owner: globalConfig,
@@ -110,28 +167,74 @@ export async function setup(umi: Umi, setupOptions: SetupOptions) {
         withdrawTimeLock: setupOptions.withdrawTimeLock,
         mintFeeBps: setupOptions.mintFeeBps,
         redeemFeeBps: setupOptions.redeemFeeBps,
+        maxExchangeRateChangeBps: setupOptions.maxExchangeRateChangeBps,
+        exchangeRateUpdateInterval: setupOptions.exchangeRateUpdateInterval,
+        exchangeRateRampDuration: setupOptions.exchangeRateRampDuration,
+      })
+    )
+    // The pool owner backs the dead shares with base tokens
+    .add(
+      createAssociatedToken(umi, {
+        mint: baseMint,
+      })
+    )
+    .add(
+      mint(umi, {
+        tokenManager,
+        mint: baseMint,
+        quoteMint: publicKey(setupOptions.quoteMint),
+        payerMintAta: ownerBaseMintAta,
+        payerQuoteMintAta: ownerQuoteMintAta,
+        vault: vaultIssuance,
+        associatedTokenProgram: SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+        quantity: getDeadSharesMintQuantity(
+          setupOptions.stakingInitialExchangeRate,
+          setupOptions.xMintDecimals,
+          setupOptions.mintFeeBps
+        ),
+        proof: getMerkleProof(
+          setupOptions.allowList,
+          umi.identity.publicKey.toString()
+        ),
       })
     )
     .add(
       initializePoolManager(umi, {
         poolManager,
+        yieldSchedule,
+        rateFeed,
+        rateHistory,
         vault: vaultStaking,
+        ownerBaseMintAta,
+        deadShares,
+        tokenManager,
         metadata: xMetadata,
         baseMint,
         xMint,
+        xTokenProgram: SPL_TOKEN_PROGRAM_ID,
         associatedTokenProgram: SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+        parityIssuanceProgram: PARITY_ISSUANCE_PROGRAM_ID,
+        poolId: setupOptions.poolId,
         name: setupOptions.xMintName,
         symbol: setupOptions.xMintSymbol,
         uri: setupOptions.xMintUri,
         decimals: setupOptions.xMintDecimals,
-        intervalAprRate: setupOptions.intervalAprRate,
+        annualYieldBps: setupOptions.annualYieldBps,
         secondsPerInterval: setupOptions.secondsPerInterval,
         initialExchangeRate: setupOptions.stakingInitialExchangeRate,
         depositCap: setupOptions.depositCapParityStaking,
+        unstakeCooldown: setupOptions.unstakeCooldown,
+        minStakeAmount: setupOptions.minStakeAmount,
+        minUnstakeAmount: setupOptions.minUnstakeAmount,
+        yieldMode: YieldMode.Compounding,
+        rewarder: defaultPublicKey(),
+        vestingPeriod: 0,
+        interestBearingXMint: false,
         owner: umi.identity,
         admin: umi.identity.publicKey,
       })
-    ).add(
+    )
+    .add(
       initializeGlobalConfig(umi, {
         globalConfig,
         baseMint,
@@ -49,6 +49,8 @@ pub struct GlobalConfig {
     pub base_mint_decimals: u8,
     pub staked_supply: u64,
     pub deposit_cap: u64,
+    pub stake_active: bool,
+    pub unstake_active: bool,
     pub exchange_rate_history: Vec<ExchangeRatePhase>,
     pub points_history: Vec<PointsEarnedPhase>,
     pub base_yield_history: Vec<BaseYieldPhase>,
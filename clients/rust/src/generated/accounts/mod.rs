@@ -7,12 +7,34 @@
 
 pub(crate) mod r#gatekeeper;
 pub(crate) mod r#global_config;
+pub(crate) mod r#guardian;
+pub(crate) mod r#minter;
 pub(crate) mod r#pool_manager;
+pub(crate) mod r#pt_guardian;
+pub(crate) mod r#rate_feed;
+pub(crate) mod r#rate_history;
+pub(crate) mod r#staker_entry;
+pub(crate) mod r#strategy;
+pub(crate) mod r#term_position;
 pub(crate) mod r#token_manager;
+pub(crate) mod r#tranche;
+pub(crate) mod r#unstake_ticket;
 pub(crate) mod r#user_stake;
+pub(crate) mod r#yield_schedule;
 
 pub use self::r#gatekeeper::*;
 pub use self::r#global_config::*;
+pub use self::r#guardian::*;
+pub use self::r#minter::*;
 pub use self::r#pool_manager::*;
+pub use self::r#pt_guardian::*;
+pub use self::r#rate_feed::*;
+pub use self::r#rate_history::*;
+pub use self::r#staker_entry::*;
+pub use self::r#strategy::*;
+pub use self::r#term_position::*;
 pub use self::r#token_manager::*;
+pub use self::r#tranche::*;
+pub use self::r#unstake_ticket::*;
 pub use self::r#user_stake::*;
+pub use self::r#yield_schedule::*;
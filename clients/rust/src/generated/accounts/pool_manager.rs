@@ -5,6 +5,8 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::FeeDestination;
+use crate::generated::types::YieldMode;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -18,6 +20,7 @@ use solana_program::pubkey::Pubkey;
 pub struct PoolManager {
     pub discriminator: [u8; 8],
     pub bump: u8,
+    pub pool_id: u64,
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -45,6 +48,7 @@ pub struct PoolManager {
     pub x_mint: Pubkey,
     pub base_mint_decimals: u8,
     pub x_mint_decimals: u8,
+    pub annual_yield_bps: u64,
     pub interval_apr_rate: u64,
     pub seconds_per_interval: i32,
     pub initial_exchange_rate: u64,
@@ -53,30 +57,114 @@ pub struct PoolManager {
     pub last_yield_change_timestamp: i64,
     pub base_balance: u64,
     pub deposit_cap: u64,
+    pub unstake_cooldown: i64,
+    pub pending_unstake_amount: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub treasury: Pubkey,
+    pub instant_unstake_fee_bps: u16,
+    pub instant_unstake_fee_destination: FeeDestination,
+    pub liquidity_buffer_target: u64,
+    pub liquidity_buffer_amount: u64,
+    pub min_stake_amount: u64,
+    pub min_unstake_amount: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub issuance_program: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub token_manager: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pending_issuance_program: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pending_token_manager: Pubkey,
+    pub issuance_update_timestamp: i64,
+    pub principal_amount: u64,
+    pub realized_yield_amount: u64,
+    pub total_yield_minted: u64,
+    pub total_boost_minted: u64,
+    pub min_accrual_interval: i64,
+    pub last_accrual_timestamp: i64,
+    pub keeper_reward: u64,
+    pub total_keeper_rewards: u64,
+    pub yield_mode: YieldMode,
+    pub x_supply: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub rewarder: Pubkey,
+    pub vesting_period: i64,
+    pub vesting_amount: u64,
+    pub vesting_start_timestamp: i64,
+    pub total_rewards_deposited: u64,
+    pub performance_fee_bps: u16,
+    pub total_performance_fees: u64,
+    pub entry_fee_bps: u16,
+    pub entry_fee_destination: FeeDestination,
+    pub exit_fee_bps: u16,
+    pub exit_fee_destination: FeeDestination,
+    pub stake_active: bool,
+    pub unstake_active: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub x_token_program: Pubkey,
+    pub checkpoint_interval: i64,
+    pub max_strategy_allocation_bps: u16,
+    pub strategy_value: u64,
+    pub allowlist_enabled: bool,
+    pub wallet_deposit_cap: u64,
 }
 
 impl PoolManager {
-    pub const LEN: usize = 231;
+    pub const LEN: usize = 680;
 
     /// Prefix values used to generate a PDA for this account.
     ///
     /// Values are positional and appear in the following order:
     ///
     ///   0. `PoolManager::PREFIX`
+    ///   1. base_mint (`Pubkey`)
+    ///   2. pool_id (`u64`)
     pub const PREFIX: &'static [u8] = "pool-manager".as_bytes();
 
     pub fn create_pda(
+        base_mint: Pubkey,
+        pool_id: u64,
         bump: u8,
     ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
         solana_program::pubkey::Pubkey::create_program_address(
-            &["pool-manager".as_bytes(), &[bump]],
+            &[
+                "pool-manager".as_bytes(),
+                base_mint.as_ref(),
+                pool_id.to_string().as_ref(),
+                &[bump],
+            ],
             &crate::PARITY_STAKING_ID,
         )
     }
 
-    pub fn find_pda() -> (solana_program::pubkey::Pubkey, u8) {
+    pub fn find_pda(base_mint: &Pubkey, pool_id: u64) -> (solana_program::pubkey::Pubkey, u8) {
         solana_program::pubkey::Pubkey::find_program_address(
-            &["pool-manager".as_bytes()],
+            &[
+                "pool-manager".as_bytes(),
+                base_mint.as_ref(),
+                pool_id.to_string().as_ref(),
+            ],
             &crate::PARITY_STAKING_ID,
         )
     }
@@ -0,0 +1,116 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::RateObservation;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Exchange rate of a pool published for other programs, at
+/// `[b"rate-feed", pool_manager]`. The layout is stable: fields are only ever
+/// appended, with `version` bumped, so readers can rely on these offsets after
+/// the 8 byte discriminator:
+///
+/// | offset | field                      | type                 |
+/// |--------|----------------------------|----------------------|
+/// | 0      | `version`                  | u8                   |
+/// | 1      | `bump`                     | u8                   |
+/// | 2      | `pool_manager`             | Pubkey               |
+/// | 34     | `x_mint`                   | Pubkey               |
+/// | 66     | `base_mint`                | Pubkey               |
+/// | 98     | `base_mint_decimals`       | u8                   |
+/// | 99     | `exchange_rate`            | u64                  |
+/// | 107    | `last_update_timestamp`    | i64                  |
+/// | 115    | `twap_exchange_rate`       | u64                  |
+/// | 123    | `realized_apy_bps`         | u64                  |
+/// | 131    | `cumulative_exchange_rate` | u128                 |
+/// | 147    | `next_observation_index`   | u32                  |
+/// | 151    | `observations`             | Vec<RateObservation> |
+///
+/// Integers are little-endian. Rates are base per x, in base mint decimals.
+///
+/// `twap_exchange_rate` is the average rate over the last
+/// `RATE_FEED_TWAP_WINDOW`, taken from the cumulative rate against the
+/// observations around the start of the window.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateFeed {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+    pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pool_manager: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub x_mint: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub base_mint: Pubkey,
+    pub base_mint_decimals: u8,
+    pub exchange_rate: u64,
+    pub last_update_timestamp: i64,
+    pub twap_exchange_rate: u64,
+    pub realized_apy_bps: u64,
+    pub cumulative_exchange_rate: u128,
+    pub next_observation_index: u32,
+    pub observations: Vec<RateObservation>,
+}
+
+impl RateFeed {
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `RateFeed::PREFIX`
+    ///   1. pool_manager (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "rate-feed".as_bytes();
+
+    pub fn create_pda(
+        pool_manager: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &["rate-feed".as_bytes(), pool_manager.as_ref(), &[bump]],
+            &crate::PARITY_STAKING_ID,
+        )
+    }
+
+    pub fn find_pda(pool_manager: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &["rate-feed".as_bytes(), pool_manager.as_ref()],
+            &crate::PARITY_STAKING_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for RateFeed {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
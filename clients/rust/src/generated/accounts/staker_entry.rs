@@ -0,0 +1,95 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Staking allowance of a wallet, at `[b"staker-entry", pool_manager, wallet]`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakerEntry {
+    pub discriminator: [u8; 8],
+    pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pool_manager: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub wallet: Pubkey,
+    pub allowed: bool,
+    pub deposit_cap: u64,
+    pub staked_amount: u64,
+}
+
+impl StakerEntry {
+    pub const LEN: usize = 90;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `StakerEntry::PREFIX`
+    ///   1. pool_manager (`Pubkey`)
+    ///   2. wallet (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "staker-entry".as_bytes();
+
+    pub fn create_pda(
+        pool_manager: Pubkey,
+        wallet: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "staker-entry".as_bytes(),
+                pool_manager.as_ref(),
+                wallet.as_ref(),
+                &[bump],
+            ],
+            &crate::PARITY_STAKING_ID,
+        )
+    }
+
+    pub fn find_pda(
+        pool_manager: &Pubkey,
+        wallet: &Pubkey,
+    ) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "staker-entry".as_bytes(),
+                pool_manager.as_ref(),
+                wallet.as_ref(),
+            ],
+            &crate::PARITY_STAKING_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for StakerEntry {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
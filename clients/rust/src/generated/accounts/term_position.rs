@@ -0,0 +1,107 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TermPosition {
+    pub discriminator: [u8; 8],
+    pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub owner: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pool_manager: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub tranche: Pubkey,
+    pub position_id: u64,
+    pub principal_amount: u64,
+    pub x_amount: u64,
+    pub interval_apr_rate: u64,
+    pub start_timestamp: i64,
+    pub maturity_timestamp: i64,
+}
+
+impl TermPosition {
+    pub const LEN: usize = 153;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `TermPosition::PREFIX`
+    ///   1. pool_manager (`Pubkey`)
+    ///   2. owner (`Pubkey`)
+    ///   3. position_id (`u64`)
+    pub const PREFIX: &'static [u8] = "term-position".as_bytes();
+
+    pub fn create_pda(
+        pool_manager: Pubkey,
+        owner: Pubkey,
+        position_id: u64,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "term-position".as_bytes(),
+                pool_manager.as_ref(),
+                owner.as_ref(),
+                position_id.to_string().as_ref(),
+                &[bump],
+            ],
+            &crate::PARITY_STAKING_ID,
+        )
+    }
+
+    pub fn find_pda(
+        pool_manager: &Pubkey,
+        owner: &Pubkey,
+        position_id: u64,
+    ) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "term-position".as_bytes(),
+                pool_manager.as_ref(),
+                owner.as_ref(),
+                position_id.to_string().as_ref(),
+            ],
+            &crate::PARITY_STAKING_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for TermPosition {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
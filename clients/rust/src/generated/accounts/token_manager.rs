@@ -65,10 +65,16 @@ pub struct TokenManager {
     pub total_collateral: u64,
     pub mint_fee_bps: u16,
     pub redeem_fee_bps: u16,
+    pub max_exchange_rate_change_bps: u16,
+    pub exchange_rate_update_interval: i64,
+    pub exchange_rate_ramp_duration: i64,
+    pub previous_exchange_rate: u64,
+    pub last_exchange_rate_update: i64,
+    pub exchange_rate_ramp_end: i64,
 }
 
 impl TokenManager {
-    pub const LEN: usize = 315;
+    pub const LEN: usize = 357;
 
     /// Prefix values used to generate a PDA for this account.
     ///
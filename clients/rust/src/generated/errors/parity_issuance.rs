@@ -70,6 +70,15 @@ pub enum ParityIssuanceError {
     /// 6019 (0x1783) - An Invalid Parameter was passed
     #[error("An Invalid Parameter was passed")]
     InvalidParam,
+    /// 6020 (0x1784) - Exchange rate change exceeds the maximum per update
+    #[error("Exchange rate change exceeds the maximum per update")]
+    ExchangeRateChangeTooLarge,
+    /// 6021 (0x1785) - Exchange rate updated too soon
+    #[error("Exchange rate updated too soon")]
+    ExchangeRateUpdateTooSoon,
+    /// 6022 (0x1786) - Token manager already migrated
+    #[error("Token manager already migrated")]
+    TokenManagerAlreadyMigrated,
 }
 
 impl solana_program::program_error::PrintProgramError for ParityIssuanceError {
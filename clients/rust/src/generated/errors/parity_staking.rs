@@ -43,6 +43,105 @@ pub enum ParityStakingError {
     /// 6010 (0x177A) - An Invalid Parameter was passed
     #[error("An Invalid Parameter was passed")]
     InvalidParam,
+    /// 6011 (0x177B) - Slippage exceeded
+    #[error("Slippage exceeded")]
+    SlippageExceeded,
+    /// 6012 (0x177C) - Unstake cooldown not elapsed
+    #[error("Unstake cooldown not elapsed")]
+    UnstakeCooldownNotElapsed,
+    /// 6013 (0x177D) - Insufficient liquidity buffer, request an unstake ticket instead
+    #[error("Insufficient liquidity buffer, request an unstake ticket instead")]
+    InsufficientLiquidityBuffer,
+    /// 6014 (0x177E) - Invalid treasury
+    #[error("Invalid treasury")]
+    InvalidTreasury,
+    /// 6015 (0x177F) - Invalid yield schedule
+    #[error("Invalid yield schedule")]
+    InvalidYieldSchedule,
+    /// 6016 (0x1780) - Amount below minimum
+    #[error("Amount below minimum")]
+    AmountBelowMinimum,
+    /// 6017 (0x1781) - Invalid issuance program
+    #[error("Invalid issuance program")]
+    InvalidIssuanceProgram,
+    /// 6018 (0x1782) - Invalid token manager
+    #[error("Invalid token manager")]
+    InvalidTokenManager,
+    /// 6019 (0x1783) - Issuance update timelock has not elapsed
+    #[error("Issuance update timelock has not elapsed")]
+    IssuanceUpdateTimelockActive,
+    /// 6020 (0x1784) - Accrual interval not elapsed
+    #[error("Accrual interval not elapsed")]
+    AccrualIntervalNotElapsed,
+    /// 6021 (0x1785) - Invalid yield mode for this operation
+    #[error("Invalid yield mode for this operation")]
+    InvalidYieldMode,
+    /// 6022 (0x1786) - Invalid rewarder
+    #[error("Invalid rewarder")]
+    InvalidRewarder,
+    /// 6023 (0x1787) - Staking is paused
+    #[error("Staking is paused")]
+    StakingPaused,
+    /// 6024 (0x1788) - Unstaking is paused
+    #[error("Unstaking is paused")]
+    UnstakingPaused,
+    /// 6025 (0x1789) - Pool status unchanged
+    #[error("Pool status unchanged")]
+    PoolStatusUnchanged,
+    /// 6026 (0x178A) - Invalid toggle active authority
+    #[error("Invalid toggle active authority")]
+    InvalidToggleActiveAuthority,
+    /// 6027 (0x178B) - Tranche is not open to new positions
+    #[error("Tranche is not open to new positions")]
+    TrancheInactive,
+    /// 6028 (0x178C) - Invalid tranche
+    #[error("Invalid tranche")]
+    InvalidTranche,
+    /// 6029 (0x178D) - Invalid x token program
+    #[error("Invalid x token program")]
+    InvalidXTokenProgram,
+    /// 6030 (0x178E) - Interest rate out of range for the x mint
+    #[error("Interest rate out of range for the x mint")]
+    InvalidInterestRate,
+    /// 6031 (0x178F) - Rate history does not cover the window
+    #[error("Rate history does not cover the window")]
+    InsufficientRateHistory,
+    /// 6032 (0x1790) - Invalid strategy
+    #[error("Invalid strategy")]
+    InvalidStrategy,
+    /// 6033 (0x1791) - Strategy is not open to new allocations
+    #[error("Strategy is not open to new allocations")]
+    StrategyInactive,
+    /// 6034 (0x1792) - Strategy cap exceeded
+    #[error("Strategy cap exceeded")]
+    StrategyCapExceeded,
+    /// 6035 (0x1793) - Strategies would exceed their share of the pool assets
+    #[error("Strategies would exceed their share of the pool assets")]
+    StrategyAllocationExceeded,
+    /// 6036 (0x1794) - Vault balance reserved for unstakes
+    #[error("Vault balance reserved for unstakes")]
+    InsufficientVaultLiquidity,
+    /// 6037 (0x1795) - Invalid strategy authority
+    #[error("Invalid strategy authority")]
+    InvalidStrategyAuthority,
+    /// 6038 (0x1796) - Wallet is not on the allowlist
+    #[error("Wallet is not on the allowlist")]
+    StakerNotAllowed,
+    /// 6039 (0x1797) - Staker entry required
+    #[error("Staker entry required")]
+    StakerEntryRequired,
+    /// 6040 (0x1798) - Wallet deposit cap exceeded
+    #[error("Wallet deposit cap exceeded")]
+    WalletDepositCapExceeded,
+    /// 6041 (0x1799) - Invalid legacy pool manager
+    #[error("Invalid legacy pool manager")]
+    InvalidLegacyPoolManager,
+    /// 6042 (0x179A) - Keeper reward requires a minimum accrual interval
+    #[error("Keeper reward requires a minimum accrual interval")]
+    KeeperRewardWithoutAccrualInterval,
+    /// 6043 (0x179B) - Setting not supported with an interest-bearing x mint
+    #[error("Setting not supported with an interest-bearing x mint")]
+    InterestBearingXMintUnsupported,
 }
 
 impl solana_program::program_error::PrintProgramError for ParityStakingError {
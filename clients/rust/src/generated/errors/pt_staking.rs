@@ -46,6 +46,21 @@ pub enum PtStakingError {
     /// 6011 (0x177B) - An Invalid Parameter was passed
     #[error("An Invalid Parameter was passed")]
     InvalidParam,
+    /// 6012 (0x177C) - Staking is paused
+    #[error("Staking is paused")]
+    StakingPaused,
+    /// 6013 (0x177D) - Unstaking is paused
+    #[error("Unstaking is paused")]
+    UnstakingPaused,
+    /// 6014 (0x177E) - Status unchanged
+    #[error("Status unchanged")]
+    StatusUnchanged,
+    /// 6015 (0x177F) - Invalid toggle active authority
+    #[error("Invalid toggle active authority")]
+    InvalidToggleActiveAuthority,
+    /// 6016 (0x1780) - Invalid legacy global config
+    #[error("Invalid legacy global config")]
+    InvalidLegacyGlobalConfig,
 }
 
 impl solana_program::program_error::PrintProgramError for PtStakingError {
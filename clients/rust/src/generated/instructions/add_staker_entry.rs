@@ -0,0 +1,469 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Accounts.
+pub struct AddStakerEntry {
+    pub pool_manager: solana_program::pubkey::Pubkey,
+
+    pub staker_entry: solana_program::pubkey::Pubkey,
+    /// The admin, or the wallet itself while the allowlist is disabled
+    pub authority: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl AddStakerEntry {
+    pub fn instruction(
+        &self,
+        args: AddStakerEntryInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AddStakerEntryInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.pool_manager,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.staker_entry,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = AddStakerEntryInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct AddStakerEntryInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl AddStakerEntryInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [189, 132, 255, 193, 115, 22, 230, 247],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddStakerEntryInstructionArgs {
+    pub wallet: Pubkey,
+    pub deposit_cap: u64,
+}
+
+/// Instruction builder for `AddStakerEntry`.
+///
+/// ### Accounts:
+///
+///   0. `[]` pool_manager
+///   1. `[writable]` staker_entry
+///   2. `[writable, signer]` authority
+///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct AddStakerEntryBuilder {
+    pool_manager: Option<solana_program::pubkey::Pubkey>,
+    staker_entry: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    wallet: Option<Pubkey>,
+    deposit_cap: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AddStakerEntryBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn pool_manager(&mut self, pool_manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_entry(&mut self, staker_entry: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.staker_entry = Some(staker_entry);
+        self
+    }
+    /// The admin, or the wallet itself while the allowlist is disabled
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn wallet(&mut self, wallet: Pubkey) -> &mut Self {
+        self.wallet = Some(wallet);
+        self
+    }
+    #[inline(always)]
+    pub fn deposit_cap(&mut self, deposit_cap: u64) -> &mut Self {
+        self.deposit_cap = Some(deposit_cap);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AddStakerEntry {
+            pool_manager: self.pool_manager.expect("pool_manager is not set"),
+            staker_entry: self.staker_entry.expect("staker_entry is not set"),
+            authority: self.authority.expect("authority is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = AddStakerEntryInstructionArgs {
+            wallet: self.wallet.clone().expect("wallet is not set"),
+            deposit_cap: self.deposit_cap.clone().expect("deposit_cap is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `add_staker_entry` CPI accounts.
+pub struct AddStakerEntryCpiAccounts<'a, 'b> {
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_entry: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The admin, or the wallet itself while the allowlist is disabled
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `add_staker_entry` CPI instruction.
+pub struct AddStakerEntryCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_entry: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The admin, or the wallet itself while the allowlist is disabled
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AddStakerEntryInstructionArgs,
+}
+
+impl<'a, 'b> AddStakerEntryCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AddStakerEntryCpiAccounts<'a, 'b>,
+        args: AddStakerEntryInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            pool_manager: accounts.pool_manager,
+            staker_entry: accounts.staker_entry,
+            authority: accounts.authority,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.pool_manager.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.staker_entry.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = AddStakerEntryInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.pool_manager.clone());
+        account_infos.push(self.staker_entry.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AddStakerEntry` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` pool_manager
+///   1. `[writable]` staker_entry
+///   2. `[writable, signer]` authority
+///   3. `[]` system_program
+pub struct AddStakerEntryCpiBuilder<'a, 'b> {
+    instruction: Box<AddStakerEntryCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AddStakerEntryCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AddStakerEntryCpiBuilderInstruction {
+            __program: program,
+            pool_manager: None,
+            staker_entry: None,
+            authority: None,
+            system_program: None,
+            wallet: None,
+            deposit_cap: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn pool_manager(
+        &mut self,
+        pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_entry(
+        &mut self,
+        staker_entry: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.staker_entry = Some(staker_entry);
+        self
+    }
+    /// The admin, or the wallet itself while the allowlist is disabled
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn wallet(&mut self, wallet: Pubkey) -> &mut Self {
+        self.instruction.wallet = Some(wallet);
+        self
+    }
+    #[inline(always)]
+    pub fn deposit_cap(&mut self, deposit_cap: u64) -> &mut Self {
+        self.instruction.deposit_cap = Some(deposit_cap);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = AddStakerEntryInstructionArgs {
+            wallet: self.instruction.wallet.clone().expect("wallet is not set"),
+            deposit_cap: self
+                .instruction
+                .deposit_cap
+                .clone()
+                .expect("deposit_cap is not set"),
+        };
+        let instruction = AddStakerEntryCpi {
+            __program: self.instruction.__program,
+
+            pool_manager: self
+                .instruction
+                .pool_manager
+                .expect("pool_manager is not set"),
+
+            staker_entry: self
+                .instruction
+                .staker_entry
+                .expect("staker_entry is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct AddStakerEntryCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    pool_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    wallet: Option<Pubkey>,
+    deposit_cap: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,697 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct AddStrategy {
+    pub pool_manager: solana_program::pubkey::Pubkey,
+
+    pub strategy: solana_program::pubkey::Pubkey,
+
+    pub strategy_program: solana_program::pubkey::Pubkey,
+
+    pub strategy_authority: solana_program::pubkey::Pubkey,
+
+    pub base_mint: solana_program::pubkey::Pubkey,
+
+    pub strategy_token_account: solana_program::pubkey::Pubkey,
+
+    pub owner: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub associated_token_program: solana_program::pubkey::Pubkey,
+}
+
+impl AddStrategy {
+    pub fn instruction(
+        &self,
+        args: AddStrategyInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AddStrategyInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.pool_manager,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.strategy,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.strategy_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.strategy_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.base_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.strategy_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.owner, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.associated_token_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = AddStrategyInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct AddStrategyInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl AddStrategyInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [64, 123, 127, 227, 192, 234, 198, 20],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddStrategyInstructionArgs {
+    pub cap: u64,
+}
+
+/// Instruction builder for `AddStrategy`.
+///
+/// ### Accounts:
+///
+///   0. `[]` pool_manager
+///   1. `[writable]` strategy
+///   2. `[]` strategy_program
+///   3. `[]` strategy_authority
+///   4. `[]` base_mint
+///   5. `[writable]` strategy_token_account
+///   6. `[writable, signer]` owner
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   9. `[]` associated_token_program
+#[derive(Default)]
+pub struct AddStrategyBuilder {
+    pool_manager: Option<solana_program::pubkey::Pubkey>,
+    strategy: Option<solana_program::pubkey::Pubkey>,
+    strategy_program: Option<solana_program::pubkey::Pubkey>,
+    strategy_authority: Option<solana_program::pubkey::Pubkey>,
+    base_mint: Option<solana_program::pubkey::Pubkey>,
+    strategy_token_account: Option<solana_program::pubkey::Pubkey>,
+    owner: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    cap: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AddStrategyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn pool_manager(&mut self, pool_manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn strategy(&mut self, strategy: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.strategy = Some(strategy);
+        self
+    }
+    #[inline(always)]
+    pub fn strategy_program(
+        &mut self,
+        strategy_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.strategy_program = Some(strategy_program);
+        self
+    }
+    #[inline(always)]
+    pub fn strategy_authority(
+        &mut self,
+        strategy_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.strategy_authority = Some(strategy_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn base_mint(&mut self, base_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.base_mint = Some(base_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn strategy_token_account(
+        &mut self,
+        strategy_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.strategy_token_account = Some(strategy_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn owner(&mut self, owner: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.associated_token_program = Some(associated_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn cap(&mut self, cap: u64) -> &mut Self {
+        self.cap = Some(cap);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AddStrategy {
+            pool_manager: self.pool_manager.expect("pool_manager is not set"),
+            strategy: self.strategy.expect("strategy is not set"),
+            strategy_program: self.strategy_program.expect("strategy_program is not set"),
+            strategy_authority: self
+                .strategy_authority
+                .expect("strategy_authority is not set"),
+            base_mint: self.base_mint.expect("base_mint is not set"),
+            strategy_token_account: self
+                .strategy_token_account
+                .expect("strategy_token_account is not set"),
+            owner: self.owner.expect("owner is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            associated_token_program: self
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+        };
+        let args = AddStrategyInstructionArgs {
+            cap: self.cap.clone().expect("cap is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `add_strategy` CPI accounts.
+pub struct AddStrategyCpiAccounts<'a, 'b> {
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub strategy: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub strategy_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub strategy_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub strategy_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `add_strategy` CPI instruction.
+pub struct AddStrategyCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub strategy: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub strategy_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub strategy_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub strategy_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AddStrategyInstructionArgs,
+}
+
+impl<'a, 'b> AddStrategyCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AddStrategyCpiAccounts<'a, 'b>,
+        args: AddStrategyInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            pool_manager: accounts.pool_manager,
+            strategy: accounts.strategy,
+            strategy_program: accounts.strategy_program,
+            strategy_authority: accounts.strategy_authority,
+            base_mint: accounts.base_mint,
+            strategy_token_account: accounts.strategy_token_account,
+            owner: accounts.owner,
+            system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            associated_token_program: accounts.associated_token_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.pool_manager.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.strategy.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.strategy_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.strategy_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.base_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.strategy_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.owner.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.associated_token_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = AddStrategyInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.pool_manager.clone());
+        account_infos.push(self.strategy.clone());
+        account_infos.push(self.strategy_program.clone());
+        account_infos.push(self.strategy_authority.clone());
+        account_infos.push(self.base_mint.clone());
+        account_infos.push(self.strategy_token_account.clone());
+        account_infos.push(self.owner.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.associated_token_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AddStrategy` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` pool_manager
+///   1. `[writable]` strategy
+///   2. `[]` strategy_program
+///   3. `[]` strategy_authority
+///   4. `[]` base_mint
+///   5. `[writable]` strategy_token_account
+///   6. `[writable, signer]` owner
+///   7. `[]` system_program
+///   8. `[]` token_program
+///   9. `[]` associated_token_program
+pub struct AddStrategyCpiBuilder<'a, 'b> {
+    instruction: Box<AddStrategyCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AddStrategyCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AddStrategyCpiBuilderInstruction {
+            __program: program,
+            pool_manager: None,
+            strategy: None,
+            strategy_program: None,
+            strategy_authority: None,
+            base_mint: None,
+            strategy_token_account: None,
+            owner: None,
+            system_program: None,
+            token_program: None,
+            associated_token_program: None,
+            cap: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn pool_manager(
+        &mut self,
+        pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn strategy(
+        &mut self,
+        strategy: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.strategy = Some(strategy);
+        self
+    }
+    #[inline(always)]
+    pub fn strategy_program(
+        &mut self,
+        strategy_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.strategy_program = Some(strategy_program);
+        self
+    }
+    #[inline(always)]
+    pub fn strategy_authority(
+        &mut self,
+        strategy_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.strategy_authority = Some(strategy_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn base_mint(
+        &mut self,
+        base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.base_mint = Some(base_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn strategy_token_account(
+        &mut self,
+        strategy_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.strategy_token_account = Some(strategy_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn owner(&mut self, owner: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.owner = Some(owner);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.associated_token_program = Some(associated_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn cap(&mut self, cap: u64) -> &mut Self {
+        self.instruction.cap = Some(cap);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = AddStrategyInstructionArgs {
+            cap: self.instruction.cap.clone().expect("cap is not set"),
+        };
+        let instruction = AddStrategyCpi {
+            __program: self.instruction.__program,
+
+            pool_manager: self
+                .instruction
+                .pool_manager
+                .expect("pool_manager is not set"),
+
+            strategy: self.instruction.strategy.expect("strategy is not set"),
+
+            strategy_program: self
+                .instruction
+                .strategy_program
+                .expect("strategy_program is not set"),
+
+            strategy_authority: self
+                .instruction
+                .strategy_authority
+                .expect("strategy_authority is not set"),
+
+            base_mint: self.instruction.base_mint.expect("base_mint is not set"),
+
+            strategy_token_account: self
+                .instruction
+                .strategy_token_account
+                .expect("strategy_token_account is not set"),
+
+            owner: self.instruction.owner.expect("owner is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            associated_token_program: self
+                .instruction
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct AddStrategyCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    pool_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    strategy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    strategy_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    strategy_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    strategy_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cap: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::YieldMode;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -22,8 +23,20 @@ pub struct InitializePoolManager {
 
     pub pool_manager: solana_program::pubkey::Pubkey,
 
+    pub yield_schedule: solana_program::pubkey::Pubkey,
+
+    pub rate_feed: solana_program::pubkey::Pubkey,
+
+    pub rate_history: solana_program::pubkey::Pubkey,
+
     pub vault: solana_program::pubkey::Pubkey,
 
+    pub owner_base_mint_ata: solana_program::pubkey::Pubkey,
+    /// shares, owned by itself so that nothing ever signs for it
+    pub dead_shares: solana_program::pubkey::Pubkey,
+    /// Token manager of the base mint, bound to the pool for yield minting
+    pub token_manager: solana_program::pubkey::Pubkey,
+
     pub owner: solana_program::pubkey::Pubkey,
 
     pub rent: solana_program::pubkey::Pubkey,
@@ -31,10 +44,14 @@ pub struct InitializePoolManager {
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
+    /// SPL Token, or Token-2022 for an interest-bearing x mint
+    pub x_token_program: solana_program::pubkey::Pubkey,
 
     pub token_metadata_program: solana_program::pubkey::Pubkey,
 
     pub associated_token_program: solana_program::pubkey::Pubkey,
+
+    pub parity_issuance_program: solana_program::pubkey::Pubkey,
 }
 
 impl InitializePoolManager {
@@ -50,7 +67,7 @@ impl InitializePoolManager {
         args: InitializePoolManagerInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.base_mint,
             false,
@@ -67,9 +84,33 @@ impl InitializePoolManager {
             self.pool_manager,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.yield_schedule,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_feed,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_history,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.vault, false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.owner_base_mint_ata,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.dead_shares,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_manager,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, true,
         ));
@@ -84,6 +125,10 @@ impl InitializePoolManager {
             self.token_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.x_token_program,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.token_metadata_program,
             false,
@@ -92,6 +137,10 @@ impl InitializePoolManager {
             self.associated_token_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.parity_issuance_program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = InitializePoolManagerInstructionData::new()
             .try_to_vec()
@@ -126,15 +175,23 @@ impl InitializePoolManagerInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct InitializePoolManagerInstructionArgs {
+    pub pool_id: u64,
     pub name: String,
     pub symbol: String,
     pub uri: String,
     pub decimals: u8,
-    pub interval_apr_rate: u64,
+    pub annual_yield_bps: u64,
     pub seconds_per_interval: i32,
     pub initial_exchange_rate: u64,
     pub admin: Pubkey,
     pub deposit_cap: u64,
+    pub unstake_cooldown: i64,
+    pub min_stake_amount: u64,
+    pub min_unstake_amount: u64,
+    pub yield_mode: YieldMode,
+    pub rewarder: Pubkey,
+    pub vesting_period: i64,
+    pub interest_bearing_x_mint: bool,
 }
 
 /// Instruction builder for `InitializePoolManager`.
@@ -145,35 +202,59 @@ pub struct InitializePoolManagerInstructionArgs {
 ///   1. `[writable]` x_mint
 ///   2. `[writable]` metadata
 ///   3. `[writable]` pool_manager
-///   4. `[writable]` vault
-///   5. `[writable, signer]` owner
-///   6. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
-///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   9. `[optional]` token_metadata_program (default to `metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s`)
-///   10. `[]` associated_token_program
+///   4. `[writable]` yield_schedule
+///   5. `[writable]` rate_feed
+///   6. `[writable]` rate_history
+///   7. `[writable]` vault
+///   8. `[writable]` owner_base_mint_ata
+///   9. `[writable]` dead_shares
+///   10. `[]` token_manager
+///   11. `[writable, signer]` owner
+///   12. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   14. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   15. `[]` x_token_program
+///   16. `[optional]` token_metadata_program (default to `metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s`)
+///   17. `[]` associated_token_program
+///   18. `[]` parity_issuance_program
 #[derive(Default)]
 pub struct InitializePoolManagerBuilder {
     base_mint: Option<solana_program::pubkey::Pubkey>,
     x_mint: Option<solana_program::pubkey::Pubkey>,
     metadata: Option<solana_program::pubkey::Pubkey>,
     pool_manager: Option<solana_program::pubkey::Pubkey>,
+    yield_schedule: Option<solana_program::pubkey::Pubkey>,
+    rate_feed: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
     vault: Option<solana_program::pubkey::Pubkey>,
+    owner_base_mint_ata: Option<solana_program::pubkey::Pubkey>,
+    dead_shares: Option<solana_program::pubkey::Pubkey>,
+    token_manager: Option<solana_program::pubkey::Pubkey>,
     owner: Option<solana_program::pubkey::Pubkey>,
     rent: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
+    x_token_program: Option<solana_program::pubkey::Pubkey>,
     token_metadata_program: Option<solana_program::pubkey::Pubkey>,
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    parity_issuance_program: Option<solana_program::pubkey::Pubkey>,
+    pool_id: Option<u64>,
     name: Option<String>,
     symbol: Option<String>,
     uri: Option<String>,
     decimals: Option<u8>,
-    interval_apr_rate: Option<u64>,
+    annual_yield_bps: Option<u64>,
     seconds_per_interval: Option<i32>,
     initial_exchange_rate: Option<u64>,
     admin: Option<Pubkey>,
     deposit_cap: Option<u64>,
+    unstake_cooldown: Option<i64>,
+    min_stake_amount: Option<u64>,
+    min_unstake_amount: Option<u64>,
+    yield_mode: Option<YieldMode>,
+    rewarder: Option<Pubkey>,
+    vesting_period: Option<i64>,
+    interest_bearing_x_mint: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -203,11 +284,46 @@ impl InitializePoolManagerBuilder {
         self
     }
     #[inline(always)]
+    pub fn yield_schedule(&mut self, yield_schedule: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(&mut self, rate_feed: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(&mut self, rate_history: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
     pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
         self.vault = Some(vault);
         self
     }
     #[inline(always)]
+    pub fn owner_base_mint_ata(
+        &mut self,
+        owner_base_mint_ata: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.owner_base_mint_ata = Some(owner_base_mint_ata);
+        self
+    }
+    /// shares, owned by itself so that nothing ever signs for it
+    #[inline(always)]
+    pub fn dead_shares(&mut self, dead_shares: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.dead_shares = Some(dead_shares);
+        self
+    }
+    /// Token manager of the base mint, bound to the pool for yield minting
+    #[inline(always)]
+    pub fn token_manager(&mut self, token_manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_manager = Some(token_manager);
+        self
+    }
+    #[inline(always)]
     pub fn owner(&mut self, owner: solana_program::pubkey::Pubkey) -> &mut Self {
         self.owner = Some(owner);
         self
@@ -230,6 +346,15 @@ impl InitializePoolManagerBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// SPL Token, or Token-2022 for an interest-bearing x mint
+    #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.x_token_program = Some(x_token_program);
+        self
+    }
     /// `[optional account, default to 'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s']`
     #[inline(always)]
     pub fn token_metadata_program(
@@ -248,6 +373,19 @@ impl InitializePoolManagerBuilder {
         self
     }
     #[inline(always)]
+    pub fn parity_issuance_program(
+        &mut self,
+        parity_issuance_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.parity_issuance_program = Some(parity_issuance_program);
+        self
+    }
+    #[inline(always)]
+    pub fn pool_id(&mut self, pool_id: u64) -> &mut Self {
+        self.pool_id = Some(pool_id);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.name = Some(name);
         self
@@ -268,8 +406,8 @@ impl InitializePoolManagerBuilder {
         self
     }
     #[inline(always)]
-    pub fn interval_apr_rate(&mut self, interval_apr_rate: u64) -> &mut Self {
-        self.interval_apr_rate = Some(interval_apr_rate);
+    pub fn annual_yield_bps(&mut self, annual_yield_bps: u64) -> &mut Self {
+        self.annual_yield_bps = Some(annual_yield_bps);
         self
     }
     #[inline(always)]
@@ -292,6 +430,41 @@ impl InitializePoolManagerBuilder {
         self.deposit_cap = Some(deposit_cap);
         self
     }
+    #[inline(always)]
+    pub fn unstake_cooldown(&mut self, unstake_cooldown: i64) -> &mut Self {
+        self.unstake_cooldown = Some(unstake_cooldown);
+        self
+    }
+    #[inline(always)]
+    pub fn min_stake_amount(&mut self, min_stake_amount: u64) -> &mut Self {
+        self.min_stake_amount = Some(min_stake_amount);
+        self
+    }
+    #[inline(always)]
+    pub fn min_unstake_amount(&mut self, min_unstake_amount: u64) -> &mut Self {
+        self.min_unstake_amount = Some(min_unstake_amount);
+        self
+    }
+    #[inline(always)]
+    pub fn yield_mode(&mut self, yield_mode: YieldMode) -> &mut Self {
+        self.yield_mode = Some(yield_mode);
+        self
+    }
+    #[inline(always)]
+    pub fn rewarder(&mut self, rewarder: Pubkey) -> &mut Self {
+        self.rewarder = Some(rewarder);
+        self
+    }
+    #[inline(always)]
+    pub fn vesting_period(&mut self, vesting_period: i64) -> &mut Self {
+        self.vesting_period = Some(vesting_period);
+        self
+    }
+    #[inline(always)]
+    pub fn interest_bearing_x_mint(&mut self, interest_bearing_x_mint: bool) -> &mut Self {
+        self.interest_bearing_x_mint = Some(interest_bearing_x_mint);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -318,7 +491,15 @@ impl InitializePoolManagerBuilder {
                 x_mint: self.x_mint.expect("x_mint is not set"),
                 metadata: self.metadata.expect("metadata is not set"),
                 pool_manager: self.pool_manager.expect("pool_manager is not set"),
+                yield_schedule: self.yield_schedule.expect("yield_schedule is not set"),
+                rate_feed: self.rate_feed.expect("rate_feed is not set"),
+                rate_history: self.rate_history.expect("rate_history is not set"),
                 vault: self.vault.expect("vault is not set"),
+                owner_base_mint_ata: self
+                    .owner_base_mint_ata
+                    .expect("owner_base_mint_ata is not set"),
+                dead_shares: self.dead_shares.expect("dead_shares is not set"),
+                token_manager: self.token_manager.expect("token_manager is not set"),
                 owner: self.owner.expect("owner is not set"),
                 rent: self.rent.unwrap_or(solana_program::pubkey!(
                     "SysvarRent111111111111111111111111111111111"
@@ -329,22 +510,27 @@ impl InitializePoolManagerBuilder {
                 token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
                 )),
+                x_token_program: self.x_token_program.expect("x_token_program is not set"),
                 token_metadata_program: self.token_metadata_program.unwrap_or(
                     solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"),
                 ),
                 associated_token_program: self
                     .associated_token_program
                     .expect("associated_token_program is not set"),
+                parity_issuance_program: self
+                    .parity_issuance_program
+                    .expect("parity_issuance_program is not set"),
             };
         let args = InitializePoolManagerInstructionArgs {
+            pool_id: self.pool_id.clone().expect("pool_id is not set"),
             name: self.name.clone().expect("name is not set"),
             symbol: self.symbol.clone().expect("symbol is not set"),
             uri: self.uri.clone().expect("uri is not set"),
             decimals: self.decimals.clone().expect("decimals is not set"),
-            interval_apr_rate: self
-                .interval_apr_rate
+            annual_yield_bps: self
+                .annual_yield_bps
                 .clone()
-                .expect("interval_apr_rate is not set"),
+                .expect("annual_yield_bps is not set"),
             seconds_per_interval: self
                 .seconds_per_interval
                 .clone()
@@ -355,6 +541,28 @@ impl InitializePoolManagerBuilder {
                 .expect("initial_exchange_rate is not set"),
             admin: self.admin.clone().expect("admin is not set"),
             deposit_cap: self.deposit_cap.clone().expect("deposit_cap is not set"),
+            unstake_cooldown: self
+                .unstake_cooldown
+                .clone()
+                .expect("unstake_cooldown is not set"),
+            min_stake_amount: self
+                .min_stake_amount
+                .clone()
+                .expect("min_stake_amount is not set"),
+            min_unstake_amount: self
+                .min_unstake_amount
+                .clone()
+                .expect("min_unstake_amount is not set"),
+            yield_mode: self.yield_mode.clone().expect("yield_mode is not set"),
+            rewarder: self.rewarder.clone().expect("rewarder is not set"),
+            vesting_period: self
+                .vesting_period
+                .clone()
+                .expect("vesting_period is not set"),
+            interest_bearing_x_mint: self
+                .interest_bearing_x_mint
+                .clone()
+                .expect("interest_bearing_x_mint is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -372,8 +580,20 @@ pub struct InitializePoolManagerCpiAccounts<'a, 'b> {
 
     pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub owner_base_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+    /// shares, owned by itself so that nothing ever signs for it
+    pub dead_shares: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token manager of the base mint, bound to the pool for yield minting
+    pub token_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub rent: &'b solana_program::account_info::AccountInfo<'a>,
@@ -381,10 +601,14 @@ pub struct InitializePoolManagerCpiAccounts<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL Token, or Token-2022 for an interest-bearing x mint
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_metadata_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `initialize_pool_manager` CPI instruction.
@@ -400,8 +624,20 @@ pub struct InitializePoolManagerCpi<'a, 'b> {
 
     pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub owner_base_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+    /// shares, owned by itself so that nothing ever signs for it
+    pub dead_shares: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token manager of the base mint, bound to the pool for yield minting
+    pub token_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub owner: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub rent: &'b solana_program::account_info::AccountInfo<'a>,
@@ -409,10 +645,14 @@ pub struct InitializePoolManagerCpi<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// SPL Token, or Token-2022 for an interest-bearing x mint
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_metadata_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: InitializePoolManagerInstructionArgs,
 }
@@ -429,13 +669,21 @@ impl<'a, 'b> InitializePoolManagerCpi<'a, 'b> {
             x_mint: accounts.x_mint,
             metadata: accounts.metadata,
             pool_manager: accounts.pool_manager,
+            yield_schedule: accounts.yield_schedule,
+            rate_feed: accounts.rate_feed,
+            rate_history: accounts.rate_history,
             vault: accounts.vault,
+            owner_base_mint_ata: accounts.owner_base_mint_ata,
+            dead_shares: accounts.dead_shares,
+            token_manager: accounts.token_manager,
             owner: accounts.owner,
             rent: accounts.rent,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
+            x_token_program: accounts.x_token_program,
             token_metadata_program: accounts.token_metadata_program,
             associated_token_program: accounts.associated_token_program,
+            parity_issuance_program: accounts.parity_issuance_program,
             __args: args,
         }
     }
@@ -472,7 +720,7 @@ impl<'a, 'b> InitializePoolManagerCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.base_mint.key,
             false,
@@ -489,10 +737,34 @@ impl<'a, 'b> InitializePoolManagerCpi<'a, 'b> {
             *self.pool_manager.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.yield_schedule.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_feed.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_history.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.vault.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.owner_base_mint_ata.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.dead_shares.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_manager.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.owner.key,
             true,
@@ -509,6 +781,10 @@ impl<'a, 'b> InitializePoolManagerCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.x_token_program.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.token_metadata_program.key,
             false,
@@ -517,6 +793,10 @@ impl<'a, 'b> InitializePoolManagerCpi<'a, 'b> {
             *self.associated_token_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.parity_issuance_program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -535,19 +815,27 @@ impl<'a, 'b> InitializePoolManagerCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(19 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.base_mint.clone());
         account_infos.push(self.x_mint.clone());
         account_infos.push(self.metadata.clone());
         account_infos.push(self.pool_manager.clone());
+        account_infos.push(self.yield_schedule.clone());
+        account_infos.push(self.rate_feed.clone());
+        account_infos.push(self.rate_history.clone());
         account_infos.push(self.vault.clone());
+        account_infos.push(self.owner_base_mint_ata.clone());
+        account_infos.push(self.dead_shares.clone());
+        account_infos.push(self.token_manager.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.rent.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
+        account_infos.push(self.x_token_program.clone());
         account_infos.push(self.token_metadata_program.clone());
         account_infos.push(self.associated_token_program.clone());
+        account_infos.push(self.parity_issuance_program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -568,13 +856,21 @@ impl<'a, 'b> InitializePoolManagerCpi<'a, 'b> {
 ///   1. `[writable]` x_mint
 ///   2. `[writable]` metadata
 ///   3. `[writable]` pool_manager
-///   4. `[writable]` vault
-///   5. `[writable, signer]` owner
-///   6. `[]` rent
-///   7. `[]` system_program
-///   8. `[]` token_program
-///   9. `[]` token_metadata_program
-///   10. `[]` associated_token_program
+///   4. `[writable]` yield_schedule
+///   5. `[writable]` rate_feed
+///   6. `[writable]` rate_history
+///   7. `[writable]` vault
+///   8. `[writable]` owner_base_mint_ata
+///   9. `[writable]` dead_shares
+///   10. `[]` token_manager
+///   11. `[writable, signer]` owner
+///   12. `[]` rent
+///   13. `[]` system_program
+///   14. `[]` token_program
+///   15. `[]` x_token_program
+///   16. `[]` token_metadata_program
+///   17. `[]` associated_token_program
+///   18. `[]` parity_issuance_program
 pub struct InitializePoolManagerCpiBuilder<'a, 'b> {
     instruction: Box<InitializePoolManagerCpiBuilderInstruction<'a, 'b>>,
 }
@@ -587,22 +883,38 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
             x_mint: None,
             metadata: None,
             pool_manager: None,
+            yield_schedule: None,
+            rate_feed: None,
+            rate_history: None,
             vault: None,
+            owner_base_mint_ata: None,
+            dead_shares: None,
+            token_manager: None,
             owner: None,
             rent: None,
             system_program: None,
             token_program: None,
+            x_token_program: None,
             token_metadata_program: None,
             associated_token_program: None,
+            parity_issuance_program: None,
+            pool_id: None,
             name: None,
             symbol: None,
             uri: None,
             decimals: None,
-            interval_apr_rate: None,
+            annual_yield_bps: None,
             seconds_per_interval: None,
             initial_exchange_rate: None,
             admin: None,
             deposit_cap: None,
+            unstake_cooldown: None,
+            min_stake_amount: None,
+            min_unstake_amount: None,
+            yield_mode: None,
+            rewarder: None,
+            vesting_period: None,
+            interest_bearing_x_mint: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -641,11 +953,61 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn yield_schedule(
+        &mut self,
+        yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(
+        &mut self,
+        rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
     pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.vault = Some(vault);
         self
     }
     #[inline(always)]
+    pub fn owner_base_mint_ata(
+        &mut self,
+        owner_base_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.owner_base_mint_ata = Some(owner_base_mint_ata);
+        self
+    }
+    /// shares, owned by itself so that nothing ever signs for it
+    #[inline(always)]
+    pub fn dead_shares(
+        &mut self,
+        dead_shares: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.dead_shares = Some(dead_shares);
+        self
+    }
+    /// Token manager of the base mint, bound to the pool for yield minting
+    #[inline(always)]
+    pub fn token_manager(
+        &mut self,
+        token_manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_manager = Some(token_manager);
+        self
+    }
+    #[inline(always)]
     pub fn owner(&mut self, owner: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.owner = Some(owner);
         self
@@ -671,6 +1033,15 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// SPL Token, or Token-2022 for an interest-bearing x mint
+    #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.x_token_program = Some(x_token_program);
+        self
+    }
     #[inline(always)]
     pub fn token_metadata_program(
         &mut self,
@@ -688,6 +1059,19 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn parity_issuance_program(
+        &mut self,
+        parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.parity_issuance_program = Some(parity_issuance_program);
+        self
+    }
+    #[inline(always)]
+    pub fn pool_id(&mut self, pool_id: u64) -> &mut Self {
+        self.instruction.pool_id = Some(pool_id);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.instruction.name = Some(name);
         self
@@ -708,8 +1092,8 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn interval_apr_rate(&mut self, interval_apr_rate: u64) -> &mut Self {
-        self.instruction.interval_apr_rate = Some(interval_apr_rate);
+    pub fn annual_yield_bps(&mut self, annual_yield_bps: u64) -> &mut Self {
+        self.instruction.annual_yield_bps = Some(annual_yield_bps);
         self
     }
     #[inline(always)]
@@ -732,6 +1116,41 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
         self.instruction.deposit_cap = Some(deposit_cap);
         self
     }
+    #[inline(always)]
+    pub fn unstake_cooldown(&mut self, unstake_cooldown: i64) -> &mut Self {
+        self.instruction.unstake_cooldown = Some(unstake_cooldown);
+        self
+    }
+    #[inline(always)]
+    pub fn min_stake_amount(&mut self, min_stake_amount: u64) -> &mut Self {
+        self.instruction.min_stake_amount = Some(min_stake_amount);
+        self
+    }
+    #[inline(always)]
+    pub fn min_unstake_amount(&mut self, min_unstake_amount: u64) -> &mut Self {
+        self.instruction.min_unstake_amount = Some(min_unstake_amount);
+        self
+    }
+    #[inline(always)]
+    pub fn yield_mode(&mut self, yield_mode: YieldMode) -> &mut Self {
+        self.instruction.yield_mode = Some(yield_mode);
+        self
+    }
+    #[inline(always)]
+    pub fn rewarder(&mut self, rewarder: Pubkey) -> &mut Self {
+        self.instruction.rewarder = Some(rewarder);
+        self
+    }
+    #[inline(always)]
+    pub fn vesting_period(&mut self, vesting_period: i64) -> &mut Self {
+        self.instruction.vesting_period = Some(vesting_period);
+        self
+    }
+    #[inline(always)]
+    pub fn interest_bearing_x_mint(&mut self, interest_bearing_x_mint: bool) -> &mut Self {
+        self.instruction.interest_bearing_x_mint = Some(interest_bearing_x_mint);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -774,6 +1193,11 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = InitializePoolManagerInstructionArgs {
+            pool_id: self
+                .instruction
+                .pool_id
+                .clone()
+                .expect("pool_id is not set"),
             name: self.instruction.name.clone().expect("name is not set"),
             symbol: self.instruction.symbol.clone().expect("symbol is not set"),
             uri: self.instruction.uri.clone().expect("uri is not set"),
@@ -782,11 +1206,11 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
                 .decimals
                 .clone()
                 .expect("decimals is not set"),
-            interval_apr_rate: self
+            annual_yield_bps: self
                 .instruction
-                .interval_apr_rate
+                .annual_yield_bps
                 .clone()
-                .expect("interval_apr_rate is not set"),
+                .expect("annual_yield_bps is not set"),
             seconds_per_interval: self
                 .instruction
                 .seconds_per_interval
@@ -803,6 +1227,41 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
                 .deposit_cap
                 .clone()
                 .expect("deposit_cap is not set"),
+            unstake_cooldown: self
+                .instruction
+                .unstake_cooldown
+                .clone()
+                .expect("unstake_cooldown is not set"),
+            min_stake_amount: self
+                .instruction
+                .min_stake_amount
+                .clone()
+                .expect("min_stake_amount is not set"),
+            min_unstake_amount: self
+                .instruction
+                .min_unstake_amount
+                .clone()
+                .expect("min_unstake_amount is not set"),
+            yield_mode: self
+                .instruction
+                .yield_mode
+                .clone()
+                .expect("yield_mode is not set"),
+            rewarder: self
+                .instruction
+                .rewarder
+                .clone()
+                .expect("rewarder is not set"),
+            vesting_period: self
+                .instruction
+                .vesting_period
+                .clone()
+                .expect("vesting_period is not set"),
+            interest_bearing_x_mint: self
+                .instruction
+                .interest_bearing_x_mint
+                .clone()
+                .expect("interest_bearing_x_mint is not set"),
         };
         let instruction = InitializePoolManagerCpi {
             __program: self.instruction.__program,
@@ -818,8 +1277,35 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
                 .pool_manager
                 .expect("pool_manager is not set"),
 
+            yield_schedule: self
+                .instruction
+                .yield_schedule
+                .expect("yield_schedule is not set"),
+
+            rate_feed: self.instruction.rate_feed.expect("rate_feed is not set"),
+
+            rate_history: self
+                .instruction
+                .rate_history
+                .expect("rate_history is not set"),
+
             vault: self.instruction.vault.expect("vault is not set"),
 
+            owner_base_mint_ata: self
+                .instruction
+                .owner_base_mint_ata
+                .expect("owner_base_mint_ata is not set"),
+
+            dead_shares: self
+                .instruction
+                .dead_shares
+                .expect("dead_shares is not set"),
+
+            token_manager: self
+                .instruction
+                .token_manager
+                .expect("token_manager is not set"),
+
             owner: self.instruction.owner.expect("owner is not set"),
 
             rent: self.instruction.rent.expect("rent is not set"),
@@ -834,6 +1320,11 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
                 .token_program
                 .expect("token_program is not set"),
 
+            x_token_program: self
+                .instruction
+                .x_token_program
+                .expect("x_token_program is not set"),
+
             token_metadata_program: self
                 .instruction
                 .token_metadata_program
@@ -843,6 +1334,11 @@ impl<'a, 'b> InitializePoolManagerCpiBuilder<'a, 'b> {
                 .instruction
                 .associated_token_program
                 .expect("associated_token_program is not set"),
+
+            parity_issuance_program: self
+                .instruction
+                .parity_issuance_program
+                .expect("parity_issuance_program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -858,22 +1354,38 @@ struct InitializePoolManagerCpiBuilderInstruction<'a, 'b> {
     x_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     pool_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    yield_schedule: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_feed: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner_base_mint_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    dead_shares: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     rent: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    x_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_metadata_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    parity_issuance_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pool_id: Option<u64>,
     name: Option<String>,
     symbol: Option<String>,
     uri: Option<String>,
     decimals: Option<u8>,
-    interval_apr_rate: Option<u64>,
+    annual_yield_bps: Option<u64>,
     seconds_per_interval: Option<i32>,
     initial_exchange_rate: Option<u64>,
     admin: Option<Pubkey>,
     deposit_cap: Option<u64>,
+    unstake_cooldown: Option<i64>,
+    min_stake_amount: Option<u64>,
+    min_unstake_amount: Option<u64>,
+    yield_mode: Option<YieldMode>,
+    rewarder: Option<Pubkey>,
+    vesting_period: Option<i64>,
+    interest_bearing_x_mint: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
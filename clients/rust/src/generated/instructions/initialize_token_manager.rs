@@ -138,6 +138,9 @@ pub struct InitializeTokenManagerInstructionArgs {
     pub withdraw_execution_window: i64,
     pub mint_fee_bps: u16,
     pub redeem_fee_bps: u16,
+    pub max_exchange_rate_change_bps: u16,
+    pub exchange_rate_update_interval: i64,
+    pub exchange_rate_ramp_duration: i64,
 }
 
 /// Instruction builder for `InitializeTokenManager`.
@@ -182,6 +185,9 @@ pub struct InitializeTokenManagerBuilder {
     withdraw_execution_window: Option<i64>,
     mint_fee_bps: Option<u16>,
     redeem_fee_bps: Option<u16>,
+    max_exchange_rate_change_bps: Option<u16>,
+    exchange_rate_update_interval: Option<i64>,
+    exchange_rate_ramp_duration: Option<i64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -324,6 +330,24 @@ impl InitializeTokenManagerBuilder {
         self.redeem_fee_bps = Some(redeem_fee_bps);
         self
     }
+    #[inline(always)]
+    pub fn max_exchange_rate_change_bps(&mut self, max_exchange_rate_change_bps: u16) -> &mut Self {
+        self.max_exchange_rate_change_bps = Some(max_exchange_rate_change_bps);
+        self
+    }
+    #[inline(always)]
+    pub fn exchange_rate_update_interval(
+        &mut self,
+        exchange_rate_update_interval: i64,
+    ) -> &mut Self {
+        self.exchange_rate_update_interval = Some(exchange_rate_update_interval);
+        self
+    }
+    #[inline(always)]
+    pub fn exchange_rate_ramp_duration(&mut self, exchange_rate_ramp_duration: i64) -> &mut Self {
+        self.exchange_rate_ramp_duration = Some(exchange_rate_ramp_duration);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -401,6 +425,18 @@ impl InitializeTokenManagerBuilder {
                 .redeem_fee_bps
                 .clone()
                 .expect("redeem_fee_bps is not set"),
+            max_exchange_rate_change_bps: self
+                .max_exchange_rate_change_bps
+                .clone()
+                .expect("max_exchange_rate_change_bps is not set"),
+            exchange_rate_update_interval: self
+                .exchange_rate_update_interval
+                .clone()
+                .expect("exchange_rate_update_interval is not set"),
+            exchange_rate_ramp_duration: self
+                .exchange_rate_ramp_duration
+                .clone()
+                .expect("exchange_rate_ramp_duration is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -653,6 +689,9 @@ impl<'a, 'b> InitializeTokenManagerCpiBuilder<'a, 'b> {
             withdraw_execution_window: None,
             mint_fee_bps: None,
             redeem_fee_bps: None,
+            max_exchange_rate_change_bps: None,
+            exchange_rate_update_interval: None,
+            exchange_rate_ramp_duration: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -803,6 +842,24 @@ impl<'a, 'b> InitializeTokenManagerCpiBuilder<'a, 'b> {
         self.instruction.redeem_fee_bps = Some(redeem_fee_bps);
         self
     }
+    #[inline(always)]
+    pub fn max_exchange_rate_change_bps(&mut self, max_exchange_rate_change_bps: u16) -> &mut Self {
+        self.instruction.max_exchange_rate_change_bps = Some(max_exchange_rate_change_bps);
+        self
+    }
+    #[inline(always)]
+    pub fn exchange_rate_update_interval(
+        &mut self,
+        exchange_rate_update_interval: i64,
+    ) -> &mut Self {
+        self.instruction.exchange_rate_update_interval = Some(exchange_rate_update_interval);
+        self
+    }
+    #[inline(always)]
+    pub fn exchange_rate_ramp_duration(&mut self, exchange_rate_ramp_duration: i64) -> &mut Self {
+        self.instruction.exchange_rate_ramp_duration = Some(exchange_rate_ramp_duration);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -895,6 +952,21 @@ impl<'a, 'b> InitializeTokenManagerCpiBuilder<'a, 'b> {
                 .redeem_fee_bps
                 .clone()
                 .expect("redeem_fee_bps is not set"),
+            max_exchange_rate_change_bps: self
+                .instruction
+                .max_exchange_rate_change_bps
+                .clone()
+                .expect("max_exchange_rate_change_bps is not set"),
+            exchange_rate_update_interval: self
+                .instruction
+                .exchange_rate_update_interval
+                .clone()
+                .expect("exchange_rate_update_interval is not set"),
+            exchange_rate_ramp_duration: self
+                .instruction
+                .exchange_rate_ramp_duration
+                .clone()
+                .expect("exchange_rate_ramp_duration is not set"),
         };
         let instruction = InitializeTokenManagerCpi {
             __program: self.instruction.__program,
@@ -971,6 +1043,9 @@ struct InitializeTokenManagerCpiBuilderInstruction<'a, 'b> {
     withdraw_execution_window: Option<i64>,
     mint_fee_bps: Option<u16>,
     redeem_fee_bps: Option<u16>,
+    max_exchange_rate_change_bps: Option<u16>,
+    exchange_rate_update_interval: Option<i64>,
+    exchange_rate_ramp_duration: Option<i64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
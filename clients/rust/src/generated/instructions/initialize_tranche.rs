@@ -0,0 +1,615 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct InitializeTranche {
+    pub pool_manager: solana_program::pubkey::Pubkey,
+
+    pub tranche: solana_program::pubkey::Pubkey,
+
+    pub x_mint: solana_program::pubkey::Pubkey,
+    /// Holds the x tokens of the tranche's open positions
+    pub tranche_vault: solana_program::pubkey::Pubkey,
+
+    pub admin: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub x_token_program: solana_program::pubkey::Pubkey,
+}
+
+impl InitializeTranche {
+    pub fn instruction(
+        &self,
+        args: InitializeTrancheInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: InitializeTrancheInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.pool_manager,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tranche,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.x_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tranche_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.admin, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.x_token_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = InitializeTrancheInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct InitializeTrancheInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl InitializeTrancheInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [129, 162, 50, 217, 235, 134, 123, 252],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializeTrancheInstructionArgs {
+    pub tranche_id: u64,
+    pub term_duration: i64,
+    pub annual_yield_bps: u64,
+}
+
+/// Instruction builder for `InitializeTranche`.
+///
+/// ### Accounts:
+///
+///   0. `[]` pool_manager
+///   1. `[writable]` tranche
+///   2. `[]` x_mint
+///   3. `[writable]` tranche_vault
+///   4. `[writable, signer]` admin
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[]` x_token_program
+#[derive(Default)]
+pub struct InitializeTrancheBuilder {
+    pool_manager: Option<solana_program::pubkey::Pubkey>,
+    tranche: Option<solana_program::pubkey::Pubkey>,
+    x_mint: Option<solana_program::pubkey::Pubkey>,
+    tranche_vault: Option<solana_program::pubkey::Pubkey>,
+    admin: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    x_token_program: Option<solana_program::pubkey::Pubkey>,
+    tranche_id: Option<u64>,
+    term_duration: Option<i64>,
+    annual_yield_bps: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl InitializeTrancheBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn pool_manager(&mut self, pool_manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn tranche(&mut self, tranche: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tranche = Some(tranche);
+        self
+    }
+    #[inline(always)]
+    pub fn x_mint(&mut self, x_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.x_mint = Some(x_mint);
+        self
+    }
+    /// Holds the x tokens of the tranche's open positions
+    #[inline(always)]
+    pub fn tranche_vault(&mut self, tranche_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tranche_vault = Some(tranche_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn admin(&mut self, admin: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.admin = Some(admin);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn tranche_id(&mut self, tranche_id: u64) -> &mut Self {
+        self.tranche_id = Some(tranche_id);
+        self
+    }
+    #[inline(always)]
+    pub fn term_duration(&mut self, term_duration: i64) -> &mut Self {
+        self.term_duration = Some(term_duration);
+        self
+    }
+    #[inline(always)]
+    pub fn annual_yield_bps(&mut self, annual_yield_bps: u64) -> &mut Self {
+        self.annual_yield_bps = Some(annual_yield_bps);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = InitializeTranche {
+            pool_manager: self.pool_manager.expect("pool_manager is not set"),
+            tranche: self.tranche.expect("tranche is not set"),
+            x_mint: self.x_mint.expect("x_mint is not set"),
+            tranche_vault: self.tranche_vault.expect("tranche_vault is not set"),
+            admin: self.admin.expect("admin is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            x_token_program: self.x_token_program.expect("x_token_program is not set"),
+        };
+        let args = InitializeTrancheInstructionArgs {
+            tranche_id: self.tranche_id.clone().expect("tranche_id is not set"),
+            term_duration: self
+                .term_duration
+                .clone()
+                .expect("term_duration is not set"),
+            annual_yield_bps: self
+                .annual_yield_bps
+                .clone()
+                .expect("annual_yield_bps is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `initialize_tranche` CPI accounts.
+pub struct InitializeTrancheCpiAccounts<'a, 'b> {
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tranche: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holds the x tokens of the tranche's open positions
+    pub tranche_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `initialize_tranche` CPI instruction.
+pub struct InitializeTrancheCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tranche: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holds the x tokens of the tranche's open positions
+    pub tranche_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: InitializeTrancheInstructionArgs,
+}
+
+impl<'a, 'b> InitializeTrancheCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: InitializeTrancheCpiAccounts<'a, 'b>,
+        args: InitializeTrancheInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            pool_manager: accounts.pool_manager,
+            tranche: accounts.tranche,
+            x_mint: accounts.x_mint,
+            tranche_vault: accounts.tranche_vault,
+            admin: accounts.admin,
+            system_program: accounts.system_program,
+            x_token_program: accounts.x_token_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.pool_manager.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tranche.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.x_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tranche_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.admin.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.x_token_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = InitializeTrancheInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.pool_manager.clone());
+        account_infos.push(self.tranche.clone());
+        account_infos.push(self.x_mint.clone());
+        account_infos.push(self.tranche_vault.clone());
+        account_infos.push(self.admin.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.x_token_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `InitializeTranche` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` pool_manager
+///   1. `[writable]` tranche
+///   2. `[]` x_mint
+///   3. `[writable]` tranche_vault
+///   4. `[writable, signer]` admin
+///   5. `[]` system_program
+///   6. `[]` x_token_program
+pub struct InitializeTrancheCpiBuilder<'a, 'b> {
+    instruction: Box<InitializeTrancheCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> InitializeTrancheCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(InitializeTrancheCpiBuilderInstruction {
+            __program: program,
+            pool_manager: None,
+            tranche: None,
+            x_mint: None,
+            tranche_vault: None,
+            admin: None,
+            system_program: None,
+            x_token_program: None,
+            tranche_id: None,
+            term_duration: None,
+            annual_yield_bps: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn pool_manager(
+        &mut self,
+        pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn tranche(
+        &mut self,
+        tranche: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tranche = Some(tranche);
+        self
+    }
+    #[inline(always)]
+    pub fn x_mint(
+        &mut self,
+        x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.x_mint = Some(x_mint);
+        self
+    }
+    /// Holds the x tokens of the tranche's open positions
+    #[inline(always)]
+    pub fn tranche_vault(
+        &mut self,
+        tranche_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tranche_vault = Some(tranche_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn admin(&mut self, admin: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.admin = Some(admin);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn tranche_id(&mut self, tranche_id: u64) -> &mut Self {
+        self.instruction.tranche_id = Some(tranche_id);
+        self
+    }
+    #[inline(always)]
+    pub fn term_duration(&mut self, term_duration: i64) -> &mut Self {
+        self.instruction.term_duration = Some(term_duration);
+        self
+    }
+    #[inline(always)]
+    pub fn annual_yield_bps(&mut self, annual_yield_bps: u64) -> &mut Self {
+        self.instruction.annual_yield_bps = Some(annual_yield_bps);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = InitializeTrancheInstructionArgs {
+            tranche_id: self
+                .instruction
+                .tranche_id
+                .clone()
+                .expect("tranche_id is not set"),
+            term_duration: self
+                .instruction
+                .term_duration
+                .clone()
+                .expect("term_duration is not set"),
+            annual_yield_bps: self
+                .instruction
+                .annual_yield_bps
+                .clone()
+                .expect("annual_yield_bps is not set"),
+        };
+        let instruction = InitializeTrancheCpi {
+            __program: self.instruction.__program,
+
+            pool_manager: self
+                .instruction
+                .pool_manager
+                .expect("pool_manager is not set"),
+
+            tranche: self.instruction.tranche.expect("tranche is not set"),
+
+            x_mint: self.instruction.x_mint.expect("x_mint is not set"),
+
+            tranche_vault: self
+                .instruction
+                .tranche_vault
+                .expect("tranche_vault is not set"),
+
+            admin: self.instruction.admin.expect("admin is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            x_token_program: self
+                .instruction
+                .x_token_program
+                .expect("x_token_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct InitializeTrancheCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    pool_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tranche: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    x_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tranche_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    x_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tranche_id: Option<u64>,
+    term_duration: Option<i64>,
+    annual_yield_bps: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,980 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct MigratePoolManager {
+    pub legacy_pool_manager: solana_program::pubkey::Pubkey,
+
+    pub legacy_vault: solana_program::pubkey::Pubkey,
+
+    pub base_mint: solana_program::pubkey::Pubkey,
+    /// The legacy x mint, handed over to the new pool manager
+    pub x_mint: solana_program::pubkey::Pubkey,
+
+    pub metadata: solana_program::pubkey::Pubkey,
+
+    pub pool_manager: solana_program::pubkey::Pubkey,
+
+    pub yield_schedule: solana_program::pubkey::Pubkey,
+
+    pub rate_feed: solana_program::pubkey::Pubkey,
+
+    pub rate_history: solana_program::pubkey::Pubkey,
+
+    pub vault: solana_program::pubkey::Pubkey,
+    /// Token manager of the base mint, bound to the pool for yield minting
+    pub token_manager: solana_program::pubkey::Pubkey,
+
+    pub owner: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub token_metadata_program: solana_program::pubkey::Pubkey,
+
+    pub associated_token_program: solana_program::pubkey::Pubkey,
+
+    pub parity_issuance_program: solana_program::pubkey::Pubkey,
+}
+
+impl MigratePoolManager {
+    pub fn instruction(
+        &self,
+        args: MigratePoolManagerInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: MigratePoolManagerInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(17 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.legacy_pool_manager,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.legacy_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.base_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.x_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.metadata,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.pool_manager,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.yield_schedule,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_feed,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_history,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_manager,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.owner, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_metadata_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.associated_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.parity_issuance_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = MigratePoolManagerInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct MigratePoolManagerInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl MigratePoolManagerInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [40, 181, 89, 155, 227, 240, 87, 5],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigratePoolManagerInstructionArgs {
+    pub pool_id: u64,
+}
+
+/// Instruction builder for `MigratePoolManager`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` legacy_pool_manager
+///   1. `[writable]` legacy_vault
+///   2. `[]` base_mint
+///   3. `[writable]` x_mint
+///   4. `[writable]` metadata
+///   5. `[writable]` pool_manager
+///   6. `[writable]` yield_schedule
+///   7. `[writable]` rate_feed
+///   8. `[writable]` rate_history
+///   9. `[writable]` vault
+///   10. `[]` token_manager
+///   11. `[writable, signer]` owner
+///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   13. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   14. `[optional]` token_metadata_program (default to `metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s`)
+///   15. `[]` associated_token_program
+///   16. `[]` parity_issuance_program
+#[derive(Default)]
+pub struct MigratePoolManagerBuilder {
+    legacy_pool_manager: Option<solana_program::pubkey::Pubkey>,
+    legacy_vault: Option<solana_program::pubkey::Pubkey>,
+    base_mint: Option<solana_program::pubkey::Pubkey>,
+    x_mint: Option<solana_program::pubkey::Pubkey>,
+    metadata: Option<solana_program::pubkey::Pubkey>,
+    pool_manager: Option<solana_program::pubkey::Pubkey>,
+    yield_schedule: Option<solana_program::pubkey::Pubkey>,
+    rate_feed: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    token_manager: Option<solana_program::pubkey::Pubkey>,
+    owner: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    token_metadata_program: Option<solana_program::pubkey::Pubkey>,
+    associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    parity_issuance_program: Option<solana_program::pubkey::Pubkey>,
+    pool_id: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MigratePoolManagerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn legacy_pool_manager(
+        &mut self,
+        legacy_pool_manager: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.legacy_pool_manager = Some(legacy_pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn legacy_vault(&mut self, legacy_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.legacy_vault = Some(legacy_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn base_mint(&mut self, base_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.base_mint = Some(base_mint);
+        self
+    }
+    /// The legacy x mint, handed over to the new pool manager
+    #[inline(always)]
+    pub fn x_mint(&mut self, x_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.x_mint = Some(x_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn metadata(&mut self, metadata: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.metadata = Some(metadata);
+        self
+    }
+    #[inline(always)]
+    pub fn pool_manager(&mut self, pool_manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn yield_schedule(&mut self, yield_schedule: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(&mut self, rate_feed: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(&mut self, rate_history: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    /// Token manager of the base mint, bound to the pool for yield minting
+    #[inline(always)]
+    pub fn token_manager(&mut self, token_manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_manager = Some(token_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn owner(&mut self, owner: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to 'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s']`
+    #[inline(always)]
+    pub fn token_metadata_program(
+        &mut self,
+        token_metadata_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.token_metadata_program = Some(token_metadata_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.associated_token_program = Some(associated_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn parity_issuance_program(
+        &mut self,
+        parity_issuance_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.parity_issuance_program = Some(parity_issuance_program);
+        self
+    }
+    #[inline(always)]
+    pub fn pool_id(&mut self, pool_id: u64) -> &mut Self {
+        self.pool_id = Some(pool_id);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts =
+            MigratePoolManager {
+                legacy_pool_manager: self
+                    .legacy_pool_manager
+                    .expect("legacy_pool_manager is not set"),
+                legacy_vault: self.legacy_vault.expect("legacy_vault is not set"),
+                base_mint: self.base_mint.expect("base_mint is not set"),
+                x_mint: self.x_mint.expect("x_mint is not set"),
+                metadata: self.metadata.expect("metadata is not set"),
+                pool_manager: self.pool_manager.expect("pool_manager is not set"),
+                yield_schedule: self.yield_schedule.expect("yield_schedule is not set"),
+                rate_feed: self.rate_feed.expect("rate_feed is not set"),
+                rate_history: self.rate_history.expect("rate_history is not set"),
+                vault: self.vault.expect("vault is not set"),
+                token_manager: self.token_manager.expect("token_manager is not set"),
+                owner: self.owner.expect("owner is not set"),
+                system_program: self
+                    .system_program
+                    .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+                token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+                )),
+                token_metadata_program: self.token_metadata_program.unwrap_or(
+                    solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"),
+                ),
+                associated_token_program: self
+                    .associated_token_program
+                    .expect("associated_token_program is not set"),
+                parity_issuance_program: self
+                    .parity_issuance_program
+                    .expect("parity_issuance_program is not set"),
+            };
+        let args = MigratePoolManagerInstructionArgs {
+            pool_id: self.pool_id.clone().expect("pool_id is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `migrate_pool_manager` CPI accounts.
+pub struct MigratePoolManagerCpiAccounts<'a, 'b> {
+    pub legacy_pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub legacy_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The legacy x mint, handed over to the new pool manager
+    pub x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub metadata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token manager of the base mint, bound to the pool for yield minting
+    pub token_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_metadata_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `migrate_pool_manager` CPI instruction.
+pub struct MigratePoolManagerCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub legacy_pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub legacy_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The legacy x mint, handed over to the new pool manager
+    pub x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub metadata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token manager of the base mint, bound to the pool for yield minting
+    pub token_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub owner: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_metadata_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: MigratePoolManagerInstructionArgs,
+}
+
+impl<'a, 'b> MigratePoolManagerCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MigratePoolManagerCpiAccounts<'a, 'b>,
+        args: MigratePoolManagerInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            legacy_pool_manager: accounts.legacy_pool_manager,
+            legacy_vault: accounts.legacy_vault,
+            base_mint: accounts.base_mint,
+            x_mint: accounts.x_mint,
+            metadata: accounts.metadata,
+            pool_manager: accounts.pool_manager,
+            yield_schedule: accounts.yield_schedule,
+            rate_feed: accounts.rate_feed,
+            rate_history: accounts.rate_history,
+            vault: accounts.vault,
+            token_manager: accounts.token_manager,
+            owner: accounts.owner,
+            system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            token_metadata_program: accounts.token_metadata_program,
+            associated_token_program: accounts.associated_token_program,
+            parity_issuance_program: accounts.parity_issuance_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(17 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.legacy_pool_manager.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.legacy_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.base_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.x_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.metadata.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.pool_manager.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.yield_schedule.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_feed.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_history.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_manager.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.owner.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_metadata_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.associated_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.parity_issuance_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = MigratePoolManagerInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(17 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.legacy_pool_manager.clone());
+        account_infos.push(self.legacy_vault.clone());
+        account_infos.push(self.base_mint.clone());
+        account_infos.push(self.x_mint.clone());
+        account_infos.push(self.metadata.clone());
+        account_infos.push(self.pool_manager.clone());
+        account_infos.push(self.yield_schedule.clone());
+        account_infos.push(self.rate_feed.clone());
+        account_infos.push(self.rate_history.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.token_manager.clone());
+        account_infos.push(self.owner.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.token_metadata_program.clone());
+        account_infos.push(self.associated_token_program.clone());
+        account_infos.push(self.parity_issuance_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MigratePoolManager` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` legacy_pool_manager
+///   1. `[writable]` legacy_vault
+///   2. `[]` base_mint
+///   3. `[writable]` x_mint
+///   4. `[writable]` metadata
+///   5. `[writable]` pool_manager
+///   6. `[writable]` yield_schedule
+///   7. `[writable]` rate_feed
+///   8. `[writable]` rate_history
+///   9. `[writable]` vault
+///   10. `[]` token_manager
+///   11. `[writable, signer]` owner
+///   12. `[]` system_program
+///   13. `[]` token_program
+///   14. `[]` token_metadata_program
+///   15. `[]` associated_token_program
+///   16. `[]` parity_issuance_program
+pub struct MigratePoolManagerCpiBuilder<'a, 'b> {
+    instruction: Box<MigratePoolManagerCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MigratePoolManagerCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MigratePoolManagerCpiBuilderInstruction {
+            __program: program,
+            legacy_pool_manager: None,
+            legacy_vault: None,
+            base_mint: None,
+            x_mint: None,
+            metadata: None,
+            pool_manager: None,
+            yield_schedule: None,
+            rate_feed: None,
+            rate_history: None,
+            vault: None,
+            token_manager: None,
+            owner: None,
+            system_program: None,
+            token_program: None,
+            token_metadata_program: None,
+            associated_token_program: None,
+            parity_issuance_program: None,
+            pool_id: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn legacy_pool_manager(
+        &mut self,
+        legacy_pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.legacy_pool_manager = Some(legacy_pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn legacy_vault(
+        &mut self,
+        legacy_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.legacy_vault = Some(legacy_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn base_mint(
+        &mut self,
+        base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.base_mint = Some(base_mint);
+        self
+    }
+    /// The legacy x mint, handed over to the new pool manager
+    #[inline(always)]
+    pub fn x_mint(
+        &mut self,
+        x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.x_mint = Some(x_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn metadata(
+        &mut self,
+        metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.metadata = Some(metadata);
+        self
+    }
+    #[inline(always)]
+    pub fn pool_manager(
+        &mut self,
+        pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn yield_schedule(
+        &mut self,
+        yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(
+        &mut self,
+        rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    /// Token manager of the base mint, bound to the pool for yield minting
+    #[inline(always)]
+    pub fn token_manager(
+        &mut self,
+        token_manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_manager = Some(token_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn owner(&mut self, owner: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.owner = Some(owner);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_metadata_program(
+        &mut self,
+        token_metadata_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_metadata_program = Some(token_metadata_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.associated_token_program = Some(associated_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn parity_issuance_program(
+        &mut self,
+        parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.parity_issuance_program = Some(parity_issuance_program);
+        self
+    }
+    #[inline(always)]
+    pub fn pool_id(&mut self, pool_id: u64) -> &mut Self {
+        self.instruction.pool_id = Some(pool_id);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = MigratePoolManagerInstructionArgs {
+            pool_id: self
+                .instruction
+                .pool_id
+                .clone()
+                .expect("pool_id is not set"),
+        };
+        let instruction = MigratePoolManagerCpi {
+            __program: self.instruction.__program,
+
+            legacy_pool_manager: self
+                .instruction
+                .legacy_pool_manager
+                .expect("legacy_pool_manager is not set"),
+
+            legacy_vault: self
+                .instruction
+                .legacy_vault
+                .expect("legacy_vault is not set"),
+
+            base_mint: self.instruction.base_mint.expect("base_mint is not set"),
+
+            x_mint: self.instruction.x_mint.expect("x_mint is not set"),
+
+            metadata: self.instruction.metadata.expect("metadata is not set"),
+
+            pool_manager: self
+                .instruction
+                .pool_manager
+                .expect("pool_manager is not set"),
+
+            yield_schedule: self
+                .instruction
+                .yield_schedule
+                .expect("yield_schedule is not set"),
+
+            rate_feed: self.instruction.rate_feed.expect("rate_feed is not set"),
+
+            rate_history: self
+                .instruction
+                .rate_history
+                .expect("rate_history is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            token_manager: self
+                .instruction
+                .token_manager
+                .expect("token_manager is not set"),
+
+            owner: self.instruction.owner.expect("owner is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            token_metadata_program: self
+                .instruction
+                .token_metadata_program
+                .expect("token_metadata_program is not set"),
+
+            associated_token_program: self
+                .instruction
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+
+            parity_issuance_program: self
+                .instruction
+                .parity_issuance_program
+                .expect("parity_issuance_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct MigratePoolManagerCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    legacy_pool_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    legacy_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    x_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pool_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    yield_schedule: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_feed: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_metadata_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    parity_issuance_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pool_id: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -20,6 +20,8 @@ pub struct MintAdmin {
 
     pub minter: solana_program::pubkey::Pubkey,
 
+    pub minter_authorization: Option<solana_program::pubkey::Pubkey>,
+
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
@@ -40,7 +42,7 @@ impl MintAdmin {
         args: MintAdminInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.token_manager,
             false,
@@ -56,6 +58,17 @@ impl MintAdmin {
             self.minter,
             true,
         ));
+        if let Some(minter_authorization) = self.minter_authorization {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                minter_authorization,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_ISSUANCE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -111,15 +124,17 @@ pub struct MintAdminInstructionArgs {
 ///   1. `[writable]` mint
 ///   2. `[writable]` minter_mint_ata
 ///   3. `[signer]` minter
-///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   5. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   6. `[]` associated_token_program
+///   4. `[optional]` minter_authorization
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   7. `[]` associated_token_program
 #[derive(Default)]
 pub struct MintAdminBuilder {
     token_manager: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
     minter_mint_ata: Option<solana_program::pubkey::Pubkey>,
     minter: Option<solana_program::pubkey::Pubkey>,
+    minter_authorization: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
@@ -154,6 +169,15 @@ impl MintAdminBuilder {
         self.minter = Some(minter);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn minter_authorization(
+        &mut self,
+        minter_authorization: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.minter_authorization = minter_authorization;
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -204,6 +228,7 @@ impl MintAdminBuilder {
             mint: self.mint.expect("mint is not set"),
             minter_mint_ata: self.minter_mint_ata.expect("minter_mint_ata is not set"),
             minter: self.minter.expect("minter is not set"),
+            minter_authorization: self.minter_authorization,
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -232,6 +257,8 @@ pub struct MintAdminCpiAccounts<'a, 'b> {
 
     pub minter: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -252,6 +279,8 @@ pub struct MintAdminCpi<'a, 'b> {
 
     pub minter: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -273,6 +302,7 @@ impl<'a, 'b> MintAdminCpi<'a, 'b> {
             mint: accounts.mint,
             minter_mint_ata: accounts.minter_mint_ata,
             minter: accounts.minter,
+            minter_authorization: accounts.minter_authorization,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
             associated_token_program: accounts.associated_token_program,
@@ -312,7 +342,7 @@ impl<'a, 'b> MintAdminCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.token_manager.key,
             false,
@@ -329,6 +359,17 @@ impl<'a, 'b> MintAdminCpi<'a, 'b> {
             *self.minter.key,
             true,
         ));
+        if let Some(minter_authorization) = self.minter_authorization {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *minter_authorization.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_ISSUANCE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -357,12 +398,15 @@ impl<'a, 'b> MintAdminCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.token_manager.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.minter_mint_ata.clone());
         account_infos.push(self.minter.clone());
+        if let Some(minter_authorization) = self.minter_authorization {
+            account_infos.push(minter_authorization.clone());
+        }
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.associated_token_program.clone());
@@ -386,9 +430,10 @@ impl<'a, 'b> MintAdminCpi<'a, 'b> {
 ///   1. `[writable]` mint
 ///   2. `[writable]` minter_mint_ata
 ///   3. `[signer]` minter
-///   4. `[]` system_program
-///   5. `[]` token_program
-///   6. `[]` associated_token_program
+///   4. `[optional]` minter_authorization
+///   5. `[]` system_program
+///   6. `[]` token_program
+///   7. `[]` associated_token_program
 pub struct MintAdminCpiBuilder<'a, 'b> {
     instruction: Box<MintAdminCpiBuilderInstruction<'a, 'b>>,
 }
@@ -401,6 +446,7 @@ impl<'a, 'b> MintAdminCpiBuilder<'a, 'b> {
             mint: None,
             minter_mint_ata: None,
             minter: None,
+            minter_authorization: None,
             system_program: None,
             token_program: None,
             associated_token_program: None,
@@ -438,6 +484,15 @@ impl<'a, 'b> MintAdminCpiBuilder<'a, 'b> {
         self.instruction.minter = Some(minter);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn minter_authorization(
+        &mut self,
+        minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.minter_authorization = minter_authorization;
+        self
+    }
     #[inline(always)]
     pub fn system_program(
         &mut self,
@@ -532,6 +587,8 @@ impl<'a, 'b> MintAdminCpiBuilder<'a, 'b> {
 
             minter: self.instruction.minter.expect("minter is not set"),
 
+            minter_authorization: self.instruction.minter_authorization,
+
             system_program: self
                 .instruction
                 .system_program
@@ -561,6 +618,7 @@ struct MintAdminCpiBuilderInstruction<'a, 'b> {
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     minter_mint_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     minter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
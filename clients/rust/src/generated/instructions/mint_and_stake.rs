@@ -0,0 +1,1206 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct MintAndStake {
+    pub pool_manager: solana_program::pubkey::Pubkey,
+
+    pub yield_schedule: solana_program::pubkey::Pubkey,
+
+    pub rate_feed: solana_program::pubkey::Pubkey,
+
+    pub rate_history: solana_program::pubkey::Pubkey,
+
+    pub base_mint: solana_program::pubkey::Pubkey,
+
+    pub payer_base_mint_ata: solana_program::pubkey::Pubkey,
+
+    pub x_mint: solana_program::pubkey::Pubkey,
+
+    pub payer_x_mint_ata: solana_program::pubkey::Pubkey,
+
+    pub vault: solana_program::pubkey::Pubkey,
+
+    pub treasury: Option<solana_program::pubkey::Pubkey>,
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    pub staker_entry: Option<solana_program::pubkey::Pubkey>,
+
+    pub payer: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub x_token_program: solana_program::pubkey::Pubkey,
+
+    pub associated_token_program: solana_program::pubkey::Pubkey,
+
+    pub token_manager: solana_program::pubkey::Pubkey,
+
+    pub quote_mint: solana_program::pubkey::Pubkey,
+
+    pub payer_quote_mint_ata: solana_program::pubkey::Pubkey,
+
+    pub issuance_vault: solana_program::pubkey::Pubkey,
+
+    pub parity_issuance_program: solana_program::pubkey::Pubkey,
+}
+
+impl MintAndStake {
+    pub fn instruction(
+        &self,
+        args: MintAndStakeInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: MintAndStakeInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.pool_manager,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.yield_schedule,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_feed,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_history,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.base_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer_base_mint_ata,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.x_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer_x_mint_ata,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                treasury, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                staker_entry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.x_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.associated_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.token_manager,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.quote_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer_quote_mint_ata,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.issuance_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.parity_issuance_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = MintAndStakeInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct MintAndStakeInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl MintAndStakeInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [124, 212, 1, 155, 34, 143, 20, 135],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintAndStakeInstructionArgs {
+    pub quantity: u64,
+    pub proof: Vec<[u8; 32]>,
+    pub min_x_amount: u64,
+}
+
+/// Instruction builder for `MintAndStake`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` pool_manager
+///   1. `[writable]` yield_schedule
+///   2. `[writable]` rate_feed
+///   3. `[writable]` rate_history
+///   4. `[writable]` base_mint
+///   5. `[writable]` payer_base_mint_ata
+///   6. `[writable]` x_mint
+///   7. `[writable]` payer_x_mint_ata
+///   8. `[writable]` vault
+///   9. `[writable, optional]` treasury
+///   10. `[writable, optional]` staker_entry
+///   11. `[writable, signer]` payer
+///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   13. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   14. `[]` x_token_program
+///   15. `[]` associated_token_program
+///   16. `[writable]` token_manager
+///   17. `[]` quote_mint
+///   18. `[writable]` payer_quote_mint_ata
+///   19. `[writable]` issuance_vault
+///   20. `[]` parity_issuance_program
+#[derive(Default)]
+pub struct MintAndStakeBuilder {
+    pool_manager: Option<solana_program::pubkey::Pubkey>,
+    yield_schedule: Option<solana_program::pubkey::Pubkey>,
+    rate_feed: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
+    base_mint: Option<solana_program::pubkey::Pubkey>,
+    payer_base_mint_ata: Option<solana_program::pubkey::Pubkey>,
+    x_mint: Option<solana_program::pubkey::Pubkey>,
+    payer_x_mint_ata: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    treasury: Option<solana_program::pubkey::Pubkey>,
+    staker_entry: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    x_token_program: Option<solana_program::pubkey::Pubkey>,
+    associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    token_manager: Option<solana_program::pubkey::Pubkey>,
+    quote_mint: Option<solana_program::pubkey::Pubkey>,
+    payer_quote_mint_ata: Option<solana_program::pubkey::Pubkey>,
+    issuance_vault: Option<solana_program::pubkey::Pubkey>,
+    parity_issuance_program: Option<solana_program::pubkey::Pubkey>,
+    quantity: Option<u64>,
+    proof: Option<Vec<[u8; 32]>>,
+    min_x_amount: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MintAndStakeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn pool_manager(&mut self, pool_manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn yield_schedule(&mut self, yield_schedule: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(&mut self, rate_feed: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(&mut self, rate_history: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
+    pub fn base_mint(&mut self, base_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.base_mint = Some(base_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn payer_base_mint_ata(
+        &mut self,
+        payer_base_mint_ata: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.payer_base_mint_ata = Some(payer_base_mint_ata);
+        self
+    }
+    #[inline(always)]
+    pub fn x_mint(&mut self, x_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.x_mint = Some(x_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn payer_x_mint_ata(
+        &mut self,
+        payer_x_mint_ata: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.payer_x_mint_ata = Some(payer_x_mint_ata);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.treasury = treasury;
+        self
+    }
+    /// `[optional account]`
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    #[inline(always)]
+    pub fn staker_entry(
+        &mut self,
+        staker_entry: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.staker_entry = staker_entry;
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.associated_token_program = Some(associated_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_manager(&mut self, token_manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_manager = Some(token_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn quote_mint(&mut self, quote_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.quote_mint = Some(quote_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn payer_quote_mint_ata(
+        &mut self,
+        payer_quote_mint_ata: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.payer_quote_mint_ata = Some(payer_quote_mint_ata);
+        self
+    }
+    #[inline(always)]
+    pub fn issuance_vault(&mut self, issuance_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.issuance_vault = Some(issuance_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn parity_issuance_program(
+        &mut self,
+        parity_issuance_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.parity_issuance_program = Some(parity_issuance_program);
+        self
+    }
+    #[inline(always)]
+    pub fn quantity(&mut self, quantity: u64) -> &mut Self {
+        self.quantity = Some(quantity);
+        self
+    }
+    #[inline(always)]
+    pub fn proof(&mut self, proof: Vec<[u8; 32]>) -> &mut Self {
+        self.proof = Some(proof);
+        self
+    }
+    #[inline(always)]
+    pub fn min_x_amount(&mut self, min_x_amount: u64) -> &mut Self {
+        self.min_x_amount = Some(min_x_amount);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = MintAndStake {
+            pool_manager: self.pool_manager.expect("pool_manager is not set"),
+            yield_schedule: self.yield_schedule.expect("yield_schedule is not set"),
+            rate_feed: self.rate_feed.expect("rate_feed is not set"),
+            rate_history: self.rate_history.expect("rate_history is not set"),
+            base_mint: self.base_mint.expect("base_mint is not set"),
+            payer_base_mint_ata: self
+                .payer_base_mint_ata
+                .expect("payer_base_mint_ata is not set"),
+            x_mint: self.x_mint.expect("x_mint is not set"),
+            payer_x_mint_ata: self.payer_x_mint_ata.expect("payer_x_mint_ata is not set"),
+            vault: self.vault.expect("vault is not set"),
+            treasury: self.treasury,
+            staker_entry: self.staker_entry,
+            payer: self.payer.expect("payer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            x_token_program: self.x_token_program.expect("x_token_program is not set"),
+            associated_token_program: self
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+            token_manager: self.token_manager.expect("token_manager is not set"),
+            quote_mint: self.quote_mint.expect("quote_mint is not set"),
+            payer_quote_mint_ata: self
+                .payer_quote_mint_ata
+                .expect("payer_quote_mint_ata is not set"),
+            issuance_vault: self.issuance_vault.expect("issuance_vault is not set"),
+            parity_issuance_program: self
+                .parity_issuance_program
+                .expect("parity_issuance_program is not set"),
+        };
+        let args = MintAndStakeInstructionArgs {
+            quantity: self.quantity.clone().expect("quantity is not set"),
+            proof: self.proof.clone().expect("proof is not set"),
+            min_x_amount: self.min_x_amount.clone().expect("min_x_amount is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `mint_and_stake` CPI accounts.
+pub struct MintAndStakeCpiAccounts<'a, 'b> {
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer_base_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer_x_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    pub staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub quote_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer_quote_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub issuance_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `mint_and_stake` CPI instruction.
+pub struct MintAndStakeCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer_base_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer_x_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    pub staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub quote_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer_quote_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub issuance_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: MintAndStakeInstructionArgs,
+}
+
+impl<'a, 'b> MintAndStakeCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MintAndStakeCpiAccounts<'a, 'b>,
+        args: MintAndStakeInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            pool_manager: accounts.pool_manager,
+            yield_schedule: accounts.yield_schedule,
+            rate_feed: accounts.rate_feed,
+            rate_history: accounts.rate_history,
+            base_mint: accounts.base_mint,
+            payer_base_mint_ata: accounts.payer_base_mint_ata,
+            x_mint: accounts.x_mint,
+            payer_x_mint_ata: accounts.payer_x_mint_ata,
+            vault: accounts.vault,
+            treasury: accounts.treasury,
+            staker_entry: accounts.staker_entry,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            x_token_program: accounts.x_token_program,
+            associated_token_program: accounts.associated_token_program,
+            token_manager: accounts.token_manager,
+            quote_mint: accounts.quote_mint,
+            payer_quote_mint_ata: accounts.payer_quote_mint_ata,
+            issuance_vault: accounts.issuance_vault,
+            parity_issuance_program: accounts.parity_issuance_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(21 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.pool_manager.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.yield_schedule.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_feed.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_history.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.base_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer_base_mint_ata.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.x_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer_x_mint_ata.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *treasury.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *staker_entry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.x_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.associated_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.token_manager.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.quote_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer_quote_mint_ata.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.issuance_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.parity_issuance_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = MintAndStakeInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(21 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.pool_manager.clone());
+        account_infos.push(self.yield_schedule.clone());
+        account_infos.push(self.rate_feed.clone());
+        account_infos.push(self.rate_history.clone());
+        account_infos.push(self.base_mint.clone());
+        account_infos.push(self.payer_base_mint_ata.clone());
+        account_infos.push(self.x_mint.clone());
+        account_infos.push(self.payer_x_mint_ata.clone());
+        account_infos.push(self.vault.clone());
+        if let Some(treasury) = self.treasury {
+            account_infos.push(treasury.clone());
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            account_infos.push(staker_entry.clone());
+        }
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.x_token_program.clone());
+        account_infos.push(self.associated_token_program.clone());
+        account_infos.push(self.token_manager.clone());
+        account_infos.push(self.quote_mint.clone());
+        account_infos.push(self.payer_quote_mint_ata.clone());
+        account_infos.push(self.issuance_vault.clone());
+        account_infos.push(self.parity_issuance_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MintAndStake` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` pool_manager
+///   1. `[writable]` yield_schedule
+///   2. `[writable]` rate_feed
+///   3. `[writable]` rate_history
+///   4. `[writable]` base_mint
+///   5. `[writable]` payer_base_mint_ata
+///   6. `[writable]` x_mint
+///   7. `[writable]` payer_x_mint_ata
+///   8. `[writable]` vault
+///   9. `[writable, optional]` treasury
+///   10. `[writable, optional]` staker_entry
+///   11. `[writable, signer]` payer
+///   12. `[]` system_program
+///   13. `[]` token_program
+///   14. `[]` x_token_program
+///   15. `[]` associated_token_program
+///   16. `[writable]` token_manager
+///   17. `[]` quote_mint
+///   18. `[writable]` payer_quote_mint_ata
+///   19. `[writable]` issuance_vault
+///   20. `[]` parity_issuance_program
+pub struct MintAndStakeCpiBuilder<'a, 'b> {
+    instruction: Box<MintAndStakeCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MintAndStakeCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MintAndStakeCpiBuilderInstruction {
+            __program: program,
+            pool_manager: None,
+            yield_schedule: None,
+            rate_feed: None,
+            rate_history: None,
+            base_mint: None,
+            payer_base_mint_ata: None,
+            x_mint: None,
+            payer_x_mint_ata: None,
+            vault: None,
+            treasury: None,
+            staker_entry: None,
+            payer: None,
+            system_program: None,
+            token_program: None,
+            x_token_program: None,
+            associated_token_program: None,
+            token_manager: None,
+            quote_mint: None,
+            payer_quote_mint_ata: None,
+            issuance_vault: None,
+            parity_issuance_program: None,
+            quantity: None,
+            proof: None,
+            min_x_amount: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn pool_manager(
+        &mut self,
+        pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn yield_schedule(
+        &mut self,
+        yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(
+        &mut self,
+        rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
+    pub fn base_mint(
+        &mut self,
+        base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.base_mint = Some(base_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn payer_base_mint_ata(
+        &mut self,
+        payer_base_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.payer_base_mint_ata = Some(payer_base_mint_ata);
+        self
+    }
+    #[inline(always)]
+    pub fn x_mint(
+        &mut self,
+        x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.x_mint = Some(x_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn payer_x_mint_ata(
+        &mut self,
+        payer_x_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.payer_x_mint_ata = Some(payer_x_mint_ata);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury = treasury;
+        self
+    }
+    /// `[optional account]`
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    #[inline(always)]
+    pub fn staker_entry(
+        &mut self,
+        staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.staker_entry = staker_entry;
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.associated_token_program = Some(associated_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_manager(
+        &mut self,
+        token_manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_manager = Some(token_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn quote_mint(
+        &mut self,
+        quote_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.quote_mint = Some(quote_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn payer_quote_mint_ata(
+        &mut self,
+        payer_quote_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.payer_quote_mint_ata = Some(payer_quote_mint_ata);
+        self
+    }
+    #[inline(always)]
+    pub fn issuance_vault(
+        &mut self,
+        issuance_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.issuance_vault = Some(issuance_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn parity_issuance_program(
+        &mut self,
+        parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.parity_issuance_program = Some(parity_issuance_program);
+        self
+    }
+    #[inline(always)]
+    pub fn quantity(&mut self, quantity: u64) -> &mut Self {
+        self.instruction.quantity = Some(quantity);
+        self
+    }
+    #[inline(always)]
+    pub fn proof(&mut self, proof: Vec<[u8; 32]>) -> &mut Self {
+        self.instruction.proof = Some(proof);
+        self
+    }
+    #[inline(always)]
+    pub fn min_x_amount(&mut self, min_x_amount: u64) -> &mut Self {
+        self.instruction.min_x_amount = Some(min_x_amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = MintAndStakeInstructionArgs {
+            quantity: self
+                .instruction
+                .quantity
+                .clone()
+                .expect("quantity is not set"),
+            proof: self.instruction.proof.clone().expect("proof is not set"),
+            min_x_amount: self
+                .instruction
+                .min_x_amount
+                .clone()
+                .expect("min_x_amount is not set"),
+        };
+        let instruction = MintAndStakeCpi {
+            __program: self.instruction.__program,
+
+            pool_manager: self
+                .instruction
+                .pool_manager
+                .expect("pool_manager is not set"),
+
+            yield_schedule: self
+                .instruction
+                .yield_schedule
+                .expect("yield_schedule is not set"),
+
+            rate_feed: self.instruction.rate_feed.expect("rate_feed is not set"),
+
+            rate_history: self
+                .instruction
+                .rate_history
+                .expect("rate_history is not set"),
+
+            base_mint: self.instruction.base_mint.expect("base_mint is not set"),
+
+            payer_base_mint_ata: self
+                .instruction
+                .payer_base_mint_ata
+                .expect("payer_base_mint_ata is not set"),
+
+            x_mint: self.instruction.x_mint.expect("x_mint is not set"),
+
+            payer_x_mint_ata: self
+                .instruction
+                .payer_x_mint_ata
+                .expect("payer_x_mint_ata is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            treasury: self.instruction.treasury,
+
+            staker_entry: self.instruction.staker_entry,
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            x_token_program: self
+                .instruction
+                .x_token_program
+                .expect("x_token_program is not set"),
+
+            associated_token_program: self
+                .instruction
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+
+            token_manager: self
+                .instruction
+                .token_manager
+                .expect("token_manager is not set"),
+
+            quote_mint: self.instruction.quote_mint.expect("quote_mint is not set"),
+
+            payer_quote_mint_ata: self
+                .instruction
+                .payer_quote_mint_ata
+                .expect("payer_quote_mint_ata is not set"),
+
+            issuance_vault: self
+                .instruction
+                .issuance_vault
+                .expect("issuance_vault is not set"),
+
+            parity_issuance_program: self
+                .instruction
+                .parity_issuance_program
+                .expect("parity_issuance_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct MintAndStakeCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    pool_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    yield_schedule: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_feed: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer_base_mint_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    x_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer_x_mint_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    x_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    quote_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer_quote_mint_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    issuance_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    parity_issuance_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    quantity: Option<u64>,
+    proof: Option<Vec<[u8; 32]>>,
+    min_x_amount: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
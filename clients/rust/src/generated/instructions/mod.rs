@@ -5,70 +5,150 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#accrue_yield;
 pub(crate) mod r#add_gatekeeper;
+pub(crate) mod r#add_guardian;
+pub(crate) mod r#add_minter;
+pub(crate) mod r#add_pt_guardian;
+pub(crate) mod r#add_staker_entry;
+pub(crate) mod r#add_strategy;
+pub(crate) mod r#allocate_to_strategy;
+pub(crate) mod r#claim_unstake;
+pub(crate) mod r#close_term_position;
 pub(crate) mod r#deposit_funds;
+pub(crate) mod r#deposit_rewards;
+pub(crate) mod r#emergency_withdraw_all;
+pub(crate) mod r#get_accrued_yield;
+pub(crate) mod r#get_effective_apy;
+pub(crate) mod r#get_trailing_apy;
 pub(crate) mod r#init_pt_stake;
 pub(crate) mod r#initialize_global_config;
 pub(crate) mod r#initialize_pool_manager;
 pub(crate) mod r#initialize_token_manager;
+pub(crate) mod r#initialize_tranche;
 pub(crate) mod r#initialize_withdraw_funds;
 pub(crate) mod r#initiate_update_global_config_owner;
+pub(crate) mod r#initiate_update_issuance;
 pub(crate) mod r#initiate_update_manager_owner;
 pub(crate) mod r#initiate_update_pool_owner;
+pub(crate) mod r#migrate_global_config;
+pub(crate) mod r#migrate_pool_manager;
+pub(crate) mod r#migrate_token_manager;
 pub(crate) mod r#mint;
 pub(crate) mod r#mint_admin;
+pub(crate) mod r#mint_and_stake;
+pub(crate) mod r#open_term_position;
+pub(crate) mod r#preview_mint;
+pub(crate) mod r#preview_redeem;
+pub(crate) mod r#preview_stake;
+pub(crate) mod r#preview_unstake;
 pub(crate) mod r#pt_stake;
 pub(crate) mod r#pt_unstake;
 pub(crate) mod r#redeem;
+pub(crate) mod r#refresh_rate_feed;
 pub(crate) mod r#remove_gatekeeper;
+pub(crate) mod r#remove_guardian;
+pub(crate) mod r#remove_minter;
+pub(crate) mod r#remove_pt_guardian;
+pub(crate) mod r#report_strategy_value;
+pub(crate) mod r#request_unstake;
 pub(crate) mod r#stake;
 pub(crate) mod r#toggle_active;
+pub(crate) mod r#toggle_pt_staking_active;
+pub(crate) mod r#toggle_staking_active;
 pub(crate) mod r#unstake;
+pub(crate) mod r#unstake_and_redeem;
 pub(crate) mod r#update_annual_yield;
 pub(crate) mod r#update_global_config;
 pub(crate) mod r#update_global_config_owner;
+pub(crate) mod r#update_issuance;
 pub(crate) mod r#update_manager_owner;
 pub(crate) mod r#update_mint_metadata;
 pub(crate) mod r#update_pool_manager;
 pub(crate) mod r#update_pool_owner;
+pub(crate) mod r#update_staker_entry;
+pub(crate) mod r#update_strategy;
 pub(crate) mod r#update_token_manager_admin;
 pub(crate) mod r#update_token_manager_owner;
+pub(crate) mod r#update_tranche;
 pub(crate) mod r#update_xmint_metadata;
+pub(crate) mod r#update_yield_schedule;
 pub(crate) mod r#withdraw_excess_issuance;
 pub(crate) mod r#withdraw_excess_parity;
 pub(crate) mod r#withdraw_excess_pt;
+pub(crate) mod r#withdraw_from_strategy;
 pub(crate) mod r#withdraw_funds;
 
+pub use self::r#accrue_yield::*;
 pub use self::r#add_gatekeeper::*;
+pub use self::r#add_guardian::*;
+pub use self::r#add_minter::*;
+pub use self::r#add_pt_guardian::*;
+pub use self::r#add_staker_entry::*;
+pub use self::r#add_strategy::*;
+pub use self::r#allocate_to_strategy::*;
+pub use self::r#claim_unstake::*;
+pub use self::r#close_term_position::*;
 pub use self::r#deposit_funds::*;
+pub use self::r#deposit_rewards::*;
+pub use self::r#emergency_withdraw_all::*;
+pub use self::r#get_accrued_yield::*;
+pub use self::r#get_effective_apy::*;
+pub use self::r#get_trailing_apy::*;
 pub use self::r#init_pt_stake::*;
 pub use self::r#initialize_global_config::*;
 pub use self::r#initialize_pool_manager::*;
 pub use self::r#initialize_token_manager::*;
+pub use self::r#initialize_tranche::*;
 pub use self::r#initialize_withdraw_funds::*;
 pub use self::r#initiate_update_global_config_owner::*;
+pub use self::r#initiate_update_issuance::*;
 pub use self::r#initiate_update_manager_owner::*;
 pub use self::r#initiate_update_pool_owner::*;
+pub use self::r#migrate_global_config::*;
+pub use self::r#migrate_pool_manager::*;
+pub use self::r#migrate_token_manager::*;
 pub use self::r#mint::*;
 pub use self::r#mint_admin::*;
+pub use self::r#mint_and_stake::*;
+pub use self::r#open_term_position::*;
+pub use self::r#preview_mint::*;
+pub use self::r#preview_redeem::*;
+pub use self::r#preview_stake::*;
+pub use self::r#preview_unstake::*;
 pub use self::r#pt_stake::*;
 pub use self::r#pt_unstake::*;
 pub use self::r#redeem::*;
+pub use self::r#refresh_rate_feed::*;
 pub use self::r#remove_gatekeeper::*;
+pub use self::r#remove_guardian::*;
+pub use self::r#remove_minter::*;
+pub use self::r#remove_pt_guardian::*;
+pub use self::r#report_strategy_value::*;
+pub use self::r#request_unstake::*;
 pub use self::r#stake::*;
 pub use self::r#toggle_active::*;
+pub use self::r#toggle_pt_staking_active::*;
+pub use self::r#toggle_staking_active::*;
 pub use self::r#unstake::*;
+pub use self::r#unstake_and_redeem::*;
 pub use self::r#update_annual_yield::*;
 pub use self::r#update_global_config::*;
 pub use self::r#update_global_config_owner::*;
+pub use self::r#update_issuance::*;
 pub use self::r#update_manager_owner::*;
 pub use self::r#update_mint_metadata::*;
 pub use self::r#update_pool_manager::*;
 pub use self::r#update_pool_owner::*;
+pub use self::r#update_staker_entry::*;
+pub use self::r#update_strategy::*;
 pub use self::r#update_token_manager_admin::*;
 pub use self::r#update_token_manager_owner::*;
+pub use self::r#update_tranche::*;
 pub use self::r#update_xmint_metadata::*;
+pub use self::r#update_yield_schedule::*;
 pub use self::r#withdraw_excess_issuance::*;
 pub use self::r#withdraw_excess_parity::*;
 pub use self::r#withdraw_excess_pt::*;
+pub use self::r#withdraw_from_strategy::*;
 pub use self::r#withdraw_funds::*;
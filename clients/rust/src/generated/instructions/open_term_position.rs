@@ -0,0 +1,1146 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct OpenTermPosition {
+    pub pool_manager: solana_program::pubkey::Pubkey,
+
+    pub yield_schedule: solana_program::pubkey::Pubkey,
+
+    pub rate_feed: solana_program::pubkey::Pubkey,
+
+    pub rate_history: solana_program::pubkey::Pubkey,
+
+    pub base_mint: solana_program::pubkey::Pubkey,
+
+    pub payer_base_mint_ata: solana_program::pubkey::Pubkey,
+
+    pub x_mint: solana_program::pubkey::Pubkey,
+
+    pub payer_x_mint_ata: solana_program::pubkey::Pubkey,
+
+    pub vault: solana_program::pubkey::Pubkey,
+
+    pub treasury: Option<solana_program::pubkey::Pubkey>,
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    pub staker_entry: Option<solana_program::pubkey::Pubkey>,
+
+    pub payer: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub x_token_program: solana_program::pubkey::Pubkey,
+
+    pub associated_token_program: solana_program::pubkey::Pubkey,
+
+    pub tranche: solana_program::pubkey::Pubkey,
+
+    pub tranche_vault: solana_program::pubkey::Pubkey,
+
+    pub term_position: solana_program::pubkey::Pubkey,
+
+    pub system_program_2: solana_program::pubkey::Pubkey,
+}
+
+impl OpenTermPosition {
+    pub fn instruction(
+        &self,
+        args: OpenTermPositionInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: OpenTermPositionInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.pool_manager,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.yield_schedule,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_feed,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_history,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.base_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer_base_mint_ata,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.x_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer_x_mint_ata,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                treasury, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                staker_entry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.x_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.associated_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tranche,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tranche_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.term_position,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program_2,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = OpenTermPositionInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct OpenTermPositionInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl OpenTermPositionInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [9, 26, 208, 31, 33, 139, 92, 62],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OpenTermPositionInstructionArgs {
+    pub quantity: u64,
+    pub position_id: u64,
+}
+
+/// Instruction builder for `OpenTermPosition`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` pool_manager
+///   1. `[writable]` yield_schedule
+///   2. `[writable]` rate_feed
+///   3. `[writable]` rate_history
+///   4. `[writable]` base_mint
+///   5. `[writable]` payer_base_mint_ata
+///   6. `[writable]` x_mint
+///   7. `[writable]` payer_x_mint_ata
+///   8. `[writable]` vault
+///   9. `[writable, optional]` treasury
+///   10. `[writable, optional]` staker_entry
+///   11. `[writable, signer]` payer
+///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   13. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   14. `[]` x_token_program
+///   15. `[]` associated_token_program
+///   16. `[writable]` tranche
+///   17. `[writable]` tranche_vault
+///   18. `[writable]` term_position
+///   19. `[optional]` system_program_2 (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct OpenTermPositionBuilder {
+    pool_manager: Option<solana_program::pubkey::Pubkey>,
+    yield_schedule: Option<solana_program::pubkey::Pubkey>,
+    rate_feed: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
+    base_mint: Option<solana_program::pubkey::Pubkey>,
+    payer_base_mint_ata: Option<solana_program::pubkey::Pubkey>,
+    x_mint: Option<solana_program::pubkey::Pubkey>,
+    payer_x_mint_ata: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    treasury: Option<solana_program::pubkey::Pubkey>,
+    staker_entry: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    x_token_program: Option<solana_program::pubkey::Pubkey>,
+    associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    tranche: Option<solana_program::pubkey::Pubkey>,
+    tranche_vault: Option<solana_program::pubkey::Pubkey>,
+    term_position: Option<solana_program::pubkey::Pubkey>,
+    system_program_2: Option<solana_program::pubkey::Pubkey>,
+    quantity: Option<u64>,
+    position_id: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl OpenTermPositionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn pool_manager(&mut self, pool_manager: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn yield_schedule(&mut self, yield_schedule: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(&mut self, rate_feed: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(&mut self, rate_history: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
+    pub fn base_mint(&mut self, base_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.base_mint = Some(base_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn payer_base_mint_ata(
+        &mut self,
+        payer_base_mint_ata: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.payer_base_mint_ata = Some(payer_base_mint_ata);
+        self
+    }
+    #[inline(always)]
+    pub fn x_mint(&mut self, x_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.x_mint = Some(x_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn payer_x_mint_ata(
+        &mut self,
+        payer_x_mint_ata: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.payer_x_mint_ata = Some(payer_x_mint_ata);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.treasury = treasury;
+        self
+    }
+    /// `[optional account]`
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    #[inline(always)]
+    pub fn staker_entry(
+        &mut self,
+        staker_entry: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.staker_entry = staker_entry;
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.associated_token_program = Some(associated_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn tranche(&mut self, tranche: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tranche = Some(tranche);
+        self
+    }
+    #[inline(always)]
+    pub fn tranche_vault(&mut self, tranche_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tranche_vault = Some(tranche_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn term_position(&mut self, term_position: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.term_position = Some(term_position);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program_2(
+        &mut self,
+        system_program_2: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.system_program_2 = Some(system_program_2);
+        self
+    }
+    #[inline(always)]
+    pub fn quantity(&mut self, quantity: u64) -> &mut Self {
+        self.quantity = Some(quantity);
+        self
+    }
+    #[inline(always)]
+    pub fn position_id(&mut self, position_id: u64) -> &mut Self {
+        self.position_id = Some(position_id);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = OpenTermPosition {
+            pool_manager: self.pool_manager.expect("pool_manager is not set"),
+            yield_schedule: self.yield_schedule.expect("yield_schedule is not set"),
+            rate_feed: self.rate_feed.expect("rate_feed is not set"),
+            rate_history: self.rate_history.expect("rate_history is not set"),
+            base_mint: self.base_mint.expect("base_mint is not set"),
+            payer_base_mint_ata: self
+                .payer_base_mint_ata
+                .expect("payer_base_mint_ata is not set"),
+            x_mint: self.x_mint.expect("x_mint is not set"),
+            payer_x_mint_ata: self.payer_x_mint_ata.expect("payer_x_mint_ata is not set"),
+            vault: self.vault.expect("vault is not set"),
+            treasury: self.treasury,
+            staker_entry: self.staker_entry,
+            payer: self.payer.expect("payer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            x_token_program: self.x_token_program.expect("x_token_program is not set"),
+            associated_token_program: self
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+            tranche: self.tranche.expect("tranche is not set"),
+            tranche_vault: self.tranche_vault.expect("tranche_vault is not set"),
+            term_position: self.term_position.expect("term_position is not set"),
+            system_program_2: self
+                .system_program_2
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = OpenTermPositionInstructionArgs {
+            quantity: self.quantity.clone().expect("quantity is not set"),
+            position_id: self.position_id.clone().expect("position_id is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `open_term_position` CPI accounts.
+pub struct OpenTermPositionCpiAccounts<'a, 'b> {
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer_base_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer_x_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    pub staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tranche: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tranche_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub term_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program_2: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `open_term_position` CPI instruction.
+pub struct OpenTermPositionCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer_base_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer_x_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    pub staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tranche: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tranche_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub term_position: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program_2: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: OpenTermPositionInstructionArgs,
+}
+
+impl<'a, 'b> OpenTermPositionCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: OpenTermPositionCpiAccounts<'a, 'b>,
+        args: OpenTermPositionInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            pool_manager: accounts.pool_manager,
+            yield_schedule: accounts.yield_schedule,
+            rate_feed: accounts.rate_feed,
+            rate_history: accounts.rate_history,
+            base_mint: accounts.base_mint,
+            payer_base_mint_ata: accounts.payer_base_mint_ata,
+            x_mint: accounts.x_mint,
+            payer_x_mint_ata: accounts.payer_x_mint_ata,
+            vault: accounts.vault,
+            treasury: accounts.treasury,
+            staker_entry: accounts.staker_entry,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+            token_program: accounts.token_program,
+            x_token_program: accounts.x_token_program,
+            associated_token_program: accounts.associated_token_program,
+            tranche: accounts.tranche,
+            tranche_vault: accounts.tranche_vault,
+            term_position: accounts.term_position,
+            system_program_2: accounts.system_program_2,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(20 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.pool_manager.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.yield_schedule.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_feed.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_history.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.base_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer_base_mint_ata.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.x_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer_x_mint_ata.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *treasury.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *staker_entry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.x_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.associated_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tranche.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tranche_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.term_position.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program_2.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = OpenTermPositionInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PARITY_STAKING_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(20 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.pool_manager.clone());
+        account_infos.push(self.yield_schedule.clone());
+        account_infos.push(self.rate_feed.clone());
+        account_infos.push(self.rate_history.clone());
+        account_infos.push(self.base_mint.clone());
+        account_infos.push(self.payer_base_mint_ata.clone());
+        account_infos.push(self.x_mint.clone());
+        account_infos.push(self.payer_x_mint_ata.clone());
+        account_infos.push(self.vault.clone());
+        if let Some(treasury) = self.treasury {
+            account_infos.push(treasury.clone());
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            account_infos.push(staker_entry.clone());
+        }
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.x_token_program.clone());
+        account_infos.push(self.associated_token_program.clone());
+        account_infos.push(self.tranche.clone());
+        account_infos.push(self.tranche_vault.clone());
+        account_infos.push(self.term_position.clone());
+        account_infos.push(self.system_program_2.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `OpenTermPosition` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` pool_manager
+///   1. `[writable]` yield_schedule
+///   2. `[writable]` rate_feed
+///   3. `[writable]` rate_history
+///   4. `[writable]` base_mint
+///   5. `[writable]` payer_base_mint_ata
+///   6. `[writable]` x_mint
+///   7. `[writable]` payer_x_mint_ata
+///   8. `[writable]` vault
+///   9. `[writable, optional]` treasury
+///   10. `[writable, optional]` staker_entry
+///   11. `[writable, signer]` payer
+///   12. `[]` system_program
+///   13. `[]` token_program
+///   14. `[]` x_token_program
+///   15. `[]` associated_token_program
+///   16. `[writable]` tranche
+///   17. `[writable]` tranche_vault
+///   18. `[writable]` term_position
+///   19. `[]` system_program_2
+pub struct OpenTermPositionCpiBuilder<'a, 'b> {
+    instruction: Box<OpenTermPositionCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> OpenTermPositionCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(OpenTermPositionCpiBuilderInstruction {
+            __program: program,
+            pool_manager: None,
+            yield_schedule: None,
+            rate_feed: None,
+            rate_history: None,
+            base_mint: None,
+            payer_base_mint_ata: None,
+            x_mint: None,
+            payer_x_mint_ata: None,
+            vault: None,
+            treasury: None,
+            staker_entry: None,
+            payer: None,
+            system_program: None,
+            token_program: None,
+            x_token_program: None,
+            associated_token_program: None,
+            tranche: None,
+            tranche_vault: None,
+            term_position: None,
+            system_program_2: None,
+            quantity: None,
+            position_id: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn pool_manager(
+        &mut self,
+        pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pool_manager = Some(pool_manager);
+        self
+    }
+    #[inline(always)]
+    pub fn yield_schedule(
+        &mut self,
+        yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(
+        &mut self,
+        rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
+    pub fn base_mint(
+        &mut self,
+        base_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.base_mint = Some(base_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn payer_base_mint_ata(
+        &mut self,
+        payer_base_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.payer_base_mint_ata = Some(payer_base_mint_ata);
+        self
+    }
+    #[inline(always)]
+    pub fn x_mint(
+        &mut self,
+        x_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.x_mint = Some(x_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn payer_x_mint_ata(
+        &mut self,
+        payer_x_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.payer_x_mint_ata = Some(payer_x_mint_ata);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury = treasury;
+        self
+    }
+    /// `[optional account]`
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    #[inline(always)]
+    pub fn staker_entry(
+        &mut self,
+        staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.staker_entry = staker_entry;
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.associated_token_program = Some(associated_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn tranche(
+        &mut self,
+        tranche: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tranche = Some(tranche);
+        self
+    }
+    #[inline(always)]
+    pub fn tranche_vault(
+        &mut self,
+        tranche_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tranche_vault = Some(tranche_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn term_position(
+        &mut self,
+        term_position: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.term_position = Some(term_position);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program_2(
+        &mut self,
+        system_program_2: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program_2 = Some(system_program_2);
+        self
+    }
+    #[inline(always)]
+    pub fn quantity(&mut self, quantity: u64) -> &mut Self {
+        self.instruction.quantity = Some(quantity);
+        self
+    }
+    #[inline(always)]
+    pub fn position_id(&mut self, position_id: u64) -> &mut Self {
+        self.instruction.position_id = Some(position_id);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = OpenTermPositionInstructionArgs {
+            quantity: self
+                .instruction
+                .quantity
+                .clone()
+                .expect("quantity is not set"),
+            position_id: self
+                .instruction
+                .position_id
+                .clone()
+                .expect("position_id is not set"),
+        };
+        let instruction = OpenTermPositionCpi {
+            __program: self.instruction.__program,
+
+            pool_manager: self
+                .instruction
+                .pool_manager
+                .expect("pool_manager is not set"),
+
+            yield_schedule: self
+                .instruction
+                .yield_schedule
+                .expect("yield_schedule is not set"),
+
+            rate_feed: self.instruction.rate_feed.expect("rate_feed is not set"),
+
+            rate_history: self
+                .instruction
+                .rate_history
+                .expect("rate_history is not set"),
+
+            base_mint: self.instruction.base_mint.expect("base_mint is not set"),
+
+            payer_base_mint_ata: self
+                .instruction
+                .payer_base_mint_ata
+                .expect("payer_base_mint_ata is not set"),
+
+            x_mint: self.instruction.x_mint.expect("x_mint is not set"),
+
+            payer_x_mint_ata: self
+                .instruction
+                .payer_x_mint_ata
+                .expect("payer_x_mint_ata is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            treasury: self.instruction.treasury,
+
+            staker_entry: self.instruction.staker_entry,
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            x_token_program: self
+                .instruction
+                .x_token_program
+                .expect("x_token_program is not set"),
+
+            associated_token_program: self
+                .instruction
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+
+            tranche: self.instruction.tranche.expect("tranche is not set"),
+
+            tranche_vault: self
+                .instruction
+                .tranche_vault
+                .expect("tranche_vault is not set"),
+
+            term_position: self
+                .instruction
+                .term_position
+                .expect("term_position is not set"),
+
+            system_program_2: self
+                .instruction
+                .system_program_2
+                .expect("system_program_2 is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct OpenTermPositionCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    pool_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    yield_schedule: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_feed: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer_base_mint_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    x_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer_x_mint_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    x_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tranche: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tranche_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    term_position: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program_2: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    quantity: Option<u64>,
+    position_id: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -14,6 +14,12 @@ use borsh::{BorshDeserialize, BorshSerialize};
 pub struct Stake {
     pub pool_manager: solana_program::pubkey::Pubkey,
 
+    pub yield_schedule: solana_program::pubkey::Pubkey,
+
+    pub rate_feed: solana_program::pubkey::Pubkey,
+
+    pub rate_history: solana_program::pubkey::Pubkey,
+
     pub base_mint: solana_program::pubkey::Pubkey,
 
     pub payer_base_mint_ata: solana_program::pubkey::Pubkey,
@@ -24,12 +30,18 @@ pub struct Stake {
 
     pub vault: solana_program::pubkey::Pubkey,
 
+    pub treasury: Option<solana_program::pubkey::Pubkey>,
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    pub staker_entry: Option<solana_program::pubkey::Pubkey>,
+
     pub payer: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
 
+    pub x_token_program: solana_program::pubkey::Pubkey,
+
     pub associated_token_program: solana_program::pubkey::Pubkey,
 }
 
@@ -46,11 +58,23 @@ impl Stake {
         args: StakeInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.pool_manager,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.yield_schedule,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_feed,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_history,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.base_mint,
             false,
@@ -70,6 +94,27 @@ impl Stake {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.vault, false,
         ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                treasury, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                staker_entry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
@@ -81,6 +126,10 @@ impl Stake {
             self.token_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.x_token_program,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.associated_token_program,
             false,
@@ -125,26 +174,38 @@ pub struct StakeInstructionArgs {
 /// ### Accounts:
 ///
 ///   0. `[writable]` pool_manager
-///   1. `[writable]` base_mint
-///   2. `[writable]` payer_base_mint_ata
-///   3. `[writable]` x_mint
-///   4. `[writable]` payer_x_mint_ata
-///   5. `[writable]` vault
-///   6. `[writable, signer]` payer
-///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   9. `[]` associated_token_program
+///   1. `[writable]` yield_schedule
+///   2. `[writable]` rate_feed
+///   3. `[writable]` rate_history
+///   4. `[writable]` base_mint
+///   5. `[writable]` payer_base_mint_ata
+///   6. `[writable]` x_mint
+///   7. `[writable]` payer_x_mint_ata
+///   8. `[writable]` vault
+///   9. `[writable, optional]` treasury
+///   10. `[writable, optional]` staker_entry
+///   11. `[writable, signer]` payer
+///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   13. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   14. `[]` x_token_program
+///   15. `[]` associated_token_program
 #[derive(Default)]
 pub struct StakeBuilder {
     pool_manager: Option<solana_program::pubkey::Pubkey>,
+    yield_schedule: Option<solana_program::pubkey::Pubkey>,
+    rate_feed: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
     base_mint: Option<solana_program::pubkey::Pubkey>,
     payer_base_mint_ata: Option<solana_program::pubkey::Pubkey>,
     x_mint: Option<solana_program::pubkey::Pubkey>,
     payer_x_mint_ata: Option<solana_program::pubkey::Pubkey>,
     vault: Option<solana_program::pubkey::Pubkey>,
+    treasury: Option<solana_program::pubkey::Pubkey>,
+    staker_entry: Option<solana_program::pubkey::Pubkey>,
     payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
+    x_token_program: Option<solana_program::pubkey::Pubkey>,
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
     quantity: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -160,6 +221,21 @@ impl StakeBuilder {
         self
     }
     #[inline(always)]
+    pub fn yield_schedule(&mut self, yield_schedule: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(&mut self, rate_feed: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(&mut self, rate_history: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
     pub fn base_mint(&mut self, base_mint: solana_program::pubkey::Pubkey) -> &mut Self {
         self.base_mint = Some(base_mint);
         self
@@ -190,6 +266,22 @@ impl StakeBuilder {
         self.vault = Some(vault);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.treasury = treasury;
+        self
+    }
+    /// `[optional account]`
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    #[inline(always)]
+    pub fn staker_entry(
+        &mut self,
+        staker_entry: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.staker_entry = staker_entry;
+        self
+    }
     #[inline(always)]
     pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
         self.payer = Some(payer);
@@ -208,6 +300,14 @@ impl StakeBuilder {
         self
     }
     #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
     pub fn associated_token_program(
         &mut self,
         associated_token_program: solana_program::pubkey::Pubkey,
@@ -242,6 +342,9 @@ impl StakeBuilder {
     pub fn instruction(&self) -> solana_program::instruction::Instruction {
         let accounts = Stake {
             pool_manager: self.pool_manager.expect("pool_manager is not set"),
+            yield_schedule: self.yield_schedule.expect("yield_schedule is not set"),
+            rate_feed: self.rate_feed.expect("rate_feed is not set"),
+            rate_history: self.rate_history.expect("rate_history is not set"),
             base_mint: self.base_mint.expect("base_mint is not set"),
             payer_base_mint_ata: self
                 .payer_base_mint_ata
@@ -249,6 +352,8 @@ impl StakeBuilder {
             x_mint: self.x_mint.expect("x_mint is not set"),
             payer_x_mint_ata: self.payer_x_mint_ata.expect("payer_x_mint_ata is not set"),
             vault: self.vault.expect("vault is not set"),
+            treasury: self.treasury,
+            staker_entry: self.staker_entry,
             payer: self.payer.expect("payer is not set"),
             system_program: self
                 .system_program
@@ -256,6 +361,7 @@ impl StakeBuilder {
             token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
+            x_token_program: self.x_token_program.expect("x_token_program is not set"),
             associated_token_program: self
                 .associated_token_program
                 .expect("associated_token_program is not set"),
@@ -272,6 +378,12 @@ impl StakeBuilder {
 pub struct StakeCpiAccounts<'a, 'b> {
     pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub payer_base_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
@@ -282,12 +394,18 @@ pub struct StakeCpiAccounts<'a, 'b> {
 
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    pub staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
@@ -298,6 +416,12 @@ pub struct StakeCpi<'a, 'b> {
 
     pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub payer_base_mint_ata: &'b solana_program::account_info::AccountInfo<'a>,
@@ -308,12 +432,18 @@ pub struct StakeCpi<'a, 'b> {
 
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    pub staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: StakeInstructionArgs,
@@ -328,14 +458,20 @@ impl<'a, 'b> StakeCpi<'a, 'b> {
         Self {
             __program: program,
             pool_manager: accounts.pool_manager,
+            yield_schedule: accounts.yield_schedule,
+            rate_feed: accounts.rate_feed,
+            rate_history: accounts.rate_history,
             base_mint: accounts.base_mint,
             payer_base_mint_ata: accounts.payer_base_mint_ata,
             x_mint: accounts.x_mint,
             payer_x_mint_ata: accounts.payer_x_mint_ata,
             vault: accounts.vault,
+            treasury: accounts.treasury,
+            staker_entry: accounts.staker_entry,
             payer: accounts.payer,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
+            x_token_program: accounts.x_token_program,
             associated_token_program: accounts.associated_token_program,
             __args: args,
         }
@@ -373,11 +509,23 @@ impl<'a, 'b> StakeCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.pool_manager.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.yield_schedule.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_feed.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_history.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.base_mint.key,
             false,
@@ -398,6 +546,28 @@ impl<'a, 'b> StakeCpi<'a, 'b> {
             *self.vault.key,
             false,
         ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *treasury.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *staker_entry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
             true,
@@ -410,6 +580,10 @@ impl<'a, 'b> StakeCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.x_token_program.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.associated_token_program.key,
             false,
@@ -430,17 +604,27 @@ impl<'a, 'b> StakeCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(16 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.pool_manager.clone());
+        account_infos.push(self.yield_schedule.clone());
+        account_infos.push(self.rate_feed.clone());
+        account_infos.push(self.rate_history.clone());
         account_infos.push(self.base_mint.clone());
         account_infos.push(self.payer_base_mint_ata.clone());
         account_infos.push(self.x_mint.clone());
         account_infos.push(self.payer_x_mint_ata.clone());
         account_infos.push(self.vault.clone());
+        if let Some(treasury) = self.treasury {
+            account_infos.push(treasury.clone());
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            account_infos.push(staker_entry.clone());
+        }
         account_infos.push(self.payer.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
+        account_infos.push(self.x_token_program.clone());
         account_infos.push(self.associated_token_program.clone());
         remaining_accounts
             .iter()
@@ -459,15 +643,21 @@ impl<'a, 'b> StakeCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[writable]` pool_manager
-///   1. `[writable]` base_mint
-///   2. `[writable]` payer_base_mint_ata
-///   3. `[writable]` x_mint
-///   4. `[writable]` payer_x_mint_ata
-///   5. `[writable]` vault
-///   6. `[writable, signer]` payer
-///   7. `[]` system_program
-///   8. `[]` token_program
-///   9. `[]` associated_token_program
+///   1. `[writable]` yield_schedule
+///   2. `[writable]` rate_feed
+///   3. `[writable]` rate_history
+///   4. `[writable]` base_mint
+///   5. `[writable]` payer_base_mint_ata
+///   6. `[writable]` x_mint
+///   7. `[writable]` payer_x_mint_ata
+///   8. `[writable]` vault
+///   9. `[writable, optional]` treasury
+///   10. `[writable, optional]` staker_entry
+///   11. `[writable, signer]` payer
+///   12. `[]` system_program
+///   13. `[]` token_program
+///   14. `[]` x_token_program
+///   15. `[]` associated_token_program
 pub struct StakeCpiBuilder<'a, 'b> {
     instruction: Box<StakeCpiBuilderInstruction<'a, 'b>>,
 }
@@ -477,14 +667,20 @@ impl<'a, 'b> StakeCpiBuilder<'a, 'b> {
         let instruction = Box::new(StakeCpiBuilderInstruction {
             __program: program,
             pool_manager: None,
+            yield_schedule: None,
+            rate_feed: None,
+            rate_history: None,
             base_mint: None,
             payer_base_mint_ata: None,
             x_mint: None,
             payer_x_mint_ata: None,
             vault: None,
+            treasury: None,
+            staker_entry: None,
             payer: None,
             system_program: None,
             token_program: None,
+            x_token_program: None,
             associated_token_program: None,
             quantity: None,
             __remaining_accounts: Vec::new(),
@@ -500,6 +696,30 @@ impl<'a, 'b> StakeCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn yield_schedule(
+        &mut self,
+        yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(
+        &mut self,
+        rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
     pub fn base_mint(
         &mut self,
         base_mint: &'b solana_program::account_info::AccountInfo<'a>,
@@ -536,6 +756,25 @@ impl<'a, 'b> StakeCpiBuilder<'a, 'b> {
         self.instruction.vault = Some(vault);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury = treasury;
+        self
+    }
+    /// `[optional account]`
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    #[inline(always)]
+    pub fn staker_entry(
+        &mut self,
+        staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.staker_entry = staker_entry;
+        self
+    }
     #[inline(always)]
     pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.payer = Some(payer);
@@ -558,6 +797,14 @@ impl<'a, 'b> StakeCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
     pub fn associated_token_program(
         &mut self,
         associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -626,6 +873,18 @@ impl<'a, 'b> StakeCpiBuilder<'a, 'b> {
                 .pool_manager
                 .expect("pool_manager is not set"),
 
+            yield_schedule: self
+                .instruction
+                .yield_schedule
+                .expect("yield_schedule is not set"),
+
+            rate_feed: self.instruction.rate_feed.expect("rate_feed is not set"),
+
+            rate_history: self
+                .instruction
+                .rate_history
+                .expect("rate_history is not set"),
+
             base_mint: self.instruction.base_mint.expect("base_mint is not set"),
 
             payer_base_mint_ata: self
@@ -642,6 +901,10 @@ impl<'a, 'b> StakeCpiBuilder<'a, 'b> {
 
             vault: self.instruction.vault.expect("vault is not set"),
 
+            treasury: self.instruction.treasury,
+
+            staker_entry: self.instruction.staker_entry,
+
             payer: self.instruction.payer.expect("payer is not set"),
 
             system_program: self
@@ -654,6 +917,11 @@ impl<'a, 'b> StakeCpiBuilder<'a, 'b> {
                 .token_program
                 .expect("token_program is not set"),
 
+            x_token_program: self
+                .instruction
+                .x_token_program
+                .expect("x_token_program is not set"),
+
             associated_token_program: self
                 .instruction
                 .associated_token_program
@@ -670,14 +938,20 @@ impl<'a, 'b> StakeCpiBuilder<'a, 'b> {
 struct StakeCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     pool_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    yield_schedule: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_feed: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     base_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payer_base_mint_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     x_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payer_x_mint_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    x_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     quantity: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -0,0 +1,448 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct TogglePtStakingActive {
+    pub global_config: solana_program::pubkey::Pubkey,
+
+    pub authority: solana_program::pubkey::Pubkey,
+
+    pub guardian: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl TogglePtStakingActive {
+    pub fn instruction(
+        &self,
+        args: TogglePtStakingActiveInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: TogglePtStakingActiveInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.global_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        if let Some(guardian) = self.guardian {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                guardian, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PT_STAKING_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = TogglePtStakingActiveInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::PT_STAKING_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct TogglePtStakingActiveInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl TogglePtStakingActiveInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [51, 157, 191, 23, 239, 202, 160, 93],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TogglePtStakingActiveInstructionArgs {
+    pub stake_active: bool,
+    pub unstake_active: bool,
+}
+
+/// Instruction builder for `TogglePtStakingActive`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` global_config
+///   1. `[signer]` authority
+///   2. `[optional]` guardian
+#[derive(Default)]
+pub struct TogglePtStakingActiveBuilder {
+    global_config: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    guardian: Option<solana_program::pubkey::Pubkey>,
+    stake_active: Option<bool>,
+    unstake_active: Option<bool>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl TogglePtStakingActiveBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn global_config(&mut self, global_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.global_config = Some(global_config);
+        self
+    }
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn guardian(&mut self, guardian: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.guardian = guardian;
+        self
+    }
+    #[inline(always)]
+    pub fn stake_active(&mut self, stake_active: bool) -> &mut Self {
+        self.stake_active = Some(stake_active);
+        self
+    }
+    #[inline(always)]
+    pub fn unstake_active(&mut self, unstake_active: bool) -> &mut Self {
+        self.unstake_active = Some(unstake_active);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = TogglePtStakingActive {
+            global_config: self.global_config.expect("global_config is not set"),
+            authority: self.authority.expect("authority is not set"),
+            guardian: self.guardian,
+        };
+        let args = TogglePtStakingActiveInstructionArgs {
+            stake_active: self.stake_active.clone().expect("stake_active is not set"),
+            unstake_active: self
+                .unstake_active
+                .clone()
+                .expect("unstake_active is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `toggle_pt_staking_active` CPI accounts.
+pub struct TogglePtStakingActiveCpiAccounts<'a, 'b> {
+    pub global_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub guardian: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `toggle_pt_staking_active` CPI instruction.
+pub struct TogglePtStakingActiveCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub global_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub guardian: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: TogglePtStakingActiveInstructionArgs,
+}
+
+impl<'a, 'b> TogglePtStakingActiveCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: TogglePtStakingActiveCpiAccounts<'a, 'b>,
+        args: TogglePtStakingActiveInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            global_config: accounts.global_config,
+            authority: accounts.authority,
+            guardian: accounts.guardian,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.global_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        if let Some(guardian) = self.guardian {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *guardian.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PT_STAKING_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = TogglePtStakingActiveInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::PT_STAKING_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.global_config.clone());
+        account_infos.push(self.authority.clone());
+        if let Some(guardian) = self.guardian {
+            account_infos.push(guardian.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `TogglePtStakingActive` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` global_config
+///   1. `[signer]` authority
+///   2. `[optional]` guardian
+pub struct TogglePtStakingActiveCpiBuilder<'a, 'b> {
+    instruction: Box<TogglePtStakingActiveCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> TogglePtStakingActiveCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(TogglePtStakingActiveCpiBuilderInstruction {
+            __program: program,
+            global_config: None,
+            authority: None,
+            guardian: None,
+            stake_active: None,
+            unstake_active: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn global_config(
+        &mut self,
+        global_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.global_config = Some(global_config);
+        self
+    }
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn guardian(
+        &mut self,
+        guardian: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.guardian = guardian;
+        self
+    }
+    #[inline(always)]
+    pub fn stake_active(&mut self, stake_active: bool) -> &mut Self {
+        self.instruction.stake_active = Some(stake_active);
+        self
+    }
+    #[inline(always)]
+    pub fn unstake_active(&mut self, unstake_active: bool) -> &mut Self {
+        self.instruction.unstake_active = Some(unstake_active);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = TogglePtStakingActiveInstructionArgs {
+            stake_active: self
+                .instruction
+                .stake_active
+                .clone()
+                .expect("stake_active is not set"),
+            unstake_active: self
+                .instruction
+                .unstake_active
+                .clone()
+                .expect("unstake_active is not set"),
+        };
+        let instruction = TogglePtStakingActiveCpi {
+            __program: self.instruction.__program,
+
+            global_config: self
+                .instruction
+                .global_config
+                .expect("global_config is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            guardian: self.instruction.guardian,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct TogglePtStakingActiveCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    global_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    guardian: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake_active: Option<bool>,
+    unstake_active: Option<bool>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -14,6 +14,12 @@ use borsh::{BorshDeserialize, BorshSerialize};
 pub struct Unstake {
     pub pool_manager: solana_program::pubkey::Pubkey,
 
+    pub yield_schedule: solana_program::pubkey::Pubkey,
+
+    pub rate_feed: solana_program::pubkey::Pubkey,
+
+    pub rate_history: solana_program::pubkey::Pubkey,
+
     pub token_manager: solana_program::pubkey::Pubkey,
 
     pub base_mint: solana_program::pubkey::Pubkey,
@@ -26,14 +32,22 @@ pub struct Unstake {
 
     pub vault: solana_program::pubkey::Pubkey,
 
+    pub treasury: Option<solana_program::pubkey::Pubkey>,
+    /// Releases the unstaked base from the wallet deposit cap when passed
+    pub staker_entry: Option<solana_program::pubkey::Pubkey>,
+
     pub payer: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
 
+    pub x_token_program: solana_program::pubkey::Pubkey,
+
     pub associated_token_program: solana_program::pubkey::Pubkey,
 
+    pub minter_authorization: Option<solana_program::pubkey::Pubkey>,
+
     pub parity_issuance_program: solana_program::pubkey::Pubkey,
 }
 
@@ -50,11 +64,23 @@ impl Unstake {
         args: UnstakeInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.pool_manager,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.yield_schedule,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_feed,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_history,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.token_manager,
             false,
@@ -78,6 +104,27 @@ impl Unstake {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.vault, false,
         ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                treasury, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                staker_entry,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
@@ -89,10 +136,25 @@ impl Unstake {
             self.token_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.x_token_program,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.associated_token_program,
             false,
         ));
+        if let Some(minter_authorization) = self.minter_authorization {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                minter_authorization,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.parity_issuance_program,
             false,
@@ -137,30 +199,44 @@ pub struct UnstakeInstructionArgs {
 /// ### Accounts:
 ///
 ///   0. `[writable]` pool_manager
-///   1. `[writable]` token_manager
-///   2. `[writable]` base_mint
-///   3. `[writable]` payer_base_mint_ata
-///   4. `[writable]` x_mint
-///   5. `[writable]` payer_x_mint_ata
-///   6. `[writable]` vault
-///   7. `[writable, signer]` payer
-///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   10. `[]` associated_token_program
-///   11. `[]` parity_issuance_program
+///   1. `[writable]` yield_schedule
+///   2. `[writable]` rate_feed
+///   3. `[writable]` rate_history
+///   4. `[writable]` token_manager
+///   5. `[writable]` base_mint
+///   6. `[writable]` payer_base_mint_ata
+///   7. `[writable]` x_mint
+///   8. `[writable]` payer_x_mint_ata
+///   9. `[writable]` vault
+///   10. `[writable, optional]` treasury
+///   11. `[writable, optional]` staker_entry
+///   12. `[writable, signer]` payer
+///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   14. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   15. `[]` x_token_program
+///   16. `[]` associated_token_program
+///   17. `[optional]` minter_authorization
+///   18. `[]` parity_issuance_program
 #[derive(Default)]
 pub struct UnstakeBuilder {
     pool_manager: Option<solana_program::pubkey::Pubkey>,
+    yield_schedule: Option<solana_program::pubkey::Pubkey>,
+    rate_feed: Option<solana_program::pubkey::Pubkey>,
+    rate_history: Option<solana_program::pubkey::Pubkey>,
     token_manager: Option<solana_program::pubkey::Pubkey>,
     base_mint: Option<solana_program::pubkey::Pubkey>,
     payer_base_mint_ata: Option<solana_program::pubkey::Pubkey>,
     x_mint: Option<solana_program::pubkey::Pubkey>,
     payer_x_mint_ata: Option<solana_program::pubkey::Pubkey>,
     vault: Option<solana_program::pubkey::Pubkey>,
+    treasury: Option<solana_program::pubkey::Pubkey>,
+    staker_entry: Option<solana_program::pubkey::Pubkey>,
     payer: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
+    x_token_program: Option<solana_program::pubkey::Pubkey>,
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    minter_authorization: Option<solana_program::pubkey::Pubkey>,
     parity_issuance_program: Option<solana_program::pubkey::Pubkey>,
     quantity: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -176,6 +252,21 @@ impl UnstakeBuilder {
         self
     }
     #[inline(always)]
+    pub fn yield_schedule(&mut self, yield_schedule: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(&mut self, rate_feed: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(&mut self, rate_history: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
     pub fn token_manager(&mut self, token_manager: solana_program::pubkey::Pubkey) -> &mut Self {
         self.token_manager = Some(token_manager);
         self
@@ -211,6 +302,22 @@ impl UnstakeBuilder {
         self.vault = Some(vault);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.treasury = treasury;
+        self
+    }
+    /// `[optional account]`
+    /// Releases the unstaked base from the wallet deposit cap when passed
+    #[inline(always)]
+    pub fn staker_entry(
+        &mut self,
+        staker_entry: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.staker_entry = staker_entry;
+        self
+    }
     #[inline(always)]
     pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
         self.payer = Some(payer);
@@ -229,6 +336,14 @@ impl UnstakeBuilder {
         self
     }
     #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
     pub fn associated_token_program(
         &mut self,
         associated_token_program: solana_program::pubkey::Pubkey,
@@ -236,6 +351,15 @@ impl UnstakeBuilder {
         self.associated_token_program = Some(associated_token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn minter_authorization(
+        &mut self,
+        minter_authorization: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.minter_authorization = minter_authorization;
+        self
+    }
     #[inline(always)]
     pub fn parity_issuance_program(
         &mut self,
@@ -271,6 +395,9 @@ impl UnstakeBuilder {
     pub fn instruction(&self) -> solana_program::instruction::Instruction {
         let accounts = Unstake {
             pool_manager: self.pool_manager.expect("pool_manager is not set"),
+            yield_schedule: self.yield_schedule.expect("yield_schedule is not set"),
+            rate_feed: self.rate_feed.expect("rate_feed is not set"),
+            rate_history: self.rate_history.expect("rate_history is not set"),
             token_manager: self.token_manager.expect("token_manager is not set"),
             base_mint: self.base_mint.expect("base_mint is not set"),
             payer_base_mint_ata: self
@@ -279,6 +406,8 @@ impl UnstakeBuilder {
             x_mint: self.x_mint.expect("x_mint is not set"),
             payer_x_mint_ata: self.payer_x_mint_ata.expect("payer_x_mint_ata is not set"),
             vault: self.vault.expect("vault is not set"),
+            treasury: self.treasury,
+            staker_entry: self.staker_entry,
             payer: self.payer.expect("payer is not set"),
             system_program: self
                 .system_program
@@ -286,9 +415,11 @@ impl UnstakeBuilder {
             token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
+            x_token_program: self.x_token_program.expect("x_token_program is not set"),
             associated_token_program: self
                 .associated_token_program
                 .expect("associated_token_program is not set"),
+            minter_authorization: self.minter_authorization,
             parity_issuance_program: self
                 .parity_issuance_program
                 .expect("parity_issuance_program is not set"),
@@ -305,6 +436,12 @@ impl UnstakeBuilder {
 pub struct UnstakeCpiAccounts<'a, 'b> {
     pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub token_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
@@ -317,14 +454,22 @@ pub struct UnstakeCpiAccounts<'a, 'b> {
 
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Releases the unstaked base from the wallet deposit cap when passed
+    pub staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
@@ -335,6 +480,12 @@ pub struct UnstakeCpi<'a, 'b> {
 
     pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub token_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
@@ -347,14 +498,22 @@ pub struct UnstakeCpi<'a, 'b> {
 
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Releases the unstaked base from the wallet deposit cap when passed
+    pub staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: UnstakeInstructionArgs,
@@ -369,16 +528,23 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
         Self {
             __program: program,
             pool_manager: accounts.pool_manager,
+            yield_schedule: accounts.yield_schedule,
+            rate_feed: accounts.rate_feed,
+            rate_history: accounts.rate_history,
             token_manager: accounts.token_manager,
             base_mint: accounts.base_mint,
             payer_base_mint_ata: accounts.payer_base_mint_ata,
             x_mint: accounts.x_mint,
             payer_x_mint_ata: accounts.payer_x_mint_ata,
             vault: accounts.vault,
+            treasury: accounts.treasury,
+            staker_entry: accounts.staker_entry,
             payer: accounts.payer,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
+            x_token_program: accounts.x_token_program,
             associated_token_program: accounts.associated_token_program,
+            minter_authorization: accounts.minter_authorization,
             parity_issuance_program: accounts.parity_issuance_program,
             __args: args,
         }
@@ -416,11 +582,23 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.pool_manager.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.yield_schedule.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_feed.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_history.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.token_manager.key,
             false,
@@ -445,6 +623,28 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
             *self.vault.key,
             false,
         ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *treasury.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *staker_entry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
             true,
@@ -457,10 +657,25 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.x_token_program.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.associated_token_program.key,
             false,
         ));
+        if let Some(minter_authorization) = self.minter_authorization {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *minter_authorization.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.parity_issuance_program.key,
             false,
@@ -481,19 +696,32 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(19 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.pool_manager.clone());
+        account_infos.push(self.yield_schedule.clone());
+        account_infos.push(self.rate_feed.clone());
+        account_infos.push(self.rate_history.clone());
         account_infos.push(self.token_manager.clone());
         account_infos.push(self.base_mint.clone());
         account_infos.push(self.payer_base_mint_ata.clone());
         account_infos.push(self.x_mint.clone());
         account_infos.push(self.payer_x_mint_ata.clone());
         account_infos.push(self.vault.clone());
+        if let Some(treasury) = self.treasury {
+            account_infos.push(treasury.clone());
+        }
+        if let Some(staker_entry) = self.staker_entry {
+            account_infos.push(staker_entry.clone());
+        }
         account_infos.push(self.payer.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
+        account_infos.push(self.x_token_program.clone());
         account_infos.push(self.associated_token_program.clone());
+        if let Some(minter_authorization) = self.minter_authorization {
+            account_infos.push(minter_authorization.clone());
+        }
         account_infos.push(self.parity_issuance_program.clone());
         remaining_accounts
             .iter()
@@ -512,17 +740,24 @@ impl<'a, 'b> UnstakeCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[writable]` pool_manager
-///   1. `[writable]` token_manager
-///   2. `[writable]` base_mint
-///   3. `[writable]` payer_base_mint_ata
-///   4. `[writable]` x_mint
-///   5. `[writable]` payer_x_mint_ata
-///   6. `[writable]` vault
-///   7. `[writable, signer]` payer
-///   8. `[]` system_program
-///   9. `[]` token_program
-///   10. `[]` associated_token_program
-///   11. `[]` parity_issuance_program
+///   1. `[writable]` yield_schedule
+///   2. `[writable]` rate_feed
+///   3. `[writable]` rate_history
+///   4. `[writable]` token_manager
+///   5. `[writable]` base_mint
+///   6. `[writable]` payer_base_mint_ata
+///   7. `[writable]` x_mint
+///   8. `[writable]` payer_x_mint_ata
+///   9. `[writable]` vault
+///   10. `[writable, optional]` treasury
+///   11. `[writable, optional]` staker_entry
+///   12. `[writable, signer]` payer
+///   13. `[]` system_program
+///   14. `[]` token_program
+///   15. `[]` x_token_program
+///   16. `[]` associated_token_program
+///   17. `[optional]` minter_authorization
+///   18. `[]` parity_issuance_program
 pub struct UnstakeCpiBuilder<'a, 'b> {
     instruction: Box<UnstakeCpiBuilderInstruction<'a, 'b>>,
 }
@@ -532,16 +767,23 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
         let instruction = Box::new(UnstakeCpiBuilderInstruction {
             __program: program,
             pool_manager: None,
+            yield_schedule: None,
+            rate_feed: None,
+            rate_history: None,
             token_manager: None,
             base_mint: None,
             payer_base_mint_ata: None,
             x_mint: None,
             payer_x_mint_ata: None,
             vault: None,
+            treasury: None,
+            staker_entry: None,
             payer: None,
             system_program: None,
             token_program: None,
+            x_token_program: None,
             associated_token_program: None,
+            minter_authorization: None,
             parity_issuance_program: None,
             quantity: None,
             __remaining_accounts: Vec::new(),
@@ -557,6 +799,30 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn yield_schedule(
+        &mut self,
+        yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(
+        &mut self,
+        rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_history(
+        &mut self,
+        rate_history: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_history = Some(rate_history);
+        self
+    }
+    #[inline(always)]
     pub fn token_manager(
         &mut self,
         token_manager: &'b solana_program::account_info::AccountInfo<'a>,
@@ -601,6 +867,25 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
         self.instruction.vault = Some(vault);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury = treasury;
+        self
+    }
+    /// `[optional account]`
+    /// Releases the unstaked base from the wallet deposit cap when passed
+    #[inline(always)]
+    pub fn staker_entry(
+        &mut self,
+        staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.staker_entry = staker_entry;
+        self
+    }
     #[inline(always)]
     pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.payer = Some(payer);
@@ -623,6 +908,14 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
     pub fn associated_token_program(
         &mut self,
         associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -630,6 +923,15 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
         self.instruction.associated_token_program = Some(associated_token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn minter_authorization(
+        &mut self,
+        minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.minter_authorization = minter_authorization;
+        self
+    }
     #[inline(always)]
     pub fn parity_issuance_program(
         &mut self,
@@ -699,6 +1001,18 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
                 .pool_manager
                 .expect("pool_manager is not set"),
 
+            yield_schedule: self
+                .instruction
+                .yield_schedule
+                .expect("yield_schedule is not set"),
+
+            rate_feed: self.instruction.rate_feed.expect("rate_feed is not set"),
+
+            rate_history: self
+                .instruction
+                .rate_history
+                .expect("rate_history is not set"),
+
             token_manager: self
                 .instruction
                 .token_manager
@@ -720,6 +1034,10 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
 
             vault: self.instruction.vault.expect("vault is not set"),
 
+            treasury: self.instruction.treasury,
+
+            staker_entry: self.instruction.staker_entry,
+
             payer: self.instruction.payer.expect("payer is not set"),
 
             system_program: self
@@ -732,11 +1050,18 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
                 .token_program
                 .expect("token_program is not set"),
 
+            x_token_program: self
+                .instruction
+                .x_token_program
+                .expect("x_token_program is not set"),
+
             associated_token_program: self
                 .instruction
                 .associated_token_program
                 .expect("associated_token_program is not set"),
 
+            minter_authorization: self.instruction.minter_authorization,
+
             parity_issuance_program: self
                 .instruction
                 .parity_issuance_program
@@ -753,16 +1078,23 @@ impl<'a, 'b> UnstakeCpiBuilder<'a, 'b> {
 struct UnstakeCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     pool_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    yield_schedule: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_feed: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     base_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payer_base_mint_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     x_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payer_x_mint_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    x_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     parity_issuance_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     quantity: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -14,6 +14,10 @@ use borsh::{BorshDeserialize, BorshSerialize};
 pub struct UpdateAnnualYield {
     pub pool_manager: solana_program::pubkey::Pubkey,
 
+    pub yield_schedule: solana_program::pubkey::Pubkey,
+
+    pub rate_feed: solana_program::pubkey::Pubkey,
+
     pub token_manager: solana_program::pubkey::Pubkey,
 
     pub base_mint: solana_program::pubkey::Pubkey,
@@ -22,14 +26,20 @@ pub struct UpdateAnnualYield {
 
     pub vault: solana_program::pubkey::Pubkey,
 
+    pub treasury: Option<solana_program::pubkey::Pubkey>,
+
     pub admin: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
 
+    pub x_token_program: solana_program::pubkey::Pubkey,
+
     pub associated_token_program: solana_program::pubkey::Pubkey,
 
+    pub minter_authorization: Option<solana_program::pubkey::Pubkey>,
+
     pub parity_issuance_program: solana_program::pubkey::Pubkey,
 }
 
@@ -46,11 +56,19 @@ impl UpdateAnnualYield {
         args: UpdateAnnualYieldInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.pool_manager,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.yield_schedule,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.rate_feed,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.token_manager,
             false,
@@ -59,13 +77,23 @@ impl UpdateAnnualYield {
             self.base_mint,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.x_mint,
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.vault, false,
         ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                treasury, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.admin, true,
         ));
@@ -77,10 +105,25 @@ impl UpdateAnnualYield {
             self.token_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.x_token_program,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.associated_token_program,
             false,
         ));
+        if let Some(minter_authorization) = self.minter_authorization {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                minter_authorization,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.parity_issuance_program,
             false,
@@ -119,7 +162,7 @@ impl UpdateAnnualYieldInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UpdateAnnualYieldInstructionArgs {
-    pub interval_apr_rate: u64,
+    pub annual_yield_bps: u64,
 }
 
 /// Instruction builder for `UpdateAnnualYield`.
@@ -127,28 +170,38 @@ pub struct UpdateAnnualYieldInstructionArgs {
 /// ### Accounts:
 ///
 ///   0. `[writable]` pool_manager
-///   1. `[writable]` token_manager
-///   2. `[writable]` base_mint
-///   3. `[]` x_mint
-///   4. `[writable]` vault
-///   5. `[writable, signer]` admin
-///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   8. `[]` associated_token_program
-///   9. `[]` parity_issuance_program
+///   1. `[writable]` yield_schedule
+///   2. `[writable]` rate_feed
+///   3. `[writable]` token_manager
+///   4. `[writable]` base_mint
+///   5. `[writable]` x_mint
+///   6. `[writable]` vault
+///   7. `[writable, optional]` treasury
+///   8. `[writable, signer]` admin
+///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   10. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   11. `[]` x_token_program
+///   12. `[]` associated_token_program
+///   13. `[optional]` minter_authorization
+///   14. `[]` parity_issuance_program
 #[derive(Default)]
 pub struct UpdateAnnualYieldBuilder {
     pool_manager: Option<solana_program::pubkey::Pubkey>,
+    yield_schedule: Option<solana_program::pubkey::Pubkey>,
+    rate_feed: Option<solana_program::pubkey::Pubkey>,
     token_manager: Option<solana_program::pubkey::Pubkey>,
     base_mint: Option<solana_program::pubkey::Pubkey>,
     x_mint: Option<solana_program::pubkey::Pubkey>,
     vault: Option<solana_program::pubkey::Pubkey>,
+    treasury: Option<solana_program::pubkey::Pubkey>,
     admin: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
+    x_token_program: Option<solana_program::pubkey::Pubkey>,
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    minter_authorization: Option<solana_program::pubkey::Pubkey>,
     parity_issuance_program: Option<solana_program::pubkey::Pubkey>,
-    interval_apr_rate: Option<u64>,
+    annual_yield_bps: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -162,6 +215,16 @@ impl UpdateAnnualYieldBuilder {
         self
     }
     #[inline(always)]
+    pub fn yield_schedule(&mut self, yield_schedule: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(&mut self, rate_feed: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
     pub fn token_manager(&mut self, token_manager: solana_program::pubkey::Pubkey) -> &mut Self {
         self.token_manager = Some(token_manager);
         self
@@ -181,6 +244,12 @@ impl UpdateAnnualYieldBuilder {
         self.vault = Some(vault);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.treasury = treasury;
+        self
+    }
     #[inline(always)]
     pub fn admin(&mut self, admin: solana_program::pubkey::Pubkey) -> &mut Self {
         self.admin = Some(admin);
@@ -199,6 +268,14 @@ impl UpdateAnnualYieldBuilder {
         self
     }
     #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
     pub fn associated_token_program(
         &mut self,
         associated_token_program: solana_program::pubkey::Pubkey,
@@ -206,6 +283,15 @@ impl UpdateAnnualYieldBuilder {
         self.associated_token_program = Some(associated_token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn minter_authorization(
+        &mut self,
+        minter_authorization: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.minter_authorization = minter_authorization;
+        self
+    }
     #[inline(always)]
     pub fn parity_issuance_program(
         &mut self,
@@ -215,8 +301,8 @@ impl UpdateAnnualYieldBuilder {
         self
     }
     #[inline(always)]
-    pub fn interval_apr_rate(&mut self, interval_apr_rate: u64) -> &mut Self {
-        self.interval_apr_rate = Some(interval_apr_rate);
+    pub fn annual_yield_bps(&mut self, annual_yield_bps: u64) -> &mut Self {
+        self.annual_yield_bps = Some(annual_yield_bps);
         self
     }
     /// Add an aditional account to the instruction.
@@ -241,10 +327,13 @@ impl UpdateAnnualYieldBuilder {
     pub fn instruction(&self) -> solana_program::instruction::Instruction {
         let accounts = UpdateAnnualYield {
             pool_manager: self.pool_manager.expect("pool_manager is not set"),
+            yield_schedule: self.yield_schedule.expect("yield_schedule is not set"),
+            rate_feed: self.rate_feed.expect("rate_feed is not set"),
             token_manager: self.token_manager.expect("token_manager is not set"),
             base_mint: self.base_mint.expect("base_mint is not set"),
             x_mint: self.x_mint.expect("x_mint is not set"),
             vault: self.vault.expect("vault is not set"),
+            treasury: self.treasury,
             admin: self.admin.expect("admin is not set"),
             system_program: self
                 .system_program
@@ -252,18 +341,20 @@ impl UpdateAnnualYieldBuilder {
             token_program: self.token_program.unwrap_or(solana_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
+            x_token_program: self.x_token_program.expect("x_token_program is not set"),
             associated_token_program: self
                 .associated_token_program
                 .expect("associated_token_program is not set"),
+            minter_authorization: self.minter_authorization,
             parity_issuance_program: self
                 .parity_issuance_program
                 .expect("parity_issuance_program is not set"),
         };
         let args = UpdateAnnualYieldInstructionArgs {
-            interval_apr_rate: self
-                .interval_apr_rate
+            annual_yield_bps: self
+                .annual_yield_bps
                 .clone()
-                .expect("interval_apr_rate is not set"),
+                .expect("annual_yield_bps is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -274,6 +365,10 @@ impl UpdateAnnualYieldBuilder {
 pub struct UpdateAnnualYieldCpiAccounts<'a, 'b> {
     pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub token_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
@@ -282,14 +377,20 @@ pub struct UpdateAnnualYieldCpiAccounts<'a, 'b> {
 
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
@@ -300,6 +401,10 @@ pub struct UpdateAnnualYieldCpi<'a, 'b> {
 
     pub pool_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub token_manager: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub base_mint: &'b solana_program::account_info::AccountInfo<'a>,
@@ -308,14 +413,20 @@ pub struct UpdateAnnualYieldCpi<'a, 'b> {
 
     pub vault: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub parity_issuance_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: UpdateAnnualYieldInstructionArgs,
@@ -330,14 +441,19 @@ impl<'a, 'b> UpdateAnnualYieldCpi<'a, 'b> {
         Self {
             __program: program,
             pool_manager: accounts.pool_manager,
+            yield_schedule: accounts.yield_schedule,
+            rate_feed: accounts.rate_feed,
             token_manager: accounts.token_manager,
             base_mint: accounts.base_mint,
             x_mint: accounts.x_mint,
             vault: accounts.vault,
+            treasury: accounts.treasury,
             admin: accounts.admin,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
+            x_token_program: accounts.x_token_program,
             associated_token_program: accounts.associated_token_program,
+            minter_authorization: accounts.minter_authorization,
             parity_issuance_program: accounts.parity_issuance_program,
             __args: args,
         }
@@ -375,11 +491,19 @@ impl<'a, 'b> UpdateAnnualYieldCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.pool_manager.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.yield_schedule.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.rate_feed.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.token_manager.key,
             false,
@@ -388,7 +512,7 @@ impl<'a, 'b> UpdateAnnualYieldCpi<'a, 'b> {
             *self.base_mint.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.x_mint.key,
             false,
         ));
@@ -396,6 +520,17 @@ impl<'a, 'b> UpdateAnnualYieldCpi<'a, 'b> {
             *self.vault.key,
             false,
         ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *treasury.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.admin.key,
             true,
@@ -408,10 +543,25 @@ impl<'a, 'b> UpdateAnnualYieldCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.x_token_program.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.associated_token_program.key,
             false,
         ));
+        if let Some(minter_authorization) = self.minter_authorization {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *minter_authorization.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::PARITY_STAKING_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.parity_issuance_program.key,
             false,
@@ -434,17 +584,26 @@ impl<'a, 'b> UpdateAnnualYieldCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(15 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.pool_manager.clone());
+        account_infos.push(self.yield_schedule.clone());
+        account_infos.push(self.rate_feed.clone());
         account_infos.push(self.token_manager.clone());
         account_infos.push(self.base_mint.clone());
         account_infos.push(self.x_mint.clone());
         account_infos.push(self.vault.clone());
+        if let Some(treasury) = self.treasury {
+            account_infos.push(treasury.clone());
+        }
         account_infos.push(self.admin.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
+        account_infos.push(self.x_token_program.clone());
         account_infos.push(self.associated_token_program.clone());
+        if let Some(minter_authorization) = self.minter_authorization {
+            account_infos.push(minter_authorization.clone());
+        }
         account_infos.push(self.parity_issuance_program.clone());
         remaining_accounts
             .iter()
@@ -463,15 +622,20 @@ impl<'a, 'b> UpdateAnnualYieldCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[writable]` pool_manager
-///   1. `[writable]` token_manager
-///   2. `[writable]` base_mint
-///   3. `[]` x_mint
-///   4. `[writable]` vault
-///   5. `[writable, signer]` admin
-///   6. `[]` system_program
-///   7. `[]` token_program
-///   8. `[]` associated_token_program
-///   9. `[]` parity_issuance_program
+///   1. `[writable]` yield_schedule
+///   2. `[writable]` rate_feed
+///   3. `[writable]` token_manager
+///   4. `[writable]` base_mint
+///   5. `[writable]` x_mint
+///   6. `[writable]` vault
+///   7. `[writable, optional]` treasury
+///   8. `[writable, signer]` admin
+///   9. `[]` system_program
+///   10. `[]` token_program
+///   11. `[]` x_token_program
+///   12. `[]` associated_token_program
+///   13. `[optional]` minter_authorization
+///   14. `[]` parity_issuance_program
 pub struct UpdateAnnualYieldCpiBuilder<'a, 'b> {
     instruction: Box<UpdateAnnualYieldCpiBuilderInstruction<'a, 'b>>,
 }
@@ -481,16 +645,21 @@ impl<'a, 'b> UpdateAnnualYieldCpiBuilder<'a, 'b> {
         let instruction = Box::new(UpdateAnnualYieldCpiBuilderInstruction {
             __program: program,
             pool_manager: None,
+            yield_schedule: None,
+            rate_feed: None,
             token_manager: None,
             base_mint: None,
             x_mint: None,
             vault: None,
+            treasury: None,
             admin: None,
             system_program: None,
             token_program: None,
+            x_token_program: None,
             associated_token_program: None,
+            minter_authorization: None,
             parity_issuance_program: None,
-            interval_apr_rate: None,
+            annual_yield_bps: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -504,6 +673,22 @@ impl<'a, 'b> UpdateAnnualYieldCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn yield_schedule(
+        &mut self,
+        yield_schedule: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.yield_schedule = Some(yield_schedule);
+        self
+    }
+    #[inline(always)]
+    pub fn rate_feed(
+        &mut self,
+        rate_feed: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rate_feed = Some(rate_feed);
+        self
+    }
+    #[inline(always)]
     pub fn token_manager(
         &mut self,
         token_manager: &'b solana_program::account_info::AccountInfo<'a>,
@@ -532,6 +717,15 @@ impl<'a, 'b> UpdateAnnualYieldCpiBuilder<'a, 'b> {
         self.instruction.vault = Some(vault);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury = treasury;
+        self
+    }
     #[inline(always)]
     pub fn admin(&mut self, admin: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.admin = Some(admin);
@@ -554,6 +748,14 @@ impl<'a, 'b> UpdateAnnualYieldCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn x_token_program(
+        &mut self,
+        x_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.x_token_program = Some(x_token_program);
+        self
+    }
+    #[inline(always)]
     pub fn associated_token_program(
         &mut self,
         associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -561,6 +763,15 @@ impl<'a, 'b> UpdateAnnualYieldCpiBuilder<'a, 'b> {
         self.instruction.associated_token_program = Some(associated_token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn minter_authorization(
+        &mut self,
+        minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.minter_authorization = minter_authorization;
+        self
+    }
     #[inline(always)]
     pub fn parity_issuance_program(
         &mut self,
@@ -570,8 +781,8 @@ impl<'a, 'b> UpdateAnnualYieldCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn interval_apr_rate(&mut self, interval_apr_rate: u64) -> &mut Self {
-        self.instruction.interval_apr_rate = Some(interval_apr_rate);
+    pub fn annual_yield_bps(&mut self, annual_yield_bps: u64) -> &mut Self {
+        self.instruction.annual_yield_bps = Some(annual_yield_bps);
         self
     }
     /// Add an additional account to the instruction.
@@ -616,11 +827,11 @@ impl<'a, 'b> UpdateAnnualYieldCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = UpdateAnnualYieldInstructionArgs {
-            interval_apr_rate: self
+            annual_yield_bps: self
                 .instruction
-                .interval_apr_rate
+                .annual_yield_bps
                 .clone()
-                .expect("interval_apr_rate is not set"),
+                .expect("annual_yield_bps is not set"),
         };
         let instruction = UpdateAnnualYieldCpi {
             __program: self.instruction.__program,
@@ -630,6 +841,13 @@ impl<'a, 'b> UpdateAnnualYieldCpiBuilder<'a, 'b> {
                 .pool_manager
                 .expect("pool_manager is not set"),
 
+            yield_schedule: self
+                .instruction
+                .yield_schedule
+                .expect("yield_schedule is not set"),
+
+            rate_feed: self.instruction.rate_feed.expect("rate_feed is not set"),
+
             token_manager: self
                 .instruction
                 .token_manager
@@ -641,6 +859,8 @@ impl<'a, 'b> UpdateAnnualYieldCpiBuilder<'a, 'b> {
 
             vault: self.instruction.vault.expect("vault is not set"),
 
+            treasury: self.instruction.treasury,
+
             admin: self.instruction.admin.expect("admin is not set"),
 
             system_program: self
@@ -653,11 +873,18 @@ impl<'a, 'b> UpdateAnnualYieldCpiBuilder<'a, 'b> {
                 .token_program
                 .expect("token_program is not set"),
 
+            x_token_program: self
+                .instruction
+                .x_token_program
+                .expect("x_token_program is not set"),
+
             associated_token_program: self
                 .instruction
                 .associated_token_program
                 .expect("associated_token_program is not set"),
 
+            minter_authorization: self.instruction.minter_authorization,
+
             parity_issuance_program: self
                 .instruction
                 .parity_issuance_program
@@ -674,16 +901,21 @@ impl<'a, 'b> UpdateAnnualYieldCpiBuilder<'a, 'b> {
 struct UpdateAnnualYieldCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     pool_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    yield_schedule: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rate_feed: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     base_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     x_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    treasury: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    x_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    minter_authorization: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     parity_issuance_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    interval_apr_rate: Option<u64>,
+    annual_yield_bps: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
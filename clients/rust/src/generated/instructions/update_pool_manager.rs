@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::FeeDestination;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -80,6 +81,26 @@ impl UpdatePoolManagerInstructionData {
 pub struct UpdatePoolManagerInstructionArgs {
     pub new_admin: Option<Pubkey>,
     pub new_deposit_cap: Option<u64>,
+    pub new_unstake_cooldown: Option<i64>,
+    pub new_treasury: Option<Pubkey>,
+    pub new_instant_unstake_fee_bps: Option<u16>,
+    pub new_instant_unstake_fee_destination: Option<FeeDestination>,
+    pub new_liquidity_buffer_target: Option<u64>,
+    pub new_min_stake_amount: Option<u64>,
+    pub new_min_unstake_amount: Option<u64>,
+    pub new_min_accrual_interval: Option<i64>,
+    pub new_keeper_reward: Option<u64>,
+    pub new_rewarder: Option<Pubkey>,
+    pub new_vesting_period: Option<i64>,
+    pub new_performance_fee_bps: Option<u16>,
+    pub new_entry_fee_bps: Option<u16>,
+    pub new_entry_fee_destination: Option<FeeDestination>,
+    pub new_exit_fee_bps: Option<u16>,
+    pub new_exit_fee_destination: Option<FeeDestination>,
+    pub new_checkpoint_interval: Option<i64>,
+    pub new_max_strategy_allocation_bps: Option<u16>,
+    pub new_allowlist_enabled: Option<bool>,
+    pub new_wallet_deposit_cap: Option<u64>,
 }
 
 /// Instruction builder for `UpdatePoolManager`.
@@ -96,6 +117,26 @@ pub struct UpdatePoolManagerBuilder {
     owner: Option<solana_program::pubkey::Pubkey>,
     new_admin: Option<Pubkey>,
     new_deposit_cap: Option<u64>,
+    new_unstake_cooldown: Option<i64>,
+    new_treasury: Option<Pubkey>,
+    new_instant_unstake_fee_bps: Option<u16>,
+    new_instant_unstake_fee_destination: Option<FeeDestination>,
+    new_liquidity_buffer_target: Option<u64>,
+    new_min_stake_amount: Option<u64>,
+    new_min_unstake_amount: Option<u64>,
+    new_min_accrual_interval: Option<i64>,
+    new_keeper_reward: Option<u64>,
+    new_rewarder: Option<Pubkey>,
+    new_vesting_period: Option<i64>,
+    new_performance_fee_bps: Option<u16>,
+    new_entry_fee_bps: Option<u16>,
+    new_entry_fee_destination: Option<FeeDestination>,
+    new_exit_fee_bps: Option<u16>,
+    new_exit_fee_destination: Option<FeeDestination>,
+    new_checkpoint_interval: Option<i64>,
+    new_max_strategy_allocation_bps: Option<u16>,
+    new_allowlist_enabled: Option<bool>,
+    new_wallet_deposit_cap: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -130,6 +171,138 @@ impl UpdatePoolManagerBuilder {
         self.new_deposit_cap = Some(new_deposit_cap);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_unstake_cooldown(&mut self, new_unstake_cooldown: i64) -> &mut Self {
+        self.new_unstake_cooldown = Some(new_unstake_cooldown);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_treasury(&mut self, new_treasury: Pubkey) -> &mut Self {
+        self.new_treasury = Some(new_treasury);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_instant_unstake_fee_bps(&mut self, new_instant_unstake_fee_bps: u16) -> &mut Self {
+        self.new_instant_unstake_fee_bps = Some(new_instant_unstake_fee_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_instant_unstake_fee_destination(
+        &mut self,
+        new_instant_unstake_fee_destination: FeeDestination,
+    ) -> &mut Self {
+        self.new_instant_unstake_fee_destination = Some(new_instant_unstake_fee_destination);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_liquidity_buffer_target(&mut self, new_liquidity_buffer_target: u64) -> &mut Self {
+        self.new_liquidity_buffer_target = Some(new_liquidity_buffer_target);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_min_stake_amount(&mut self, new_min_stake_amount: u64) -> &mut Self {
+        self.new_min_stake_amount = Some(new_min_stake_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_min_unstake_amount(&mut self, new_min_unstake_amount: u64) -> &mut Self {
+        self.new_min_unstake_amount = Some(new_min_unstake_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_min_accrual_interval(&mut self, new_min_accrual_interval: i64) -> &mut Self {
+        self.new_min_accrual_interval = Some(new_min_accrual_interval);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_keeper_reward(&mut self, new_keeper_reward: u64) -> &mut Self {
+        self.new_keeper_reward = Some(new_keeper_reward);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_rewarder(&mut self, new_rewarder: Pubkey) -> &mut Self {
+        self.new_rewarder = Some(new_rewarder);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_vesting_period(&mut self, new_vesting_period: i64) -> &mut Self {
+        self.new_vesting_period = Some(new_vesting_period);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_performance_fee_bps(&mut self, new_performance_fee_bps: u16) -> &mut Self {
+        self.new_performance_fee_bps = Some(new_performance_fee_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_entry_fee_bps(&mut self, new_entry_fee_bps: u16) -> &mut Self {
+        self.new_entry_fee_bps = Some(new_entry_fee_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_entry_fee_destination(
+        &mut self,
+        new_entry_fee_destination: FeeDestination,
+    ) -> &mut Self {
+        self.new_entry_fee_destination = Some(new_entry_fee_destination);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_exit_fee_bps(&mut self, new_exit_fee_bps: u16) -> &mut Self {
+        self.new_exit_fee_bps = Some(new_exit_fee_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_exit_fee_destination(
+        &mut self,
+        new_exit_fee_destination: FeeDestination,
+    ) -> &mut Self {
+        self.new_exit_fee_destination = Some(new_exit_fee_destination);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_checkpoint_interval(&mut self, new_checkpoint_interval: i64) -> &mut Self {
+        self.new_checkpoint_interval = Some(new_checkpoint_interval);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_max_strategy_allocation_bps(
+        &mut self,
+        new_max_strategy_allocation_bps: u16,
+    ) -> &mut Self {
+        self.new_max_strategy_allocation_bps = Some(new_max_strategy_allocation_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_allowlist_enabled(&mut self, new_allowlist_enabled: bool) -> &mut Self {
+        self.new_allowlist_enabled = Some(new_allowlist_enabled);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_wallet_deposit_cap(&mut self, new_wallet_deposit_cap: u64) -> &mut Self {
+        self.new_wallet_deposit_cap = Some(new_wallet_deposit_cap);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -158,6 +331,26 @@ impl UpdatePoolManagerBuilder {
         let args = UpdatePoolManagerInstructionArgs {
             new_admin: self.new_admin.clone(),
             new_deposit_cap: self.new_deposit_cap.clone(),
+            new_unstake_cooldown: self.new_unstake_cooldown.clone(),
+            new_treasury: self.new_treasury.clone(),
+            new_instant_unstake_fee_bps: self.new_instant_unstake_fee_bps.clone(),
+            new_instant_unstake_fee_destination: self.new_instant_unstake_fee_destination.clone(),
+            new_liquidity_buffer_target: self.new_liquidity_buffer_target.clone(),
+            new_min_stake_amount: self.new_min_stake_amount.clone(),
+            new_min_unstake_amount: self.new_min_unstake_amount.clone(),
+            new_min_accrual_interval: self.new_min_accrual_interval.clone(),
+            new_keeper_reward: self.new_keeper_reward.clone(),
+            new_rewarder: self.new_rewarder.clone(),
+            new_vesting_period: self.new_vesting_period.clone(),
+            new_performance_fee_bps: self.new_performance_fee_bps.clone(),
+            new_entry_fee_bps: self.new_entry_fee_bps.clone(),
+            new_entry_fee_destination: self.new_entry_fee_destination.clone(),
+            new_exit_fee_bps: self.new_exit_fee_bps.clone(),
+            new_exit_fee_destination: self.new_exit_fee_destination.clone(),
+            new_checkpoint_interval: self.new_checkpoint_interval.clone(),
+            new_max_strategy_allocation_bps: self.new_max_strategy_allocation_bps.clone(),
+            new_allowlist_enabled: self.new_allowlist_enabled.clone(),
+            new_wallet_deposit_cap: self.new_wallet_deposit_cap.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -302,6 +495,26 @@ impl<'a, 'b> UpdatePoolManagerCpiBuilder<'a, 'b> {
             owner: None,
             new_admin: None,
             new_deposit_cap: None,
+            new_unstake_cooldown: None,
+            new_treasury: None,
+            new_instant_unstake_fee_bps: None,
+            new_instant_unstake_fee_destination: None,
+            new_liquidity_buffer_target: None,
+            new_min_stake_amount: None,
+            new_min_unstake_amount: None,
+            new_min_accrual_interval: None,
+            new_keeper_reward: None,
+            new_rewarder: None,
+            new_vesting_period: None,
+            new_performance_fee_bps: None,
+            new_entry_fee_bps: None,
+            new_entry_fee_destination: None,
+            new_exit_fee_bps: None,
+            new_exit_fee_destination: None,
+            new_checkpoint_interval: None,
+            new_max_strategy_allocation_bps: None,
+            new_allowlist_enabled: None,
+            new_wallet_deposit_cap: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -336,6 +549,139 @@ impl<'a, 'b> UpdatePoolManagerCpiBuilder<'a, 'b> {
         self.instruction.new_deposit_cap = Some(new_deposit_cap);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_unstake_cooldown(&mut self, new_unstake_cooldown: i64) -> &mut Self {
+        self.instruction.new_unstake_cooldown = Some(new_unstake_cooldown);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_treasury(&mut self, new_treasury: Pubkey) -> &mut Self {
+        self.instruction.new_treasury = Some(new_treasury);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_instant_unstake_fee_bps(&mut self, new_instant_unstake_fee_bps: u16) -> &mut Self {
+        self.instruction.new_instant_unstake_fee_bps = Some(new_instant_unstake_fee_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_instant_unstake_fee_destination(
+        &mut self,
+        new_instant_unstake_fee_destination: FeeDestination,
+    ) -> &mut Self {
+        self.instruction.new_instant_unstake_fee_destination =
+            Some(new_instant_unstake_fee_destination);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_liquidity_buffer_target(&mut self, new_liquidity_buffer_target: u64) -> &mut Self {
+        self.instruction.new_liquidity_buffer_target = Some(new_liquidity_buffer_target);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_min_stake_amount(&mut self, new_min_stake_amount: u64) -> &mut Self {
+        self.instruction.new_min_stake_amount = Some(new_min_stake_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_min_unstake_amount(&mut self, new_min_unstake_amount: u64) -> &mut Self {
+        self.instruction.new_min_unstake_amount = Some(new_min_unstake_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_min_accrual_interval(&mut self, new_min_accrual_interval: i64) -> &mut Self {
+        self.instruction.new_min_accrual_interval = Some(new_min_accrual_interval);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_keeper_reward(&mut self, new_keeper_reward: u64) -> &mut Self {
+        self.instruction.new_keeper_reward = Some(new_keeper_reward);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_rewarder(&mut self, new_rewarder: Pubkey) -> &mut Self {
+        self.instruction.new_rewarder = Some(new_rewarder);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_vesting_period(&mut self, new_vesting_period: i64) -> &mut Self {
+        self.instruction.new_vesting_period = Some(new_vesting_period);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_performance_fee_bps(&mut self, new_performance_fee_bps: u16) -> &mut Self {
+        self.instruction.new_performance_fee_bps = Some(new_performance_fee_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_entry_fee_bps(&mut self, new_entry_fee_bps: u16) -> &mut Self {
+        self.instruction.new_entry_fee_bps = Some(new_entry_fee_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_entry_fee_destination(
+        &mut self,
+        new_entry_fee_destination: FeeDestination,
+    ) -> &mut Self {
+        self.instruction.new_entry_fee_destination = Some(new_entry_fee_destination);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_exit_fee_bps(&mut self, new_exit_fee_bps: u16) -> &mut Self {
+        self.instruction.new_exit_fee_bps = Some(new_exit_fee_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_exit_fee_destination(
+        &mut self,
+        new_exit_fee_destination: FeeDestination,
+    ) -> &mut Self {
+        self.instruction.new_exit_fee_destination = Some(new_exit_fee_destination);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_checkpoint_interval(&mut self, new_checkpoint_interval: i64) -> &mut Self {
+        self.instruction.new_checkpoint_interval = Some(new_checkpoint_interval);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_max_strategy_allocation_bps(
+        &mut self,
+        new_max_strategy_allocation_bps: u16,
+    ) -> &mut Self {
+        self.instruction.new_max_strategy_allocation_bps = Some(new_max_strategy_allocation_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_allowlist_enabled(&mut self, new_allowlist_enabled: bool) -> &mut Self {
+        self.instruction.new_allowlist_enabled = Some(new_allowlist_enabled);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_wallet_deposit_cap(&mut self, new_wallet_deposit_cap: u64) -> &mut Self {
+        self.instruction.new_wallet_deposit_cap = Some(new_wallet_deposit_cap);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -380,6 +726,32 @@ impl<'a, 'b> UpdatePoolManagerCpiBuilder<'a, 'b> {
         let args = UpdatePoolManagerInstructionArgs {
             new_admin: self.instruction.new_admin.clone(),
             new_deposit_cap: self.instruction.new_deposit_cap.clone(),
+            new_unstake_cooldown: self.instruction.new_unstake_cooldown.clone(),
+            new_treasury: self.instruction.new_treasury.clone(),
+            new_instant_unstake_fee_bps: self.instruction.new_instant_unstake_fee_bps.clone(),
+            new_instant_unstake_fee_destination: self
+                .instruction
+                .new_instant_unstake_fee_destination
+                .clone(),
+            new_liquidity_buffer_target: self.instruction.new_liquidity_buffer_target.clone(),
+            new_min_stake_amount: self.instruction.new_min_stake_amount.clone(),
+            new_min_unstake_amount: self.instruction.new_min_unstake_amount.clone(),
+            new_min_accrual_interval: self.instruction.new_min_accrual_interval.clone(),
+            new_keeper_reward: self.instruction.new_keeper_reward.clone(),
+            new_rewarder: self.instruction.new_rewarder.clone(),
+            new_vesting_period: self.instruction.new_vesting_period.clone(),
+            new_performance_fee_bps: self.instruction.new_performance_fee_bps.clone(),
+            new_entry_fee_bps: self.instruction.new_entry_fee_bps.clone(),
+            new_entry_fee_destination: self.instruction.new_entry_fee_destination.clone(),
+            new_exit_fee_bps: self.instruction.new_exit_fee_bps.clone(),
+            new_exit_fee_destination: self.instruction.new_exit_fee_destination.clone(),
+            new_checkpoint_interval: self.instruction.new_checkpoint_interval.clone(),
+            new_max_strategy_allocation_bps: self
+                .instruction
+                .new_max_strategy_allocation_bps
+                .clone(),
+            new_allowlist_enabled: self.instruction.new_allowlist_enabled.clone(),
+            new_wallet_deposit_cap: self.instruction.new_wallet_deposit_cap.clone(),
         };
         let instruction = UpdatePoolManagerCpi {
             __program: self.instruction.__program,
@@ -408,6 +780,26 @@ struct UpdatePoolManagerCpiBuilderInstruction<'a, 'b> {
     owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     new_admin: Option<Pubkey>,
     new_deposit_cap: Option<u64>,
+    new_unstake_cooldown: Option<i64>,
+    new_treasury: Option<Pubkey>,
+    new_instant_unstake_fee_bps: Option<u16>,
+    new_instant_unstake_fee_destination: Option<FeeDestination>,
+    new_liquidity_buffer_target: Option<u64>,
+    new_min_stake_amount: Option<u64>,
+    new_min_unstake_amount: Option<u64>,
+    new_min_accrual_interval: Option<i64>,
+    new_keeper_reward: Option<u64>,
+    new_rewarder: Option<Pubkey>,
+    new_vesting_period: Option<i64>,
+    new_performance_fee_bps: Option<u16>,
+    new_entry_fee_bps: Option<u16>,
+    new_entry_fee_destination: Option<FeeDestination>,
+    new_exit_fee_bps: Option<u16>,
+    new_exit_fee_destination: Option<FeeDestination>,
+    new_checkpoint_interval: Option<i64>,
+    new_max_strategy_allocation_bps: Option<u16>,
+    new_allowlist_enabled: Option<bool>,
+    new_wallet_deposit_cap: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
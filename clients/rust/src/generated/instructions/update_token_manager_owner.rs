@@ -81,6 +81,9 @@ pub struct UpdateTokenManagerOwnerInstructionArgs {
     pub new_mint_fee_bps: Option<u16>,
     pub new_redeem_fee_bps: Option<u16>,
     pub new_exchange_rate: Option<u64>,
+    pub new_max_exchange_rate_change_bps: Option<u16>,
+    pub new_exchange_rate_update_interval: Option<i64>,
+    pub new_exchange_rate_ramp_duration: Option<i64>,
 }
 
 /// Instruction builder for `UpdateTokenManagerOwner`.
@@ -101,6 +104,9 @@ pub struct UpdateTokenManagerOwnerBuilder {
     new_mint_fee_bps: Option<u16>,
     new_redeem_fee_bps: Option<u16>,
     new_exchange_rate: Option<u64>,
+    new_max_exchange_rate_change_bps: Option<u16>,
+    new_exchange_rate_update_interval: Option<i64>,
+    new_exchange_rate_ramp_duration: Option<i64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -169,6 +175,33 @@ impl UpdateTokenManagerOwnerBuilder {
         self.new_exchange_rate = Some(new_exchange_rate);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_max_exchange_rate_change_bps(
+        &mut self,
+        new_max_exchange_rate_change_bps: u16,
+    ) -> &mut Self {
+        self.new_max_exchange_rate_change_bps = Some(new_max_exchange_rate_change_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_exchange_rate_update_interval(
+        &mut self,
+        new_exchange_rate_update_interval: i64,
+    ) -> &mut Self {
+        self.new_exchange_rate_update_interval = Some(new_exchange_rate_update_interval);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_exchange_rate_ramp_duration(
+        &mut self,
+        new_exchange_rate_ramp_duration: i64,
+    ) -> &mut Self {
+        self.new_exchange_rate_ramp_duration = Some(new_exchange_rate_ramp_duration);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -202,6 +235,9 @@ impl UpdateTokenManagerOwnerBuilder {
             new_mint_fee_bps: self.new_mint_fee_bps.clone(),
             new_redeem_fee_bps: self.new_redeem_fee_bps.clone(),
             new_exchange_rate: self.new_exchange_rate.clone(),
+            new_max_exchange_rate_change_bps: self.new_max_exchange_rate_change_bps.clone(),
+            new_exchange_rate_update_interval: self.new_exchange_rate_update_interval.clone(),
+            new_exchange_rate_ramp_duration: self.new_exchange_rate_ramp_duration.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -340,6 +376,9 @@ impl<'a, 'b> UpdateTokenManagerOwnerCpiBuilder<'a, 'b> {
             new_mint_fee_bps: None,
             new_redeem_fee_bps: None,
             new_exchange_rate: None,
+            new_max_exchange_rate_change_bps: None,
+            new_exchange_rate_update_interval: None,
+            new_exchange_rate_ramp_duration: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -408,6 +447,34 @@ impl<'a, 'b> UpdateTokenManagerOwnerCpiBuilder<'a, 'b> {
         self.instruction.new_exchange_rate = Some(new_exchange_rate);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_max_exchange_rate_change_bps(
+        &mut self,
+        new_max_exchange_rate_change_bps: u16,
+    ) -> &mut Self {
+        self.instruction.new_max_exchange_rate_change_bps = Some(new_max_exchange_rate_change_bps);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_exchange_rate_update_interval(
+        &mut self,
+        new_exchange_rate_update_interval: i64,
+    ) -> &mut Self {
+        self.instruction.new_exchange_rate_update_interval =
+            Some(new_exchange_rate_update_interval);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn new_exchange_rate_ramp_duration(
+        &mut self,
+        new_exchange_rate_ramp_duration: i64,
+    ) -> &mut Self {
+        self.instruction.new_exchange_rate_ramp_duration = Some(new_exchange_rate_ramp_duration);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -458,6 +525,18 @@ impl<'a, 'b> UpdateTokenManagerOwnerCpiBuilder<'a, 'b> {
             new_mint_fee_bps: self.instruction.new_mint_fee_bps.clone(),
             new_redeem_fee_bps: self.instruction.new_redeem_fee_bps.clone(),
             new_exchange_rate: self.instruction.new_exchange_rate.clone(),
+            new_max_exchange_rate_change_bps: self
+                .instruction
+                .new_max_exchange_rate_change_bps
+                .clone(),
+            new_exchange_rate_update_interval: self
+                .instruction
+                .new_exchange_rate_update_interval
+                .clone(),
+            new_exchange_rate_ramp_duration: self
+                .instruction
+                .new_exchange_rate_ramp_duration
+                .clone(),
         };
         let instruction = UpdateTokenManagerOwnerCpi {
             __program: self.instruction.__program,
@@ -489,6 +568,9 @@ struct UpdateTokenManagerOwnerCpiBuilderInstruction<'a, 'b> {
     new_mint_fee_bps: Option<u16>,
     new_redeem_fee_bps: Option<u16>,
     new_exchange_rate: Option<u64>,
+    new_max_exchange_rate_change_bps: Option<u16>,
+    new_exchange_rate_update_interval: Option<i64>,
+    new_exchange_rate_ramp_duration: Option<i64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
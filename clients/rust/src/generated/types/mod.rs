@@ -5,10 +5,36 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#accrued_yield;
 pub(crate) mod r#base_yield_phase;
 pub(crate) mod r#exchange_rate_phase;
+pub(crate) mod r#fee_destination;
+pub(crate) mod r#legacy_global_config;
+pub(crate) mod r#legacy_pool_manager;
+pub(crate) mod r#mint_preview;
 pub(crate) mod r#points_earned_phase;
+pub(crate) mod r#rate_checkpoint;
+pub(crate) mod r#rate_observation;
+pub(crate) mod r#redeem_preview;
+pub(crate) mod r#scheduled_rate;
+pub(crate) mod r#stake_preview;
+pub(crate) mod r#unstake_path;
+pub(crate) mod r#unstake_preview;
+pub(crate) mod r#yield_mode;
 
+pub use self::r#accrued_yield::*;
 pub use self::r#base_yield_phase::*;
 pub use self::r#exchange_rate_phase::*;
+pub use self::r#fee_destination::*;
+pub use self::r#legacy_global_config::*;
+pub use self::r#legacy_pool_manager::*;
+pub use self::r#mint_preview::*;
 pub use self::r#points_earned_phase::*;
+pub use self::r#rate_checkpoint::*;
+pub use self::r#rate_observation::*;
+pub use self::r#redeem_preview::*;
+pub use self::r#scheduled_rate::*;
+pub use self::r#stake_preview::*;
+pub use self::r#unstake_path::*;
+pub use self::r#unstake_preview::*;
+pub use self::r#yield_mode::*;
@@ -1,2 +1,5 @@
 pub mod generated;
+pub mod sdk;
 pub use generated::*;
+
+pub use parity_math as math;
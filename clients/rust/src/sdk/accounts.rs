@@ -0,0 +1,63 @@
+//! Account deserializers that check the Anchor discriminator before decoding.
+
+use std::io::{Error, ErrorKind};
+
+use solana_program::hash::hash;
+
+use crate::accounts::{
+    GlobalConfig, PoolManager, RateFeed, RateHistory, TokenManager, UserStake, YieldSchedule,
+};
+
+/// First 8 bytes of `sha256("account:<name>")`, prefixed to every Anchor account.
+pub fn discriminator(account_name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator
+        .copy_from_slice(&hash(format!("account:{}", account_name).as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+fn check_discriminator(data: &[u8], account_name: &str) -> Result<(), Error> {
+    if data.len() < 8 || data[..8] != discriminator(account_name) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("account is not a {}", account_name),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn token_manager(data: &[u8]) -> Result<TokenManager, Error> {
+    check_discriminator(data, "TokenManager")?;
+    TokenManager::from_bytes(data)
+}
+
+pub fn pool_manager(data: &[u8]) -> Result<PoolManager, Error> {
+    check_discriminator(data, "PoolManager")?;
+    PoolManager::from_bytes(data)
+}
+
+pub fn yield_schedule(data: &[u8]) -> Result<YieldSchedule, Error> {
+    check_discriminator(data, "YieldSchedule")?;
+    YieldSchedule::from_bytes(data)
+}
+
+pub fn rate_feed(data: &[u8]) -> Result<RateFeed, Error> {
+    check_discriminator(data, "RateFeed")?;
+    RateFeed::from_bytes(data)
+}
+
+pub fn rate_history(data: &[u8]) -> Result<RateHistory, Error> {
+    check_discriminator(data, "RateHistory")?;
+    RateHistory::from_bytes(data)
+}
+
+pub fn global_config(data: &[u8]) -> Result<GlobalConfig, Error> {
+    check_discriminator(data, "GlobalConfig")?;
+    GlobalConfig::from_bytes(data)
+}
+
+pub fn user_stake(data: &[u8]) -> Result<UserStake, Error> {
+    check_discriminator(data, "UserStake")?;
+    UserStake::from_bytes(data)
+}
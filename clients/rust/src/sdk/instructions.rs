@@ -0,0 +1,272 @@
+//! One-call instruction builders that resolve every account from the state of
+//! the program they target.
+
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::{
+    accounts::{GlobalConfig, PoolManager, TokenManager},
+    instructions::{
+        AccrueYieldBuilder, ClaimUnstakeBuilder, DepositRewardsBuilder, InitPtStakeBuilder,
+        MintBuilder, PtStakeBuilder, PtUnstakeBuilder, RedeemBuilder, RefreshRateFeedBuilder,
+        StakeBuilder, UnstakeBuilder,
+    },
+    sdk::pda,
+};
+
+/// The issuance program, resolved from its token manager.
+#[derive(Clone, Debug)]
+pub struct Issuance {
+    pub token_manager: Pubkey,
+    pub mint: Pubkey,
+    pub quote_mint: Pubkey,
+}
+
+impl Issuance {
+    pub fn new(token_manager_address: Pubkey, token_manager: &TokenManager) -> Self {
+        Self {
+            token_manager: token_manager_address,
+            mint: token_manager.mint,
+            quote_mint: token_manager.quote_mint,
+        }
+    }
+
+    pub fn mint(&self, payer: &Pubkey, quantity: u64, proof: Vec<[u8; 32]>) -> Instruction {
+        MintBuilder::new()
+            .token_manager(self.token_manager)
+            .mint(self.mint)
+            .payer_mint_ata(pda::associated_token_address(payer, &self.mint))
+            .quote_mint(self.quote_mint)
+            .payer_quote_mint_ata(pda::associated_token_address(payer, &self.quote_mint))
+            .vault(pda::associated_token_address(
+                &self.token_manager,
+                &self.quote_mint,
+            ))
+            .payer(*payer)
+            .associated_token_program(pda::ASSOCIATED_TOKEN_PROGRAM_ID)
+            .quantity(quantity)
+            .proof(proof)
+            .instruction()
+    }
+
+    pub fn redeem(&self, payer: &Pubkey, quantity: u64, proof: Vec<[u8; 32]>) -> Instruction {
+        RedeemBuilder::new()
+            .token_manager(self.token_manager)
+            .mint(self.mint)
+            .payer_mint_ata(pda::associated_token_address(payer, &self.mint))
+            .quote_mint(self.quote_mint)
+            .payer_quote_mint_ata(pda::associated_token_address(payer, &self.quote_mint))
+            .vault(pda::associated_token_address(
+                &self.token_manager,
+                &self.quote_mint,
+            ))
+            .payer(*payer)
+            .associated_token_program(pda::ASSOCIATED_TOKEN_PROGRAM_ID)
+            .quantity(quantity)
+            .proof(proof)
+            .instruction()
+    }
+}
+
+/// A staking pool, resolved from its pool manager.
+#[derive(Clone, Debug)]
+pub struct StakingPool {
+    pub pool_manager: Pubkey,
+    pub base_mint: Pubkey,
+    pub x_mint: Pubkey,
+    pub x_token_program: Pubkey,
+    pub token_manager: Pubkey,
+    pub issuance_program: Pubkey,
+    pub treasury: Option<Pubkey>,
+    pub minter_authorization: Option<Pubkey>,
+    /// Stakes and unstakes pass the payer's staker entry, the pool enforces an
+    /// allowlist or wallet caps
+    pub staker_entry_required: bool,
+}
+
+impl StakingPool {
+    pub fn new(pool_manager: &PoolManager) -> Self {
+        Self {
+            pool_manager: pda::pool_manager(&pool_manager.base_mint, pool_manager.pool_id),
+            base_mint: pool_manager.base_mint,
+            x_mint: pool_manager.x_mint,
+            x_token_program: pool_manager.x_token_program,
+            token_manager: pool_manager.token_manager,
+            issuance_program: pool_manager.issuance_program,
+            treasury: (pool_manager.treasury != Pubkey::default()).then_some(pool_manager.treasury),
+            minter_authorization: None,
+            staker_entry_required: pool_manager.allowlist_enabled
+                || pool_manager.wallet_deposit_cap > 0,
+        }
+    }
+
+    /// Passes the minter authorization of the pool manager, required for yield
+    /// minting unless the pool manager is the token manager's minter.
+    pub fn with_minter_authorization(mut self) -> Self {
+        self.minter_authorization = Some(pda::minter(&self.pool_manager));
+        self
+    }
+
+    pub fn vault(&self) -> Pubkey {
+        pda::pool_vault(&self.pool_manager, &self.base_mint)
+    }
+
+    pub fn x_mint_ata(&self, owner: &Pubkey) -> Pubkey {
+        pda::associated_token_address_with_program(owner, &self.x_mint, &self.x_token_program)
+    }
+
+    pub fn stake(&self, payer: &Pubkey, quantity: u64) -> Instruction {
+        StakeBuilder::new()
+            .pool_manager(self.pool_manager)
+            .yield_schedule(pda::yield_schedule(&self.pool_manager))
+            .rate_feed(pda::rate_feed(&self.pool_manager))
+            .rate_history(pda::rate_history(&self.pool_manager))
+            .base_mint(self.base_mint)
+            .payer_base_mint_ata(pda::associated_token_address(payer, &self.base_mint))
+            .x_mint(self.x_mint)
+            .payer_x_mint_ata(self.x_mint_ata(payer))
+            .vault(self.vault())
+            .treasury(self.treasury)
+            .staker_entry(
+                self.staker_entry_required
+                    .then(|| pda::staker_entry(&self.pool_manager, payer)),
+            )
+            .payer(*payer)
+            .x_token_program(self.x_token_program)
+            .associated_token_program(pda::ASSOCIATED_TOKEN_PROGRAM_ID)
+            .quantity(quantity)
+            .instruction()
+    }
+
+    pub fn unstake(&self, payer: &Pubkey, quantity: u64) -> Instruction {
+        UnstakeBuilder::new()
+            .pool_manager(self.pool_manager)
+            .yield_schedule(pda::yield_schedule(&self.pool_manager))
+            .rate_feed(pda::rate_feed(&self.pool_manager))
+            .rate_history(pda::rate_history(&self.pool_manager))
+            .token_manager(self.token_manager)
+            .base_mint(self.base_mint)
+            .payer_base_mint_ata(pda::associated_token_address(payer, &self.base_mint))
+            .x_mint(self.x_mint)
+            .payer_x_mint_ata(self.x_mint_ata(payer))
+            .vault(self.vault())
+            .treasury(self.treasury)
+            .staker_entry(
+                self.staker_entry_required
+                    .then(|| pda::staker_entry(&self.pool_manager, payer)),
+            )
+            .payer(*payer)
+            .x_token_program(self.x_token_program)
+            .associated_token_program(pda::ASSOCIATED_TOKEN_PROGRAM_ID)
+            .minter_authorization(self.minter_authorization)
+            .parity_issuance_program(self.issuance_program)
+            .quantity(quantity)
+            .instruction()
+    }
+
+    /// Mints the outstanding yield into the vault, paying the keeper reward to
+    /// the keeper's base mint ATA.
+    pub fn accrue_yield(&self, keeper: &Pubkey) -> Instruction {
+        AccrueYieldBuilder::new()
+            .pool_manager(self.pool_manager)
+            .yield_schedule(pda::yield_schedule(&self.pool_manager))
+            .rate_feed(pda::rate_feed(&self.pool_manager))
+            .rate_history(pda::rate_history(&self.pool_manager))
+            .token_manager(self.token_manager)
+            .base_mint(self.base_mint)
+            .x_mint(self.x_mint)
+            .vault(self.vault())
+            .treasury(self.treasury)
+            .keeper_base_mint_ata(Some(pda::associated_token_address(keeper, &self.base_mint)))
+            .keeper(*keeper)
+            .x_token_program(self.x_token_program)
+            .associated_token_program(pda::ASSOCIATED_TOKEN_PROGRAM_ID)
+            .minter_authorization(self.minter_authorization)
+            .parity_issuance_program(self.issuance_program)
+            .instruction()
+    }
+
+    /// Publishes the current exchange rate to the pool's rate feed.
+    pub fn refresh_rate_feed(&self) -> Instruction {
+        RefreshRateFeedBuilder::new()
+            .pool_manager(self.pool_manager)
+            .yield_schedule(pda::yield_schedule(&self.pool_manager))
+            .rate_feed(pda::rate_feed(&self.pool_manager))
+            .instruction()
+    }
+
+    /// Deposits rewards into a streaming pool from the rewarder's base mint ATA.
+    pub fn deposit_rewards(&self, rewarder: &Pubkey, quantity: u64) -> Instruction {
+        DepositRewardsBuilder::new()
+            .pool_manager(self.pool_manager)
+            .rate_feed(pda::rate_feed(&self.pool_manager))
+            .base_mint(self.base_mint)
+            .vault(self.vault())
+            .rewarder_base_mint_ata(pda::associated_token_address(rewarder, &self.base_mint))
+            .treasury(self.treasury)
+            .rewarder(*rewarder)
+            .associated_token_program(pda::ASSOCIATED_TOKEN_PROGRAM_ID)
+            .quantity(quantity)
+            .instruction()
+    }
+
+    pub fn claim_unstake(&self, payer: &Pubkey, ticket_id: u64) -> Instruction {
+        ClaimUnstakeBuilder::new()
+            .pool_manager(self.pool_manager)
+            .unstake_ticket(pda::unstake_ticket(&self.pool_manager, payer, ticket_id))
+            .base_mint(self.base_mint)
+            .payer_base_mint_ata(pda::associated_token_address(payer, &self.base_mint))
+            .vault(self.vault())
+            .payer(*payer)
+            .associated_token_program(pda::ASSOCIATED_TOKEN_PROGRAM_ID)
+            .instruction()
+    }
+}
+
+/// The PT staking program, resolved from its global config.
+#[derive(Clone, Debug)]
+pub struct PtStaking {
+    pub global_config: Pubkey,
+    pub base_mint: Pubkey,
+}
+
+impl PtStaking {
+    pub fn new(global_config: &GlobalConfig) -> Self {
+        Self {
+            global_config: pda::global_config(),
+            base_mint: global_config.base_mint,
+        }
+    }
+
+    pub fn init_pt_stake(&self, user: &Pubkey) -> Instruction {
+        InitPtStakeBuilder::new()
+            .user_stake(pda::user_stake(user))
+            .user(*user)
+            .instruction()
+    }
+
+    pub fn pt_stake(&self, user: &Pubkey, quantity: u64) -> Instruction {
+        PtStakeBuilder::new()
+            .global_config(self.global_config)
+            .user_stake(pda::user_stake(user))
+            .base_mint(self.base_mint)
+            .user_base_mint_ata(pda::associated_token_address(user, &self.base_mint))
+            .vault(pda::pt_staking_vault(&self.base_mint))
+            .user(*user)
+            .associated_token_program(pda::ASSOCIATED_TOKEN_PROGRAM_ID)
+            .quantity(quantity)
+            .instruction()
+    }
+
+    pub fn pt_unstake(&self, user: &Pubkey, quantity: u64) -> Instruction {
+        PtUnstakeBuilder::new()
+            .global_config(self.global_config)
+            .user_stake(pda::user_stake(user))
+            .base_mint(self.base_mint)
+            .user_base_mint_ata(pda::associated_token_address(user, &self.base_mint))
+            .vault(pda::pt_staking_vault(&self.base_mint))
+            .user(*user)
+            .associated_token_program(pda::ASSOCIATED_TOKEN_PROGRAM_ID)
+            .quantity(quantity)
+            .instruction()
+    }
+}
@@ -0,0 +1,4 @@
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+pub mod quotes;
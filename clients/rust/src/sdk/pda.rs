@@ -0,0 +1,198 @@
+//! PDA derivations matching the seeds used by the programs.
+
+use solana_program::{pubkey, pubkey::Pubkey};
+
+use crate::{PARITY_ISSUANCE_ID, PARITY_STAKING_ID, PT_STAKING_ID};
+
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+pub fn associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    associated_token_address_with_program(wallet, mint, &TOKEN_PROGRAM_ID)
+}
+
+/// Associated token address of a mint owned by `token_program`, such as an
+/// interest-bearing x mint under Token-2022.
+pub fn associated_token_address_with_program(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn metadata(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+// Issuance
+
+pub fn token_manager() -> Pubkey {
+    Pubkey::find_program_address(&[b"token-manager"], &PARITY_ISSUANCE_ID).0
+}
+
+pub fn issuance_mint() -> Pubkey {
+    Pubkey::find_program_address(&[b"mint"], &PARITY_ISSUANCE_ID).0
+}
+
+pub fn issuance_vault(quote_mint: &Pubkey) -> Pubkey {
+    associated_token_address(&token_manager(), quote_mint)
+}
+
+pub fn gatekeeper(wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"gatekeeper", wallet.as_ref()], &PARITY_ISSUANCE_ID).0
+}
+
+pub fn minter(wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"minter", wallet.as_ref()], &PARITY_ISSUANCE_ID).0
+}
+
+// Staking
+
+pub fn pool_manager(base_mint: &Pubkey, pool_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"pool-manager", base_mint.as_ref(), &pool_id.to_le_bytes()],
+        &PARITY_STAKING_ID,
+    )
+    .0
+}
+
+pub fn x_mint(pool_manager: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"mint", pool_manager.as_ref()], &PARITY_STAKING_ID).0
+}
+
+pub fn yield_schedule(pool_manager: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"yield-schedule", pool_manager.as_ref()],
+        &PARITY_STAKING_ID,
+    )
+    .0
+}
+
+pub fn rate_feed(pool_manager: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"rate-feed", pool_manager.as_ref()], &PARITY_STAKING_ID).0
+}
+
+pub fn rate_history(pool_manager: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"rate-history", pool_manager.as_ref()],
+        &PARITY_STAKING_ID,
+    )
+    .0
+}
+
+pub fn dead_shares(pool_manager: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"dead-shares", pool_manager.as_ref()], &PARITY_STAKING_ID).0
+}
+
+pub fn unstake_ticket(pool_manager: &Pubkey, owner: &Pubkey, ticket_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"unstake-ticket",
+            pool_manager.as_ref(),
+            owner.as_ref(),
+            &ticket_id.to_le_bytes(),
+        ],
+        &PARITY_STAKING_ID,
+    )
+    .0
+}
+
+pub fn pool_vault(pool_manager: &Pubkey, base_mint: &Pubkey) -> Pubkey {
+    associated_token_address(pool_manager, base_mint)
+}
+
+pub fn tranche(pool_manager: &Pubkey, tranche_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"tranche", pool_manager.as_ref(), &tranche_id.to_le_bytes()],
+        &PARITY_STAKING_ID,
+    )
+    .0
+}
+
+pub fn tranche_vault(tranche: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"tranche-vault", tranche.as_ref()], &PARITY_STAKING_ID).0
+}
+
+pub fn term_position(pool_manager: &Pubkey, owner: &Pubkey, position_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"term-position",
+            pool_manager.as_ref(),
+            owner.as_ref(),
+            &position_id.to_le_bytes(),
+        ],
+        &PARITY_STAKING_ID,
+    )
+    .0
+}
+
+pub fn strategy(pool_manager: &Pubkey, strategy_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"strategy",
+            pool_manager.as_ref(),
+            strategy_program.as_ref(),
+        ],
+        &PARITY_STAKING_ID,
+    )
+    .0
+}
+
+pub fn strategy_authority(strategy: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"strategy-authority", strategy.as_ref()],
+        &PARITY_STAKING_ID,
+    )
+    .0
+}
+
+pub fn staker_entry(pool_manager: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"staker-entry", pool_manager.as_ref(), wallet.as_ref()],
+        &PARITY_STAKING_ID,
+    )
+    .0
+}
+
+pub fn guardian(pool_manager: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"guardian", pool_manager.as_ref(), wallet.as_ref()],
+        &PARITY_STAKING_ID,
+    )
+    .0
+}
+
+// PT staking
+
+pub fn global_config() -> Pubkey {
+    Pubkey::find_program_address(&[b"global-config"], &PT_STAKING_ID).0
+}
+
+pub fn user_stake(user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"user-stake", user.as_ref()], &PT_STAKING_ID).0
+}
+
+pub fn pt_staking_vault(base_mint: &Pubkey) -> Pubkey {
+    associated_token_address(&global_config(), base_mint)
+}
+
+pub fn pt_guardian(wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"guardian", wallet.as_ref()], &PT_STAKING_ID).0
+}
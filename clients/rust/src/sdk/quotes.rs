@@ -0,0 +1,411 @@
+//! Quotes computed from account state with the maths the programs use, so
+//! that they match on-chain results exactly. Every quote returns `None` where
+//! the program would fail with a calculation overflow.
+
+use parity_math::{compounding, conversion, decimals, fees, points, vesting};
+
+use crate::{
+    accounts::{GlobalConfig, PoolManager, TokenManager},
+    types::{
+        BaseYieldPhase, ExchangeRatePhase, FeeDestination, PointsEarnedPhase, ScheduledRate,
+        UnstakePath, YieldMode,
+    },
+};
+
+pub use parity_math::DEAD_SHARES;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MintQuote {
+    pub mint_amount: u64,
+    pub fee_amount: u64,
+    pub quote_amount: u64,
+    pub exchange_rate: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RedeemQuote {
+    pub quote_amount: u64,
+    pub fee_amount: u64,
+    pub exchange_rate: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StakeQuote {
+    pub x_amount: u64,
+    pub fee_amount: u64,
+    pub exchange_rate: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnstakeQuote {
+    pub base_amount: u64,
+    pub fee_amount: u64,
+    pub exchange_rate: u64,
+    pub unstake_path: UnstakePath,
+}
+
+// Issuance
+
+pub fn issuance_exchange_rate(token_manager: &TokenManager, current_timestamp: i64) -> Option<u64> {
+    conversion::ramp_exchange_rate(
+        token_manager.previous_exchange_rate,
+        token_manager.exchange_rate,
+        token_manager.last_exchange_rate_update,
+        token_manager.exchange_rate_ramp_end,
+        current_timestamp,
+    )
+}
+
+pub fn quote_mint(
+    token_manager: &TokenManager,
+    quantity: u64,
+    current_timestamp: i64,
+) -> Option<MintQuote> {
+    let exchange_rate = issuance_exchange_rate(token_manager, current_timestamp)?;
+    let fee_amount = fees::fee_rounded_down(quantity, token_manager.mint_fee_bps)?;
+    let normalized_quantity = decimals::normalize_quantity(
+        quantity,
+        token_manager.mint_decimals,
+        token_manager.quote_mint_decimals,
+    )?;
+
+    Some(MintQuote {
+        mint_amount: quantity.checked_sub(fee_amount)?,
+        fee_amount,
+        quote_amount: conversion::calculate_quote_amount(
+            normalized_quantity,
+            exchange_rate,
+            token_manager.quote_mint_decimals,
+        )?,
+        exchange_rate,
+    })
+}
+
+pub fn quote_redeem(
+    token_manager: &TokenManager,
+    quantity: u64,
+    current_timestamp: i64,
+) -> Option<RedeemQuote> {
+    let exchange_rate = issuance_exchange_rate(token_manager, current_timestamp)?;
+    let normalized_quantity = decimals::normalize_quantity(
+        quantity,
+        token_manager.mint_decimals,
+        token_manager.quote_mint_decimals,
+    )?;
+    let quote_amount = conversion::calculate_quote_amount(
+        normalized_quantity,
+        exchange_rate,
+        token_manager.quote_mint_decimals,
+    )?;
+    let fee_amount = fees::fee_rounded_down(quote_amount, token_manager.redeem_fee_bps)?;
+
+    Some(RedeemQuote {
+        quote_amount: quote_amount.checked_sub(fee_amount)?,
+        fee_amount,
+        exchange_rate,
+    })
+}
+
+/// Quote tokens the owner can withdraw while keeping the emergency fund.
+pub fn max_withdrawable_amount(
+    token_manager: &TokenManager,
+    mint_supply: u64,
+    vault_amount: u64,
+    current_timestamp: i64,
+) -> Option<u64> {
+    let exchange_rate = issuance_exchange_rate(token_manager, current_timestamp)?;
+    let mint_decimals = 10u128.checked_pow(token_manager.mint_decimals.into())?;
+    let quote_mint_decimals = 10u128.checked_pow(token_manager.quote_mint_decimals.into())?;
+
+    let required_collateral =
+        (mint_supply as u128).checked_mul(exchange_rate as u128)? / mint_decimals;
+    let min_required_collateral = (required_collateral
+        .checked_mul(token_manager.emergency_fund_basis_points as u128)?
+        / 10000)
+        .checked_mul(quote_mint_decimals)?
+        / mint_decimals;
+
+    Some(vault_amount.saturating_sub(min_required_collateral as u64))
+}
+
+// Staking
+
+/// Exchange rate of the pool at `current_timestamp`, compounding piecewise
+/// across the scheduled rates of its yield schedule that are in effect.
+pub fn staking_exchange_rate(
+    pool_manager: &PoolManager,
+    scheduled_rates: &[ScheduledRate],
+    current_timestamp: i64,
+) -> Option<u64> {
+    if pool_manager.yield_mode == YieldMode::RewardStreaming {
+        return streaming_exchange_rate(pool_manager, current_timestamp);
+    }
+
+    compounding::compound_scheduled_exchange_rate(
+        pool_manager.last_yield_change_exchange_rate,
+        pool_manager.interval_apr_rate,
+        pool_manager.seconds_per_interval as i64,
+        pool_manager.last_yield_change_timestamp,
+        scheduled_rates
+            .iter()
+            .map(|rate| (rate.effective_timestamp, rate.annual_yield_bps)),
+        current_timestamp,
+    )
+}
+
+/// Exchange rate of a streaming pool: its assets less the rewards that have
+/// not vested yet, over its x supply.
+pub fn streaming_exchange_rate(pool_manager: &PoolManager, current_timestamp: i64) -> Option<u64> {
+    let normalized_x_supply = decimals::normalize_quantity(
+        pool_manager.x_supply,
+        pool_manager.x_mint_decimals,
+        pool_manager.base_mint_decimals,
+    )?;
+    let unvested_amount = vesting::unvested_amount(
+        pool_manager.vesting_amount,
+        pool_manager.vesting_start_timestamp,
+        pool_manager.vesting_period,
+        current_timestamp,
+    )?;
+
+    vesting::streaming_exchange_rate(
+        pool_manager
+            .base_balance
+            .saturating_sub(pool_manager.pending_unstake_amount),
+        unvested_amount,
+        normalized_x_supply,
+        pool_manager.base_mint_decimals,
+        pool_manager.initial_exchange_rate,
+    )
+}
+
+/// Stake of `quantity` base tokens, net of the entry fee. A fee left to the
+/// stakers raises the exchange rate before the stake mints.
+pub fn quote_stake(
+    pool_manager: &PoolManager,
+    scheduled_rates: &[ScheduledRate],
+    quantity: u64,
+    current_timestamp: i64,
+) -> Option<StakeQuote> {
+    let mut exchange_rate =
+        staking_exchange_rate(pool_manager, scheduled_rates, current_timestamp)?;
+    let fee_amount = fees::fee_rounded_up(quantity, pool_manager.entry_fee_bps)?;
+    let base_amount = quantity.checked_sub(fee_amount)?;
+
+    if pool_manager.entry_fee_destination == FeeDestination::Stakers
+        && fee_amount > 0
+        && pool_manager.x_supply > DEAD_SHARES
+    {
+        exchange_rate = match pool_manager.yield_mode {
+            YieldMode::Compounding => {
+                let normalized_x_supply = decimals::normalize_quantity(
+                    pool_manager.x_supply,
+                    pool_manager.x_mint_decimals,
+                    pool_manager.base_mint_decimals,
+                )?;
+
+                conversion::raise_exchange_rate(
+                    exchange_rate,
+                    fee_amount,
+                    normalized_x_supply,
+                    pool_manager.base_mint_decimals,
+                )?
+            }
+            YieldMode::RewardStreaming => {
+                let mut pool_manager = pool_manager.clone();
+                pool_manager.base_balance = pool_manager.base_balance.checked_add(fee_amount)?;
+
+                streaming_exchange_rate(&pool_manager, current_timestamp)?
+            }
+        };
+    }
+
+    let normalized_base_amount = decimals::normalize_quantity(
+        base_amount,
+        pool_manager.base_mint_decimals,
+        pool_manager.x_mint_decimals,
+    )?;
+
+    Some(StakeQuote {
+        x_amount: conversion::base_to_x(
+            normalized_base_amount,
+            exchange_rate,
+            pool_manager.base_mint_decimals,
+        )?,
+        fee_amount,
+        exchange_rate,
+    })
+}
+
+/// Unstake of `quantity` x tokens, net of the exit fee and, while a cooldown is
+/// set, of the instant unstake fee.
+pub fn quote_unstake(
+    pool_manager: &PoolManager,
+    scheduled_rates: &[ScheduledRate],
+    quantity: u64,
+    current_timestamp: i64,
+) -> Option<UnstakeQuote> {
+    let exchange_rate = staking_exchange_rate(pool_manager, scheduled_rates, current_timestamp)?;
+    let normalized_quantity = decimals::normalize_quantity(
+        quantity,
+        pool_manager.x_mint_decimals,
+        pool_manager.base_mint_decimals,
+    )?;
+    let base_amount = conversion::x_to_base(
+        normalized_quantity,
+        exchange_rate,
+        pool_manager.base_mint_decimals,
+    )?;
+
+    let exit_fee = fees::fee_rounded_up(base_amount, pool_manager.exit_fee_bps)?;
+    let base_amount = base_amount.checked_sub(exit_fee)?;
+
+    // While a cooldown is set unstakes take the instant path and pay its fee
+    let (unstake_path, instant_unstake_fee) = if pool_manager.unstake_cooldown > 0 {
+        (
+            UnstakePath::Instant,
+            fees::fee_rounded_up(base_amount, pool_manager.instant_unstake_fee_bps)?,
+        )
+    } else {
+        (UnstakePath::Standard, 0)
+    };
+
+    Some(UnstakeQuote {
+        base_amount: base_amount.checked_sub(instant_unstake_fee)?,
+        fee_amount: exit_fee.checked_add(instant_unstake_fee)?,
+        exchange_rate,
+        unstake_path,
+    })
+}
+
+/// Yield the x supply has accrued beyond the base tokens backing it, which
+/// is minted into the vault on the next stake, unstake or yield update.
+pub fn accrued_unminted_yield(
+    pool_manager: &PoolManager,
+    scheduled_rates: &[ScheduledRate],
+    x_mint_supply: u64,
+    current_timestamp: i64,
+) -> Option<u64> {
+    let exchange_rate = staking_exchange_rate(pool_manager, scheduled_rates, current_timestamp)?;
+    let normalized_x_mint_supply = decimals::normalize_quantity(
+        x_mint_supply,
+        pool_manager.x_mint_decimals,
+        pool_manager.base_mint_decimals,
+    )?;
+    let x_supply_value = conversion::x_to_base(
+        normalized_x_mint_supply,
+        exchange_rate,
+        pool_manager.base_mint_decimals,
+    )?;
+    let backing_amount = pool_manager
+        .base_balance
+        .saturating_sub(pool_manager.pending_unstake_amount);
+
+    Some(x_supply_value.saturating_sub(backing_amount))
+}
+
+// PT staking
+
+enum CombinedPhase<'a> {
+    ExchangeRate(&'a ExchangeRatePhase),
+    BaseYield(&'a BaseYieldPhase),
+}
+
+/// Points earned by `staked_amount` since `staking_timestamp`, split per
+/// exchange rate phase.
+pub fn calculate_points(
+    global_config: &GlobalConfig,
+    staked_amount: u64,
+    staking_timestamp: i64,
+    current_timestamp: i64,
+) -> Option<Vec<PointsEarnedPhase>> {
+    let mut points_history = Vec::new();
+    let mut current_time = staking_timestamp;
+    let mut accumulated_points: u128 = 0;
+
+    // Combine both histories and sort by start_date
+    let mut combined_phases: Vec<(i64, CombinedPhase)> = global_config
+        .exchange_rate_history
+        .iter()
+        .map(|phase| (phase.start_date, CombinedPhase::ExchangeRate(phase)))
+        .chain(
+            global_config
+                .base_yield_history
+                .iter()
+                .map(|phase| (phase.start_date, CombinedPhase::BaseYield(phase))),
+        )
+        .collect();
+    combined_phases.sort_by_key(|&(date, _)| date);
+
+    let mut current_exchange_rate = global_config.exchange_rate_history.first()?;
+    let mut current_base_yield = global_config.base_yield_history.first()?;
+
+    for &(phase_start, ref phase) in combined_phases.iter() {
+        if phase_start >= current_timestamp {
+            break;
+        }
+
+        if phase_start <= current_time {
+            match phase {
+                CombinedPhase::ExchangeRate(exchange_rate_phase) => {
+                    // When exchange rate changes, push accumulated points and reset
+                    if accumulated_points > 0 {
+                        points_history.push(PointsEarnedPhase {
+                            exchange_rate: current_exchange_rate.exchange_rate,
+                            points: accumulated_points as u64,
+                            index: current_exchange_rate.index,
+                        });
+                        accumulated_points = 0;
+                    }
+                    current_exchange_rate = exchange_rate_phase;
+                }
+                CombinedPhase::BaseYield(base_yield_phase) => current_base_yield = base_yield_phase,
+            }
+            continue;
+        }
+
+        accumulated_points += points::calculate_points(
+            staked_amount,
+            current_base_yield.base_yield_bps,
+            current_exchange_rate.exchange_rate,
+            phase_start - current_time,
+            global_config.base_mint_decimals,
+        )?;
+        current_time = phase_start;
+
+        match phase {
+            CombinedPhase::ExchangeRate(exchange_rate_phase) => {
+                points_history.push(PointsEarnedPhase {
+                    exchange_rate: current_exchange_rate.exchange_rate,
+                    points: accumulated_points as u64,
+                    index: current_exchange_rate.index,
+                });
+                accumulated_points = 0;
+                current_exchange_rate = exchange_rate_phase;
+            }
+            CombinedPhase::BaseYield(base_yield_phase) => current_base_yield = base_yield_phase,
+        }
+    }
+
+    // Calculate points for the final phase
+    if current_time < current_timestamp {
+        accumulated_points += points::calculate_points(
+            staked_amount,
+            current_base_yield.base_yield_bps,
+            current_exchange_rate.exchange_rate,
+            current_timestamp - current_time,
+            global_config.base_mint_decimals,
+        )?;
+    }
+
+    // Push the final accumulated points
+    if accumulated_points > 0 {
+        points_history.push(PointsEarnedPhase {
+            exchange_rate: current_exchange_rate.exchange_rate,
+            points: accumulated_points as u64,
+            index: current_exchange_rate.index,
+        });
+    }
+
+    Some(points_history)
+}
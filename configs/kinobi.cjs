@@ -20,6 +20,46 @@ kinobi.update(
   })
 );
 
+// Rename nodes that share a name across programs.
+kinobi.update(
+  new k.updateAccountsVisitor({
+    "ptStaking.guardian": { name: "ptGuardian" },
+  })
+);
+kinobi.update(
+  new k.updateInstructionsVisitor({
+    "parityStaking.toggleActive": { name: "toggleStakingActive" },
+    "ptStaking.toggleActive": { name: "togglePtStakingActive" },
+    "ptStaking.addGuardian": { name: "addPtGuardian" },
+    "ptStaking.removeGuardian": { name: "removePtGuardian" },
+  })
+);
+
+// Composite account structs repeat accounts that Anchor needs at the top
+// level (e.g. `system_program` for `init`), so number the repeats to keep
+// each account its own field.
+kinobi.update(
+  k.bottomUpTransformerVisitor([
+    {
+      select: "[instructionNode]",
+      transform: (node) => {
+        const seen = {};
+        return k.instructionNode({
+          ...node,
+          accounts: node.accounts.map((account) => {
+            seen[account.name] = (seen[account.name] ?? 0) + 1;
+            if (seen[account.name] === 1) return account;
+            return k.instructionAccountNode({
+              ...account,
+              name: `${account.name}${seen[account.name]}`,
+            });
+          }),
+        });
+      },
+    },
+  ])
+);
+
 // Update accounts.
 kinobi.update(
   new k.updateAccountsVisitor({
@@ -30,7 +70,17 @@ kinobi.update(
     },
     poolManager: {
       seeds: [
-        k.constantPdaSeedNodeFromString("pool-manager")
+        k.constantPdaSeedNodeFromString("pool-manager"),
+        k.variablePdaSeedNode(
+          "baseMint",
+          k.publicKeyTypeNode(),
+          "The address of the base mint"
+        ),
+        k.variablePdaSeedNode(
+          "poolId",
+          k.numberTypeNode("u64"),
+          "The id of the pool"
+        ),
       ],
     },
     gatekeeper: {
@@ -43,11 +93,61 @@ kinobi.update(
         ),
       ],
     },
+    minter: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("minter"),
+        k.variablePdaSeedNode(
+          "wallet",
+          k.publicKeyTypeNode(),
+          "The address of the minter wallet"
+        ),
+      ],
+    },
     globalConfig: {
       seeds: [
         k.constantPdaSeedNodeFromString("global-config")
       ]
     },
+    yieldSchedule: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("yield-schedule"),
+        k.variablePdaSeedNode(
+          "poolManager",
+          k.publicKeyTypeNode(),
+          "The address of the pool manager"
+        ),
+      ],
+    },
+    deadShares: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("dead-shares"),
+        k.variablePdaSeedNode(
+          "poolManager",
+          k.publicKeyTypeNode(),
+          "The address of the pool manager"
+        ),
+      ],
+    },
+    unstakeTicket: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("unstake-ticket"),
+        k.variablePdaSeedNode(
+          "poolManager",
+          k.publicKeyTypeNode(),
+          "The address of the pool manager"
+        ),
+        k.variablePdaSeedNode(
+          "owner",
+          k.publicKeyTypeNode(),
+          "The address of the ticket owner"
+        ),
+        k.variablePdaSeedNode(
+          "ticketId",
+          k.numberTypeNode("u64"),
+          "The id of the unstake ticket"
+        ),
+      ],
+    },
     userStake: {
       seeds: [
         k.constantPdaSeedNodeFromString("user-stake"),
@@ -57,7 +157,117 @@ kinobi.update(
           "The address of the user wallet"
         ),
       ]
-    }
+    },
+    "parityStaking.guardian": {
+      seeds: [
+        k.constantPdaSeedNodeFromString("guardian"),
+        k.variablePdaSeedNode(
+          "poolManager",
+          k.publicKeyTypeNode(),
+          "The address of the pool manager"
+        ),
+        k.variablePdaSeedNode(
+          "wallet",
+          k.publicKeyTypeNode(),
+          "The address of the guardian wallet"
+        ),
+      ],
+    },
+    "ptStaking.ptGuardian": {
+      seeds: [
+        k.constantPdaSeedNodeFromString("guardian"),
+        k.variablePdaSeedNode(
+          "wallet",
+          k.publicKeyTypeNode(),
+          "The address of the guardian wallet"
+        ),
+      ],
+    },
+    strategy: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("strategy"),
+        k.variablePdaSeedNode(
+          "poolManager",
+          k.publicKeyTypeNode(),
+          "The address of the pool manager"
+        ),
+        k.variablePdaSeedNode(
+          "strategyProgram",
+          k.publicKeyTypeNode(),
+          "The address of the strategy program"
+        ),
+      ],
+    },
+    stakerEntry: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("staker-entry"),
+        k.variablePdaSeedNode(
+          "poolManager",
+          k.publicKeyTypeNode(),
+          "The address of the pool manager"
+        ),
+        k.variablePdaSeedNode(
+          "wallet",
+          k.publicKeyTypeNode(),
+          "The address of the staker wallet"
+        ),
+      ],
+    },
+    tranche: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("tranche"),
+        k.variablePdaSeedNode(
+          "poolManager",
+          k.publicKeyTypeNode(),
+          "The address of the pool manager"
+        ),
+        k.variablePdaSeedNode(
+          "trancheId",
+          k.numberTypeNode("u64"),
+          "The id of the tranche"
+        ),
+      ],
+    },
+    termPosition: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("term-position"),
+        k.variablePdaSeedNode(
+          "poolManager",
+          k.publicKeyTypeNode(),
+          "The address of the pool manager"
+        ),
+        k.variablePdaSeedNode(
+          "owner",
+          k.publicKeyTypeNode(),
+          "The address of the position owner"
+        ),
+        k.variablePdaSeedNode(
+          "positionId",
+          k.numberTypeNode("u64"),
+          "The id of the term position"
+        ),
+      ],
+    },
+    rateFeed: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("rate-feed"),
+        k.variablePdaSeedNode(
+          "poolManager",
+          k.publicKeyTypeNode(),
+          "The address of the pool manager"
+        ),
+      ],
+    },
+    rateHistory: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("rate-history"),
+        k.variablePdaSeedNode(
+          "poolManager",
+          k.publicKeyTypeNode(),
+          "The address of the pool manager"
+        ),
+      ],
+    },
   })
 );
 
@@ -0,0 +1,18 @@
+#!/bin/bash
+
+SCRIPT_DIR=$(cd -- "$(dirname -- "${BASH_SOURCE[0]}")" &>/dev/null && pwd)
+# go to parent folder
+cd $(dirname $(dirname $(dirname $SCRIPT_DIR)))
+WORKING_DIR=$(pwd)
+
+# regenerate the IDLs and both clients from the program sources
+pnpm generate || exit 1
+
+# the committed IDLs and generated clients must match what was just generated
+cd ${WORKING_DIR}
+if [ -n "$(git status --porcelain -- idls clients/js/src/generated clients/rust/src/generated)" ]; then
+    echo "Generated IDLs or clients are out of date, run 'pnpm generate' and commit the result:"
+    git status --short -- idls clients/js/src/generated clients/rust/src/generated
+    git --no-pager diff --stat -- idls clients/js/src/generated clients/rust/src/generated
+    exit 1
+fi
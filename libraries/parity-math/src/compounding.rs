@@ -0,0 +1,248 @@
+use ruint::aliases::U256;
+
+use crate::{
+    u64x64_math::{
+        calculate_interval_apr_rate, from_decimal, pow, pow_fraction, MAX_EXPONENTIAL, ONE,
+        SCALE_OFFSET,
+    },
+    SECONDS_PER_YEAR,
+};
+
+/// Grows `exchange_rate` by `interval_apr_rate`, a per interval compounding
+/// factor with 10^12 precision, over every full interval in `elapsed_time`
+/// and accrues linearly over the remaining partial interval.
+pub fn compound_exchange_rate(
+    exchange_rate: u64,
+    interval_apr_rate: u64,
+    seconds_per_interval: i64,
+    elapsed_time: i64,
+) -> Option<u64> {
+    if seconds_per_interval <= 0 {
+        return None;
+    }
+    if elapsed_time <= 0 {
+        return Some(exchange_rate);
+    }
+
+    let mut remaining_intervals = elapsed_time / seconds_per_interval;
+    let remaining_seconds = elapsed_time % seconds_per_interval;
+
+    let interval_rate = from_decimal(interval_apr_rate as u128)?;
+
+    // Compound at most a year of intervals per pow call. This keeps the
+    // exponent below MAX_EXPONENTIAL and the growth of each chunk small
+    // enough for pow to stay precise.
+    let intervals_per_chunk =
+        (SECONDS_PER_YEAR as i64 / seconds_per_interval).clamp(1, MAX_EXPONENTIAL as i64 - 1);
+
+    let mut growth = U256::from(ONE);
+    while remaining_intervals > 0 {
+        let chunk_intervals = remaining_intervals.min(intervals_per_chunk);
+        let chunk_growth = pow(interval_rate, chunk_intervals as i32)?;
+
+        growth = mul_shr(growth, U256::from(chunk_growth))?;
+        remaining_intervals -= chunk_intervals;
+    }
+
+    // Accrue the remaining seconds linearly on top of the compounded growth
+    let linear_growth = interval_rate
+        .checked_sub(ONE)?
+        .checked_mul(remaining_seconds as u128)?
+        / seconds_per_interval as u128;
+    growth = mul_shr(growth, U256::from(ONE.checked_add(linear_growth)?))?;
+
+    let new_exchange_rate = mul_shr(growth, U256::from(exchange_rate))?;
+
+    u64::try_from(new_exchange_rate).ok()
+}
+
+/// Compounds `exchange_rate` from `timestamp` to `current_timestamp`, switching
+/// to each scheduled annual yield, given as `(effective_timestamp,
+/// annual_yield_bps)` in effective order, once it takes effect.
+pub fn compound_scheduled_exchange_rate(
+    exchange_rate: u64,
+    interval_apr_rate: u64,
+    seconds_per_interval: i64,
+    timestamp: i64,
+    scheduled_rates: impl IntoIterator<Item = (i64, u64)>,
+    current_timestamp: i64,
+) -> Option<u64> {
+    let mut exchange_rate = exchange_rate;
+    let mut interval_apr_rate = interval_apr_rate;
+    let mut timestamp = timestamp;
+
+    for (effective_timestamp, annual_yield_bps) in scheduled_rates {
+        if effective_timestamp > current_timestamp {
+            break;
+        }
+
+        // A yield change after the boundary already compounded past it
+        let boundary_timestamp = effective_timestamp.max(timestamp);
+        exchange_rate = compound_exchange_rate(
+            exchange_rate,
+            interval_apr_rate,
+            seconds_per_interval,
+            boundary_timestamp.checked_sub(timestamp)?,
+        )?;
+        timestamp = boundary_timestamp;
+        interval_apr_rate = calculate_interval_apr_rate(
+            annual_yield_bps,
+            seconds_per_interval as u128,
+            SECONDS_PER_YEAR,
+        )?
+        .try_into()
+        .ok()?;
+    }
+
+    compound_exchange_rate(
+        exchange_rate,
+        interval_apr_rate,
+        seconds_per_interval,
+        current_timestamp.checked_sub(timestamp)?,
+    )
+}
+
+/// Annual yield in basis points, compounded, that grows `start_exchange_rate`
+/// into `end_exchange_rate` over `elapsed_time` seconds. Zero when the rate did
+/// not grow.
+pub fn annualized_yield_bps(
+    start_exchange_rate: u64,
+    end_exchange_rate: u64,
+    elapsed_time: i64,
+) -> Option<u64> {
+    if start_exchange_rate == 0 || elapsed_time <= 0 {
+        return None;
+    }
+    if end_exchange_rate <= start_exchange_rate {
+        return Some(0);
+    }
+
+    let growth = ((end_exchange_rate as u128) << SCALE_OFFSET) / start_exchange_rate as u128;
+    let annual_growth = pow_fraction(growth, SECONDS_PER_YEAR, elapsed_time as u128)?;
+
+    // Round to the nearest basis point
+    let annual_yield_bps =
+        (annual_growth.checked_sub(ONE)?.checked_mul(10000)? + (ONE >> 1)) >> SCALE_OFFSET;
+    annual_yield_bps.try_into().ok()
+}
+
+// Multiplies by a 64x64 value, failing once the result no longer fits 128 bits
+fn mul_shr(value: U256, multiplier: U256) -> Option<U256> {
+    let result = value.checked_mul(multiplier)? >> SCALE_OFFSET as usize;
+
+    if result > U256::from(u128::MAX) {
+        return None;
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compound_exchange_rate() {
+        // 20% a year compounded every 8 hours
+        let interval_apr_rate = 1000166517567;
+        let seconds_per_interval = 8 * 60 * 60;
+
+        assert_eq!(
+            compound_exchange_rate(1_000_000, interval_apr_rate, seconds_per_interval, 0),
+            Some(1_000_000)
+        );
+        assert_eq!(
+            compound_exchange_rate(
+                1_000_000,
+                interval_apr_rate,
+                seconds_per_interval,
+                SECONDS_PER_YEAR as i64
+            ),
+            Some(1_199_999) // Rounded down
+        );
+
+        assert_eq!(
+            compound_exchange_rate(1_000_000, interval_apr_rate, 0, 1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_compound_scheduled_exchange_rate() {
+        let interval_apr_rate = 1000166517567;
+        let seconds_per_interval = 8 * 60 * 60;
+        let year = SECONDS_PER_YEAR as i64;
+
+        // Without a schedule it compounds like compound_exchange_rate
+        assert_eq!(
+            compound_scheduled_exchange_rate(
+                1_000_000,
+                interval_apr_rate,
+                seconds_per_interval,
+                0,
+                [],
+                year
+            ),
+            Some(1_199_999)
+        );
+
+        // Dropping to 0% after a year stops the growth
+        assert_eq!(
+            compound_scheduled_exchange_rate(
+                1_000_000,
+                interval_apr_rate,
+                seconds_per_interval,
+                0,
+                [(year, 0)],
+                2 * year
+            ),
+            Some(1_199_999)
+        );
+
+        // Rates that are not effective yet are ignored
+        assert_eq!(
+            compound_scheduled_exchange_rate(
+                1_000_000,
+                interval_apr_rate,
+                seconds_per_interval,
+                0,
+                [(2 * year, 0)],
+                year
+            ),
+            Some(1_199_999)
+        );
+
+        // A rate effective before the last yield change applies from then on
+        assert_eq!(
+            compound_scheduled_exchange_rate(
+                1_000_000,
+                interval_apr_rate,
+                seconds_per_interval,
+                year,
+                [(0, 0)],
+                2 * year
+            ),
+            Some(1_000_000)
+        );
+    }
+
+    #[test]
+    fn test_annualized_yield_bps() {
+        let year = SECONDS_PER_YEAR as i64;
+
+        assert_eq!(annualized_yield_bps(1_000_000, 1_200_000, year), Some(2000));
+
+        // Half a year at 20% compounds to the same annual yield
+        assert_eq!(
+            annualized_yield_bps(1_000_000, 1_095_445, year / 2),
+            Some(2000)
+        );
+
+        // A rate that did not grow yields nothing
+        assert_eq!(annualized_yield_bps(1_000_000, 1_000_000, year), Some(0));
+        assert_eq!(annualized_yield_bps(1_000_000, 900_000, year), Some(0));
+
+        assert_eq!(annualized_yield_bps(0, 1_000_000, year), None);
+        assert_eq!(annualized_yield_bps(1_000_000, 1_200_000, 0), None);
+    }
+}
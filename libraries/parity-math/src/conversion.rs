@@ -0,0 +1,191 @@
+/// Exchange rate ramping linearly from `previous_exchange_rate` at
+/// `ramp_start` to `exchange_rate` at `ramp_end`.
+pub fn ramp_exchange_rate(
+    previous_exchange_rate: u64,
+    exchange_rate: u64,
+    ramp_start: i64,
+    ramp_end: i64,
+    current_timestamp: i64,
+) -> Option<u64> {
+    // Outside of a ramp the target exchange rate applies as is
+    if current_timestamp >= ramp_end {
+        return Some(exchange_rate);
+    }
+    if current_timestamp <= ramp_start {
+        return Some(previous_exchange_rate);
+    }
+
+    // Interpolate linearly from the previous rate to the target rate
+    let elapsed = (current_timestamp - ramp_start) as i128;
+    let duration = (ramp_end - ramp_start) as i128;
+    let rate_difference = exchange_rate as i128 - previous_exchange_rate as i128;
+
+    let ramped_difference = rate_difference
+        .checked_mul(elapsed)?
+        .checked_div(duration)?;
+
+    u64::try_from((previous_exchange_rate as i128).checked_add(ramped_difference)?).ok()
+}
+
+/// Quote amount for `normalized_quantity` mint tokens, at an exchange rate
+/// expressed in quote mint decimals. Rounds down.
+pub fn calculate_quote_amount(
+    normalized_quantity: u64,
+    exchange_rate: u64,
+    quote_mint_decimals: u8,
+) -> Option<u64> {
+    let quote_amount = (normalized_quantity as u128)
+        .checked_mul(exchange_rate as u128)?
+        .checked_div(10u128.checked_pow(quote_mint_decimals.into())?)?;
+
+    u64::try_from(quote_amount).ok()
+}
+
+/// x amount for `normalized_base_amount` base tokens, at an exchange rate
+/// expressed in base mint decimals. Rounds down.
+pub fn base_to_x(
+    normalized_base_amount: u64,
+    exchange_rate: u64,
+    base_mint_decimals: u8,
+) -> Option<u64> {
+    let x_amount = (normalized_base_amount as u128)
+        .checked_mul(10u128.checked_pow(base_mint_decimals.into())?)?
+        .checked_div(exchange_rate as u128)?;
+
+    u64::try_from(x_amount).ok()
+}
+
+/// Base amount for `normalized_x_amount` x tokens, at an exchange rate
+/// expressed in base mint decimals. Rounds down.
+pub fn x_to_base(
+    normalized_x_amount: u64,
+    exchange_rate: u64,
+    base_mint_decimals: u8,
+) -> Option<u64> {
+    let base_amount = (normalized_x_amount as u128)
+        .checked_mul(exchange_rate as u128)?
+        .checked_div(10u128.checked_pow(base_mint_decimals.into())?)?;
+
+    u64::try_from(base_amount).ok()
+}
+
+/// Exchange rate at which `normalized_x_supply` x tokens are worth
+/// `base_assets` base tokens, expressed in base mint decimals. Rounds down.
+pub fn assets_to_exchange_rate(
+    base_assets: u64,
+    normalized_x_supply: u64,
+    base_mint_decimals: u8,
+) -> Option<u64> {
+    let exchange_rate = (base_assets as u128)
+        .checked_mul(10u128.checked_pow(base_mint_decimals.into())?)?
+        .checked_div(normalized_x_supply as u128)?;
+
+    u64::try_from(exchange_rate).ok()
+}
+
+/// Exchange rate raised so that `normalized_x_supply` x tokens absorb
+/// `base_amount` more base tokens, expressed in base mint decimals. Rounds
+/// down.
+pub fn raise_exchange_rate(
+    exchange_rate: u64,
+    base_amount: u64,
+    normalized_x_supply: u64,
+    base_mint_decimals: u8,
+) -> Option<u64> {
+    let exchange_rate_increase = (base_amount as u128)
+        .checked_mul(10u128.checked_pow(base_mint_decimals.into())?)?
+        .checked_div(normalized_x_supply as u128)?;
+
+    u64::try_from((exchange_rate as u128).checked_add(exchange_rate_increase)?).ok()
+}
+
+/// Base amount backing `x_amount` x tokens, at an exchange rate expressed in
+/// base mint decimals. Scales from x decimals directly so that no precision is
+/// lost before rounding up.
+pub fn x_to_base_rounded_up(x_amount: u64, exchange_rate: u64, x_mint_decimals: u8) -> Option<u64> {
+    let x_decimals = 10u128.checked_pow(x_mint_decimals.into())?;
+    let base_amount = (x_amount as u128)
+        .checked_mul(exchange_rate as u128)?
+        .checked_add(x_decimals - 1)?
+        / x_decimals;
+
+    u64::try_from(base_amount).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ramp_exchange_rate() {
+        assert_eq!(
+            ramp_exchange_rate(1_000_000, 1_100_000, 100, 200, 50),
+            Some(1_000_000)
+        );
+        assert_eq!(
+            ramp_exchange_rate(1_000_000, 1_100_000, 100, 200, 150),
+            Some(1_050_000)
+        );
+        assert_eq!(
+            ramp_exchange_rate(1_100_000, 1_000_000, 100, 200, 175),
+            Some(1_025_000)
+        );
+        assert_eq!(
+            ramp_exchange_rate(1_000_000, 1_100_000, 100, 200, 200),
+            Some(1_100_000)
+        );
+    }
+
+    #[test]
+    fn test_calculate_quote_amount() {
+        assert_eq!(calculate_quote_amount(10_000, 2_000_000, 6), Some(20_000));
+        assert_eq!(calculate_quote_amount(0, 2_000_000, 6), Some(0));
+        assert_eq!(calculate_quote_amount(u64::MAX, u64::MAX, 0), None);
+    }
+
+    #[test]
+    fn test_base_x_round_trip_rounds_down() {
+        let exchange_rate = 1_095_445;
+
+        let x_amount = base_to_x(1_000_000, exchange_rate, 6).unwrap();
+        assert_eq!(x_amount, 912_871);
+
+        let base_amount = x_to_base(x_amount, exchange_rate, 6).unwrap();
+        assert!(base_amount <= 1_000_000);
+
+        // Rounding up covers the truncated remainder
+        let required_base_amount = x_to_base_rounded_up(x_amount, exchange_rate, 6).unwrap();
+        assert_eq!(required_base_amount, base_amount + 1);
+
+        assert_eq!(base_to_x(1_000_000, 0, 6), None);
+    }
+
+    #[test]
+    fn test_assets_to_exchange_rate() {
+        assert_eq!(
+            assets_to_exchange_rate(1_200_000, 1_000_000, 6),
+            Some(1_200_000)
+        );
+        // Rounds down, so the x supply is never worth more than the assets
+        let exchange_rate = assets_to_exchange_rate(1_000_000, 3_000_000, 6).unwrap();
+        assert_eq!(exchange_rate, 333_333);
+        assert!(x_to_base(3_000_000, exchange_rate, 6).unwrap() <= 1_000_000);
+
+        assert_eq!(assets_to_exchange_rate(1_000_000, 0, 6), None);
+    }
+
+    #[test]
+    fn test_raise_exchange_rate() {
+        // 1,000 base tokens spread over 10,000 x tokens raise the rate by 0.1
+        assert_eq!(
+            raise_exchange_rate(1_000_000, 1_000_000_000, 10_000_000_000, 6),
+            Some(1_100_000)
+        );
+        assert_eq!(
+            raise_exchange_rate(1_000_000, 0, 10_000_000_000, 6),
+            Some(1_000_000)
+        );
+
+        assert_eq!(raise_exchange_rate(1_000_000, 1_000_000, 0, 6), None);
+    }
+}
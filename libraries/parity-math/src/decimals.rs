@@ -0,0 +1,26 @@
+/// Rescales `quantity` from `from_decimals` to `to_decimals`, rounding down.
+pub fn normalize_quantity(quantity: u64, from_decimals: u8, to_decimals: u8) -> Option<u64> {
+    let decimal_difference = from_decimals.abs_diff(to_decimals) as u32;
+    let scale = 10u128.checked_pow(decimal_difference)?;
+
+    if from_decimals > to_decimals {
+        u64::try_from((quantity as u128) / scale).ok()
+    } else {
+        u64::try_from((quantity as u128).checked_mul(scale)?).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_quantity() {
+        assert_eq!(normalize_quantity(1_000_000, 6, 6), Some(1_000_000));
+        assert_eq!(normalize_quantity(1_000_000, 6, 9), Some(1_000_000_000));
+        assert_eq!(normalize_quantity(1_000_000_999, 9, 6), Some(1_000_000));
+
+        // Overflowing the output fails instead of truncating
+        assert_eq!(normalize_quantity(u64::MAX, 6, 9), None);
+    }
+}
@@ -0,0 +1,39 @@
+//! Fees charged in basis points.
+
+/// Fee of `fee_bps` on `amount`, rounded down.
+pub fn fee_rounded_down(amount: u64, fee_bps: u16) -> Option<u64> {
+    let fee_amount = (amount as u128).checked_mul(fee_bps as u128)? / 10000;
+
+    u64::try_from(fee_amount).ok()
+}
+
+/// Fee of `fee_bps` on `amount`, rounded up in favour of whoever charges it.
+pub fn fee_rounded_up(amount: u64, fee_bps: u16) -> Option<u64> {
+    let fee_amount = (amount as u128)
+        .checked_mul(fee_bps as u128)?
+        .checked_add(9999)?
+        / 10000;
+
+    u64::try_from(fee_amount).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_rounding() {
+        assert_eq!(fee_rounded_down(1_000_000, 50), Some(5_000));
+        assert_eq!(fee_rounded_up(1_000_000, 50), Some(5_000));
+
+        assert_eq!(fee_rounded_down(1_999, 50), Some(9));
+        assert_eq!(fee_rounded_up(1_999, 50), Some(10));
+
+        assert_eq!(fee_rounded_down(1_000_000, 0), Some(0));
+        assert_eq!(fee_rounded_up(1_000_000, 0), Some(0));
+
+        // Large amounts do not overflow
+        assert_eq!(fee_rounded_down(u64::MAX, 10000), Some(u64::MAX));
+        assert_eq!(fee_rounded_up(u64::MAX, 10000), Some(u64::MAX));
+    }
+}
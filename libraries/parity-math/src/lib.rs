@@ -0,0 +1,20 @@
+//! Maths shared by the Parity programs and the Rust client, so that off-chain
+//! quotes match on-chain results exactly. Every function is checked and
+//! returns `None` on overflow, leaving the error type to the caller.
+
+#![cfg_attr(not(test), no_std)]
+
+pub mod compounding;
+pub mod conversion;
+pub mod decimals;
+pub mod fees;
+pub mod points;
+pub mod twap;
+pub mod u64x64_math;
+pub mod vesting;
+
+pub const SECONDS_PER_YEAR: u128 = 365 * 24 * 60 * 60;
+
+/// x tokens the staking program locks at pool initialization, so that the x
+/// supply never drops back to zero.
+pub const DEAD_SHARES: u64 = 1000;
@@ -0,0 +1,40 @@
+use crate::{u64x64_math::PRECISION, SECONDS_PER_YEAR};
+
+/// Points earned by `staked_amount` base tokens over `duration` seconds at
+/// `base_yield_bps` a year, scaled by the exchange rate and normalized by the
+/// base mint decimals. Rounds down.
+pub fn calculate_points(
+    staked_amount: u64,
+    base_yield_bps: u64,
+    exchange_rate: u64,
+    duration: i64,
+    base_mint_decimals: u8,
+) -> Option<u128> {
+    let duration_in_years = u128::try_from(duration)
+        .ok()?
+        .checked_mul(PRECISION)?
+        .checked_div(SECONDS_PER_YEAR)?;
+
+    (staked_amount as u128)
+        .checked_mul(base_yield_bps as u128)?
+        .checked_mul(duration_in_years)?
+        .checked_mul(exchange_rate as u128)?
+        .checked_div(PRECISION)?
+        .checked_div(10000)?
+        .checked_div(10u128.checked_pow(base_mint_decimals.into())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_points() {
+        // 1000 tokens at 20% for a year with an exchange rate of 1
+        let points = calculate_points(1_000_000_000, 2000, 1, SECONDS_PER_YEAR as i64, 6);
+        assert_eq!(points, Some(200));
+
+        assert_eq!(calculate_points(1_000_000_000, 2000, 1, 0, 6), Some(0));
+        assert_eq!(calculate_points(1_000_000_000, 2000, 1, -1, 6), None);
+    }
+}
@@ -0,0 +1,92 @@
+//! Time-weighted averages of the exchange rate, taken from a cumulative sum of
+//! the rate times the seconds it held. The rate is assumed to move linearly
+//! between two observations.
+
+/// Adds the rate over the last `elapsed` seconds, moving from `previous_rate`
+/// to `rate`, to `cumulative`. Rounds down.
+pub fn accumulate(cumulative: u128, previous_rate: u64, rate: u64, elapsed: i64) -> Option<u128> {
+    if elapsed < 0 {
+        return None;
+    }
+
+    let area = (previous_rate as u128 + rate as u128).checked_mul(elapsed as u128)? / 2;
+
+    cumulative.checked_add(area)
+}
+
+/// Cumulative sum `elapsed` seconds into an `interval` that starts at `rate`
+/// with `cumulative` and ends at `next_rate`. Rounds down.
+pub fn interpolate(
+    cumulative: u128,
+    rate: u64,
+    next_rate: u64,
+    elapsed: i64,
+    interval: i64,
+) -> Option<u128> {
+    if interval <= 0 || elapsed < 0 || elapsed > interval {
+        return None;
+    }
+
+    let rate_change =
+        (next_rate as i128 - rate as i128).checked_mul(elapsed as i128)? / interval as i128;
+    let elapsed_rate = u64::try_from(rate as i128 + rate_change).ok()?;
+
+    accumulate(cumulative, rate, elapsed_rate, elapsed)
+}
+
+/// Average rate between two cumulative sums taken `elapsed` seconds apart.
+/// Rounds down.
+pub fn time_weighted_average(
+    start_cumulative: u128,
+    end_cumulative: u128,
+    elapsed: i64,
+) -> Option<u64> {
+    if elapsed <= 0 {
+        return None;
+    }
+
+    let average = end_cumulative.checked_sub(start_cumulative)? / elapsed as u128;
+
+    u64::try_from(average).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accumulate() {
+        assert_eq!(accumulate(0, 1_000, 1_000, 100), Some(100_000));
+        assert_eq!(accumulate(100_000, 1_000, 2_000, 100), Some(250_000));
+        assert_eq!(accumulate(100_000, 1_000, 2_000, 0), Some(100_000));
+
+        assert_eq!(accumulate(0, 1_000, 1_000, -1), None);
+        assert_eq!(accumulate(u128::MAX, 1_000, 1_000, 1), None);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        // Halfway from 1_000 to 2_000 the rate is 1_500
+        assert_eq!(interpolate(0, 1_000, 2_000, 50, 100), Some(62_500));
+        assert_eq!(interpolate(0, 1_000, 2_000, 0, 100), Some(0));
+        assert_eq!(
+            interpolate(0, 1_000, 2_000, 100, 100),
+            accumulate(0, 1_000, 2_000, 100)
+        );
+        // Falling rates
+        assert_eq!(interpolate(0, 2_000, 1_000, 50, 100), Some(87_500));
+
+        assert_eq!(interpolate(0, 1_000, 2_000, 101, 100), None);
+        assert_eq!(interpolate(0, 1_000, 2_000, -1, 100), None);
+        assert_eq!(interpolate(0, 1_000, 2_000, 0, 0), None);
+    }
+
+    #[test]
+    fn test_time_weighted_average() {
+        assert_eq!(time_weighted_average(100_000, 250_000, 100), Some(1_500));
+        assert_eq!(time_weighted_average(0, 100_000, 100), Some(1_000));
+
+        assert_eq!(time_weighted_average(0, 100_000, 0), None);
+        assert_eq!(time_weighted_average(100_000, 0, 100), None);
+    }
+}
@@ -15,7 +15,7 @@ pub const SCALE_OFFSET: u8 = 64;
 // If we convert 443636 to binary form, it will be 1101100010011110100 (19 bits).
 // Which, the 19 bits are the bits the binary exponential will loop through.
 // The 20th bit will be 0x80000,  which the exponential already > the maximum number of bin Q64.64 can support
-const MAX_EXPONENTIAL: u32 = 0x80000; // 1048576
+pub const MAX_EXPONENTIAL: u32 = 0x80000; // 1048576
 
 // 1.0000... representation of 64x64
 pub const ONE: u128 = 1u128 << SCALE_OFFSET;
@@ -27,7 +27,11 @@ pub fn pow(base: u128, exp: i32) -> Option<u128> {
         return Some(1u128 << 64);
     }
 
-    let exp: u32 = if invert { exp.abs() as u32 } else { exp as u32 };
+    let exp: u32 = if invert {
+        exp.unsigned_abs()
+    } else {
+        exp as u32
+    };
 
     if exp >= MAX_EXPONENTIAL {
         return None;
@@ -146,28 +150,123 @@ pub fn pow(base: u128, exp: i32) -> Option<u128> {
     Some(result)
 }
 
-// pub fn calculate_eight_hour_apr(basis_points: u128) -> Option<u128> {
-//     // Calculate the annual rate in fixed-point representation
-//     let annual_rate = ONE + (basis_points * ONE) / (PRECISION * 10000);
-//     msg!("annual_rate: {}", annual_rate);
+// ln(2) representation of 64x64
+const LN_2: u128 = 0xB17217F7D1CF79AB;
 
-//     // Number of 8-hour intervals in a year
-//     let intervals_per_year = 3 * 365;
+// Binary logarithm of a 64x64 value >= 1.0
+pub fn log2(value: u128) -> Option<u128> {
+    if value < ONE {
+        return None;
+    }
+
+    // Integer part is the position of the most significant bit above the radix point
+    let integer_part = 127 - value.leading_zeros() - SCALE_OFFSET as u32;
+    let mut result = (integer_part as u128) << SCALE_OFFSET;
+
+    // Fractional part bit by bit, squaring the normalized value in [1, 2)
+    let two = U256::from(ONE << 1);
+    let mut normalized = U256::from(value >> integer_part);
+    let mut bit = ONE >> 1;
+
+    while bit > 0 {
+        normalized = (normalized * normalized) >> SCALE_OFFSET as usize;
+        if normalized >= two {
+            normalized >>= 1;
+            result |= bit;
+        }
+        bit >>= 1;
+    }
+
+    Some(result)
+}
+
+// Natural logarithm of a 64x64 value >= 1.0
+pub fn ln(value: u128) -> Option<u128> {
+    let log2_value = U256::from(log2(value)?);
+    let (ln_value, _) = log2_value
+        .checked_mul(U256::from(LN_2))?
+        .overflowing_shr(SCALE_OFFSET.into());
+    ln_value.try_into().ok()
+}
+
+// e^value of a 64x64 value
+pub fn exp(value: u128) -> Option<u128> {
+    // e^value = 2^k * e^r with r < ln(2)
+    let k = value / LN_2;
+    let r = value % LN_2;
+
+    // e^r < 2, so anything shifted past 2^62 no longer fits a 64x64 value
+    if k > 62 {
+        return None;
+    }
+
+    // Taylor series of e^r, stops once the terms vanish below the 64x64 resolution
+    let mut result = ONE;
+    let mut term = ONE;
+    let mut n = 1u128;
 
-//     // Calculate the natural logarithm of the annual rate
-//     let ln_annual_rate = (annual_rate - ONE) * ONE / annual_rate;
-//     msg!("ln_annual_rate: {}", ln_annual_rate);
+    while term > 0 {
+        term = ((term * r) >> SCALE_OFFSET) / n;
+        result += term;
+        n += 1;
+    }
+
+    result.checked_shl(k as u32)
+}
+
+// base^(numerator / denominator) of a 64x64 base >= 1.0
+pub fn pow_fraction(base: u128, numerator: u128, denominator: u128) -> Option<u128> {
+    let exponent = ln(base)?.checked_mul(numerator)?.checked_div(denominator)?;
+    exp(exponent)
+}
 
-//     // Scale the logarithm by the fraction of the year represented by 8 hours
-//     let scaled_ln = ln_annual_rate / intervals_per_year;
-//     msg!("scaled_ln: {}", scaled_ln);
+// Per interval compounding factor, with 10^12 precision, that compounds to
+// `annual_yield_bps` over `seconds_per_year`
+pub fn calculate_interval_apr_rate(
+    annual_yield_bps: u64,
+    seconds_per_interval: u128,
+    seconds_per_year: u128,
+) -> Option<u128> {
+    let annual_rate = ONE.checked_add((annual_yield_bps as u128).checked_mul(ONE)? / 10000)?;
+    let interval_rate = pow_fraction(annual_rate, seconds_per_interval, seconds_per_year)?;
+    to_decimal(interval_rate)
+}
 
-//     // Exponentiate the result to get the 8-hour rate
-//     let eight_hour_rate = ONE + scaled_ln;
-//     msg!("eight_hour_rate: {}", eight_hour_rate);
+// Annual yield in basis points of a per interval compounding factor with 10^12 precision
+pub fn calculate_annual_yield_bps(
+    interval_apr_rate: u128,
+    seconds_per_interval: u128,
+    seconds_per_year: u128,
+) -> Option<u64> {
+    let annual_rate = pow_fraction(
+        from_decimal(interval_apr_rate)?,
+        seconds_per_year,
+        seconds_per_interval,
+    )?;
+
+    // Round to the nearest basis point
+    let annual_yield_bps =
+        (annual_rate.checked_sub(ONE)?.checked_mul(10000)? + (ONE >> 1)) >> SCALE_OFFSET;
+    annual_yield_bps.try_into().ok()
+}
 
-//     Some(eight_hour_rate)
-// }
+// Continuously compounded annual rate in basis points of a per interval
+// compounding factor with 10^12 precision, n * ln(interval rate) over the n
+// intervals in a year
+pub fn calculate_continuous_rate_bps(
+    interval_apr_rate: u128,
+    seconds_per_interval: u128,
+    seconds_per_year: u128,
+) -> Option<u64> {
+    let annual_ln = U256::from(ln(from_decimal(interval_apr_rate)?)?)
+        .checked_mul(U256::from(seconds_per_year))?
+        .checked_div(U256::from(seconds_per_interval))?;
+
+    // Round to the nearest basis point
+    let (rate_bps, _) = (annual_ln.checked_mul(U256::from(10000))? + U256::from(ONE >> 1))
+        .overflowing_shr(SCALE_OFFSET.into());
+    rate_bps.try_into().ok()
+}
 
 pub fn to_decimal(value: u128) -> Option<u128> {
     let value = U256::from(value);
@@ -192,16 +291,95 @@ mod tests {
     use super::*;
 
     #[test]
-    // fn test_calculate_eight_hour_apr() {
-    //     // Test with 2000 basis points (20% APY)
-    //     let basis_points = 2000;
-    //     let expected_eight_hour_apr = 1_000_182_648_401; // Adjust this value based on expected result
+    fn test_ln_and_exp() {
+        // ln(1) = 0 and e^0 = 1
+        assert_eq!(ln(ONE).unwrap(), 0);
+        assert_eq!(exp(0).unwrap(), ONE);
 
-    //     let result = calculate_eight_hour_apr(basis_points).unwrap();
-    //     let result_decimal = to_decimal(result).unwrap();
+        // ln(2) and back
+        assert_eq!(ln(2 * ONE).unwrap(), LN_2);
+        assert_eq!(exp(LN_2).unwrap(), 2 * ONE);
+
+        // e^ln(1.2) round trip
+        let value = from_decimal(1_200_000_000_000).unwrap();
+        assert_eq!(
+            to_decimal(exp(ln(value).unwrap()).unwrap()).unwrap(),
+            1_199_999_999_999
+        );
+
+        // Values below 1.0 and results above the 64x64 range are rejected
+        assert_eq!(ln(ONE - 1), None);
+        assert_eq!(exp(64 * LN_2), None);
+    }
+
+    #[test]
+    fn test_calculate_interval_apr_rate() {
+        // 20% APY compounded every 8 hours
+        assert_eq!(
+            calculate_interval_apr_rate(2000, 8 * 60 * 60, 365 * 24 * 60 * 60).unwrap(),
+            1_000_166_517_567
+        );
+
+        // 10% APY compounded every 8 hours
+        assert_eq!(
+            calculate_interval_apr_rate(1000, 8 * 60 * 60, 365 * 24 * 60 * 60).unwrap(),
+            1_000_087_045_048
+        );
+
+        // No yield
+        assert_eq!(
+            calculate_interval_apr_rate(0, 8 * 60 * 60, 365 * 24 * 60 * 60).unwrap(),
+            PRECISION
+        );
+    }
+
+    #[test]
+    fn test_calculate_annual_yield_bps() {
+        assert_eq!(
+            calculate_annual_yield_bps(1_000_166_517_567, 8 * 60 * 60, 365 * 24 * 60 * 60).unwrap(),
+            2000
+        );
+        assert_eq!(
+            calculate_annual_yield_bps(PRECISION, 8 * 60 * 60, 365 * 24 * 60 * 60).unwrap(),
+            0
+        );
+
+        // Round trip over a range of yields
+        for annual_yield_bps in [1, 50, 500, 1234, 5000, 10000, 50000] {
+            let interval_apr_rate =
+                calculate_interval_apr_rate(annual_yield_bps, 8 * 60 * 60, 365 * 24 * 60 * 60)
+                    .unwrap();
+            assert_eq!(
+                calculate_annual_yield_bps(interval_apr_rate, 8 * 60 * 60, 365 * 24 * 60 * 60)
+                    .unwrap(),
+                annual_yield_bps
+            );
+        }
+    }
+
+    #[test]
+    fn test_calculate_continuous_rate_bps() {
+        // 20% APY is ln(1.2) = 18.23% compounded continuously
+        assert_eq!(
+            calculate_continuous_rate_bps(1_000_166_517_567, 8 * 60 * 60, 365 * 24 * 60 * 60)
+                .unwrap(),
+            1823
+        );
+
+        // 10% APY is ln(1.1) = 9.53%
+        assert_eq!(
+            calculate_continuous_rate_bps(1_000_087_045_048, 8 * 60 * 60, 365 * 24 * 60 * 60)
+                .unwrap(),
+            953
+        );
+
+        // No yield
+        assert_eq!(
+            calculate_continuous_rate_bps(PRECISION, 8 * 60 * 60, 365 * 24 * 60 * 60).unwrap(),
+            0
+        );
+    }
 
-    //     assert_eq!(result_decimal, expected_eight_hour_apr);
-    // }
     #[test]
     fn test_pow_positive_exponent() {
         // Test base^exp with positive exponent
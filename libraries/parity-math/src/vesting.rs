@@ -0,0 +1,86 @@
+//! Linear vesting of deposited rewards.
+
+/// Part of `vesting_amount` still locked at `current_timestamp`, vesting
+/// linearly over `vesting_period` seconds from `vesting_start`. Rounds up, so
+/// that rewards are never counted before they vest.
+pub fn unvested_amount(
+    vesting_amount: u64,
+    vesting_start: i64,
+    vesting_period: i64,
+    current_timestamp: i64,
+) -> Option<u64> {
+    let elapsed = current_timestamp.checked_sub(vesting_start)?.max(0);
+    if vesting_period <= 0 || elapsed >= vesting_period {
+        return Some(0);
+    }
+
+    let remaining = (vesting_period - elapsed) as u128;
+    let period = vesting_period as u128;
+    let unvested_amount = (vesting_amount as u128)
+        .checked_mul(remaining)?
+        .checked_add(period - 1)?
+        / period;
+
+    u64::try_from(unvested_amount).ok()
+}
+
+/// Exchange rate of a streaming pool, whose `backing_amount` of base tokens
+/// less the `unvested_amount` of rewards back `normalized_x_supply` x tokens.
+/// The initial exchange rate applies while there is no x supply.
+pub fn streaming_exchange_rate(
+    backing_amount: u64,
+    unvested_amount: u64,
+    normalized_x_supply: u64,
+    base_mint_decimals: u8,
+    initial_exchange_rate: u64,
+) -> Option<u64> {
+    if normalized_x_supply == 0 {
+        return Some(initial_exchange_rate);
+    }
+
+    crate::conversion::assets_to_exchange_rate(
+        backing_amount.saturating_sub(unvested_amount),
+        normalized_x_supply,
+        base_mint_decimals,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unvested_amount() {
+        assert_eq!(unvested_amount(1_000, 100, 1_000, 100), Some(1_000));
+        assert_eq!(unvested_amount(1_000, 100, 1_000, 600), Some(500));
+        assert_eq!(unvested_amount(1_000, 100, 1_000, 1_100), Some(0));
+        assert_eq!(unvested_amount(1_000, 100, 1_000, 5_000), Some(0));
+
+        // Rounds up
+        assert_eq!(unvested_amount(10, 0, 3, 1), Some(7));
+
+        // Before the start nothing has vested
+        assert_eq!(unvested_amount(1_000, 100, 1_000, 50), Some(1_000));
+
+        // Without a vesting period rewards vest immediately
+        assert_eq!(unvested_amount(1_000, 100, 0, 100), Some(0));
+    }
+
+    #[test]
+    fn test_streaming_exchange_rate() {
+        assert_eq!(
+            streaming_exchange_rate(1_200_000, 0, 1_000_000, 6, 1_000_000),
+            Some(1_200_000)
+        );
+        // Unvested rewards do not back the x supply yet
+        assert_eq!(
+            streaming_exchange_rate(1_200_000, 200_000, 1_000_000, 6, 1_000_000),
+            Some(1_000_000)
+        );
+        // Without an x supply the initial exchange rate applies
+        assert_eq!(
+            streaming_exchange_rate(1_200_000, 0, 0, 6, 1_000_000),
+            Some(1_000_000)
+        );
+    }
+}
@@ -42,4 +42,10 @@ pub enum ParityIssuanceError {
     PendingWithdrawalExists,
     #[msg("An Invalid Parameter was passed")]
     InvalidParam,
+    #[msg("Exchange rate change exceeds the maximum per update")]
+    ExchangeRateChangeTooLarge,
+    #[msg("Exchange rate updated too soon")]
+    ExchangeRateUpdateTooSoon,
+    #[msg("Token manager already migrated")]
+    TokenManagerAlreadyMigrated,
 }
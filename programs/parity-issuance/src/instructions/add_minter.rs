@@ -0,0 +1,26 @@
+use crate::{Minter, ParityIssuanceError, TokenManager};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(new_minter: Pubkey)]
+pub struct AddMinter<'info> {
+    #[account(mut, seeds = [b"token-manager"], bump)]
+    pub token_manager: Account<'info, TokenManager>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + 32,
+        seeds = [b"minter", new_minter.key().as_ref()],
+        bump
+    )]
+    pub minter: Account<'info, Minter>,
+    #[account(mut, address = token_manager.admin @ ParityIssuanceError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddMinter>, new_minter: Pubkey) -> Result<()> {
+    let minter = &mut ctx.accounts.minter;
+    minter.wallet = new_minter;
+    Ok(())
+}
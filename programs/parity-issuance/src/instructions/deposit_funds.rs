@@ -56,7 +56,8 @@ pub fn handler(ctx: Context<DepositFunds>, quantity: u64) -> Result<()> {
     }
 
     // Check if deposit exceeds 100% collateral
-    token_manager.check_excessive_deposit(quote_amount, mint.supply)?;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    token_manager.check_excessive_deposit(quote_amount, mint.supply, current_timestamp)?;
 
     // Deposit
     transfer_checked(
@@ -25,6 +25,9 @@ pub struct InitializeTokenManagerParams {
     pub withdraw_execution_window: i64,
     pub mint_fee_bps: u16,
     pub redeem_fee_bps: u16,
+    pub max_exchange_rate_change_bps: u16,
+    pub exchange_rate_update_interval: i64,
+    pub exchange_rate_ramp_duration: i64,
 }
 
 impl InitializeTokenManagerParams {
@@ -78,6 +81,14 @@ impl InitializeTokenManagerParams {
             return err!(ParityIssuanceError::InvalidParam);
         }
 
+        // Validate exchange rate guardrails
+        if self.max_exchange_rate_change_bps == 0 || self.max_exchange_rate_change_bps > 10000 {
+            return err!(ParityIssuanceError::InvalidParam);
+        }
+        if self.exchange_rate_update_interval < 0 || self.exchange_rate_ramp_duration < 0 {
+            return err!(ParityIssuanceError::InvalidParam);
+        }
+
         Ok(())
     }
 }
@@ -182,6 +193,11 @@ pub fn handler(
     token_manager.quote_mint = ctx.accounts.quote_mint.key();
     token_manager.quote_mint_decimals = ctx.accounts.quote_mint.decimals;
     token_manager.exchange_rate = params.exchange_rate;
+    // Exchange rate guardrails
+    token_manager.max_exchange_rate_change_bps = params.max_exchange_rate_change_bps;
+    token_manager.exchange_rate_update_interval = params.exchange_rate_update_interval;
+    token_manager.exchange_rate_ramp_duration = params.exchange_rate_ramp_duration;
+    token_manager.previous_exchange_rate = params.exchange_rate;
     // Other
     token_manager.total_collateral = 0;
     token_manager.emergency_fund_basis_points = params.emergency_fund_basis_points;
@@ -191,6 +207,8 @@ pub fn handler(
     let clock = Clock::get()?;
     let current_slot = clock.slot;
     token_manager.current_slot = current_slot;
+    token_manager.last_exchange_rate_update = clock.unix_timestamp;
+    token_manager.exchange_rate_ramp_end = clock.unix_timestamp;
     token_manager.current_slot_volume = 0;
     token_manager.limit_per_slot = params.limit_per_slot;
 
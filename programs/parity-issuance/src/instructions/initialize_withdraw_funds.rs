@@ -50,8 +50,12 @@ pub fn handler(ctx: Context<InitializeWithdrawFunds>, quantity: u64) -> Result<(
         return err!(ParityIssuanceError::InvalidQuantity);
     }
 
-    let max_withdrawable_amount =
-        token_manager.calculate_max_withdrawable_amount(mint.supply, vault_balance)?;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let max_withdrawable_amount = token_manager.calculate_max_withdrawable_amount(
+        mint.supply,
+        vault_balance,
+        current_timestamp,
+    )?;
     msg!("Max withdrawable amount: {}", max_withdrawable_amount);
     msg!("Quote amount: {}", quote_amount);
     msg!("Mint supply: {}", mint.supply);
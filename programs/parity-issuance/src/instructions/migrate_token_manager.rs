@@ -0,0 +1,62 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{ParityIssuanceError, TokenManager, LEGACY_TOKEN_MANAGER_SIZE, TOKEN_MANAGER_SIZE};
+
+#[derive(Accounts)]
+pub struct MigrateTokenManager<'info> {
+    /// CHECK: Deserialized in the handler, legacy accounts are too short for `TokenManager`
+    #[account(
+        mut,
+        seeds = [b"token-manager"],
+        bump,
+        owner = crate::ID,
+    )]
+    pub token_manager: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateTokenManager>) -> Result<()> {
+    let token_manager_info = ctx.accounts.token_manager.to_account_info();
+    if token_manager_info.data_len() != LEGACY_TOKEN_MANAGER_SIZE {
+        return err!(ParityIssuanceError::TokenManagerAlreadyMigrated);
+    }
+
+    // Top up the rent for the appended fields
+    let minimum_balance = Rent::get()?.minimum_balance(TOKEN_MANAGER_SIZE);
+    let lamports_diff = minimum_balance.saturating_sub(token_manager_info.lamports());
+    if lamports_diff > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: token_manager_info.clone(),
+                },
+            ),
+            lamports_diff,
+        )?;
+    }
+    token_manager_info.realloc(TOKEN_MANAGER_SIZE, true)?;
+
+    // The new fields are appended, so the legacy data deserializes with them zeroed
+    let mut token_manager = {
+        let data = token_manager_info.try_borrow_data()?;
+        TokenManager::try_deserialize(&mut &data[..])?
+    };
+    if token_manager.owner != ctx.accounts.owner.key() {
+        return err!(ParityIssuanceError::InvalidOwner);
+    }
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    token_manager.migrate_exchange_rate_guardrails(current_timestamp);
+
+    let mut data = token_manager_info.try_borrow_mut_data()?;
+    token_manager.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
@@ -61,19 +61,19 @@ pub fn handler(ctx: Context<MintTokens>, quantity: u64, proof: Vec<[u8; 32]>) ->
         return err!(ParityIssuanceError::MintAndRedemptionsPaused);
     }
 
-     // Check if the quantity to mint is greater than zero
-     if quantity == 0 {
+    // Check if the quantity to mint is greater than zero
+    if quantity == 0 {
         return err!(ParityIssuanceError::InvalidQuantity);
     }
 
-
     // Allow List check
     let leaf: solana_program::keccak::Hash =
         solana_program::keccak::hashv(&[payer.key().to_string().as_bytes()]);
     token_manager.verify_merkle_proof(proof, &leaf.0)?;
 
     // Block Limit check
-    let current_slot: u64 = Clock::get()?.slot;
+    let clock = Clock::get()?;
+    let current_slot: u64 = clock.slot;
     token_manager.check_block_limit(quantity, current_slot)?;
 
     // Minting
@@ -81,11 +81,7 @@ pub fn handler(ctx: Context<MintTokens>, quantity: u64, proof: Vec<[u8; 32]>) ->
     let signer_seeds: &[&[&[u8]]] = &[&[b"token-manager", &[bump]]];
 
     // Calculate mint fee
-    let mint_fee = quantity
-        .checked_mul(token_manager.mint_fee_bps as u64)
-        .ok_or(ParityIssuanceError::CalculationOverflow)?
-        .checked_div(10000)
-        .ok_or(ParityIssuanceError::CalculationOverflow)?;
+    let mint_fee = token_manager.calculate_mint_fee(quantity)?;
 
     // deduct the mint fee from the mint amount
     let mint_amount = quantity
@@ -109,7 +105,8 @@ pub fn handler(ctx: Context<MintTokens>, quantity: u64, proof: Vec<[u8; 32]>) ->
 
     let normalized_quantity = token_manager.calculate_normalized_quantity(quantity)?;
     msg!("Normalized quantity: {}", normalized_quantity);
-    let quote_amount = token_manager.calculate_quote_amount(normalized_quantity)?;
+    let quote_amount =
+        token_manager.calculate_quote_amount(normalized_quantity, clock.unix_timestamp)?;
     msg!("Quote amount: {}", quote_amount);
 
     transfer_checked(
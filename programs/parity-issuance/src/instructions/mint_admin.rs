@@ -4,7 +4,7 @@ use anchor_spl::{
     token::{mint_to, Mint, MintTo, Token, TokenAccount},
 };
 
-use crate::{ParityIssuanceError, TokenManager};
+use crate::{Minter, ParityIssuanceError, TokenManager};
 
 #[derive(Accounts)]
 pub struct MintAdminTokens<'info> {
@@ -27,14 +27,26 @@ pub struct MintAdminTokens<'info> {
     )]
     pub minter_mint_ata: Account<'info, TokenAccount>,
     // Other
-    #[account(address = token_manager.minter @ ParityIssuanceError::InvalidMinter)]
     pub minter: Signer<'info>,
+    #[account(
+        seeds = [b"minter", minter.key().as_ref()],
+        bump,
+    )]
+    pub minter_authorization: Option<Account<'info, Minter>>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 pub fn handler(ctx: Context<MintAdminTokens>, quantity: u64) -> Result<()> {
+    // Minter can be either the token manager minter or an added minter
+    let is_token_manager_minter = ctx.accounts.token_manager.minter == ctx.accounts.minter.key();
+    let is_added_minter = ctx.accounts.minter_authorization.is_some();
+    require!(
+        is_token_manager_minter || is_added_minter,
+        ParityIssuanceError::InvalidMinter
+    );
+
     let token_manager = &mut ctx.accounts.token_manager;
 
     // Minting
@@ -1,12 +1,17 @@
 pub mod add_gatekeeper;
+pub mod add_minter;
 pub mod deposit_funds;
 pub mod initialize_token_manager;
 pub mod initialize_withdraw_funds;
 pub mod initiate_update_manager_owner;
+pub mod migrate_token_manager;
 pub mod mint;
 pub mod mint_admin;
+pub mod preview_mint;
+pub mod preview_redeem;
 pub mod redeem;
 pub mod remove_gatekeeper;
+pub mod remove_minter;
 pub mod toggle_active;
 pub mod update_manager_owner;
 pub mod update_mint_metadata;
@@ -16,14 +21,19 @@ pub mod withdraw_funds;
 pub mod withdraw_excess_issuance;
 
 pub use add_gatekeeper::*;
+pub use add_minter::*;
 pub use deposit_funds::*;
 pub use initialize_token_manager::*;
 pub use initialize_withdraw_funds::*;
 pub use initiate_update_manager_owner::*;
+pub use migrate_token_manager::*;
 pub use mint::*;
 pub use mint_admin::*;
+pub use preview_mint::*;
+pub use preview_redeem::*;
 pub use redeem::*;
 pub use remove_gatekeeper::*;
+pub use remove_minter::*;
 pub use toggle_active::*;
 pub use update_manager_owner::*;
 pub use update_mint_metadata::*;
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::{MintPreview, TokenManager};
+
+#[derive(Accounts)]
+pub struct PreviewMint<'info> {
+    #[account(seeds = [b"token-manager"], bump)]
+    pub token_manager: Account<'info, TokenManager>,
+}
+
+pub fn handler(ctx: Context<PreviewMint>, quantity: u64) -> Result<MintPreview> {
+    let clock = Clock::get()?;
+
+    ctx.accounts
+        .token_manager
+        .preview_mint(quantity, clock.slot, clock.unix_timestamp)
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::{RedeemPreview, TokenManager};
+
+#[derive(Accounts)]
+pub struct PreviewRedeem<'info> {
+    #[account(seeds = [b"token-manager"], bump)]
+    pub token_manager: Account<'info, TokenManager>,
+}
+
+pub fn handler(ctx: Context<PreviewRedeem>, quantity: u64) -> Result<RedeemPreview> {
+    let clock = Clock::get()?;
+
+    ctx.accounts
+        .token_manager
+        .preview_redeem(quantity, clock.slot, clock.unix_timestamp)
+}
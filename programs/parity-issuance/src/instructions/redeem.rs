@@ -57,8 +57,8 @@ pub fn handler(ctx: Context<RedeemTokens>, quantity: u64, proof: Vec<[u8; 32]>)
         return err!(ParityIssuanceError::MintAndRedemptionsPaused);
     }
 
-     // Check if the quantity to redeem is greater than zero
-     if quantity == 0 {
+    // Check if the quantity to redeem is greater than zero
+    if quantity == 0 {
         return err!(ParityIssuanceError::InvalidQuantity);
     }
 
@@ -68,7 +68,8 @@ pub fn handler(ctx: Context<RedeemTokens>, quantity: u64, proof: Vec<[u8; 32]>)
     token_manager.verify_merkle_proof(proof, &leaf.0)?;
 
     // Block Limit check
-    let current_slot: u64 = Clock::get()?.slot;
+    let clock = Clock::get()?;
+    let current_slot: u64 = clock.slot;
     token_manager.check_block_limit(quantity, current_slot)?;
 
     // Burning
@@ -94,15 +95,12 @@ pub fn handler(ctx: Context<RedeemTokens>, quantity: u64, proof: Vec<[u8; 32]>)
 
     let normalized_quantity = token_manager.calculate_normalized_quantity(quantity)?;
     msg!("Normalized quantity: {}", normalized_quantity);
-    let mut quote_amount = token_manager.calculate_quote_amount(normalized_quantity)?;
+    let mut quote_amount =
+        token_manager.calculate_quote_amount(normalized_quantity, clock.unix_timestamp)?;
     msg!("Quote amount: {}", quote_amount);
 
     // Calculate redeem fee
-    let redeem_fee = quote_amount
-        .checked_mul(token_manager.redeem_fee_bps as u64)
-        .ok_or(ParityIssuanceError::CalculationOverflow)?
-        .checked_div(10000)
-        .ok_or(ParityIssuanceError::CalculationOverflow)?;
+    let redeem_fee = token_manager.calculate_redeem_fee(quote_amount)?;
 
     // deduct the reddem fee from the burn amount
     quote_amount = quote_amount
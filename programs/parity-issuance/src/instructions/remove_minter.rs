@@ -0,0 +1,21 @@
+use crate::{Minter, ParityIssuanceError, TokenManager};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveMinter<'info> {
+    #[account(
+        mut,
+        seeds = [b"token-manager"],
+        bump = token_manager.bump
+    )]
+    pub token_manager: Account<'info, TokenManager>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"minter", minter.wallet.as_ref()],
+        bump
+    )]
+    pub minter: Account<'info, Minter>,
+    #[account(address = token_manager.admin @ ParityIssuanceError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
@@ -12,6 +12,9 @@ pub struct UpdateTokenManagerOwnerParams {
     pub new_mint_fee_bps: Option<u16>,
     pub new_redeem_fee_bps: Option<u16>,
     pub new_exchange_rate: Option<u64>,
+    pub new_max_exchange_rate_change_bps: Option<u16>,
+    pub new_exchange_rate_update_interval: Option<i64>,
+    pub new_exchange_rate_ramp_duration: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -81,11 +84,33 @@ pub fn handler(
         token_manager.redeem_fee_bps = redeem_fee_bps;
     }
 
+    if let Some(max_exchange_rate_change_bps) = params.new_max_exchange_rate_change_bps {
+        if max_exchange_rate_change_bps == 0 || max_exchange_rate_change_bps > 10000 {
+            return err!(ParityIssuanceError::InvalidParam);
+        }
+        token_manager.max_exchange_rate_change_bps = max_exchange_rate_change_bps;
+    }
+
+    if let Some(exchange_rate_update_interval) = params.new_exchange_rate_update_interval {
+        if exchange_rate_update_interval < 0 {
+            return err!(ParityIssuanceError::InvalidParam);
+        }
+        token_manager.exchange_rate_update_interval = exchange_rate_update_interval;
+    }
+
+    if let Some(exchange_rate_ramp_duration) = params.new_exchange_rate_ramp_duration {
+        if exchange_rate_ramp_duration < 0 {
+            return err!(ParityIssuanceError::InvalidParam);
+        }
+        token_manager.exchange_rate_ramp_duration = exchange_rate_ramp_duration;
+    }
+
     if let Some(new_exchange_rate) = params.new_exchange_rate {
         if new_exchange_rate == 0 {
             return err!(ParityIssuanceError::InvalidParam); // Ensure exchange rate is greater than zero
         }
-        token_manager.exchange_rate = new_exchange_rate;
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        token_manager.update_exchange_rate(new_exchange_rate, current_timestamp)?;
     }
 
     Ok(())
@@ -81,7 +81,7 @@ pub fn handler(ctx: Context<WithdrawFunds>) -> Result<()> {
     // Calculate max withdrawable amount
     let vault_amount = ctx.accounts.vault.amount; // Get the actual vault amount
     let max_withdrawable_amount =
-        token_manager.calculate_max_withdrawable_amount(mint.supply, vault_amount)?;
+        token_manager.calculate_max_withdrawable_amount(mint.supply, vault_amount, timestamp)?;
 
     if pending_withdrawal_amount > max_withdrawable_amount {
         msg!("Pending withdrawal amount: {}", pending_withdrawal_amount);
@@ -29,6 +29,14 @@ pub mod parity_issuance {
         redeem::handler(ctx, quantity, proof)
     }
 
+    pub fn preview_mint(ctx: Context<PreviewMint>, quantity: u64) -> Result<MintPreview> {
+        preview_mint::handler(ctx, quantity)
+    }
+
+    pub fn preview_redeem(ctx: Context<PreviewRedeem>, quantity: u64) -> Result<RedeemPreview> {
+        preview_redeem::handler(ctx, quantity)
+    }
+
     pub fn toggle_active(ctx: Context<ToggleActive>, active: bool) -> Result<()> {
         toggle_active::handler(ctx, active)
     }
@@ -94,7 +102,19 @@ pub mod parity_issuance {
         Ok(())
     }
 
+    pub fn add_minter(ctx: Context<AddMinter>, new_minter: Pubkey) -> Result<()> {
+        add_minter::handler(ctx, new_minter)
+    }
+
+    pub fn remove_minter(_ctx: Context<RemoveMinter>) -> Result<()> {
+        Ok(())
+    }
+
     pub fn withdraw_excess_issuance(ctx: Context<WithdrawExcessIssuance>) -> Result<()> {
         withdraw_excess_issuance::handler(ctx)
     }
+
+    pub fn migrate_token_manager(ctx: Context<MigrateTokenManager>) -> Result<()> {
+        migrate_token_manager::handler(ctx)
+    }
 }
@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
 
+use parity_math::{conversion, decimals, fees};
+
 use crate::ParityIssuanceError;
 
-pub const TOKEN_MANAGER_SIZE: usize = 8 + (32 * 7) + (8 * 9) + (2 * 3) + (1 * 5);
+pub const TOKEN_MANAGER_SIZE: usize = 8 + (32 * 7) + (8 * 14) + (2 * 4) + (1 * 5);
+// Token managers created before the exchange rate guardrails were added
+pub const LEGACY_TOKEN_MANAGER_SIZE: usize = 8 + (32 * 7) + (8 * 9) + (2 * 3) + (1 * 5);
 
 #[account]
 pub struct TokenManager {
@@ -39,6 +43,14 @@ pub struct TokenManager {
     pub total_collateral: u64, // 8
     pub mint_fee_bps: u16,     // 2
     pub redeem_fee_bps: u16,   // 2
+
+    // Exchange rate guardrails, appended by `migrate_token_manager` on older accounts
+    pub max_exchange_rate_change_bps: u16,  // 2
+    pub exchange_rate_update_interval: i64, // 8
+    pub exchange_rate_ramp_duration: i64,   // 8
+    pub previous_exchange_rate: u64,        // 8
+    pub last_exchange_rate_update: i64,     // 8
+    pub exchange_rate_ramp_end: i64,        // 8
 }
 
 #[account]
@@ -46,41 +58,173 @@ pub struct Gatekeeper {
     pub wallet: Pubkey,
 }
 
+#[account]
+pub struct Minter {
+    pub wallet: Pubkey,
+}
+
+/// Quote returned by `preview_mint`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MintPreview {
+    pub mint_amount: u64,           // mint tokens received, net of fees
+    pub fee_amount: u64,            // mint tokens withheld as fees
+    pub quote_amount: u64,          // quote tokens paid
+    pub exchange_rate: u64,         // quote per mint, in quote mint decimals
+    pub remaining_slot_volume: u64, // room left under the limit per slot
+    pub active: bool,
+}
+
+/// Quote returned by `preview_redeem`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RedeemPreview {
+    pub quote_amount: u64,          // quote tokens received, net of fees
+    pub fee_amount: u64,            // quote tokens withheld as fees
+    pub exchange_rate: u64,         // quote per mint, in quote mint decimals
+    pub remaining_slot_volume: u64, // room left under the limit per slot
+    pub active: bool,
+}
+
 impl TokenManager {
     pub fn calculate_normalized_quantity(&self, quantity: u64) -> Result<u64> {
-        // Calculate the absolute difference in decimals between mint and quote mint
-        let decimal_difference =
-            (self.mint_decimals as i32 - self.quote_mint_decimals as i32).abs() as u32;
-
-        // If mint decimals are greater, divide the quantity by 10^decimal_difference
-        if self.mint_decimals > self.quote_mint_decimals {
-            (quantity as u128)
-                .checked_div(10u128.pow(decimal_difference))
-                .ok_or(ParityIssuanceError::CalculationOverflow.into())
-                .map(|result| result as u64)
-        // If mint decimals are lesser, multiply the quantity by 10^decimal_difference
-        } else if self.mint_decimals < self.quote_mint_decimals {
-            (quantity as u128)
-                .checked_mul(10u128.pow(decimal_difference))
-                .ok_or(ParityIssuanceError::CalculationOverflow.into())
-                .map(|result| result as u64)
-        // If decimals are equal, return the quantity as is
-        } else {
-            Ok(quantity)
+        // Rescale the quantity from mint decimals to quote mint decimals
+        decimals::normalize_quantity(quantity, self.mint_decimals, self.quote_mint_decimals)
+            .ok_or(ParityIssuanceError::CalculationOverflow.into())
+    }
+
+    pub fn calculate_exchange_rate(&self, current_timestamp: i64) -> Result<u64> {
+        // Ramps from the previous rate to the target rate since the last update
+        conversion::ramp_exchange_rate(
+            self.previous_exchange_rate,
+            self.exchange_rate,
+            self.last_exchange_rate_update,
+            self.exchange_rate_ramp_end,
+            current_timestamp,
+        )
+        .ok_or(ParityIssuanceError::CalculationOverflow.into())
+    }
+
+    pub fn migrate_exchange_rate_guardrails(&mut self, current_timestamp: i64) {
+        // Older token managers could set any rate instantly, so start from the
+        // loosest guardrails and let the owner tighten them afterwards
+        self.max_exchange_rate_change_bps = 10000;
+        self.exchange_rate_update_interval = 0;
+        self.exchange_rate_ramp_duration = 0;
+        self.previous_exchange_rate = self.exchange_rate;
+        self.last_exchange_rate_update = current_timestamp;
+        self.exchange_rate_ramp_end = current_timestamp;
+    }
+
+    pub fn update_exchange_rate(
+        &mut self,
+        new_exchange_rate: u64,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        // Check the minimum interval between updates
+        let next_update_timestamp = self
+            .last_exchange_rate_update
+            .checked_add(self.exchange_rate_update_interval)
+            .ok_or(ParityIssuanceError::CalculationOverflow)?;
+        if current_timestamp < next_update_timestamp {
+            return err!(ParityIssuanceError::ExchangeRateUpdateTooSoon);
         }
+
+        // Check the maximum change per update against the rate currently in effect
+        let current_exchange_rate = self.calculate_exchange_rate(current_timestamp)?;
+        let max_change = (current_exchange_rate as u128)
+            .checked_mul(self.max_exchange_rate_change_bps as u128)
+            .ok_or(ParityIssuanceError::CalculationOverflow)?
+            .checked_div(10000)
+            .ok_or(ParityIssuanceError::CalculationOverflow)?;
+        let change = (new_exchange_rate as i128 - current_exchange_rate as i128).unsigned_abs();
+        if change > max_change {
+            return err!(ParityIssuanceError::ExchangeRateChangeTooLarge);
+        }
+
+        // Start ramping from the rate currently in effect
+        self.previous_exchange_rate = current_exchange_rate;
+        self.exchange_rate = new_exchange_rate;
+        self.last_exchange_rate_update = current_timestamp;
+        self.exchange_rate_ramp_end = current_timestamp
+            .checked_add(self.exchange_rate_ramp_duration)
+            .ok_or(ParityIssuanceError::CalculationOverflow)?;
+
+        Ok(())
     }
 
-    pub fn calculate_quote_amount(&self, normalized_quantity: u64) -> Result<u64> {
+    pub fn calculate_quote_amount(
+        &self,
+        normalized_quantity: u64,
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        let exchange_rate = self.calculate_exchange_rate(current_timestamp)?;
+
         // Multiply the normalized quantity by the exchange rate
-        (normalized_quantity as u128)
-            .checked_mul(self.exchange_rate as u128)
+        conversion::calculate_quote_amount(
+            normalized_quantity,
+            exchange_rate,
+            self.quote_mint_decimals,
+        )
+        .ok_or(ParityIssuanceError::CalculationOverflow.into())
+    }
+
+    pub fn calculate_mint_fee(&self, quantity: u64) -> Result<u64> {
+        fees::fee_rounded_down(quantity, self.mint_fee_bps)
             .ok_or(ParityIssuanceError::CalculationOverflow.into())
-            .and_then(|result| {
-                result
-                    .checked_div(10u128.pow(self.quote_mint_decimals.into()))
-                    .ok_or(ParityIssuanceError::CalculationOverflow.into())
-            })
-            .map(|result| result as u64)
+    }
+
+    pub fn calculate_redeem_fee(&self, quote_amount: u64) -> Result<u64> {
+        fees::fee_rounded_down(quote_amount, self.redeem_fee_bps)
+            .ok_or(ParityIssuanceError::CalculationOverflow.into())
+    }
+
+    pub fn calculate_remaining_slot_volume(&self, current_slot: u64) -> u64 {
+        if self.current_slot == current_slot {
+            self.limit_per_slot.saturating_sub(self.current_slot_volume)
+        } else {
+            self.limit_per_slot
+        }
+    }
+
+    pub fn preview_mint(
+        &self,
+        quantity: u64,
+        current_slot: u64,
+        current_timestamp: i64,
+    ) -> Result<MintPreview> {
+        let fee_amount = self.calculate_mint_fee(quantity)?;
+        let normalized_quantity = self.calculate_normalized_quantity(quantity)?;
+
+        Ok(MintPreview {
+            mint_amount: quantity
+                .checked_sub(fee_amount)
+                .ok_or(ParityIssuanceError::CalculationOverflow)?,
+            fee_amount,
+            quote_amount: self.calculate_quote_amount(normalized_quantity, current_timestamp)?,
+            exchange_rate: self.calculate_exchange_rate(current_timestamp)?,
+            remaining_slot_volume: self.calculate_remaining_slot_volume(current_slot),
+            active: self.active,
+        })
+    }
+
+    pub fn preview_redeem(
+        &self,
+        quantity: u64,
+        current_slot: u64,
+        current_timestamp: i64,
+    ) -> Result<RedeemPreview> {
+        let normalized_quantity = self.calculate_normalized_quantity(quantity)?;
+        let quote_amount = self.calculate_quote_amount(normalized_quantity, current_timestamp)?;
+        let fee_amount = self.calculate_redeem_fee(quote_amount)?;
+
+        Ok(RedeemPreview {
+            quote_amount: quote_amount
+                .checked_sub(fee_amount)
+                .ok_or(ParityIssuanceError::CalculationOverflow)?,
+            fee_amount,
+            exchange_rate: self.calculate_exchange_rate(current_timestamp)?,
+            remaining_slot_volume: self.calculate_remaining_slot_volume(current_slot),
+            active: self.active,
+        })
     }
 
     pub fn check_block_limit(&mut self, quantity: u64, current_slot: u64) -> Result<()> {
@@ -124,14 +268,20 @@ impl TokenManager {
         }
     }
 
-    pub fn check_excessive_deposit(&self, quote_amount: u64, mint_supply: u64) -> Result<()> {
+    pub fn check_excessive_deposit(
+        &self,
+        quote_amount: u64,
+        mint_supply: u64,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        let exchange_rate = self.calculate_exchange_rate(current_timestamp)?;
         let new_total_collateral = (self.total_collateral as u128)
             .checked_add(quote_amount as u128)
             .ok_or(ParityIssuanceError::CalculationOverflow)?;
         let max_collateral = (mint_supply as u128)
             .checked_div(10u128.pow(self.mint_decimals.into()))
             .ok_or(ParityIssuanceError::CalculationOverflow)?
-            .checked_mul(self.exchange_rate as u128)
+            .checked_mul(exchange_rate as u128)
             .ok_or(ParityIssuanceError::CalculationOverflow)?
             .checked_div(10u128.pow(self.mint_decimals.into()))
             .ok_or(ParityIssuanceError::CalculationOverflow)?
@@ -155,9 +305,11 @@ impl TokenManager {
         &self,
         mint_supply: u64,
         vault_amount: u64,
+        current_timestamp: i64,
     ) -> Result<u64> {
+        let exchange_rate = self.calculate_exchange_rate(current_timestamp)?;
         let required_collateral = (mint_supply as u128)
-            .checked_mul(self.exchange_rate as u128)
+            .checked_mul(exchange_rate as u128)
             .ok_or(ParityIssuanceError::CalculationOverflow)?
             .checked_div(10u128.pow(self.mint_decimals.into()))
             .ok_or(ParityIssuanceError::CalculationOverflow)?;
@@ -206,6 +358,12 @@ mod tests {
             total_collateral: 0,
             mint_fee_bps: 0,
             redeem_fee_bps: 0,
+            max_exchange_rate_change_bps: 500,
+            exchange_rate_update_interval: 3600,
+            exchange_rate_ramp_duration: 0,
+            previous_exchange_rate: 1000000,
+            last_exchange_rate_update: 0,
+            exchange_rate_ramp_end: 0,
         }
     }
 
@@ -242,18 +400,54 @@ mod tests {
         token_manager.exchange_rate = 2000000;
 
         // Test case where normalized quantity is correctly converted to quote amount
-        let result = token_manager.calculate_quote_amount(10000).unwrap();
+        let result = token_manager.calculate_quote_amount(10000, 0).unwrap();
         assert_eq!(result, 20000);
 
         // Test case where normalized quantity is zero
-        let result = token_manager.calculate_quote_amount(0).unwrap();
+        let result = token_manager.calculate_quote_amount(0, 0).unwrap();
         assert_eq!(result, 0);
 
         // Test case where normalized quantity is large
-        let result = token_manager.calculate_quote_amount(1000000000).unwrap();
+        let result = token_manager.calculate_quote_amount(1000000000, 0).unwrap();
         assert_eq!(result, 2000000000);
     }
 
+    #[test]
+    fn test_preview_mint_and_redeem() {
+        let mut token_manager = default_token_manager();
+        token_manager.mint_fee_bps = 10;
+        token_manager.redeem_fee_bps = 20;
+        token_manager.limit_per_slot = 1000000;
+        token_manager.current_slot = 5;
+        token_manager.current_slot_volume = 300000;
+
+        let preview = token_manager.preview_mint(1000000, 5, 0).unwrap();
+        assert_eq!(
+            preview,
+            MintPreview {
+                mint_amount: 999000,
+                fee_amount: 1000,
+                quote_amount: 1000000,
+                exchange_rate: 1000000,
+                remaining_slot_volume: 700000,
+                active: true,
+            }
+        );
+
+        // The slot volume resets on a new slot
+        let preview = token_manager.preview_redeem(1000000, 6, 0).unwrap();
+        assert_eq!(
+            preview,
+            RedeemPreview {
+                quote_amount: 998000,
+                fee_amount: 2000,
+                exchange_rate: 1000000,
+                remaining_slot_volume: 1000000,
+                active: true,
+            }
+        );
+    }
+
     #[test]
     fn test_calculate_max_withdrawable_amount() {
         let mut token_manager = default_token_manager();
@@ -263,13 +457,13 @@ mod tests {
 
         // Test case where mint supply is 1000000
         let result = token_manager
-            .calculate_max_withdrawable_amount(10000000000, vault_amount)
+            .calculate_max_withdrawable_amount(10000000000, vault_amount, 0)
             .unwrap();
         assert_eq!(result, 9500000000); // 5% of 1000000 is 50000, so max withdrawable is 1000000 - 50000
 
         // Test case where mint supply is 0
         let result = token_manager
-            .calculate_max_withdrawable_amount(0, vault_amount)
+            .calculate_max_withdrawable_amount(0, vault_amount, 0)
             .unwrap();
         assert_eq!(result, 10000000000); // No collateral required, so all collateral is withdrawable
     }
@@ -280,11 +474,98 @@ mod tests {
         token_manager.total_collateral = 500000; // Main value
 
         // Test case where deposit is within limit
-        let result = token_manager.check_excessive_deposit(500000, 1000000);
+        let result = token_manager.check_excessive_deposit(500000, 1000000, 0);
         assert!(result.is_ok());
 
         // Test case where deposit exceeds limit
-        let result = token_manager.check_excessive_deposit(500001, 1000000);
+        let result = token_manager.check_excessive_deposit(500001, 1000000, 0);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_update_exchange_rate_guardrails() {
+        let mut token_manager = default_token_manager();
+
+        // Test case where the minimum interval has not passed yet
+        let result = token_manager.update_exchange_rate(1010000, 1800);
+        assert!(result.is_err());
+
+        // Test case where the change exceeds the maximum change per update
+        let result = token_manager.update_exchange_rate(1060000, 3600);
+        assert!(result.is_err());
+
+        // Test case where the change is within bounds
+        let result = token_manager.update_exchange_rate(1050000, 3600);
+        assert!(result.is_ok());
+        assert_eq!(token_manager.exchange_rate, 1050000);
+        assert_eq!(
+            token_manager.calculate_exchange_rate(3600).unwrap(),
+            1050000
+        );
+    }
+
+    #[test]
+    fn test_calculate_exchange_rate_ramp() {
+        let mut token_manager = default_token_manager();
+        token_manager.exchange_rate_ramp_duration = 1000;
+
+        token_manager.update_exchange_rate(1040000, 3600).unwrap();
+
+        // Test case where the ramp just started
+        assert_eq!(
+            token_manager.calculate_exchange_rate(3600).unwrap(),
+            1000000
+        );
+
+        // Test case where the ramp is half way through
+        assert_eq!(
+            token_manager.calculate_exchange_rate(4100).unwrap(),
+            1020000
+        );
+
+        // Test case where the ramp is finished
+        assert_eq!(
+            token_manager.calculate_exchange_rate(4600).unwrap(),
+            1040000
+        );
+        assert_eq!(
+            token_manager.calculate_quote_amount(1000000, 4100).unwrap(),
+            1020000
+        );
+
+        // Test case where the rate ramps down from the rate in effect
+        token_manager.update_exchange_rate(1000000, 7200).unwrap();
+        assert_eq!(token_manager.previous_exchange_rate, 1040000);
+        assert_eq!(
+            token_manager.calculate_exchange_rate(7700).unwrap(),
+            1020000
+        );
+    }
+
+    #[test]
+    fn test_migrate_legacy_token_manager() {
+        let mut token_manager = default_token_manager();
+        token_manager.redeem_fee_bps = 30;
+        let mut data = Vec::new();
+        token_manager.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), TOKEN_MANAGER_SIZE);
+
+        // A legacy account is the current layout without the trailing guardrails
+        data.truncate(LEGACY_TOKEN_MANAGER_SIZE);
+        data.resize(TOKEN_MANAGER_SIZE, 0);
+        let mut migrated = TokenManager::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(migrated.exchange_rate, 1000000);
+        assert_eq!(migrated.redeem_fee_bps, 30);
+        assert_eq!(migrated.max_exchange_rate_change_bps, 0);
+
+        migrated.migrate_exchange_rate_guardrails(3600);
+        assert_eq!(migrated.max_exchange_rate_change_bps, 10000);
+        assert_eq!(migrated.previous_exchange_rate, 1000000);
+        assert_eq!(migrated.calculate_exchange_rate(3600).unwrap(), 1000000);
+
+        // Test case where the rate can be updated right away
+        let result = migrated.update_exchange_rate(1500000, 3600);
+        assert!(result.is_ok());
+        assert_eq!(migrated.calculate_exchange_rate(3600).unwrap(), 1500000);
+    }
 }
@@ -24,4 +24,70 @@ pub enum ParityStakingError {
     MaxIntervalAprRateExceeded,
     #[msg("An Invalid Parameter was passed")]
     InvalidParam,
+    #[msg("Slippage exceeded")]
+    SlippageExceeded,
+    #[msg("Unstake cooldown not elapsed")]
+    UnstakeCooldownNotElapsed,
+    #[msg("Insufficient liquidity buffer, request an unstake ticket instead")]
+    InsufficientLiquidityBuffer,
+    #[msg("Invalid treasury")]
+    InvalidTreasury,
+    #[msg("Invalid yield schedule")]
+    InvalidYieldSchedule,
+    #[msg("Amount below minimum")]
+    AmountBelowMinimum,
+    #[msg("Invalid issuance program")]
+    InvalidIssuanceProgram,
+    #[msg("Invalid token manager")]
+    InvalidTokenManager,
+    #[msg("Issuance update timelock has not elapsed")]
+    IssuanceUpdateTimelockActive,
+    #[msg("Accrual interval not elapsed")]
+    AccrualIntervalNotElapsed,
+    #[msg("Invalid yield mode for this operation")]
+    InvalidYieldMode,
+    #[msg("Invalid rewarder")]
+    InvalidRewarder,
+    #[msg("Staking is paused")]
+    StakingPaused,
+    #[msg("Unstaking is paused")]
+    UnstakingPaused,
+    #[msg("Pool status unchanged")]
+    PoolStatusUnchanged,
+    #[msg("Invalid toggle active authority")]
+    InvalidToggleActiveAuthority,
+    #[msg("Tranche is not open to new positions")]
+    TrancheInactive,
+    #[msg("Invalid tranche")]
+    InvalidTranche,
+    #[msg("Invalid x token program")]
+    InvalidXTokenProgram,
+    #[msg("Interest rate out of range for the x mint")]
+    InvalidInterestRate,
+    #[msg("Rate history does not cover the window")]
+    InsufficientRateHistory,
+    #[msg("Invalid strategy")]
+    InvalidStrategy,
+    #[msg("Strategy is not open to new allocations")]
+    StrategyInactive,
+    #[msg("Strategy cap exceeded")]
+    StrategyCapExceeded,
+    #[msg("Strategies would exceed their share of the pool assets")]
+    StrategyAllocationExceeded,
+    #[msg("Vault balance reserved for unstakes")]
+    InsufficientVaultLiquidity,
+    #[msg("Invalid strategy authority")]
+    InvalidStrategyAuthority,
+    #[msg("Wallet is not on the allowlist")]
+    StakerNotAllowed,
+    #[msg("Staker entry required")]
+    StakerEntryRequired,
+    #[msg("Wallet deposit cap exceeded")]
+    WalletDepositCapExceeded,
+    #[msg("Invalid legacy pool manager")]
+    InvalidLegacyPoolManager,
+    #[msg("Keeper reward requires a minimum accrual interval")]
+    KeeperRewardWithoutAccrualInterval,
+    #[msg("Setting not supported with an interest-bearing x mint")]
+    InterestBearingXMintUnsupported,
 }
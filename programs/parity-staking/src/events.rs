@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::UnstakePath;
+
+#[event]
+pub struct StakeEvent {
+    pub pool_manager: Pubkey,
+    pub payer: Pubkey,
+    pub base_amount: u64, // base tokens paid, fee included
+    pub x_amount: u64,
+    pub fee_amount: u64,
+    pub exchange_rate: u64,
+}
+
+#[event]
+pub struct UnstakeEvent {
+    pub pool_manager: Pubkey,
+    pub payer: Pubkey,
+    pub x_amount: u64,
+    pub base_amount: u64, // paid out or locked in an unstake ticket, net of fees
+    pub fee_amount: u64,
+    pub unstake_path: UnstakePath,
+    pub exchange_rate: u64,
+}
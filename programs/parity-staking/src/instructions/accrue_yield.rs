@@ -0,0 +1,228 @@
+use crate::{
+    error::ParityStakingError, instructions::update_annual_yield::sync_x_mint_interest_rate,
+    PoolManager, RateFeed, RateHistory, YieldSchedule,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+    token_interface::{self, TokenInterface},
+};
+use parity_issuance::cpi::{accounts::MintAdminTokens, mint_admin};
+
+#[derive(Accounts)]
+pub struct AccrueYield<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump = yield_schedule.bump,
+    )]
+    pub yield_schedule: Account<'info, YieldSchedule>,
+    #[account(
+        mut,
+        seeds = [b"rate-feed", pool_manager.key().as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Account<'info, RateFeed>,
+    #[account(
+        mut,
+        seeds = [b"rate-history", pool_manager.key().as_ref()],
+        bump = rate_history.bump,
+    )]
+    pub rate_history: Account<'info, RateHistory>,
+    /// CHECK: Token manager recorded on the pool manager, deserialized in the mint_admin CPI call
+    #[account(
+        mut,
+        address = pool_manager.token_manager @ ParityStakingError::InvalidTokenManager,
+    )]
+    pub token_manager: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        mint::decimals = pool_manager.base_mint_decimals,
+        address = pool_manager.base_mint,
+    )]
+    pub base_mint: Account<'info, Mint>,
+    #[account(mut, address = pool_manager.x_mint)]
+    pub x_mint: InterfaceAccount<'info, token_interface::Mint>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = pool_manager,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = pool_manager.treasury @ ParityStakingError::InvalidTreasury,
+        token::mint = base_mint,
+    )]
+    pub treasury: Option<Account<'info, TokenAccount>>,
+    /// Receives the keeper reward, none is paid without it
+    #[account(mut, token::mint = base_mint)]
+    pub keeper_base_mint_ata: Option<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    #[account(
+        address = pool_manager.x_token_program @ ParityStakingError::InvalidXTokenProgram,
+    )]
+    pub x_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: Minter authorization of the pool manager, checked in the mint_admin CPI call
+    pub minter_authorization: Option<UncheckedAccount<'info>>,
+    /// CHECK: Issuance program recorded on the pool manager
+    #[account(
+        executable,
+        address = pool_manager.issuance_program @ ParityStakingError::InvalidIssuanceProgram,
+    )]
+    pub parity_issuance_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<AccrueYield>) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    ctx.accounts
+        .pool_manager
+        .check_accrual_interval(current_timestamp)?;
+    ctx.accounts
+        .yield_schedule
+        .apply(&mut ctx.accounts.pool_manager, current_timestamp)?;
+
+    // Scheduled rate changes land here, so the x mint rate follows them
+    sync_x_mint_interest_rate(
+        &ctx.accounts.pool_manager,
+        &ctx.accounts.x_mint,
+        &ctx.accounts.x_token_program,
+    )?;
+
+    let pool_manager = &mut ctx.accounts.pool_manager;
+
+    // Base held by the strategies backs the x supply too
+    let pool_assets = pool_manager.calculate_pool_assets(ctx.accounts.vault.amount)?;
+    let amount_to_mint = pool_manager.calculate_amount_to_mint(
+        ctx.accounts.x_mint.supply,
+        current_timestamp,
+        pool_assets,
+    )?;
+    let keeper_reward = match ctx.accounts.keeper_base_mint_ata {
+        Some(_) => pool_manager.calculate_keeper_reward(amount_to_mint),
+        None => 0,
+    };
+
+    // Minted on top of the yield so that the exchange rate stays net of fees
+    let performance_fee = pool_manager.calculate_minted_performance_fee(amount_to_mint)?;
+
+    msg!("Amount to mint: {}", amount_to_mint);
+    msg!("Performance fee: {}", performance_fee);
+    msg!("Keeper reward: {}", keeper_reward);
+
+    let bump = pool_manager.bump;
+    let base_mint_key = pool_manager.base_mint;
+    let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"pool-manager",
+        base_mint_key.as_ref(),
+        &pool_id_bytes,
+        &[bump],
+    ]];
+
+    if amount_to_mint > 0 {
+        // The fee and keeper reward are minted on top of the yield so the vault stays fully backed
+        let mint_context = CpiContext::new_with_signer(
+            ctx.accounts.parity_issuance_program.to_account_info(),
+            MintAdminTokens {
+                token_manager: ctx.accounts.token_manager.to_account_info(),
+                minter_mint_ata: ctx.accounts.vault.to_account_info(),
+                minter: pool_manager.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                mint: ctx.accounts.base_mint.to_account_info(),
+                minter_authorization: ctx
+                    .accounts
+                    .minter_authorization
+                    .as_ref()
+                    .map(|minter_authorization| minter_authorization.to_account_info()),
+            },
+            signer_seeds,
+        );
+
+        mint_admin(
+            mint_context,
+            amount_to_mint
+                .checked_add(performance_fee)
+                .and_then(|amount| amount.checked_add(keeper_reward))
+                .ok_or(ParityStakingError::CalculationOverflow)?,
+        )?;
+    }
+
+    if performance_fee > 0 {
+        let treasury = ctx
+            .accounts
+            .treasury
+            .as_ref()
+            .ok_or(ParityStakingError::InvalidTreasury)?;
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: treasury.to_account_info(),
+                    mint: ctx.accounts.base_mint.to_account_info(),
+                    authority: pool_manager.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            performance_fee,
+            pool_manager.base_mint_decimals,
+        )?;
+
+        pool_manager.record_performance_fee(performance_fee)?;
+    }
+
+    if let Some(keeper_base_mint_ata) = &ctx.accounts.keeper_base_mint_ata {
+        if keeper_reward > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.vault.to_account_info(),
+                        to: keeper_base_mint_ata.to_account_info(),
+                        mint: ctx.accounts.base_mint.to_account_info(),
+                        authority: pool_manager.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                keeper_reward,
+                pool_manager.base_mint_decimals,
+            )?;
+
+            pool_manager.record_keeper_reward(keeper_reward)?;
+        }
+    }
+
+    pool_manager.record_yield_minted(amount_to_mint)?;
+    pool_manager.last_accrual_timestamp = current_timestamp;
+
+    ctx.accounts
+        .rate_feed
+        .refresh(&ctx.accounts.pool_manager, current_timestamp)?;
+    ctx.accounts.rate_history.record(
+        ctx.accounts.rate_feed.exchange_rate,
+        current_timestamp,
+        ctx.accounts.pool_manager.checkpoint_interval,
+    )?;
+
+    Ok(())
+}
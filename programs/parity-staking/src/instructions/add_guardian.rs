@@ -0,0 +1,34 @@
+use crate::{error::ParityStakingError, Guardian, PoolManager, GUARDIAN_LENGTH};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(new_guardian: Pubkey)]
+pub struct AddGuardian<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        init,
+        payer = admin,
+        space = GUARDIAN_LENGTH,
+        seeds = [b"guardian", pool_manager.key().as_ref(), new_guardian.as_ref()],
+        bump
+    )]
+    pub guardian: Account<'info, Guardian>,
+    #[account(mut, address = pool_manager.admin @ ParityStakingError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddGuardian>, new_guardian: Pubkey) -> Result<()> {
+    let guardian = &mut ctx.accounts.guardian;
+    guardian.pool_manager = ctx.accounts.pool_manager.key();
+    guardian.wallet = new_guardian;
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ParityStakingError, PoolManager, StakerEntry, STAKER_ENTRY_LENGTH};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct AddStakerEntryParams {
+    pub wallet: Pubkey,
+    pub deposit_cap: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: AddStakerEntryParams)]
+pub struct AddStakerEntry<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        init,
+        payer = authority,
+        space = STAKER_ENTRY_LENGTH,
+        seeds = [b"staker-entry", pool_manager.key().as_ref(), params.wallet.as_ref()],
+        bump
+    )]
+    pub staker_entry: Account<'info, StakerEntry>,
+    /// The admin, or the wallet itself while the allowlist is disabled
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddStakerEntry>, params: AddStakerEntryParams) -> Result<()> {
+    let pool_manager = &ctx.accounts.pool_manager;
+    let is_admin = ctx.accounts.authority.key() == pool_manager.admin;
+
+    // Wallets can open their own entry to stake under the wallet deposit cap
+    if !is_admin {
+        if params.wallet != ctx.accounts.authority.key() {
+            return err!(ParityStakingError::InvalidAdmin);
+        }
+        if pool_manager.allowlist_enabled {
+            return err!(ParityStakingError::StakerNotAllowed);
+        }
+        if params.deposit_cap != 0 {
+            return err!(ParityStakingError::InvalidParam); // Ensure only the admin sets a wallet's own cap
+        }
+    }
+
+    let staker_entry = &mut ctx.accounts.staker_entry;
+    staker_entry.bump = ctx.bumps.staker_entry;
+    staker_entry.pool_manager = pool_manager.key();
+    staker_entry.wallet = params.wallet;
+    staker_entry.allowed = is_admin;
+    staker_entry.deposit_cap = params.deposit_cap;
+    staker_entry.staked_amount = 0;
+
+    Ok(())
+}
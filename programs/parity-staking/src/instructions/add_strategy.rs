@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{self, AssociatedToken, Create},
+    token::{Mint, Token},
+};
+
+use crate::{error::ParityStakingError, PoolManager, Strategy, STRATEGY_LENGTH};
+
+#[derive(Accounts)]
+pub struct AddStrategy<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        init,
+        payer = owner,
+        space = STRATEGY_LENGTH,
+        seeds = [
+            b"strategy",
+            pool_manager.key().as_ref(),
+            strategy_program.key().as_ref(),
+        ],
+        bump
+    )]
+    pub strategy: Account<'info, Strategy>,
+    /// CHECK: Strategy program being whitelisted, trusted by the owner to implement the strategy interface
+    #[account(
+        executable,
+        constraint = strategy_program.key() != crate::ID @ ParityStakingError::InvalidStrategy,
+    )]
+    pub strategy_program: UncheckedAccount<'info>,
+    /// CHECK: Signs for the pool in the strategy program, with no authority over the vault or the mints
+    #[account(
+        seeds = [b"strategy-authority", strategy.key().as_ref()],
+        bump,
+    )]
+    pub strategy_authority: UncheckedAccount<'info>,
+    #[account(
+        mint::decimals = pool_manager.base_mint_decimals,
+        address = pool_manager.base_mint,
+    )]
+    pub base_mint: Account<'info, Mint>,
+    /// CHECK: Associated token account of the strategy authority, created in the handler
+    #[account(mut)]
+    pub strategy_token_account: UncheckedAccount<'info>,
+    #[account(mut, address = pool_manager.owner @ ParityStakingError::InvalidOwner)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Whitelists the strategy program up to `cap` base tokens and creates the
+/// strategy token account that allocations are paid into.
+pub fn handler(ctx: Context<AddStrategy>, cap: u64) -> Result<()> {
+    // Anybody can create an associated token account, so it may exist already
+    associated_token::create_idempotent(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        Create {
+            payer: ctx.accounts.owner.to_account_info(),
+            associated_token: ctx.accounts.strategy_token_account.to_account_info(),
+            authority: ctx.accounts.strategy_authority.to_account_info(),
+            mint: ctx.accounts.base_mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    ))?;
+
+    let strategy = &mut ctx.accounts.strategy;
+    strategy.bump = ctx.bumps.strategy;
+    strategy.pool_manager = ctx.accounts.pool_manager.key();
+    strategy.strategy_program = ctx.accounts.strategy_program.key();
+    strategy.active = true;
+    strategy.cap = cap;
+    strategy.allocated_amount = 0;
+    strategy.reported_value = 0;
+    strategy.last_report_timestamp = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
+
+use crate::{
+    error::ParityStakingError, strategy_interface::StrategyContext, PoolManager, Strategy,
+};
+
+#[derive(Accounts)]
+pub struct AllocateToStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        seeds = [
+            b"strategy",
+            pool_manager.key().as_ref(),
+            strategy_program.key().as_ref(),
+        ],
+        bump = strategy.bump,
+    )]
+    pub strategy: Account<'info, Strategy>,
+    #[account(
+        mint::decimals = pool_manager.base_mint_decimals,
+        address = pool_manager.base_mint,
+    )]
+    pub base_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = pool_manager,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    /// CHECK: Strategy program whitelisted by the strategy account
+    #[account(executable)]
+    pub strategy_program: UncheckedAccount<'info>,
+    /// CHECK: Signs for the pool in the strategy program, with no authority over the vault or the mints
+    #[account(
+        seeds = [b"strategy-authority", strategy.key().as_ref()],
+        bump,
+    )]
+    pub strategy_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = strategy_authority,
+    )]
+    pub strategy_token_account: Account<'info, TokenAccount>,
+    #[account(address = pool_manager.owner @ ParityStakingError::InvalidOwner)]
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Moves `quantity` base tokens from the vault into the strategy token account
+/// and lets the strategy take them from there. The strategy's own accounts are
+/// passed as remaining accounts.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AllocateToStrategy<'info>>,
+    quantity: u64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    // Check if the quantity to allocate is greater than zero
+    if quantity == 0 {
+        return err!(ParityStakingError::InvalidQuantity);
+    }
+
+    let vault_amount = accounts.vault.amount;
+    accounts
+        .pool_manager
+        .check_strategy_allocation(quantity, vault_amount)?;
+    accounts.strategy.check_allocation(quantity)?;
+
+    let pool_manager = &accounts.pool_manager;
+    let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
+    let base_mint_key = pool_manager.base_mint;
+    let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"pool-manager",
+        base_mint_key.as_ref(),
+        &pool_id_bytes,
+        &[bump],
+    ]];
+
+    // The pool moves the funds itself, so the strategy never signs for the vault
+    transfer_checked(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: accounts.vault.to_account_info(),
+                to: accounts.strategy_token_account.to_account_info(),
+                mint: accounts.base_mint.to_account_info(),
+                authority: pool_manager.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        quantity,
+        pool_manager.base_mint_decimals,
+    )?;
+
+    StrategyContext {
+        strategy_program: accounts.strategy_program.to_account_info(),
+        strategy: accounts.strategy.key(),
+        strategy_authority: accounts.strategy_authority.to_account_info(),
+        strategy_authority_bump: ctx.bumps.strategy_authority,
+        strategy_token_account: accounts.strategy_token_account.to_account_info(),
+        vault: accounts.vault.to_account_info(),
+        base_mint: accounts.base_mint.to_account_info(),
+        token_program: accounts.token_program.to_account_info(),
+        remaining_accounts: ctx.remaining_accounts,
+    }
+    .deposit(quantity)?;
+
+    accounts.strategy.record_deposit(quantity)?;
+    accounts.pool_manager.record_strategy_deposit(quantity)?;
+
+    msg!(
+        "Strategy value: {}, pool strategy value: {}",
+        accounts.strategy.reported_value,
+        accounts.pool_manager.strategy_value
+    );
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+};
+
+use crate::{error::ParityStakingError, PoolManager, UnstakeTicket};
+
+#[derive(Accounts)]
+pub struct ClaimUnstake<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            b"unstake-ticket",
+            pool_manager.key().as_ref(),
+            payer.key().as_ref(),
+            &unstake_ticket.ticket_id.to_le_bytes(),
+        ],
+        bump = unstake_ticket.bump,
+        has_one = pool_manager,
+        constraint = unstake_ticket.owner == payer.key() @ ParityStakingError::InvalidOwner,
+    )]
+    pub unstake_ticket: Account<'info, UnstakeTicket>,
+    #[account(
+        mint::decimals = pool_manager.base_mint_decimals,
+        address = pool_manager.base_mint,
+    )]
+    pub base_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = payer,
+    )]
+    pub payer_base_mint_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = pool_manager,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    // Other
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<ClaimUnstake>) -> Result<()> {
+    let pool_manager = &mut ctx.accounts.pool_manager;
+    let unstake_ticket = &ctx.accounts.unstake_ticket;
+
+    pool_manager.check_unstake_active()?;
+
+    // Check if the cooldown has passed
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    if current_timestamp < unstake_ticket.claimable_timestamp {
+        return err!(ParityStakingError::UnstakeCooldownNotElapsed);
+    }
+
+    let base_amount = unstake_ticket.base_amount;
+
+    // Signing
+    let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
+    let base_mint_key = pool_manager.base_mint;
+    let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"pool-manager",
+        base_mint_key.as_ref(),
+        &pool_id_bytes,
+        &[bump],
+    ]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.payer_base_mint_ata.to_account_info(),
+                mint: ctx.accounts.base_mint.to_account_info(),
+                authority: pool_manager.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        base_amount,
+        pool_manager.base_mint_decimals,
+    )?;
+
+    // Update pool_manager
+    pool_manager.pending_unstake_amount = pool_manager
+        .pending_unstake_amount
+        .checked_sub(base_amount)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+    pool_manager.record_withdrawal(base_amount)?;
+
+    Ok(())
+}
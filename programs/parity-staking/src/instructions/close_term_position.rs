@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, TokenAccount, TransferChecked};
+
+use crate::{
+    error::ParityStakingError, instructions::unstake::*, TermPosition, Tranche, UnstakePath,
+};
+
+#[derive(Accounts)]
+pub struct CloseTermPosition<'info> {
+    pub unstake: Unstake<'info>,
+    #[account(
+        mut,
+        address = term_position.tranche @ ParityStakingError::InvalidTranche,
+    )]
+    pub tranche: Account<'info, Tranche>,
+    #[account(
+        mut,
+        seeds = [b"tranche-vault", tranche.key().as_ref()],
+        bump,
+    )]
+    pub tranche_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            b"term-position",
+            unstake.pool_manager.key().as_ref(),
+            owner.key().as_ref(),
+            &term_position.position_id.to_le_bytes(),
+        ],
+        bump = term_position.bump,
+        constraint = term_position.owner == owner.key() @ ParityStakingError::InvalidOwner,
+    )]
+    pub term_position: Account<'info, TermPosition>,
+    #[account(mut, address = unstake.payer.key())]
+    pub owner: Signer<'info>,
+}
+
+/// Unstakes the locked x tokens at the pool rate and, from maturity on, mints
+/// the boost that brings the payout up to the tranche rate. Matured positions
+/// are paid through the standard path, without the instant unstake fee.
+pub fn handler(ctx: Context<CloseTermPosition>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let term_position = &accounts.term_position;
+    let x_amount = term_position.x_amount;
+
+    // Land any scheduled rate changes so the x value matches the unstake
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    accounts
+        .unstake
+        .yield_schedule
+        .apply(&mut accounts.unstake.pool_manager, current_timestamp)?;
+
+    let pool_manager = &accounts.unstake.pool_manager;
+    let matured = current_timestamp >= term_position.maturity_timestamp;
+    let x_value = pool_manager.calculate_output_amount(x_amount, current_timestamp, false)?;
+    let boost_amount = term_position.calculate_boost_amount(
+        x_value,
+        pool_manager.seconds_per_interval,
+        current_timestamp,
+    )?;
+
+    // Release the x tokens to the payer so they can be unstaked
+    let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
+    let base_mint_key = pool_manager.base_mint;
+    let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"pool-manager",
+        base_mint_key.as_ref(),
+        &pool_id_bytes,
+        &[bump],
+    ]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            accounts.unstake.x_token_program.to_account_info(),
+            TransferChecked {
+                from: accounts.tranche_vault.to_account_info(),
+                to: accounts.unstake.payer_x_mint_ata.to_account_info(),
+                mint: accounts.unstake.x_mint.to_account_info(),
+                authority: pool_manager.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        x_amount,
+        pool_manager.x_mint_decimals,
+    )?;
+
+    let (base_amount, unstake_path) = if matured {
+        (
+            accounts.unstake.unstake_standard(x_amount)?,
+            UnstakePath::Standard,
+        )
+    } else {
+        accounts.unstake.unstake(x_amount)?
+    };
+
+    if boost_amount > 0 {
+        accounts.unstake.mint_to_vault(boost_amount)?;
+        accounts.unstake.transfer_from_vault(boost_amount, false)?;
+        accounts
+            .unstake
+            .pool_manager
+            .record_boost_minted(boost_amount)?;
+    }
+
+    let tranche = &mut accounts.tranche;
+    tranche.total_principal_amount = tranche
+        .total_principal_amount
+        .checked_sub(term_position.principal_amount)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+    tranche.total_boost_paid = tranche
+        .total_boost_paid
+        .checked_add(boost_amount)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+
+    msg!(
+        "Closed term position: {} through the {:?} path, {} boost",
+        base_amount,
+        unstake_path,
+        boost_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+};
+
+use crate::{error::ParityStakingError, PoolManager, RateFeed};
+
+#[derive(Accounts)]
+pub struct DepositRewards<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        seeds = [b"rate-feed", pool_manager.key().as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Account<'info, RateFeed>,
+    #[account(
+        mint::decimals = pool_manager.base_mint_decimals,
+        address = pool_manager.base_mint,
+    )]
+    pub base_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = pool_manager,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = rewarder,
+    )]
+    pub rewarder_base_mint_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = pool_manager.treasury @ ParityStakingError::InvalidTreasury,
+        token::mint = base_mint,
+    )]
+    pub treasury: Option<Account<'info, TokenAccount>>,
+    #[account(address = pool_manager.rewarder @ ParityStakingError::InvalidRewarder)]
+    pub rewarder: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(ctx: Context<DepositRewards>, quantity: u64) -> Result<()> {
+    if quantity == 0 {
+        return err!(ParityStakingError::InvalidQuantity);
+    }
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let pool_manager = &mut ctx.accounts.pool_manager;
+
+    let performance_fee = pool_manager.calculate_performance_fee(quantity)?;
+    let reward_amount = quantity
+        .checked_sub(performance_fee)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+
+    // Vests into the exchange rate over the vesting period
+    pool_manager.deposit_rewards(reward_amount, current_timestamp)?;
+
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.rewarder_base_mint_ata.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.base_mint.to_account_info(),
+                authority: ctx.accounts.rewarder.to_account_info(),
+            },
+        ),
+        reward_amount,
+        pool_manager.base_mint_decimals,
+    )?;
+
+    if performance_fee > 0 {
+        let treasury = ctx
+            .accounts
+            .treasury
+            .as_ref()
+            .ok_or(ParityStakingError::InvalidTreasury)?;
+
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.rewarder_base_mint_ata.to_account_info(),
+                    to: treasury.to_account_info(),
+                    mint: ctx.accounts.base_mint.to_account_info(),
+                    authority: ctx.accounts.rewarder.to_account_info(),
+                },
+            ),
+            performance_fee,
+            pool_manager.base_mint_decimals,
+        )?;
+
+        pool_manager.record_performance_fee(performance_fee)?;
+    }
+
+    msg!(
+        "Rewards vesting: {} until {}",
+        pool_manager.vesting_amount,
+        pool_manager.vesting_start_timestamp + pool_manager.vesting_period
+    );
+
+    ctx.accounts
+        .rate_feed
+        .refresh(&ctx.accounts.pool_manager, current_timestamp)?;
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    error::ParityStakingError,
+    instructions::withdraw_from_strategy::{pay_performance_fee, record_strategy_withdrawal},
+    strategy_interface::StrategyContext,
+    Guardian, PoolManager, Strategy,
+};
+
+#[derive(Accounts)]
+pub struct EmergencyWithdrawAll<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        seeds = [
+            b"strategy",
+            pool_manager.key().as_ref(),
+            strategy_program.key().as_ref(),
+        ],
+        bump = strategy.bump,
+    )]
+    pub strategy: Account<'info, Strategy>,
+    #[account(
+        mint::decimals = pool_manager.base_mint_decimals,
+        address = pool_manager.base_mint,
+    )]
+    pub base_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = pool_manager,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = pool_manager.treasury @ ParityStakingError::InvalidTreasury,
+        token::mint = base_mint,
+    )]
+    pub treasury: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Strategy program whitelisted by the strategy account
+    #[account(executable)]
+    pub strategy_program: UncheckedAccount<'info>,
+    /// CHECK: Signs for the pool in the strategy program, with no authority over the vault or the mints
+    #[account(
+        seeds = [b"strategy-authority", strategy.key().as_ref()],
+        bump,
+    )]
+    pub strategy_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = strategy_authority,
+    )]
+    pub strategy_token_account: Account<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"guardian", pool_manager.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub guardian: Option<Account<'info, Guardian>>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pulls everything the strategy holds back into the vault and closes it to
+/// new allocations. Whatever it fails to return is written off. The owner, the
+/// admin or a guardian can trigger it.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, EmergencyWithdrawAll<'info>>) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    let authority = accounts.authority.key();
+    if authority != accounts.pool_manager.owner
+        && authority != accounts.pool_manager.admin
+        && accounts.guardian.is_none()
+    {
+        return err!(ParityStakingError::InvalidStrategyAuthority);
+    }
+
+    let vault_amount = accounts.vault.amount;
+
+    StrategyContext {
+        strategy_program: accounts.strategy_program.to_account_info(),
+        strategy: accounts.strategy.key(),
+        strategy_authority: accounts.strategy_authority.to_account_info(),
+        strategy_authority_bump: ctx.bumps.strategy_authority,
+        strategy_token_account: accounts.strategy_token_account.to_account_info(),
+        vault: accounts.vault.to_account_info(),
+        base_mint: accounts.base_mint.to_account_info(),
+        token_program: accounts.token_program.to_account_info(),
+        remaining_accounts: ctx.remaining_accounts,
+    }
+    .withdraw(u64::MAX)?;
+
+    accounts.vault.reload()?;
+    let withdrawn_amount = accounts
+        .vault
+        .amount
+        .checked_sub(vault_amount)
+        .ok_or(ParityStakingError::InvalidStrategy)?;
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let performance_fee = record_strategy_withdrawal(
+        &mut accounts.pool_manager,
+        &mut accounts.strategy,
+        withdrawn_amount,
+        current_timestamp,
+    )?;
+    pay_performance_fee(
+        &mut accounts.pool_manager,
+        &accounts.vault,
+        accounts.treasury.as_ref(),
+        &accounts.base_mint,
+        &accounts.token_program,
+        performance_fee,
+    )?;
+
+    let pool_manager = &mut accounts.pool_manager;
+    let strategy = &mut accounts.strategy;
+
+    // The strategy holds nothing for the pool anymore
+    let lost_amount = strategy.reported_value;
+    pool_manager.record_strategy_value(lost_amount, 0, current_timestamp)?;
+    strategy.reported_value = 0;
+    strategy.allocated_amount = 0;
+    strategy.active = false;
+
+    msg!(
+        "Withdrawn from strategy: {}, written off: {}",
+        withdrawn_amount,
+        lost_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{AccruedYield, PoolManager, YieldSchedule};
+
+#[derive(Accounts)]
+pub struct GetAccruedYield<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump = yield_schedule.bump,
+    )]
+    pub yield_schedule: Account<'info, YieldSchedule>,
+    #[account(address = pool_manager.x_mint)]
+    pub x_mint: InterfaceAccount<'info, Mint>,
+}
+
+pub fn handler(ctx: Context<GetAccruedYield>) -> Result<AccruedYield> {
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    // Account for the rates in effect now without persisting them
+    let mut pool_manager = (*ctx.accounts.pool_manager).clone();
+    pool_manager.apply_scheduled_rates(
+        &ctx.accounts.yield_schedule.scheduled_rates,
+        current_timestamp,
+    )?;
+
+    let accrued_unminted_yield = pool_manager
+        .calculate_accrued_unminted_yield(ctx.accounts.x_mint.supply, current_timestamp)?;
+
+    msg!("Accrued unminted yield: {}", accrued_unminted_yield);
+
+    Ok(AccruedYield {
+        accrued_unminted_yield,
+        principal_amount: pool_manager.principal_amount,
+        realized_yield_amount: pool_manager.realized_yield_amount,
+        total_yield_minted: pool_manager.total_yield_minted,
+        total_boost_minted: pool_manager.total_boost_minted,
+        total_keeper_rewards: pool_manager.total_keeper_rewards,
+        exchange_rate: pool_manager.calculate_exchange_rate(current_timestamp)?,
+    })
+}
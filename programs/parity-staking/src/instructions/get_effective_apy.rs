@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::PoolManager;
+
+#[derive(Accounts)]
+pub struct GetEffectiveApy<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+}
+
+pub fn handler(ctx: Context<GetEffectiveApy>) -> Result<u64> {
+    let effective_apy_bps = ctx.accounts.pool_manager.calculate_effective_apy_bps()?;
+
+    msg!("Effective APY: {} bps", effective_apy_bps);
+
+    Ok(effective_apy_bps)
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{PoolManager, RateHistory, YieldSchedule};
+
+#[derive(Accounts)]
+pub struct GetTrailingApy<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump = yield_schedule.bump,
+    )]
+    pub yield_schedule: Account<'info, YieldSchedule>,
+    #[account(
+        seeds = [b"rate-history", pool_manager.key().as_ref()],
+        bump = rate_history.bump,
+    )]
+    pub rate_history: Account<'info, RateHistory>,
+}
+
+/// Realized APY in basis points over the trailing `window` seconds, measured
+/// from the newest checkpoint at least that old.
+pub fn handler(ctx: Context<GetTrailingApy>, window: i64) -> Result<u64> {
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    let exchange_rate = ctx
+        .accounts
+        .pool_manager
+        .calculate_scheduled_exchange_rate(
+            &ctx.accounts.yield_schedule.scheduled_rates,
+            current_timestamp,
+        )?;
+
+    let trailing_apy_bps = ctx.accounts.rate_history.calculate_trailing_apy_bps(
+        exchange_rate,
+        window,
+        current_timestamp,
+    )?;
+
+    msg!("Trailing APY over {}s: {} bps", window, trailing_apy_bps);
+
+    Ok(trailing_apy_bps)
+}
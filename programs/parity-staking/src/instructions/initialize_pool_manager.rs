@@ -1,4 +1,10 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    solana_program::program::invoke,
+    system_program::{
+        allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
+};
 
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -6,22 +12,43 @@ use anchor_spl::{
         create_metadata_accounts_v3, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3,
         Metadata as Metaplex,
     },
-    token::{Mint, Token, TokenAccount},
+    token::{self, transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+    token_interface::{
+        self, initialize_account3, initialize_mint2,
+        spl_token_2022::{
+            self,
+            extension::{interest_bearing_mint, ExtensionType},
+        },
+        InitializeAccount3, InitializeMint2, MintTo, TokenInterface,
+    },
 };
+use parity_issuance::{program::ParityIssuance, TokenManager};
 
-use crate::{ParityStakingError, PoolManager, MAX_INTERVAL_APR_RATE, POOL_MANAGER_LENGTH};
+use crate::{
+    FeeDestination, ParityStakingError, PoolManager, RateFeed, RateHistory, YieldMode,
+    YieldSchedule, DEAD_SHARES, DEFAULT_CHECKPOINT_INTERVAL, POOL_MANAGER_LENGTH, RATE_FEED_LENGTH,
+    RATE_FEED_VERSION, RATE_HISTORY_LENGTH, YIELD_SCHEDULE_LENGTH,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
 pub struct InitializePoolManagerParams {
+    pub pool_id: u64,
     pub name: String,
     pub symbol: String,
     pub uri: String,
     pub decimals: u8,
-    pub interval_apr_rate: u64,
+    pub annual_yield_bps: u64,
     pub seconds_per_interval: i32,
     pub initial_exchange_rate: u64,
     pub admin: Pubkey,
     pub deposit_cap: u64,
+    pub unstake_cooldown: i64,
+    pub min_stake_amount: u64,
+    pub min_unstake_amount: u64,
+    pub yield_mode: YieldMode,
+    pub rewarder: Pubkey,
+    pub vesting_period: i64,
+    pub interest_bearing_x_mint: bool,
 }
 
 impl InitializePoolManagerParams {
@@ -56,15 +83,6 @@ impl InitializePoolManagerParams {
             return err!(ParityStakingError::InvalidParam); // Ensure deposit cap is non-zero
         }
 
-        // Validate interval APR rate
-        if self.interval_apr_rate == 0 {
-            return err!(ParityStakingError::InvalidParam); // Ensure interval APR rate is non-zero
-        }
-
-        if self.interval_apr_rate > MAX_INTERVAL_APR_RATE {
-            return err!(ParityStakingError::MaxIntervalAprRateExceeded); // Ensure interval APR rate does not exceed the maximum limit
-        }
-
         // Validate seconds per interval
         if self.seconds_per_interval <= 0 {
             return err!(ParityStakingError::InvalidParam); // Ensure seconds per interval is positive
@@ -74,6 +92,27 @@ impl InitializePoolManagerParams {
             return err!(ParityStakingError::InvalidParam); // Ensure seconds per interval is within reasonable bounds
         }
 
+        // Validate unstake cooldown
+        if self.unstake_cooldown < 0 {
+            return err!(ParityStakingError::InvalidParam); // Ensure unstake cooldown is non-negative
+        }
+
+        // Validate reward streaming
+        if self.yield_mode == YieldMode::RewardStreaming {
+            if self.annual_yield_bps != 0 {
+                return err!(ParityStakingError::InvalidParam); // Ensure streaming pools do not also compound
+            }
+            if self.rewarder == Pubkey::default() {
+                return err!(ParityStakingError::InvalidRewarder); // Ensure somebody can deposit rewards
+            }
+            if self.vesting_period <= 0 {
+                return err!(ParityStakingError::InvalidParam); // Ensure vesting period is positive
+            }
+            if self.interest_bearing_x_mint {
+                return err!(ParityStakingError::InvalidYieldMode); // Ensure the x mint rate follows a fixed APR
+            }
+        }
+
         Ok(())
     }
 }
@@ -83,15 +122,13 @@ impl InitializePoolManagerParams {
 pub struct InitializePoolManager<'info> {
     /// SPL Token Mint of the underlying token to be deposited for staking
     pub base_mint: Account<'info, Mint>,
+    /// CHECK: Created in the handler, as Token-2022 extensions must be initialized before the mint
     #[account(
-        init,
-        seeds = [b"mint"],
+        mut,
+        seeds = [b"mint", pool_manager.key().as_ref()],
         bump,
-        payer = owner,
-        mint::decimals = params.decimals,
-        mint::authority = pool_manager,
     )]
-    pub x_mint: Account<'info, Mint>,
+    pub x_mint: UncheckedAccount<'info>,
     /// CHECK: Validate address by deriving pda
     #[account(
         mut,
@@ -104,12 +141,38 @@ pub struct InitializePoolManager<'info> {
       init,
       seeds = [
         b"pool-manager",
+        base_mint.key().as_ref(),
+        &params.pool_id.to_le_bytes(),
       ],
       bump,
       payer = owner,
       space = POOL_MANAGER_LENGTH,
     )]
     pub pool_manager: Box<Account<'info, PoolManager>>,
+    #[account(
+        init,
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump,
+        payer = owner,
+        space = YIELD_SCHEDULE_LENGTH,
+    )]
+    pub yield_schedule: Box<Account<'info, YieldSchedule>>,
+    #[account(
+        init,
+        seeds = [b"rate-feed", pool_manager.key().as_ref()],
+        bump,
+        payer = owner,
+        space = RATE_FEED_LENGTH,
+    )]
+    pub rate_feed: Box<Account<'info, RateFeed>>,
+    #[account(
+        init,
+        seeds = [b"rate-history", pool_manager.key().as_ref()],
+        bump,
+        payer = owner,
+        space = RATE_HISTORY_LENGTH,
+    )]
+    pub rate_history: Box<Account<'info, RateHistory>>,
     #[account(
         init,
         payer = owner,
@@ -117,13 +180,38 @@ pub struct InitializePoolManager<'info> {
         associated_token::authority = pool_manager,
     )]
     pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_base_mint_ata: Box<Account<'info, TokenAccount>>,
+    /// CHECK: Created in the handler once the x mint exists. Holds the dead
+    /// shares, owned by itself so that nothing ever signs for it
+    #[account(
+        mut,
+        seeds = [b"dead-shares", pool_manager.key().as_ref()],
+        bump,
+    )]
+    pub dead_shares: UncheckedAccount<'info>,
+    /// Token manager of the base mint, bound to the pool for yield minting
+    #[account(
+        seeds = [b"token-manager"],
+        bump = token_manager.bump,
+        seeds::program = parity_issuance_program.key(),
+        constraint = token_manager.mint == base_mint.key() @ ParityStakingError::InvalidTokenManager,
+    )]
+    pub token_manager: Box<Account<'info, TokenManager>>,
     #[account(mut)]
     pub owner: Signer<'info>,
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
+    /// SPL Token, or Token-2022 for an interest-bearing x mint
+    pub x_token_program: Interface<'info, TokenInterface>,
     pub token_metadata_program: Program<'info, Metaplex>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    pub parity_issuance_program: Program<'info, ParityIssuance>,
 }
 
 pub fn handler(
@@ -133,10 +221,121 @@ pub fn handler(
     // Validate the parameters
     params.validate()?;
 
+    // Validate the x token program against the x mint mode
+    let x_token_program_id = if params.interest_bearing_x_mint {
+        spl_token_2022::ID
+    } else {
+        token::ID
+    };
+    require_keys_eq!(
+        ctx.accounts.x_token_program.key(),
+        x_token_program_id,
+        ParityStakingError::InvalidXTokenProgram
+    );
+
     let pool_manager = &mut ctx.accounts.pool_manager;
 
     let bump = ctx.bumps.pool_manager;
-    let signer_seeds: &[&[&[u8]]] = &[&[b"pool-manager", &[bump]]];
+    let base_mint_key = ctx.accounts.base_mint.key();
+    let pool_id_bytes = params.pool_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"pool-manager",
+        base_mint_key.as_ref(),
+        &pool_id_bytes,
+        &[bump],
+    ]];
+
+    // Authorities
+    pool_manager.owner = ctx.accounts.owner.key();
+    pool_manager.admin = params.admin;
+    pool_manager.bump = bump;
+    pool_manager.pool_id = params.pool_id;
+    // Token
+    pool_manager.base_mint = ctx.accounts.base_mint.key();
+    pool_manager.base_mint_decimals = ctx.accounts.base_mint.decimals;
+    pool_manager.x_mint = ctx.accounts.x_mint.key();
+    pool_manager.x_mint_decimals = params.decimals;
+    pool_manager.initial_exchange_rate = params.initial_exchange_rate;
+    // Other
+    pool_manager.base_balance = 0;
+    pool_manager.seconds_per_interval = params.seconds_per_interval;
+    // Derives the interval APR rate, which is bounded by MAX_INTERVAL_APR_RATE
+    pool_manager.set_annual_yield(params.annual_yield_bps)?;
+
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+    pool_manager.inception_timestamp = current_timestamp;
+    pool_manager.last_yield_change_timestamp = current_timestamp;
+    pool_manager.last_yield_change_exchange_rate = params.initial_exchange_rate;
+    pool_manager.deposit_cap = params.deposit_cap;
+    pool_manager.unstake_cooldown = params.unstake_cooldown;
+    pool_manager.pending_unstake_amount = 0;
+    pool_manager.treasury = Pubkey::default();
+    pool_manager.instant_unstake_fee_bps = 0;
+    pool_manager.instant_unstake_fee_destination = FeeDestination::Stakers;
+    pool_manager.liquidity_buffer_target = 0;
+    pool_manager.liquidity_buffer_amount = 0;
+    pool_manager.min_stake_amount = params.min_stake_amount;
+    pool_manager.min_unstake_amount = params.min_unstake_amount;
+    // Issuance binding
+    pool_manager.issuance_program = ctx.accounts.parity_issuance_program.key();
+    pool_manager.token_manager = ctx.accounts.token_manager.key();
+    pool_manager.pending_issuance_program = Pubkey::default();
+    pool_manager.pending_token_manager = Pubkey::default();
+    pool_manager.issuance_update_timestamp = 0;
+    // Yield accounting
+    pool_manager.principal_amount = 0;
+    pool_manager.realized_yield_amount = 0;
+    pool_manager.total_yield_minted = 0;
+    pool_manager.total_boost_minted = 0;
+    // Yield accrual crank
+    pool_manager.min_accrual_interval = 0;
+    pool_manager.last_accrual_timestamp = current_timestamp;
+    pool_manager.keeper_reward = 0;
+    pool_manager.total_keeper_rewards = 0;
+    pool_manager.check_keeper_reward()?;
+    // Reward streaming
+    pool_manager.yield_mode = params.yield_mode;
+    pool_manager.x_supply = 0;
+    pool_manager.rewarder = params.rewarder;
+    pool_manager.vesting_period = params.vesting_period;
+    pool_manager.vesting_amount = 0;
+    pool_manager.vesting_start_timestamp = current_timestamp;
+    pool_manager.total_rewards_deposited = 0;
+    // Performance fee
+    pool_manager.performance_fee_bps = 0;
+    pool_manager.total_performance_fees = 0;
+    // Entry and exit fees
+    pool_manager.entry_fee_bps = 0;
+    pool_manager.entry_fee_destination = FeeDestination::Stakers;
+    pool_manager.exit_fee_bps = 0;
+    pool_manager.exit_fee_destination = FeeDestination::Stakers;
+    // Emergency controls
+    pool_manager.stake_active = true;
+    pool_manager.unstake_active = true;
+    // Interest-bearing x mint
+    pool_manager.x_token_program = x_token_program_id;
+    pool_manager.check_interest_bearing_x_mint()?;
+    // Rate history
+    pool_manager.checkpoint_interval = DEFAULT_CHECKPOINT_INTERVAL;
+    // Yield strategies
+    pool_manager.max_strategy_allocation_bps = 0;
+    pool_manager.strategy_value = 0;
+    // Staking access
+    pool_manager.allowlist_enabled = false;
+    pool_manager.wallet_deposit_cap = 0;
+
+    let interest_rate = if pool_manager.is_x_mint_interest_bearing() {
+        Some(pool_manager.calculate_x_mint_interest_rate()?)
+    } else {
+        None
+    };
+
+    ctx.accounts
+        .create_x_mint(params.decimals, ctx.bumps.x_mint, interest_rate)?;
+    ctx.accounts.create_dead_shares(ctx.bumps.dead_shares)?;
+
+    let pool_manager = &ctx.accounts.pool_manager;
 
     let token_data: DataV2 = DataV2 {
         name: params.name,
@@ -168,27 +367,202 @@ pub fn handler(
 
     let pool_manager = &mut ctx.accounts.pool_manager;
 
-    // Authorities
-    pool_manager.owner = ctx.accounts.owner.key();
-    pool_manager.admin = params.admin;
-    pool_manager.bump = bump;
-    // Token
-    pool_manager.base_mint = ctx.accounts.base_mint.key();
-    pool_manager.base_mint_decimals = ctx.accounts.base_mint.decimals;
-    pool_manager.x_mint = ctx.accounts.x_mint.key();
-    pool_manager.x_mint_decimals = ctx.accounts.x_mint.decimals;
-    pool_manager.initial_exchange_rate = params.initial_exchange_rate;
-    // Other
-    pool_manager.base_balance = 0;
-    pool_manager.interval_apr_rate = params.interval_apr_rate;
-    pool_manager.seconds_per_interval = params.seconds_per_interval;
+    // Back the dead shares with base tokens from the owner, rounded up
+    let dead_shares_base_amount =
+        pool_manager.calculate_required_base_amount(DEAD_SHARES, current_timestamp)?;
 
-    let clock = Clock::get()?;
-    let current_timestamp = clock.unix_timestamp;
-    pool_manager.inception_timestamp = current_timestamp;
-    pool_manager.last_yield_change_timestamp = current_timestamp;
-    pool_manager.last_yield_change_exchange_rate = params.initial_exchange_rate;
-    pool_manager.deposit_cap = params.deposit_cap;
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.owner_base_mint_ata.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.base_mint.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        dead_shares_base_amount,
+        pool_manager.base_mint_decimals,
+    )?;
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.x_token_program.to_account_info(),
+            MintTo {
+                authority: pool_manager.to_account_info(),
+                to: ctx.accounts.dead_shares.to_account_info(),
+                mint: ctx.accounts.x_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        DEAD_SHARES,
+    )?;
+
+    pool_manager.record_deposit(dead_shares_base_amount)?;
+    pool_manager.record_x_minted(DEAD_SHARES)?;
+
+    let yield_schedule = &mut ctx.accounts.yield_schedule;
+    yield_schedule.bump = ctx.bumps.yield_schedule;
+    yield_schedule.pool_manager = ctx.accounts.pool_manager.key();
+    yield_schedule.scheduled_rates = Vec::new();
+
+    let pool_manager = &ctx.accounts.pool_manager;
+    let rate_feed = &mut ctx.accounts.rate_feed;
+    rate_feed.version = RATE_FEED_VERSION;
+    rate_feed.bump = ctx.bumps.rate_feed;
+    rate_feed.pool_manager = pool_manager.key();
+    rate_feed.x_mint = pool_manager.x_mint;
+    rate_feed.base_mint = pool_manager.base_mint;
+    rate_feed.base_mint_decimals = pool_manager.base_mint_decimals;
+    rate_feed.exchange_rate = params.initial_exchange_rate;
+    rate_feed.last_update_timestamp = current_timestamp;
+    rate_feed.twap_exchange_rate = params.initial_exchange_rate;
+    rate_feed.realized_apy_bps = 0;
+    rate_feed.cumulative_exchange_rate = 0;
+    rate_feed.next_observation_index = 0;
+    rate_feed.observations = Vec::new();
+    rate_feed.record_observation()?;
+
+    // The history starts at the initial exchange rate
+    let rate_history = &mut ctx.accounts.rate_history;
+    rate_history.bump = ctx.bumps.rate_history;
+    rate_history.pool_manager = pool_manager.key();
+    rate_history.next_index = 0;
+    rate_history.checkpoints = Vec::new();
+    rate_history.record(
+        params.initial_exchange_rate,
+        current_timestamp,
+        pool_manager.checkpoint_interval,
+    )?;
 
     Ok(())
 }
+
+impl<'info> InitializePoolManager<'info> {
+    /// Creates the x mint under the x token program, initializing the
+    /// interest-bearing extension at `interest_rate` first when it is set.
+    fn create_x_mint(&self, decimals: u8, bump: u8, interest_rate: Option<i16>) -> Result<()> {
+        let extension_types: &[ExtensionType] = match interest_rate {
+            Some(_) => &[ExtensionType::InterestBearingConfig],
+            None => &[],
+        };
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+            extension_types,
+        )?;
+
+        let pool_manager_key = self.pool_manager.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[b"mint", pool_manager_key.as_ref(), &[bump]]];
+
+        self.create_pda_account(self.x_mint.to_account_info(), space, signer_seeds)?;
+
+        if let Some(interest_rate) = interest_rate {
+            // The pool manager keeps the rate in sync with the interval APR rate
+            invoke(
+                &interest_bearing_mint::instruction::initialize(
+                    &self.x_token_program.key(),
+                    &self.x_mint.key(),
+                    Some(pool_manager_key),
+                    interest_rate,
+                )?,
+                &[self.x_mint.to_account_info()],
+            )?;
+        }
+
+        initialize_mint2(
+            CpiContext::new(
+                self.x_token_program.to_account_info(),
+                InitializeMint2 {
+                    mint: self.x_mint.to_account_info(),
+                },
+            ),
+            decimals,
+            &pool_manager_key,
+            None,
+        )
+    }
+
+    fn create_dead_shares(&self, bump: u8) -> Result<()> {
+        let space =
+            ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&[])?;
+
+        let pool_manager_key = self.pool_manager.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[b"dead-shares", pool_manager_key.as_ref(), &[bump]]];
+
+        self.create_pda_account(self.dead_shares.to_account_info(), space, signer_seeds)?;
+
+        initialize_account3(CpiContext::new(
+            self.x_token_program.to_account_info(),
+            InitializeAccount3 {
+                account: self.dead_shares.to_account_info(),
+                mint: self.x_mint.to_account_info(),
+                authority: self.dead_shares.to_account_info(),
+            },
+        ))
+    }
+
+    /// Creates the PDA `account` with `space` bytes, owned by the x token
+    /// program. Anyone can send lamports to a PDA before it is created, which
+    /// makes `create_account` fail, so a funded PDA is topped up to rent
+    /// exemption, allocated and assigned instead.
+    fn create_pda_account(
+        &self,
+        account: AccountInfo<'info>,
+        space: usize,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let minimum_balance = Rent::get()?.minimum_balance(space);
+        let x_token_program = self.x_token_program.key();
+
+        if account.lamports() == 0 {
+            return create_account(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    CreateAccount {
+                        from: self.owner.to_account_info(),
+                        to: account,
+                    },
+                    signer_seeds,
+                ),
+                minimum_balance,
+                space as u64,
+                &x_token_program,
+            );
+        }
+
+        let lamports_diff = minimum_balance.saturating_sub(account.lamports());
+        if lamports_diff > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.owner.to_account_info(),
+                        to: account.clone(),
+                    },
+                ),
+                lamports_diff,
+            )?;
+        }
+
+        allocate(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Allocate {
+                    account_to_allocate: account.clone(),
+                },
+                signer_seeds,
+            ),
+            space as u64,
+        )?;
+
+        assign(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Assign {
+                    account_to_assign: account,
+                },
+                signer_seeds,
+            ),
+            &x_token_program,
+        )
+    }
+}
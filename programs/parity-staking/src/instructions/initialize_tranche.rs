@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{error::ParityStakingError, PoolManager, Tranche, TRANCHE_LENGTH};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct InitializeTrancheParams {
+    pub tranche_id: u64,
+    pub term_duration: i64,
+    pub annual_yield_bps: u64,
+}
+
+impl InitializeTrancheParams {
+    pub fn validate(&self, pool_manager: &PoolManager) -> Result<()> {
+        // Validate term duration
+        if self.term_duration <= 0 {
+            return err!(ParityStakingError::InvalidParam); // Ensure positions have a maturity
+        }
+
+        // Validate annual yield
+        if self.annual_yield_bps < pool_manager.annual_yield_bps {
+            return err!(ParityStakingError::InvalidParam); // Ensure the tranche rate is boosted over the pool rate
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(params: InitializeTrancheParams)]
+pub struct InitializeTranche<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        init,
+        payer = admin,
+        space = TRANCHE_LENGTH,
+        seeds = [
+            b"tranche",
+            pool_manager.key().as_ref(),
+            &params.tranche_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub tranche: Account<'info, Tranche>,
+    #[account(address = pool_manager.x_mint)]
+    pub x_mint: InterfaceAccount<'info, Mint>,
+    /// Holds the x tokens of the tranche's open positions
+    #[account(
+        init,
+        seeds = [b"tranche-vault", tranche.key().as_ref()],
+        bump,
+        payer = admin,
+        token::mint = x_mint,
+        token::authority = pool_manager,
+        token::token_program = x_token_program,
+    )]
+    pub tranche_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut, address = pool_manager.admin @ ParityStakingError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        address = pool_manager.x_token_program @ ParityStakingError::InvalidXTokenProgram,
+    )]
+    pub x_token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<InitializeTranche>, params: InitializeTrancheParams) -> Result<()> {
+    let pool_manager = &ctx.accounts.pool_manager;
+
+    params.validate(pool_manager)?;
+
+    let tranche = &mut ctx.accounts.tranche;
+    tranche.bump = ctx.bumps.tranche;
+    tranche.pool_manager = pool_manager.key();
+    tranche.tranche_id = params.tranche_id;
+    tranche.term_duration = params.term_duration;
+    // Bounded by MAX_INTERVAL_APR_RATE like the pool rate
+    tranche.interval_apr_rate =
+        pool_manager.calculate_interval_apr_rate(params.annual_yield_bps)?;
+    tranche.annual_yield_bps = params.annual_yield_bps;
+    tranche.active = true;
+    tranche.total_principal_amount = 0;
+    tranche.total_boost_paid = 0;
+
+    Ok(())
+}
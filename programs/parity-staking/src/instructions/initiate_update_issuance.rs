@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use parity_issuance::TokenManager;
+
+use crate::{ParityStakingError, PoolManager, ISSUANCE_UPDATE_TIMELOCK};
+
+#[derive(Accounts)]
+pub struct InitiateUpdateIssuance<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    /// CHECK: Must be an executable program, recorded as the pending issuance program
+    #[account(executable)]
+    pub new_issuance_program: UncheckedAccount<'info>,
+    /// CHECK: Owner is checked against the new issuance program and data is deserialized in the handler
+    #[account(owner = new_issuance_program.key() @ ParityStakingError::InvalidTokenManager)]
+    pub new_token_manager: UncheckedAccount<'info>,
+    #[account(address = pool_manager.owner @ ParityStakingError::InvalidOwner)]
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<InitiateUpdateIssuance>) -> Result<()> {
+    // Validate the new token manager manages the base mint
+    let data = ctx.accounts.new_token_manager.try_borrow_data()?;
+    let new_token_manager = TokenManager::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(ParityStakingError::InvalidTokenManager))?;
+    if new_token_manager.mint != ctx.accounts.pool_manager.base_mint {
+        return err!(ParityStakingError::InvalidTokenManager); // Ensure the token manager mints the base mint
+    }
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    let pool_manager = &mut ctx.accounts.pool_manager;
+    pool_manager.pending_issuance_program = ctx.accounts.new_issuance_program.key();
+    pool_manager.pending_token_manager = ctx.accounts.new_token_manager.key();
+    pool_manager.issuance_update_timestamp = current_timestamp
+        .checked_add(ISSUANCE_UPDATE_TIMELOCK)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+
+    Ok(())
+}
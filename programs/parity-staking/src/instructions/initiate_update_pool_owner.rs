@@ -6,7 +6,11 @@ use crate::{ParityStakingError, PoolManager};
 pub struct InitiateUpdatePoolOwner<'info> {
     #[account(
         mut,
-        seeds = [b"pool-manager"],
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
         bump = pool_manager.bump
     )]
     pub pool_manager: Account<'info, PoolManager>,
@@ -0,0 +1,259 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::{update_metadata_accounts_v2, Metadata as Metaplex, UpdateMetadataAccountsV2},
+    token::{
+        close_account, set_authority, spl_token::instruction::AuthorityType, transfer_checked,
+        CloseAccount, Mint, SetAuthority, Token, TokenAccount, TransferChecked,
+    },
+};
+use parity_issuance::{program::ParityIssuance, TokenManager};
+
+use crate::{
+    LegacyPoolManager, ParityStakingError, PoolManager, RateFeed, RateHistory, YieldSchedule,
+    LEGACY_POOL_MANAGER_LENGTH, POOL_MANAGER_LENGTH, RATE_FEED_LENGTH, RATE_FEED_VERSION,
+    RATE_HISTORY_LENGTH, YIELD_SCHEDULE_LENGTH,
+};
+
+#[derive(Accounts)]
+#[instruction(pool_id: u64)]
+pub struct MigratePoolManager<'info> {
+    /// CHECK: Deserialized in the handler with the legacy layout, then closed
+    #[account(
+        mut,
+        seeds = [b"pool-manager"],
+        bump,
+        owner = crate::ID,
+    )]
+    pub legacy_pool_manager: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = legacy_pool_manager,
+    )]
+    pub legacy_vault: Box<Account<'info, TokenAccount>>,
+    pub base_mint: Box<Account<'info, Mint>>,
+    /// The legacy x mint, handed over to the new pool manager
+    #[account(mut)]
+    pub x_mint: Box<Account<'info, Mint>>,
+    /// CHECK: Validate address by deriving pda
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), x_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [
+            b"pool-manager",
+            base_mint.key().as_ref(),
+            &pool_id.to_le_bytes(),
+        ],
+        bump,
+        payer = owner,
+        space = POOL_MANAGER_LENGTH,
+    )]
+    pub pool_manager: Box<Account<'info, PoolManager>>,
+    #[account(
+        init,
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump,
+        payer = owner,
+        space = YIELD_SCHEDULE_LENGTH,
+    )]
+    pub yield_schedule: Box<Account<'info, YieldSchedule>>,
+    #[account(
+        init,
+        seeds = [b"rate-feed", pool_manager.key().as_ref()],
+        bump,
+        payer = owner,
+        space = RATE_FEED_LENGTH,
+    )]
+    pub rate_feed: Box<Account<'info, RateFeed>>,
+    #[account(
+        init,
+        seeds = [b"rate-history", pool_manager.key().as_ref()],
+        bump,
+        payer = owner,
+        space = RATE_HISTORY_LENGTH,
+    )]
+    pub rate_history: Box<Account<'info, RateHistory>>,
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = base_mint,
+        associated_token::authority = pool_manager,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    /// Token manager of the base mint, bound to the pool for yield minting
+    #[account(
+        seeds = [b"token-manager"],
+        bump = token_manager.bump,
+        seeds::program = parity_issuance_program.key(),
+        constraint = token_manager.mint == base_mint.key() @ ParityStakingError::InvalidTokenManager,
+    )]
+    pub token_manager: Box<Account<'info, TokenManager>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metaplex>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub parity_issuance_program: Program<'info, ParityIssuance>,
+}
+
+/// Moves the pool that lived at the fixed `[b"pool-manager"]` seed to pool
+/// `pool_id` of its base mint. The x mint, its metadata and the vault balance
+/// are handed over to the new pool manager and the legacy account is closed.
+pub fn handler(ctx: Context<MigratePoolManager>, pool_id: u64) -> Result<()> {
+    let legacy_pool_manager = ctx.accounts.load_legacy_pool_manager()?;
+    if legacy_pool_manager.owner != ctx.accounts.owner.key() {
+        return err!(ParityStakingError::InvalidOwner);
+    }
+    if legacy_pool_manager.base_mint != ctx.accounts.base_mint.key() {
+        return err!(ParityStakingError::InvalidParam);
+    }
+    if legacy_pool_manager.x_mint != ctx.accounts.x_mint.key() {
+        return err!(ParityStakingError::InvalidXMintAddress);
+    }
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let mut pool_manager = PoolManager::from_legacy(
+        &legacy_pool_manager,
+        ctx.bumps.pool_manager,
+        pool_id,
+        ctx.accounts.x_mint.supply,
+        current_timestamp,
+    )?;
+    // Issuance binding
+    pool_manager.issuance_program = ctx.accounts.parity_issuance_program.key();
+    pool_manager.token_manager = ctx.accounts.token_manager.key();
+    let exchange_rate = pool_manager.calculate_exchange_rate(current_timestamp)?;
+    ctx.accounts.pool_manager.set_inner(pool_manager);
+
+    let legacy_bump = [ctx.bumps.legacy_pool_manager];
+    let legacy_signer_seeds: &[&[&[u8]]] = &[&[b"pool-manager", &legacy_bump]];
+    let legacy_authority = ctx.accounts.legacy_pool_manager.to_account_info();
+    let new_authority = ctx.accounts.pool_manager.key();
+
+    // Hand the x mint and its metadata over to the new pool manager
+    set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: legacy_authority.clone(),
+                account_or_mint: ctx.accounts.x_mint.to_account_info(),
+            },
+            legacy_signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        Some(new_authority),
+    )?;
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                update_authority: legacy_authority.clone(),
+            },
+            legacy_signer_seeds,
+        ),
+        Some(new_authority),
+        None,
+        None,
+        None,
+    )?;
+
+    // Move the vault balance and close the legacy vault
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.legacy_vault.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.base_mint.to_account_info(),
+                authority: legacy_authority.clone(),
+            },
+            legacy_signer_seeds,
+        ),
+        ctx.accounts.legacy_vault.amount,
+        ctx.accounts.base_mint.decimals,
+    )?;
+    close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.legacy_vault.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: legacy_authority,
+        },
+        legacy_signer_seeds,
+    ))?;
+
+    let pool_manager = &ctx.accounts.pool_manager;
+
+    let yield_schedule = &mut ctx.accounts.yield_schedule;
+    yield_schedule.bump = ctx.bumps.yield_schedule;
+    yield_schedule.pool_manager = pool_manager.key();
+    yield_schedule.scheduled_rates = Vec::new();
+
+    let rate_feed = &mut ctx.accounts.rate_feed;
+    rate_feed.version = RATE_FEED_VERSION;
+    rate_feed.bump = ctx.bumps.rate_feed;
+    rate_feed.pool_manager = pool_manager.key();
+    rate_feed.x_mint = pool_manager.x_mint;
+    rate_feed.base_mint = pool_manager.base_mint;
+    rate_feed.base_mint_decimals = pool_manager.base_mint_decimals;
+    rate_feed.exchange_rate = exchange_rate;
+    rate_feed.last_update_timestamp = current_timestamp;
+    rate_feed.twap_exchange_rate = exchange_rate;
+    rate_feed.realized_apy_bps = 0;
+    rate_feed.cumulative_exchange_rate = 0;
+    rate_feed.next_observation_index = 0;
+    rate_feed.observations = Vec::new();
+    rate_feed.record_observation()?;
+
+    // The history starts at the exchange rate at migration
+    let rate_history = &mut ctx.accounts.rate_history;
+    rate_history.bump = ctx.bumps.rate_history;
+    rate_history.pool_manager = pool_manager.key();
+    rate_history.next_index = 0;
+    rate_history.checkpoints = Vec::new();
+    rate_history.record(
+        exchange_rate,
+        current_timestamp,
+        pool_manager.checkpoint_interval,
+    )?;
+
+    ctx.accounts.close_legacy_pool_manager()
+}
+
+impl<'info> MigratePoolManager<'info> {
+    fn load_legacy_pool_manager(&self) -> Result<LegacyPoolManager> {
+        let data = self.legacy_pool_manager.try_borrow_data()?;
+        if data.len() != LEGACY_POOL_MANAGER_LENGTH || data[..8] != PoolManager::DISCRIMINATOR {
+            return err!(ParityStakingError::InvalidLegacyPoolManager);
+        }
+
+        LegacyPoolManager::deserialize(&mut &data[8..])
+            .map_err(|_| error!(ParityStakingError::InvalidLegacyPoolManager))
+    }
+
+    fn close_legacy_pool_manager(&self) -> Result<()> {
+        let legacy_pool_manager = self.legacy_pool_manager.to_account_info();
+        let owner = self.owner.to_account_info();
+
+        let lamports = legacy_pool_manager.lamports();
+        **owner.try_borrow_mut_lamports()? = owner
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        **legacy_pool_manager.try_borrow_mut_lamports()? = 0;
+
+        legacy_pool_manager.assign(&system_program::ID);
+        legacy_pool_manager.realloc(0, false)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use parity_issuance::cpi::{accounts::MintTokens, mint};
+
+use crate::{error::ParityStakingError, instructions::stake::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct MintAndStakeParams {
+    pub quantity: u64,
+    pub proof: Vec<[u8; 32]>,
+    pub min_x_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct MintAndStake<'info> {
+    pub stake: Stake<'info>,
+
+    // Issuance
+    /// CHECK: Token manager recorded on the pool manager, deserialized in the mint CPI call
+    #[account(
+        mut,
+        address = stake.pool_manager.token_manager @ ParityStakingError::InvalidTokenManager,
+    )]
+    pub token_manager: UncheckedAccount<'info>,
+    pub quote_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = stake.payer,
+    )]
+    pub payer_quote_mint_ata: Account<'info, TokenAccount>,
+    /// CHECK: This account is checked in the mint CPI call
+    #[account(mut)]
+    pub issuance_vault: UncheckedAccount<'info>,
+    /// CHECK: Issuance program recorded on the pool manager
+    #[account(
+        executable,
+        address = stake.pool_manager.issuance_program @ ParityStakingError::InvalidIssuanceProgram,
+    )]
+    pub parity_issuance_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<MintAndStake>, params: MintAndStakeParams) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    // Check if the quantity to mint is greater than zero
+    if params.quantity == 0 {
+        return err!(ParityStakingError::InvalidQuantity);
+    }
+
+    // Mint base tokens to the payer through the issuance program, which
+    // applies its own allow list, slot limit and mint fee
+    let base_balance_before = accounts.stake.payer_base_mint_ata.amount;
+
+    mint(
+        CpiContext::new(
+            accounts.parity_issuance_program.to_account_info(),
+            MintTokens {
+                token_manager: accounts.token_manager.to_account_info(),
+                mint: accounts.stake.base_mint.to_account_info(),
+                payer_mint_ata: accounts.stake.payer_base_mint_ata.to_account_info(),
+                quote_mint: accounts.quote_mint.to_account_info(),
+                payer_quote_mint_ata: accounts.payer_quote_mint_ata.to_account_info(),
+                vault: accounts.issuance_vault.to_account_info(),
+                payer: accounts.stake.payer.to_account_info(),
+                system_program: accounts.stake.system_program.to_account_info(),
+                token_program: accounts.stake.token_program.to_account_info(),
+                associated_token_program: accounts.stake.associated_token_program.to_account_info(),
+            },
+        ),
+        params.quantity,
+        params.proof,
+    )?;
+
+    accounts.stake.payer_base_mint_ata.reload()?;
+
+    // Only stake what was actually minted, net of the mint fee
+    let minted_amount = accounts
+        .stake
+        .payer_base_mint_ata
+        .amount
+        .checked_sub(base_balance_before)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+    msg!("Minted amount: {}", minted_amount);
+
+    let x_amount = accounts.stake.stake(minted_amount)?;
+
+    // Slippage check
+    if x_amount < params.min_x_amount {
+        return err!(ParityStakingError::SlippageExceeded);
+    }
+
+    Ok(())
+}
@@ -1,19 +1,81 @@
+pub mod accrue_yield;
+pub mod add_guardian;
+pub mod add_staker_entry;
+pub mod add_strategy;
+pub mod allocate_to_strategy;
+pub mod claim_unstake;
+pub mod close_term_position;
+pub mod deposit_rewards;
+pub mod emergency_withdraw_all;
+pub mod get_accrued_yield;
+pub mod get_effective_apy;
+pub mod get_trailing_apy;
 pub mod initialize_pool_manager;
+pub mod initialize_tranche;
+pub mod initiate_update_issuance;
 pub mod initiate_update_pool_owner;
+pub mod migrate_pool_manager;
+pub mod mint_and_stake;
+pub mod open_term_position;
+pub mod preview_stake;
+pub mod preview_unstake;
+pub mod refresh_rate_feed;
+pub mod remove_guardian;
+pub mod report_strategy_value;
+pub mod request_unstake;
 pub mod stake;
+pub mod toggle_active;
 pub mod unstake;
+pub mod unstake_and_redeem;
 pub mod update_annual_yield;
+pub mod update_issuance;
 pub mod update_pool_manager;
 pub mod update_pool_owner;
+pub mod update_staker_entry;
+pub mod update_strategy;
+pub mod update_tranche;
 pub mod update_xmint_metadata;
+pub mod update_yield_schedule;
 pub mod withdraw_excess_parity;
+pub mod withdraw_from_strategy;
 
+pub use accrue_yield::*;
+pub use add_guardian::*;
+pub use add_staker_entry::*;
+pub use add_strategy::*;
+pub use allocate_to_strategy::*;
+pub use claim_unstake::*;
+pub use close_term_position::*;
+pub use deposit_rewards::*;
+pub use emergency_withdraw_all::*;
+pub use get_accrued_yield::*;
+pub use get_effective_apy::*;
+pub use get_trailing_apy::*;
 pub use initialize_pool_manager::*;
+pub use initialize_tranche::*;
+pub use initiate_update_issuance::*;
 pub use initiate_update_pool_owner::*;
+pub use migrate_pool_manager::*;
+pub use mint_and_stake::*;
+pub use open_term_position::*;
+pub use preview_stake::*;
+pub use preview_unstake::*;
+pub use refresh_rate_feed::*;
+pub use remove_guardian::*;
+pub use report_strategy_value::*;
+pub use request_unstake::*;
 pub use stake::*;
+pub use toggle_active::*;
 pub use unstake::*;
+pub use unstake_and_redeem::*;
 pub use update_annual_yield::*;
+pub use update_issuance::*;
 pub use update_pool_manager::*;
 pub use update_pool_owner::*;
+pub use update_staker_entry::*;
+pub use update_strategy::*;
+pub use update_tranche::*;
 pub use update_xmint_metadata::*;
+pub use update_yield_schedule::*;
 pub use withdraw_excess_parity::*;
+pub use withdraw_from_strategy::*;
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, TokenAccount, TransferChecked};
+
+use crate::{
+    error::ParityStakingError, instructions::stake::*, TermPosition, Tranche, TERM_POSITION_LENGTH,
+};
+
+#[derive(Accounts)]
+#[instruction(quantity: u64, position_id: u64)]
+pub struct OpenTermPosition<'info> {
+    pub stake: Stake<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"tranche",
+            stake.pool_manager.key().as_ref(),
+            &tranche.tranche_id.to_le_bytes(),
+        ],
+        bump = tranche.bump,
+        constraint = tranche.pool_manager == stake.pool_manager.key() @ ParityStakingError::InvalidTranche,
+    )]
+    pub tranche: Account<'info, Tranche>,
+    #[account(
+        mut,
+        seeds = [b"tranche-vault", tranche.key().as_ref()],
+        bump,
+    )]
+    pub tranche_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init,
+        seeds = [
+            b"term-position",
+            stake.pool_manager.key().as_ref(),
+            stake.payer.key().as_ref(),
+            &position_id.to_le_bytes(),
+        ],
+        bump,
+        payer = stake.payer,
+        space = TERM_POSITION_LENGTH,
+    )]
+    pub term_position: Account<'info, TermPosition>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenTermPosition>, quantity: u64, position_id: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    if !accounts.tranche.active {
+        return err!(ParityStakingError::TrancheInactive);
+    }
+
+    // Stakes at the pool rate, the boost is only minted at maturity
+    let x_amount = accounts.stake.stake(quantity)?;
+
+    let pool_manager = &accounts.stake.pool_manager;
+    let principal_amount = quantity
+        .checked_sub(pool_manager.calculate_entry_fee(quantity)?)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+
+    // Lock the x tokens in the tranche vault until the position is closed
+    transfer_checked(
+        CpiContext::new(
+            accounts.stake.x_token_program.to_account_info(),
+            TransferChecked {
+                from: accounts.stake.payer_x_mint_ata.to_account_info(),
+                to: accounts.tranche_vault.to_account_info(),
+                mint: accounts.stake.x_mint.to_account_info(),
+                authority: accounts.stake.payer.to_account_info(),
+            },
+        ),
+        x_amount,
+        pool_manager.x_mint_decimals,
+    )?;
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let tranche = &mut accounts.tranche;
+    let maturity_timestamp = current_timestamp
+        .checked_add(tranche.term_duration)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+
+    let term_position = &mut accounts.term_position;
+    term_position.bump = ctx.bumps.term_position;
+    term_position.owner = accounts.stake.payer.key();
+    term_position.pool_manager = pool_manager.key();
+    term_position.tranche = tranche.key();
+    term_position.position_id = position_id;
+    term_position.principal_amount = principal_amount;
+    term_position.x_amount = x_amount;
+    term_position.interval_apr_rate = tranche.interval_apr_rate;
+    term_position.start_timestamp = current_timestamp;
+    term_position.maturity_timestamp = maturity_timestamp;
+
+    tranche.total_principal_amount = tranche
+        .total_principal_amount
+        .checked_add(principal_amount)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+
+    msg!("Term position matures at: {}", maturity_timestamp);
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{PoolManager, StakePreview, YieldSchedule};
+
+#[derive(Accounts)]
+pub struct PreviewStake<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump = yield_schedule.bump,
+    )]
+    pub yield_schedule: Account<'info, YieldSchedule>,
+}
+
+pub fn handler(ctx: Context<PreviewStake>, quantity: u64) -> Result<StakePreview> {
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    // Quote against the rates a stake would see without persisting them
+    let mut pool_manager = (*ctx.accounts.pool_manager).clone();
+    pool_manager.apply_scheduled_rates(
+        &ctx.accounts.yield_schedule.scheduled_rates,
+        current_timestamp,
+    )?;
+
+    pool_manager.preview_stake(quantity, current_timestamp)
+}
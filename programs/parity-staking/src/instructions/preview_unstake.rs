@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{PoolManager, UnstakePreview, YieldSchedule};
+
+#[derive(Accounts)]
+pub struct PreviewUnstake<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump = yield_schedule.bump,
+    )]
+    pub yield_schedule: Account<'info, YieldSchedule>,
+}
+
+pub fn handler(ctx: Context<PreviewUnstake>, quantity: u64) -> Result<UnstakePreview> {
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    // Quote against the rates an unstake would see without persisting them
+    let mut pool_manager = (*ctx.accounts.pool_manager).clone();
+    pool_manager.apply_scheduled_rates(
+        &ctx.accounts.yield_schedule.scheduled_rates,
+        current_timestamp,
+    )?;
+
+    pool_manager.preview_unstake(quantity, current_timestamp)
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{PoolManager, RateFeed, YieldSchedule};
+
+#[derive(Accounts)]
+pub struct RefreshRateFeed<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump = yield_schedule.bump,
+    )]
+    pub yield_schedule: Account<'info, YieldSchedule>,
+    #[account(
+        mut,
+        seeds = [b"rate-feed", pool_manager.key().as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Account<'info, RateFeed>,
+}
+
+pub fn handler(ctx: Context<RefreshRateFeed>) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    // Compounds across the scheduled rates that have passed without landing them
+    let mut pool_manager = (*ctx.accounts.pool_manager).clone();
+    pool_manager.apply_scheduled_rates(
+        &ctx.accounts.yield_schedule.scheduled_rates,
+        current_timestamp,
+    )?;
+
+    let rate_feed = &mut ctx.accounts.rate_feed;
+    rate_feed.refresh(&pool_manager, current_timestamp)?;
+
+    msg!(
+        "Exchange rate: {}, TWAP: {}, realized APY: {} bps",
+        rate_feed.exchange_rate,
+        rate_feed.twap_exchange_rate,
+        rate_feed.realized_apy_bps
+    );
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use crate::{error::ParityStakingError, Guardian, PoolManager};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveGuardian<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"guardian", pool_manager.key().as_ref(), guardian.wallet.as_ref()],
+        bump
+    )]
+    pub guardian: Account<'info, Guardian>,
+    #[account(mut, address = pool_manager.admin @ ParityStakingError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    error::ParityStakingError, instructions::withdraw_from_strategy::pay_performance_fee,
+    strategy_interface::StrategyContext, PoolManager, RateFeed, Strategy,
+};
+
+#[derive(Accounts)]
+pub struct ReportStrategyValue<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        seeds = [
+            b"strategy",
+            pool_manager.key().as_ref(),
+            strategy_program.key().as_ref(),
+        ],
+        bump = strategy.bump,
+    )]
+    pub strategy: Account<'info, Strategy>,
+    #[account(
+        mut,
+        seeds = [b"rate-feed", pool_manager.key().as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Account<'info, RateFeed>,
+    #[account(
+        mint::decimals = pool_manager.base_mint_decimals,
+        address = pool_manager.base_mint,
+    )]
+    pub base_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = pool_manager,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = pool_manager.treasury @ ParityStakingError::InvalidTreasury,
+        token::mint = base_mint,
+    )]
+    pub treasury: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Strategy program whitelisted by the strategy account
+    #[account(executable)]
+    pub strategy_program: UncheckedAccount<'info>,
+    /// CHECK: Signs for the pool in the strategy program, with no authority over the vault or the mints
+    #[account(
+        seeds = [b"strategy-authority", strategy.key().as_ref()],
+        bump,
+    )]
+    pub strategy_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = strategy_authority,
+    )]
+    pub strategy_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Books the value the strategy reports holding for the pool, permissionless.
+/// Gains pay the performance fee to the treasury. The strategy's own accounts
+/// are passed as remaining accounts.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ReportStrategyValue<'info>>) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    let value = StrategyContext {
+        strategy_program: accounts.strategy_program.to_account_info(),
+        strategy: accounts.strategy.key(),
+        strategy_authority: accounts.strategy_authority.to_account_info(),
+        strategy_authority_bump: ctx.bumps.strategy_authority,
+        strategy_token_account: accounts.strategy_token_account.to_account_info(),
+        vault: accounts.vault.to_account_info(),
+        base_mint: accounts.base_mint.to_account_info(),
+        token_program: accounts.token_program.to_account_info(),
+        remaining_accounts: ctx.remaining_accounts,
+    }
+    .report_value()?;
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let strategy = &mut accounts.strategy;
+
+    let performance_fee = accounts.pool_manager.record_strategy_value(
+        strategy.reported_value,
+        value,
+        current_timestamp,
+    )?;
+    strategy.reported_value = value;
+    strategy.last_report_timestamp = current_timestamp;
+
+    pay_performance_fee(
+        &mut accounts.pool_manager,
+        &accounts.vault,
+        accounts.treasury.as_ref(),
+        &accounts.base_mint,
+        &accounts.token_program,
+        performance_fee,
+    )?;
+
+    // Losses move a streaming pool's exchange rate right away
+    accounts
+        .rate_feed
+        .refresh(&accounts.pool_manager, current_timestamp)?;
+
+    msg!(
+        "Strategy value: {}, pool strategy value: {}",
+        value,
+        accounts.pool_manager.strategy_value
+    );
+
+    Ok(())
+}
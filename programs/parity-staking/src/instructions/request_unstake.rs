@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ParityStakingError, instructions::unstake::*, UnstakePath, UnstakeTicket,
+    UNSTAKE_TICKET_LENGTH,
+};
+
+#[derive(Accounts)]
+#[instruction(quantity: u64, ticket_id: u64)]
+pub struct RequestUnstake<'info> {
+    pub unstake: Unstake<'info>,
+    #[account(
+        init,
+        seeds = [
+            b"unstake-ticket",
+            unstake.pool_manager.key().as_ref(),
+            unstake.payer.key().as_ref(),
+            &ticket_id.to_le_bytes(),
+        ],
+        bump,
+        payer = unstake.payer,
+        space = UNSTAKE_TICKET_LENGTH,
+    )]
+    pub unstake_ticket: Account<'info, UnstakeTicket>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RequestUnstake>, quantity: u64, ticket_id: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    // The base amount is fixed at the current exchange rate and stops accruing yield
+    let base_amount = accounts.unstake.burn_and_accrue(quantity)?;
+    let (base_amount, exit_fee) = accounts.unstake.charge_exit_fee(base_amount)?;
+
+    let pool_manager = &mut accounts.unstake.pool_manager;
+    pool_manager.pending_unstake_amount = pool_manager
+        .pending_unstake_amount
+        .checked_add(base_amount)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let claimable_timestamp = current_timestamp
+        .checked_add(pool_manager.unstake_cooldown)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+
+    let unstake_ticket = &mut accounts.unstake_ticket;
+    unstake_ticket.bump = ctx.bumps.unstake_ticket;
+    unstake_ticket.owner = accounts.unstake.payer.key();
+    unstake_ticket.pool_manager = pool_manager.key();
+    unstake_ticket.ticket_id = ticket_id;
+    unstake_ticket.x_amount = quantity;
+    unstake_ticket.base_amount = base_amount;
+    unstake_ticket.request_timestamp = current_timestamp;
+    unstake_ticket.claimable_timestamp = claimable_timestamp;
+
+    msg!("Unstake ticket claimable at: {}", claimable_timestamp);
+
+    accounts
+        .unstake
+        .emit_unstake_event(quantity, base_amount, exit_fee, UnstakePath::Standard)?;
+
+    Ok(())
+}
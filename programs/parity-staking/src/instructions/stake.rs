@@ -1,15 +1,45 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{mint_to, transfer_checked, Mint, MintTo, Token, TokenAccount, TransferChecked},
+    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+    token_interface::{self, MintTo, TokenInterface},
 };
 
-use crate::{error::ParityStakingError, PoolManager};
+use crate::{
+    error::ParityStakingError, instructions::update_annual_yield::sync_x_mint_interest_rate,
+    FeeDestination, PoolManager, RateFeed, RateHistory, StakeEvent, StakerEntry, YieldSchedule,
+};
 
 #[derive(Accounts)]
 pub struct Stake<'info> {
-    #[account(mut, seeds = [b"pool-manager"], bump)]
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
     pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump = yield_schedule.bump,
+    )]
+    pub yield_schedule: Account<'info, YieldSchedule>,
+    #[account(
+        mut,
+        seeds = [b"rate-feed", pool_manager.key().as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Account<'info, RateFeed>,
+    #[account(
+        mut,
+        seeds = [b"rate-history", pool_manager.key().as_ref()],
+        bump = rate_history.bump,
+    )]
+    pub rate_history: Account<'info, RateHistory>,
     #[account(
         mut,
         mint::decimals = pool_manager.base_mint_decimals,
@@ -28,88 +58,191 @@ pub struct Stake<'info> {
     #[account(
         mut,
         address = pool_manager.x_mint,
-        seeds = [b"mint"],
-        bump,
         mint::decimals = pool_manager.x_mint_decimals,
         mint::authority = pool_manager,
     )]
-    pub x_mint: Account<'info, Mint>,
+    pub x_mint: InterfaceAccount<'info, token_interface::Mint>,
     #[account(
         mut,
         associated_token::mint = x_mint,
         associated_token::authority = payer,
+        associated_token::token_program = x_token_program,
     )]
-    pub payer_x_mint_ata: Account<'info, TokenAccount>,
+    pub payer_x_mint_ata: InterfaceAccount<'info, token_interface::TokenAccount>,
     #[account(
         mut,
         associated_token::mint = base_mint,
         associated_token::authority = pool_manager,
     )]
     pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = pool_manager.treasury @ ParityStakingError::InvalidTreasury,
+        token::mint = base_mint,
+    )]
+    pub treasury: Option<Account<'info, TokenAccount>>,
+    /// Required while the allowlist or a wallet deposit cap is enabled
+    #[account(
+        mut,
+        seeds = [b"staker-entry", pool_manager.key().as_ref(), payer.key().as_ref()],
+        bump = staker_entry.bump,
+    )]
+    pub staker_entry: Option<Account<'info, StakerEntry>>,
 
     // Other
     #[account(mut)]
     pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
+    #[account(
+        address = pool_manager.x_token_program @ ParityStakingError::InvalidXTokenProgram,
+    )]
+    pub x_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 pub fn handler(ctx: Context<Stake>, quantity: u64) -> Result<()> {
-    let pool_manager = &mut ctx.accounts.pool_manager;
+    ctx.accounts.stake(quantity)?;
 
-    let current_timestamp: i64 = Clock::get()?.unix_timestamp;
-    let total_vault_amount = ctx.accounts.vault.amount;
+    Ok(())
+}
 
-    // Check if the quantity to stake is greater than zero
-    if quantity == 0 {
-        return err!(ParityStakingError::InvalidQuantity);
-    }
+impl<'info> Stake<'info> {
+    /// Moves `quantity` base tokens from the payer into the vault and mints the
+    /// matching x amount, net of the entry fee. Returns the x amount minted.
+    pub fn stake(&mut self, quantity: u64) -> Result<u64> {
+        let current_timestamp: i64 = Clock::get()?.unix_timestamp;
+        self.yield_schedule
+            .apply(&mut self.pool_manager, current_timestamp)?;
+        // Scheduled rate changes land here, so the x mint rate follows them
+        sync_x_mint_interest_rate(&self.pool_manager, &self.x_mint, &self.x_token_program)?;
 
-    // Check if deposit exceeds the deposit cap or limit
-    pool_manager.check_excessive_deposit(quantity, total_vault_amount)?;
-
-    let x_amount = pool_manager.calculate_output_amount(quantity, current_timestamp, true)?;
-    msg!("X amount: {}", x_amount);
-
-    // Minting
-    let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
-    let signer_seeds: &[&[&[u8]]] = &[&[b"pool-manager", &[bump]]];
-
-    mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                authority: pool_manager.to_account_info(),
-                to: ctx.accounts.payer_x_mint_ata.to_account_info(),
-                mint: ctx.accounts.x_mint.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        x_amount,
-    )?;
-
-    let base_amount = quantity;
-
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.payer_base_mint_ata.to_account_info(),
-                to: ctx.accounts.vault.to_account_info(),
-                mint: ctx.accounts.base_mint.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
-            },
-        ),
-        base_amount,
-        pool_manager.base_mint_decimals,
-    )?;
-
-    // Update token_manager
-    pool_manager.base_balance = pool_manager
-        .base_balance
-        .checked_add(base_amount)
-        .ok_or(ParityStakingError::CalculationOverflow)?;
+        let pool_manager = &mut self.pool_manager;
 
-    Ok(())
+        // Check if the quantity to stake is greater than zero
+        if quantity == 0 {
+            return err!(ParityStakingError::InvalidQuantity);
+        }
+        pool_manager.check_stake_active()?;
+        pool_manager.check_minimum_stake(quantity)?;
+
+        let fee_amount = pool_manager.calculate_entry_fee(quantity)?;
+        let base_amount = quantity
+            .checked_sub(fee_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        // The cap is measured on principal, which only grows by the net amount
+        pool_manager.check_excessive_deposit(base_amount)?;
+
+        // Check the wallet against the allowlist and its own deposit cap
+        match self.staker_entry.as_mut() {
+            Some(staker_entry) => staker_entry.record_stake(base_amount, pool_manager)?,
+            None if pool_manager.is_staker_entry_required() => {
+                return err!(ParityStakingError::StakerEntryRequired);
+            }
+            None => {}
+        }
+
+        // Stakers receive the fee before the stake mints, so that it does not share in it
+        if pool_manager.entry_fee_destination == FeeDestination::Stakers {
+            pool_manager.distribute_entry_fee(fee_amount, current_timestamp)?;
+        }
+
+        let x_amount =
+            pool_manager.calculate_output_amount(base_amount, current_timestamp, true)?;
+        msg!("X amount: {}", x_amount);
+
+        // Deposits too small to mint a single x unit would be lost to rounding
+        if x_amount == 0 {
+            return err!(ParityStakingError::InvalidQuantity);
+        }
+
+        // Minting
+        let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
+        let base_mint_key = pool_manager.base_mint;
+        let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"pool-manager",
+            base_mint_key.as_ref(),
+            &pool_id_bytes,
+            &[bump],
+        ]];
+
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                self.x_token_program.to_account_info(),
+                MintTo {
+                    authority: pool_manager.to_account_info(),
+                    to: self.payer_x_mint_ata.to_account_info(),
+                    mint: self.x_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            x_amount,
+        )?;
+
+        // A fee left to the stakers is paid into the vault along with the stake
+        let (vault_amount, treasury_amount) = match pool_manager.entry_fee_destination {
+            FeeDestination::Stakers => (quantity, 0),
+            FeeDestination::Treasury => (base_amount, fee_amount),
+        };
+
+        transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.payer_base_mint_ata.to_account_info(),
+                    to: self.vault.to_account_info(),
+                    mint: self.base_mint.to_account_info(),
+                    authority: self.payer.to_account_info(),
+                },
+            ),
+            vault_amount,
+            pool_manager.base_mint_decimals,
+        )?;
+
+        if treasury_amount > 0 {
+            let treasury = self
+                .treasury
+                .as_ref()
+                .ok_or(ParityStakingError::InvalidTreasury)?;
+
+            transfer_checked(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.payer_base_mint_ata.to_account_info(),
+                        to: treasury.to_account_info(),
+                        mint: self.base_mint.to_account_info(),
+                        authority: self.payer.to_account_info(),
+                    },
+                ),
+                treasury_amount,
+                pool_manager.base_mint_decimals,
+            )?;
+        }
+
+        // Update token_manager
+        pool_manager.record_deposit(base_amount)?;
+        pool_manager.record_x_minted(x_amount)?;
+        pool_manager.top_up_liquidity_buffer(base_amount);
+
+        self.rate_feed.refresh(pool_manager, current_timestamp)?;
+        self.rate_history.record(
+            self.rate_feed.exchange_rate,
+            current_timestamp,
+            pool_manager.checkpoint_interval,
+        )?;
+
+        emit!(StakeEvent {
+            pool_manager: pool_manager.key(),
+            payer: self.payer.key(),
+            base_amount: quantity,
+            x_amount,
+            fee_amount,
+            exchange_rate: self.rate_feed.exchange_rate,
+        });
+
+        Ok(x_amount)
+    }
 }
@@ -0,0 +1,39 @@
+use crate::{Guardian, PoolManager};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ToggleActive<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"guardian", pool_manager.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub guardian: Option<Account<'info, Guardian>>,
+}
+
+pub fn handler(ctx: Context<ToggleActive>, stake_active: bool, unstake_active: bool) -> Result<()> {
+    let pool_manager = &mut ctx.accounts.pool_manager;
+
+    let is_admin = pool_manager.admin == ctx.accounts.authority.key();
+    let is_guardian = ctx.accounts.guardian.is_some();
+
+    pool_manager.update_pool_status(stake_active, unstake_active, is_admin, is_guardian)?;
+
+    msg!(
+        "Stake active: {}, unstake active: {}",
+        stake_active,
+        unstake_active
+    );
+
+    Ok(())
+}
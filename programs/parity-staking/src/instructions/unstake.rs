@@ -1,20 +1,51 @@
-use crate::{error::ParityStakingError, PoolManager};
+use crate::{
+    error::ParityStakingError, instructions::update_annual_yield::sync_x_mint_interest_rate,
+    FeeDestination, PoolManager, RateFeed, RateHistory, StakerEntry, UnstakeEvent, UnstakePath,
+    YieldSchedule, DEAD_SHARES,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{burn, transfer_checked, Burn, Mint, Token, TokenAccount, TransferChecked},
-};
-use parity_issuance::{
-    cpi::{accounts::MintAdminTokens, mint_admin},
-    program::ParityIssuance,
+    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+    token_interface::{self, Burn, TokenInterface},
 };
+use parity_issuance::cpi::{accounts::MintAdminTokens, mint_admin};
 
 #[derive(Accounts)]
 pub struct Unstake<'info> {
-    #[account(mut, seeds = [b"pool-manager"], bump)]
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
     pub pool_manager: Account<'info, PoolManager>,
-    /// CHECK: This account is checked in the mint_admin CPI call
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump = yield_schedule.bump,
+    )]
+    pub yield_schedule: Account<'info, YieldSchedule>,
+    #[account(
+        mut,
+        seeds = [b"rate-feed", pool_manager.key().as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Account<'info, RateFeed>,
+    #[account(
+        mut,
+        seeds = [b"rate-history", pool_manager.key().as_ref()],
+        bump = rate_history.bump,
+    )]
+    pub rate_history: Account<'info, RateHistory>,
+    /// CHECK: Token manager recorded on the pool manager, deserialized in the mint_admin CPI call
+    #[account(
+        mut,
+        address = pool_manager.token_manager @ ParityStakingError::InvalidTokenManager,
+    )]
     pub token_manager: UncheckedAccount<'info>,
     #[account(
         mut,
@@ -33,122 +64,368 @@ pub struct Unstake<'info> {
     #[account(
         mut,
         address = pool_manager.x_mint,
-        seeds = [b"mint"],
-        bump,
         mint::decimals = pool_manager.x_mint_decimals,
         mint::authority = pool_manager,
     )]
-    pub x_mint: Account<'info, Mint>,
+    pub x_mint: InterfaceAccount<'info, token_interface::Mint>,
     #[account(
         mut,
         associated_token::mint = x_mint,
         associated_token::authority = payer,
+        associated_token::token_program = x_token_program,
     )]
-    pub payer_x_mint_ata: Account<'info, TokenAccount>,
+    pub payer_x_mint_ata: InterfaceAccount<'info, token_interface::TokenAccount>,
     #[account(
         mut,
         associated_token::mint = base_mint,
         associated_token::authority = pool_manager,
     )]
     pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = pool_manager.treasury @ ParityStakingError::InvalidTreasury,
+        token::mint = base_mint,
+    )]
+    pub treasury: Option<Account<'info, TokenAccount>>,
+    /// Releases the unstaked base from the wallet deposit cap when passed
+    #[account(
+        mut,
+        seeds = [b"staker-entry", pool_manager.key().as_ref(), payer.key().as_ref()],
+        bump = staker_entry.bump,
+    )]
+    pub staker_entry: Option<Account<'info, StakerEntry>>,
 
     // Other
     #[account(mut)]
     pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
+    #[account(
+        address = pool_manager.x_token_program @ ParityStakingError::InvalidXTokenProgram,
+    )]
+    pub x_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub parity_issuance_program: Program<'info, ParityIssuance>,
+    /// CHECK: Minter authorization of the pool manager, checked in the mint_admin CPI call
+    pub minter_authorization: Option<UncheckedAccount<'info>>,
+    /// CHECK: Issuance program recorded on the pool manager
+    #[account(
+        executable,
+        address = pool_manager.issuance_program @ ParityStakingError::InvalidIssuanceProgram,
+    )]
+    pub parity_issuance_program: UncheckedAccount<'info>,
 }
 
-pub fn handler(ctx: Context<Unstake>, quantity: u64) -> Result<()> {
-    let pool_manager = &mut ctx.accounts.pool_manager;
-    let x_mint = &mut ctx.accounts.x_mint;
-    
+pub fn handler(ctx: Context<Unstake>, quantity: u64) -> Result<UnstakePath> {
+    let (base_amount, unstake_path) = ctx.accounts.unstake(quantity)?;
+
+    msg!(
+        "Unstaked {} through the {:?} path",
+        base_amount,
+        unstake_path
+    );
+
+    Ok(unstake_path)
+}
 
-    let current_timestamp = Clock::get()?.unix_timestamp;
-    let x_amount = quantity;
+impl<'info> Unstake<'info> {
+    /// Burns `quantity` x tokens from the payer, mints any outstanding yield into
+    /// the vault and pays out the base amount minus the exit fee. While a
+    /// cooldown is set the payout comes from the liquidity buffer minus the
+    /// instant unstake fee. Returns the base amount paid out and the path taken.
+    pub fn unstake(&mut self, quantity: u64) -> Result<(u64, UnstakePath)> {
+        let base_amount = self.burn_and_accrue(quantity)?;
+        let (base_amount, exit_fee) = self.charge_exit_fee(base_amount)?;
 
-     // Check if the quantity to unstake is greater than zero
-     if x_amount == 0 {
-        return err!(ParityStakingError::InvalidQuantity);
+        let (unstake_path, fee_amount) = self.pool_manager.calculate_unstake_fee(base_amount)?;
+
+        self.pay_out(quantity, base_amount, exit_fee, unstake_path, fee_amount)
     }
 
-    let initial_x_mint_supply = x_mint.supply;
+    /// Burns `quantity` x tokens from the payer and pays out the base amount
+    /// minus the exit fee through the standard path, whatever the cooldown, so
+    /// that no instant unstake fee is charged. Returns the base amount paid out.
+    pub fn unstake_standard(&mut self, quantity: u64) -> Result<u64> {
+        let base_amount = self.burn_and_accrue(quantity)?;
+        let (base_amount, exit_fee) = self.charge_exit_fee(base_amount)?;
 
-    // Burning
-    let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
-    let signer_seeds: &[&[&[u8]]] = &[&[b"pool-manager", &[bump]]];
+        let (output_amount, _) =
+            self.pay_out(quantity, base_amount, exit_fee, UnstakePath::Standard, 0)?;
 
-    burn(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Burn {
-                authority: ctx.accounts.payer.to_account_info(),
-                from: ctx.accounts.payer_x_mint_ata.to_account_info(),
-                mint: x_mint.to_account_info(),
-            },
-        ),
-        x_amount,
-    )?;
+        Ok(output_amount)
+    }
+
+    /// Pays out `base_amount` minus the unstake path's `fee_amount` from the
+    /// vault, or from the liquidity buffer on the instant path, and emits the
+    /// unstake event.
+    fn pay_out(
+        &mut self,
+        quantity: u64,
+        base_amount: u64,
+        exit_fee: u64,
+        unstake_path: UnstakePath,
+        fee_amount: u64,
+    ) -> Result<(u64, UnstakePath)> {
+        let output_amount = base_amount
+            .checked_sub(fee_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
 
-    // Mint Base into pool
-    let vault_balance = ctx.accounts.vault.amount; // Get the actual vault balance
-    let amount_to_mint =
-        pool_manager.calculate_amount_to_mint(initial_x_mint_supply, current_timestamp, vault_balance)?;
-    msg!("Amount to mint: {}", amount_to_mint);
+        if unstake_path == UnstakePath::Instant {
+            self.pool_manager.use_liquidity_buffer(output_amount)?;
+        }
+
+        self.transfer_from_vault(output_amount, false)?;
+        self.pool_manager.record_withdrawal(output_amount)?;
+
+        if fee_amount > 0 {
+            self.distribute_fee(
+                fee_amount,
+                self.pool_manager.instant_unstake_fee_destination,
+            )?;
+        }
+
+        self.emit_unstake_event(
+            quantity,
+            output_amount,
+            exit_fee
+                .checked_add(fee_amount)
+                .ok_or(ParityStakingError::CalculationOverflow)?,
+            unstake_path,
+        )?;
+
+        Ok((output_amount, unstake_path))
+    }
+
+    /// Takes the exit fee out of `base_amount` and distributes it. Returns the
+    /// base amount left and the fee.
+    pub fn charge_exit_fee(&mut self, base_amount: u64) -> Result<(u64, u64)> {
+        let fee_amount = self.pool_manager.calculate_exit_fee(base_amount)?;
+
+        if fee_amount > 0 {
+            self.distribute_fee(fee_amount, self.pool_manager.exit_fee_destination)?;
+        }
+
+        let base_amount = base_amount
+            .checked_sub(fee_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok((base_amount, fee_amount))
+    }
+
+    /// Refreshes the rate feed, checkpoints the rate history and emits the
+    /// unstake event at the resulting exchange rate.
+    pub fn emit_unstake_event(
+        &mut self,
+        x_amount: u64,
+        base_amount: u64,
+        fee_amount: u64,
+        unstake_path: UnstakePath,
+    ) -> Result<()> {
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        self.rate_feed
+            .refresh(&self.pool_manager, current_timestamp)?;
+        self.rate_history.record(
+            self.rate_feed.exchange_rate,
+            current_timestamp,
+            self.pool_manager.checkpoint_interval,
+        )?;
+
+        emit!(UnstakeEvent {
+            pool_manager: self.pool_manager.key(),
+            payer: self.payer.key(),
+            x_amount,
+            base_amount,
+            fee_amount,
+            unstake_path,
+            exchange_rate: self.rate_feed.exchange_rate,
+        });
+
+        Ok(())
+    }
+
+    /// Sends the fee to the treasury or leaves it in the vault for the
+    /// remaining stakers by raising the exchange rate.
+    fn distribute_fee(&mut self, fee_amount: u64, fee_destination: FeeDestination) -> Result<()> {
+        match fee_destination {
+            FeeDestination::Treasury => {
+                self.transfer_from_vault(fee_amount, true)?;
+                self.pool_manager.record_withdrawal(fee_amount)
+            }
+            FeeDestination::Stakers => {
+                let current_timestamp = Clock::get()?.unix_timestamp;
+                self.x_mint.reload()?;
+
+                // Only the dead shares are left, so nobody can receive it
+                if self.x_mint.supply > DEAD_SHARES {
+                    self.pool_manager.distribute_to_stakers(
+                        fee_amount,
+                        self.x_mint.supply,
+                        current_timestamp,
+                    )
+                } else {
+                    // The fee stays in the vault as excess
+                    self.pool_manager.record_withdrawal(fee_amount)
+                }
+            }
+        }
+    }
+
+    /// Transfers `base_amount` from the vault to the payer, or to the treasury.
+    pub fn transfer_from_vault(&mut self, base_amount: u64, to_treasury: bool) -> Result<()> {
+        let to = if to_treasury {
+            self.treasury
+                .as_ref()
+                .ok_or(ParityStakingError::InvalidTreasury)?
+                .to_account_info()
+        } else {
+            self.payer_base_mint_ata.to_account_info()
+        };
+
+        let pool_manager = &mut self.pool_manager;
+        let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
+        let base_mint_key = pool_manager.base_mint;
+        let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"pool-manager",
+            base_mint_key.as_ref(),
+            &pool_id_bytes,
+            &[bump],
+        ]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.vault.to_account_info(),
+                    to,
+                    mint: self.base_mint.to_account_info(),
+                    authority: pool_manager.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            base_amount,
+            pool_manager.base_mint_decimals,
+        )?;
+
+        Ok(())
+    }
+
+    /// Mints `base_amount` base tokens into the vault through the issuance program.
+    pub fn mint_to_vault(&mut self, base_amount: u64) -> Result<()> {
+        let pool_manager = &self.pool_manager;
+        let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
+        let base_mint_key = pool_manager.base_mint;
+        let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"pool-manager",
+            base_mint_key.as_ref(),
+            &pool_id_bytes,
+            &[bump],
+        ]];
 
-    if amount_to_mint > 0 {
         let mint_context = CpiContext::new_with_signer(
-            ctx.accounts.parity_issuance_program.to_account_info(),
+            self.parity_issuance_program.to_account_info(),
             MintAdminTokens {
-                token_manager: ctx.accounts.token_manager.to_account_info(),
-                minter_mint_ata: ctx.accounts.vault.to_account_info(),
+                token_manager: self.token_manager.to_account_info(),
+                minter_mint_ata: self.vault.to_account_info(),
                 minter: pool_manager.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
-                mint: ctx.accounts.base_mint.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                associated_token_program: self.associated_token_program.to_account_info(),
+                mint: self.base_mint.to_account_info(),
+                minter_authorization: self
+                    .minter_authorization
+                    .as_ref()
+                    .map(|minter_authorization| minter_authorization.to_account_info()),
             },
             signer_seeds,
         );
 
-        mint_admin(mint_context, amount_to_mint)?;
+        mint_admin(mint_context, base_amount)
     }
 
-    // Update newly minted balance
-    pool_manager.base_balance = pool_manager
-        .base_balance
-        .checked_add(amount_to_mint)
-        .ok_or(ParityStakingError::CalculationOverflow)?;
+    /// Burns `quantity` x tokens from the payer and mints any outstanding yield
+    /// into the vault. Returns the base amount the burnt x tokens are worth.
+    pub fn burn_and_accrue(&mut self, quantity: u64) -> Result<u64> {
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        self.yield_schedule
+            .apply(&mut self.pool_manager, current_timestamp)?;
+        // Scheduled rate changes land here, so the x mint rate follows them
+        sync_x_mint_interest_rate(&self.pool_manager, &self.x_mint, &self.x_token_program)?;
 
-    msg!("Base Balance2: {}", pool_manager.base_balance);
+        let pool_manager = &mut self.pool_manager;
+        let x_mint = &mut self.x_mint;
+        let x_amount = quantity;
 
-    let base_amount: u64 =
-        pool_manager.calculate_output_amount(quantity, current_timestamp, false)?;
+        // Check if the quantity to unstake is greater than zero
+        if x_amount == 0 {
+            return err!(ParityStakingError::InvalidQuantity);
+        }
+        pool_manager.check_unstake_active()?;
+        pool_manager.check_minimum_unstake(x_amount)?;
 
-    msg!("Base amount: {}", base_amount);
+        let initial_x_mint_supply = x_mint.supply;
 
-    transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.vault.to_account_info(),
-                to: ctx.accounts.payer_base_mint_ata.to_account_info(),
-                mint: ctx.accounts.base_mint.to_account_info(),
-                authority: pool_manager.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        base_amount,
-        pool_manager.base_mint_decimals,
-    )?;
+        // Burning
+        token_interface::burn(
+            CpiContext::new(
+                self.x_token_program.to_account_info(),
+                Burn {
+                    authority: self.payer.to_account_info(),
+                    from: self.payer_x_mint_ata.to_account_info(),
+                    mint: x_mint.to_account_info(),
+                },
+            ),
+            x_amount,
+        )?;
+
+        // Mint Base into pool
+        // Get the actual vault balance, plus the base held by the strategies
+        let vault_balance = pool_manager.calculate_pool_assets(self.vault.amount)?;
+        let amount_to_mint = pool_manager.calculate_amount_to_mint(
+            initial_x_mint_supply,
+            current_timestamp,
+            vault_balance,
+        )?;
+        // Minted on top of the yield so that the exchange rate stays net of fees
+        let performance_fee = pool_manager.calculate_minted_performance_fee(amount_to_mint)?;
+        msg!("Amount to mint: {}", amount_to_mint);
 
-    // Update pool_manager
-    pool_manager.base_balance = pool_manager
-        .base_balance
-        .checked_sub(base_amount)
-        .ok_or(ParityStakingError::CalculationOverflow)?;
+        if amount_to_mint > 0 {
+            self.mint_to_vault(
+                amount_to_mint
+                    .checked_add(performance_fee)
+                    .ok_or(ParityStakingError::CalculationOverflow)?,
+            )?;
+        }
 
-    Ok(())
+        // Update newly minted balance
+        self.pool_manager.record_yield_minted(amount_to_mint)?;
+
+        if performance_fee > 0 {
+            self.transfer_from_vault(performance_fee, true)?;
+            self.pool_manager.record_performance_fee(performance_fee)?;
+        }
+        let pool_manager = &mut self.pool_manager;
+
+        msg!("Base Balance2: {}", pool_manager.base_balance);
+
+        let base_amount: u64 =
+            pool_manager.calculate_output_amount(quantity, current_timestamp, false)?;
+
+        msg!("Base amount: {}", base_amount);
+
+        // Only mirrored once the base amount is known, as the burn must not move
+        // a streaming pool's exchange rate
+        pool_manager.record_x_burnt(x_amount)?;
+
+        if base_amount == 0 {
+            return err!(ParityStakingError::InvalidQuantity);
+        }
+
+        if let Some(staker_entry) = self.staker_entry.as_mut() {
+            staker_entry.record_unstake(base_amount);
+        }
+
+        Ok(base_amount)
+    }
 }
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use parity_issuance::cpi::{accounts::RedeemTokens, redeem};
+
+use crate::{error::ParityStakingError, instructions::unstake::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct UnstakeAndRedeemParams {
+    pub quantity: u64,
+    pub proof: Vec<[u8; 32]>,
+    pub min_quote_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct UnstakeAndRedeem<'info> {
+    pub unstake: Unstake<'info>,
+
+    // Issuance
+    pub quote_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = unstake.payer,
+    )]
+    pub payer_quote_mint_ata: Account<'info, TokenAccount>,
+    /// CHECK: This account is checked in the redeem CPI call
+    #[account(mut)]
+    pub issuance_vault: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<UnstakeAndRedeem>, params: UnstakeAndRedeemParams) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    let (base_amount, _) = accounts.unstake.unstake(params.quantity)?;
+    msg!("Base amount: {}", base_amount);
+
+    // Redeem the unstaked base tokens through the issuance program, which
+    // applies its own allow list, slot limit and redeem fee
+    let quote_balance_before = accounts.payer_quote_mint_ata.amount;
+
+    redeem(
+        CpiContext::new(
+            accounts.unstake.parity_issuance_program.to_account_info(),
+            RedeemTokens {
+                token_manager: accounts.unstake.token_manager.to_account_info(),
+                mint: accounts.unstake.base_mint.to_account_info(),
+                payer_mint_ata: accounts.unstake.payer_base_mint_ata.to_account_info(),
+                quote_mint: accounts.quote_mint.to_account_info(),
+                payer_quote_mint_ata: accounts.payer_quote_mint_ata.to_account_info(),
+                vault: accounts.issuance_vault.to_account_info(),
+                payer: accounts.unstake.payer.to_account_info(),
+                system_program: accounts.unstake.system_program.to_account_info(),
+                token_program: accounts.unstake.token_program.to_account_info(),
+                associated_token_program: accounts
+                    .unstake
+                    .associated_token_program
+                    .to_account_info(),
+            },
+        ),
+        base_amount,
+        params.proof,
+    )?;
+
+    accounts.payer_quote_mint_ata.reload()?;
+
+    let quote_amount = accounts
+        .payer_quote_mint_ata
+        .amount
+        .checked_sub(quote_balance_before)
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+    msg!("Quote amount: {}", quote_amount);
+
+    // Slippage check
+    if quote_amount < params.min_quote_amount {
+        return err!(ParityStakingError::SlippageExceeded);
+    }
+
+    Ok(())
+}
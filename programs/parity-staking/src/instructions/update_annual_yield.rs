@@ -1,18 +1,25 @@
-use crate::{error::ParityStakingError, PoolManager, MAX_INTERVAL_APR_RATE};
-use anchor_lang::prelude::*;
+use crate::{error::ParityStakingError, PoolManager, RateFeed, YieldMode, YieldSchedule};
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
-};
-use parity_issuance::{
-    cpi::{accounts::MintAdminTokens, mint_admin},
-    program::ParityIssuance,
-    TokenManager,
+    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+    token_interface::{
+        self,
+        spl_token_2022::{
+            self,
+            extension::{
+                interest_bearing_mint::{self, InterestBearingConfig},
+                BaseStateWithExtensions, StateWithExtensions,
+            },
+        },
+        TokenInterface,
+    },
 };
+use parity_issuance::cpi::{accounts::MintAdminTokens, mint_admin};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
 pub struct UpdateYieldParams {
-    pub interval_apr_rate: u64,
+    pub annual_yield_bps: u64,
 }
 
 #[derive(Accounts)]
@@ -21,12 +28,30 @@ pub struct UpdateAnnualYield<'info> {
         mut,
       seeds = [
         b"pool-manager",
+        pool_manager.base_mint.as_ref(),
+        &pool_manager.pool_id.to_le_bytes(),
       ],
-      bump,
+      bump = pool_manager.bump,
     )]
     pub pool_manager: Account<'info, PoolManager>,
-    #[account(mut)]
-    pub token_manager: Account<'info, TokenManager>,
+    #[account(
+        mut,
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump = yield_schedule.bump,
+    )]
+    pub yield_schedule: Account<'info, YieldSchedule>,
+    #[account(
+        mut,
+        seeds = [b"rate-feed", pool_manager.key().as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Account<'info, RateFeed>,
+    /// CHECK: Token manager recorded on the pool manager, deserialized in the mint_admin CPI call
+    #[account(
+        mut,
+        address = pool_manager.token_manager @ ParityStakingError::InvalidTokenManager,
+    )]
+    pub token_manager: UncheckedAccount<'info>,
     #[account(
         mut,
         mint::decimals = pool_manager.base_mint_decimals,
@@ -34,55 +59,83 @@ pub struct UpdateAnnualYield<'info> {
     )]
     pub base_mint: Account<'info, Mint>,
     #[account(
+        mut,
         mint::authority = pool_manager,
         mint::decimals = pool_manager.x_mint_decimals,
         address = pool_manager.x_mint,
     )]
-    pub x_mint: Account<'info, Mint>,
+    pub x_mint: InterfaceAccount<'info, token_interface::Mint>,
     #[account(
         mut,
         associated_token::mint = base_mint,
         associated_token::authority = pool_manager,
     )]
     pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = pool_manager.treasury @ ParityStakingError::InvalidTreasury,
+        token::mint = base_mint,
+    )]
+    pub treasury: Option<Account<'info, TokenAccount>>,
     #[account(mut, address = pool_manager.admin @ ParityStakingError::InvalidAdmin)]
     pub admin: Signer<'info>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
+    #[account(
+        address = pool_manager.x_token_program @ ParityStakingError::InvalidXTokenProgram,
+    )]
+    pub x_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub parity_issuance_program: Program<'info, ParityIssuance>,
+    /// CHECK: Minter authorization of the pool manager, checked in the mint_admin CPI call
+    pub minter_authorization: Option<UncheckedAccount<'info>>,
+    /// CHECK: Issuance program recorded on the pool manager
+    #[account(
+        executable,
+        address = pool_manager.issuance_program @ ParityStakingError::InvalidIssuanceProgram,
+    )]
+    pub parity_issuance_program: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<UpdateAnnualYield>, params: UpdateYieldParams) -> Result<()> {
-    if params.interval_apr_rate == 0 {
-        return err!(ParityStakingError::InvalidParam); // Ensure interval APR rate is non-zero
-    }
+    let pool_manager = &mut ctx.accounts.pool_manager;
+    let x_mint = &ctx.accounts.x_mint;
 
-     
-     if params.interval_apr_rate > MAX_INTERVAL_APR_RATE {
-        return err!(ParityStakingError::MaxIntervalAprRateExceeded); // Ensure interval APR rate does not exceed the maximum limit
+    // Streaming pools only earn the rewards deposited into them
+    if pool_manager.yield_mode != YieldMode::Compounding {
+        return err!(ParityStakingError::InvalidYieldMode);
     }
 
-    let pool_manager = &mut ctx.accounts.pool_manager;
-    let x_mint = &mut ctx.accounts.x_mint;
-
     let clock = Clock::get()?;
     let current_timestamp = clock.unix_timestamp;
 
-    let exchange_rate = pool_manager
-        .calculate_exchange_rate(current_timestamp)
-        .ok_or(ParityStakingError::CalculationOverflow)?;
+    // Land any scheduled rate changes before overriding the rate
+    ctx.accounts
+        .yield_schedule
+        .apply(pool_manager, current_timestamp)?;
+
+    let exchange_rate = pool_manager.calculate_exchange_rate(current_timestamp)?;
 
     msg!("Exchange Rate: {}", exchange_rate);
 
     // Mint Base into pool
     let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
-    let signer_seeds: &[&[&[u8]]] = &[&[b"pool-manager", &[bump]]];
+    let base_mint_key = pool_manager.base_mint;
+    let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"pool-manager",
+        base_mint_key.as_ref(),
+        &pool_id_bytes,
+        &[bump],
+    ]];
 
-    let vault_balance = ctx.accounts.vault.amount; // Get the actual vault balance
+    // Get the actual vault balance, plus the base held by the strategies
+    let vault_balance = pool_manager.calculate_pool_assets(ctx.accounts.vault.amount)?;
     let amount_to_mint =
         pool_manager.calculate_amount_to_mint(x_mint.supply, current_timestamp, vault_balance)?;
 
+    // Minted on top of the yield so that the exchange rate stays net of fees
+    let performance_fee = pool_manager.calculate_minted_performance_fee(amount_to_mint)?;
+
     msg!("Amount to mint: {}", amount_to_mint);
 
     if amount_to_mint > 0 {
@@ -96,20 +149,116 @@ pub fn handler(ctx: Context<UpdateAnnualYield>, params: UpdateYieldParams) -> Re
                 token_program: ctx.accounts.token_program.to_account_info(),
                 associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
                 mint: ctx.accounts.base_mint.to_account_info(),
+                minter_authorization: ctx
+                    .accounts
+                    .minter_authorization
+                    .as_ref()
+                    .map(|minter_authorization| minter_authorization.to_account_info()),
             },
             signer_seeds,
         );
 
-        mint_admin(mint_context, amount_to_mint)?;
+        mint_admin(
+            mint_context,
+            amount_to_mint
+                .checked_add(performance_fee)
+                .ok_or(ParityStakingError::CalculationOverflow)?,
+        )?;
+    }
+
+    if performance_fee > 0 {
+        let treasury = ctx
+            .accounts
+            .treasury
+            .as_ref()
+            .ok_or(ParityStakingError::InvalidTreasury)?;
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: treasury.to_account_info(),
+                    mint: ctx.accounts.base_mint.to_account_info(),
+                    authority: pool_manager.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            performance_fee,
+            pool_manager.base_mint_decimals,
+        )?;
+
+        pool_manager.record_performance_fee(performance_fee)?;
     }
 
-    pool_manager.base_balance = pool_manager
-        .base_balance
-        .checked_add(amount_to_mint)
-        .ok_or(ParityStakingError::CalculationOverflow)?;
+    pool_manager.record_yield_minted(amount_to_mint)?;
     pool_manager.last_yield_change_timestamp = current_timestamp;
     pool_manager.last_yield_change_exchange_rate = exchange_rate;
-    pool_manager.interval_apr_rate = params.interval_apr_rate;
+    // Derives the interval APR rate, which is bounded by MAX_INTERVAL_APR_RATE
+    pool_manager.set_annual_yield(params.annual_yield_bps)?;
+
+    // Wallets display the x balances accruing at the new rate
+    sync_x_mint_interest_rate(pool_manager, x_mint, &ctx.accounts.x_token_program)?;
+
+    ctx.accounts
+        .rate_feed
+        .refresh(pool_manager, current_timestamp)?;
+
+    msg!(
+        "Effective APY: {} bps",
+        pool_manager.calculate_effective_apy_bps()?
+    );
+
+    Ok(())
+}
+
+/// Sets the rate of an interest-bearing x mint to the pool's interval APR
+/// rate, when it changed. Plain SPL x mints are left alone.
+pub fn sync_x_mint_interest_rate<'info>(
+    pool_manager: &Account<'info, PoolManager>,
+    x_mint: &InterfaceAccount<'info, token_interface::Mint>,
+    x_token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
+    if !pool_manager.is_x_mint_interest_bearing() {
+        return Ok(());
+    }
+
+    let rate = pool_manager.calculate_x_mint_interest_rate()?;
+
+    // Stakes and unstakes sync as well, but only a new rate needs the update
+    let current_rate = {
+        let x_mint_info = x_mint.to_account_info();
+        let data = x_mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        i16::from(mint.get_extension::<InterestBearingConfig>()?.current_rate)
+    };
+    if current_rate == rate {
+        return Ok(());
+    }
+
+    let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
+    let base_mint_key = pool_manager.base_mint;
+    let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"pool-manager",
+        base_mint_key.as_ref(),
+        &pool_id_bytes,
+        &[bump],
+    ]];
+
+    invoke_signed(
+        &interest_bearing_mint::instruction::update_rate(
+            &x_token_program.key(),
+            &x_mint.key(),
+            &pool_manager.key(),
+            &[],
+            rate,
+        )?,
+        &[x_mint.to_account_info(), pool_manager.to_account_info()],
+        signer_seeds,
+    )?;
+
+    msg!("X mint interest rate: {} bps", rate);
 
     Ok(())
 }
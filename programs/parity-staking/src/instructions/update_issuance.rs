@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{ParityStakingError, PoolManager};
+
+#[derive(Accounts)]
+pub struct UpdateIssuance<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(address = pool_manager.owner @ ParityStakingError::InvalidOwner)]
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateIssuance>) -> Result<()> {
+    let pool_manager = &mut ctx.accounts.pool_manager;
+
+    // Validate a binding update is pending
+    if pool_manager.pending_token_manager == Pubkey::default() {
+        return err!(ParityStakingError::InvalidParam); // Ensure an update was initiated
+    }
+
+    // Validate the timelock has elapsed
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    if current_timestamp < pool_manager.issuance_update_timestamp {
+        return err!(ParityStakingError::IssuanceUpdateTimelockActive);
+    }
+
+    pool_manager.issuance_program = pool_manager.pending_issuance_program;
+    pool_manager.token_manager = pool_manager.pending_token_manager;
+    pool_manager.pending_issuance_program = Pubkey::default();
+    pool_manager.pending_token_manager = Pubkey::default();
+    pool_manager.issuance_update_timestamp = 0;
+
+    Ok(())
+}
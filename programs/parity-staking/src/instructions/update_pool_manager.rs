@@ -1,19 +1,45 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
 
-use crate::{error::ParityStakingError, PoolManager};
+use crate::{
+    error::ParityStakingError, FeeDestination, PoolManager, YieldMode, MAX_PERFORMANCE_FEE_BPS,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
 pub struct UpdatePoolManagerParams {
     pub new_admin: Option<Pubkey>,
     pub new_deposit_cap: Option<u64>,
+    pub new_unstake_cooldown: Option<i64>,
+    pub new_treasury: Option<Pubkey>,
+    pub new_instant_unstake_fee_bps: Option<u16>,
+    pub new_instant_unstake_fee_destination: Option<FeeDestination>,
+    pub new_liquidity_buffer_target: Option<u64>,
+    pub new_min_stake_amount: Option<u64>,
+    pub new_min_unstake_amount: Option<u64>,
+    pub new_min_accrual_interval: Option<i64>,
+    pub new_keeper_reward: Option<u64>,
+    pub new_rewarder: Option<Pubkey>,
+    pub new_vesting_period: Option<i64>,
+    pub new_performance_fee_bps: Option<u16>,
+    pub new_entry_fee_bps: Option<u16>,
+    pub new_entry_fee_destination: Option<FeeDestination>,
+    pub new_exit_fee_bps: Option<u16>,
+    pub new_exit_fee_destination: Option<FeeDestination>,
+    pub new_checkpoint_interval: Option<i64>,
+    pub new_max_strategy_allocation_bps: Option<u16>,
+    pub new_allowlist_enabled: Option<bool>,
+    pub new_wallet_deposit_cap: Option<u64>,
 }
 
 #[derive(Accounts)]
 pub struct UpdatePoolManager<'info> {
     #[account(
         mut,
-        seeds = [b"pool-manager"],
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
         bump = pool_manager.bump
     )]
     pub pool_manager: Account<'info, PoolManager>,
@@ -42,13 +68,154 @@ pub fn handler(ctx: Context<UpdatePoolManager>, params: UpdatePoolManagerParams)
             return err!(ParityStakingError::InvalidParam); // Ensure deposit cap is non-zero
         }
 
-        // Check that the new deposit cap is not less than the previous
-        let vault_amount = ctx.accounts.vault.amount;
-        if new_deposit_cap < vault_amount {
+        // Check that the new deposit cap still covers the principal staked
+        if new_deposit_cap < pool_manager.principal_amount {
             return err!(ParityStakingError::DepositCapTooLow);
         }
 
         pool_manager.deposit_cap = new_deposit_cap;
     }
+
+    if let Some(new_unstake_cooldown) = params.new_unstake_cooldown {
+        if new_unstake_cooldown < 0 {
+            return err!(ParityStakingError::InvalidParam); // Ensure unstake cooldown is non-negative
+        }
+        pool_manager.unstake_cooldown = new_unstake_cooldown;
+    }
+
+    if let Some(new_treasury) = params.new_treasury {
+        pool_manager.treasury = new_treasury;
+    }
+
+    if let Some(new_instant_unstake_fee_bps) = params.new_instant_unstake_fee_bps {
+        if new_instant_unstake_fee_bps > 10000 {
+            return err!(ParityStakingError::InvalidParam); // Ensure fee is at most 100%
+        }
+        pool_manager.instant_unstake_fee_bps = new_instant_unstake_fee_bps;
+    }
+
+    if let Some(new_instant_unstake_fee_destination) = params.new_instant_unstake_fee_destination {
+        pool_manager.instant_unstake_fee_destination = new_instant_unstake_fee_destination;
+    }
+
+    if let Some(new_performance_fee_bps) = params.new_performance_fee_bps {
+        if new_performance_fee_bps > MAX_PERFORMANCE_FEE_BPS {
+            return err!(ParityStakingError::InvalidParam); // Ensure fee leaves most of the yield to stakers
+        }
+        pool_manager.performance_fee_bps = new_performance_fee_bps;
+    }
+
+    if let Some(new_entry_fee_bps) = params.new_entry_fee_bps {
+        if new_entry_fee_bps > 10000 {
+            return err!(ParityStakingError::InvalidParam); // Ensure fee is at most 100%
+        }
+        pool_manager.entry_fee_bps = new_entry_fee_bps;
+    }
+
+    if let Some(new_entry_fee_destination) = params.new_entry_fee_destination {
+        pool_manager.entry_fee_destination = new_entry_fee_destination;
+    }
+
+    if let Some(new_exit_fee_bps) = params.new_exit_fee_bps {
+        if new_exit_fee_bps > 10000 {
+            return err!(ParityStakingError::InvalidParam); // Ensure fee is at most 100%
+        }
+        pool_manager.exit_fee_bps = new_exit_fee_bps;
+    }
+
+    if let Some(new_exit_fee_destination) = params.new_exit_fee_destination {
+        pool_manager.exit_fee_destination = new_exit_fee_destination;
+    }
+
+    // Fees can only be sent to a configured treasury
+    if (pool_manager.instant_unstake_fee_destination == FeeDestination::Treasury
+        || pool_manager.entry_fee_destination == FeeDestination::Treasury
+        || pool_manager.exit_fee_destination == FeeDestination::Treasury
+        || pool_manager.performance_fee_bps > 0)
+        && pool_manager.treasury == Pubkey::default()
+    {
+        return err!(ParityStakingError::InvalidTreasury);
+    }
+
+    if let Some(new_liquidity_buffer_target) = params.new_liquidity_buffer_target {
+        pool_manager.liquidity_buffer_target = new_liquidity_buffer_target;
+
+        // Reserve what the vault can spare right away, the rest is topped up by stakes
+        let available_amount = ctx
+            .accounts
+            .vault
+            .amount
+            .saturating_sub(pool_manager.pending_unstake_amount);
+        pool_manager.liquidity_buffer_amount = new_liquidity_buffer_target.min(available_amount);
+    }
+
+    if let Some(new_min_stake_amount) = params.new_min_stake_amount {
+        pool_manager.min_stake_amount = new_min_stake_amount;
+    }
+
+    if let Some(new_min_unstake_amount) = params.new_min_unstake_amount {
+        pool_manager.min_unstake_amount = new_min_unstake_amount;
+    }
+
+    if let Some(new_min_accrual_interval) = params.new_min_accrual_interval {
+        if new_min_accrual_interval < 0 {
+            return err!(ParityStakingError::InvalidParam); // Ensure accrual interval is non-negative
+        }
+        pool_manager.min_accrual_interval = new_min_accrual_interval;
+    }
+
+    if let Some(new_keeper_reward) = params.new_keeper_reward {
+        pool_manager.keeper_reward = new_keeper_reward;
+    }
+
+    // Keepers are paid at most once per accrual interval
+    pool_manager.check_keeper_reward()?;
+    // Wallets display interest-bearing x balances at the APR rate alone
+    pool_manager.check_interest_bearing_x_mint()?;
+
+    if let Some(new_rewarder) = params.new_rewarder {
+        if pool_manager.yield_mode == YieldMode::RewardStreaming
+            && new_rewarder == Pubkey::default()
+        {
+            return err!(ParityStakingError::InvalidRewarder); // Ensure somebody can deposit rewards
+        }
+        pool_manager.rewarder = new_rewarder;
+    }
+
+    if let Some(new_vesting_period) = params.new_vesting_period {
+        if new_vesting_period <= 0 {
+            return err!(ParityStakingError::InvalidParam); // Ensure vesting period is positive
+        }
+
+        // Rewards still vesting keep vesting from now over the new period
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        pool_manager.vesting_amount = pool_manager.calculate_unvested_amount(current_timestamp)?;
+        pool_manager.vesting_start_timestamp = current_timestamp;
+        pool_manager.vesting_period = new_vesting_period;
+    }
+
+    if let Some(new_checkpoint_interval) = params.new_checkpoint_interval {
+        if new_checkpoint_interval <= 0 {
+            return err!(ParityStakingError::InvalidParam); // Ensure checkpoint interval is positive
+        }
+        pool_manager.checkpoint_interval = new_checkpoint_interval;
+    }
+
+    // Strategies already over a lowered share are only closed to new allocations
+    if let Some(new_max_strategy_allocation_bps) = params.new_max_strategy_allocation_bps {
+        if new_max_strategy_allocation_bps > 10000 {
+            return err!(ParityStakingError::InvalidParam); // Ensure the share is at most 100%
+        }
+        pool_manager.max_strategy_allocation_bps = new_max_strategy_allocation_bps;
+    }
+
+    if let Some(new_allowlist_enabled) = params.new_allowlist_enabled {
+        pool_manager.allowlist_enabled = new_allowlist_enabled;
+    }
+
+    // Wallets already over a lowered cap can only stop staking
+    if let Some(new_wallet_deposit_cap) = params.new_wallet_deposit_cap {
+        pool_manager.wallet_deposit_cap = new_wallet_deposit_cap;
+    }
     Ok(())
 }
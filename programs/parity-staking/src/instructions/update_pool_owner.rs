@@ -6,7 +6,11 @@ use crate::{PoolManager, ParityStakingError};
 pub struct UpdatePoolOwner<'info> {
     #[account(
         mut,
-        seeds = [b"pool-manager"],
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
         bump = pool_manager.bump
     )]
     pub pool_manager: Account<'info, PoolManager>,
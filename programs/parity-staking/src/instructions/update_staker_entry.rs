@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ParityStakingError, PoolManager, StakerEntry};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct UpdateStakerEntryParams {
+    pub new_allowed: Option<bool>,
+    pub new_deposit_cap: Option<u64>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateStakerEntry<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        seeds = [b"staker-entry", pool_manager.key().as_ref(), staker_entry.wallet.as_ref()],
+        bump = staker_entry.bump,
+    )]
+    pub staker_entry: Account<'info, StakerEntry>,
+    #[account(address = pool_manager.admin @ ParityStakingError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateStakerEntry>, params: UpdateStakerEntryParams) -> Result<()> {
+    let staker_entry = &mut ctx.accounts.staker_entry;
+
+    // Revoking a wallet only stops new stakes, its x tokens stay redeemable
+    if let Some(new_allowed) = params.new_allowed {
+        staker_entry.allowed = new_allowed;
+    }
+
+    if let Some(new_deposit_cap) = params.new_deposit_cap {
+        staker_entry.deposit_cap = new_deposit_cap;
+    }
+
+    Ok(())
+}
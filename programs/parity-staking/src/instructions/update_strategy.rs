@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ParityStakingError, PoolManager, Strategy};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct UpdateStrategyParams {
+    pub new_cap: Option<u64>,
+    pub new_active: Option<bool>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateStrategy<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        seeds = [
+            b"strategy",
+            pool_manager.key().as_ref(),
+            strategy.strategy_program.as_ref(),
+        ],
+        bump = strategy.bump,
+        has_one = pool_manager @ ParityStakingError::InvalidStrategy,
+    )]
+    pub strategy: Account<'info, Strategy>,
+    #[account(address = pool_manager.owner @ ParityStakingError::InvalidOwner)]
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateStrategy>, params: UpdateStrategyParams) -> Result<()> {
+    let strategy = &mut ctx.accounts.strategy;
+
+    // A lower cap only stops new allocations, the owner withdraws the excess
+    if let Some(new_cap) = params.new_cap {
+        strategy.cap = new_cap;
+    }
+
+    if let Some(new_active) = params.new_active {
+        strategy.active = new_active;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ParityStakingError, PoolManager, Tranche};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct UpdateTrancheParams {
+    pub new_annual_yield_bps: Option<u64>,
+    pub new_active: Option<bool>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTranche<'info> {
+    #[account(
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        seeds = [
+            b"tranche",
+            pool_manager.key().as_ref(),
+            &tranche.tranche_id.to_le_bytes(),
+        ],
+        bump = tranche.bump,
+        has_one = pool_manager @ ParityStakingError::InvalidTranche,
+    )]
+    pub tranche: Account<'info, Tranche>,
+    #[account(address = pool_manager.admin @ ParityStakingError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateTranche>, params: UpdateTrancheParams) -> Result<()> {
+    let pool_manager = &ctx.accounts.pool_manager;
+    let tranche = &mut ctx.accounts.tranche;
+
+    // Only applies to new positions, open ones keep the rate they locked in
+    if let Some(new_annual_yield_bps) = params.new_annual_yield_bps {
+        if new_annual_yield_bps < pool_manager.annual_yield_bps {
+            return err!(ParityStakingError::InvalidParam); // Ensure the tranche rate is boosted over the pool rate
+        }
+        tranche.interval_apr_rate =
+            pool_manager.calculate_interval_apr_rate(new_annual_yield_bps)?;
+        tranche.annual_yield_bps = new_annual_yield_bps;
+    }
+
+    if let Some(new_active) = params.new_active {
+        tranche.active = new_active;
+    }
+
+    Ok(())
+}
@@ -10,7 +10,11 @@ use anchor_spl::metadata::{
 pub struct UpdateXmintMetadata<'info> {
     #[account(
         mut,
-        seeds = [b"pool-manager"],
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
         bump = pool_manager.bump
     )]
     pub pool_manager: Account<'info, PoolManager>,
@@ -48,7 +52,14 @@ pub fn handler(
     }
 
     let bump = pool_manager.bump;
-    let signer_seeds: &[&[&[u8]]] = &[&[b"pool-manager", &[bump]]];
+    let base_mint_key = pool_manager.base_mint;
+    let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"pool-manager",
+        base_mint_key.as_ref(),
+        &pool_id_bytes,
+        &[bump],
+    ]];
 
     let data = DataV2 {
         name,
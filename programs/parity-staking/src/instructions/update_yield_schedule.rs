@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ParityStakingError, PoolManager, ScheduledRate, YieldMode, YieldSchedule,
+    MAX_SCHEDULED_RATES,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct UpdateYieldScheduleParams {
+    pub scheduled_rates: Vec<ScheduledRate>,
+}
+
+impl UpdateYieldScheduleParams {
+    pub fn validate(&self, pool_manager: &PoolManager, current_timestamp: i64) -> Result<()> {
+        // Streaming pools only earn the rewards deposited into them
+        if pool_manager.yield_mode != YieldMode::Compounding {
+            return err!(ParityStakingError::InvalidYieldMode);
+        }
+
+        // Validate schedule length
+        if self.scheduled_rates.len() > MAX_SCHEDULED_RATES {
+            return err!(ParityStakingError::InvalidYieldSchedule); // Ensure the schedule fits in the account
+        }
+
+        let mut previous_timestamp = current_timestamp;
+        for scheduled_rate in &self.scheduled_rates {
+            // Ensure effective timestamps are in the future and strictly increasing
+            if scheduled_rate.effective_timestamp <= previous_timestamp {
+                return err!(ParityStakingError::InvalidYieldSchedule);
+            }
+            previous_timestamp = scheduled_rate.effective_timestamp;
+
+            // Ensure the derived interval APR rate does not exceed the maximum limit
+            pool_manager.calculate_interval_apr_rate(scheduled_rate.annual_yield_bps)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateYieldSchedule<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        seeds = [b"yield-schedule", pool_manager.key().as_ref()],
+        bump = yield_schedule.bump,
+    )]
+    pub yield_schedule: Account<'info, YieldSchedule>,
+    #[account(address = pool_manager.admin @ ParityStakingError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateYieldSchedule>, params: UpdateYieldScheduleParams) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    params.validate(&ctx.accounts.pool_manager, current_timestamp)?;
+
+    // Land the rate changes already in effect before replacing the schedule
+    let yield_schedule = &mut ctx.accounts.yield_schedule;
+    yield_schedule.apply(&mut ctx.accounts.pool_manager, current_timestamp)?;
+
+    yield_schedule.scheduled_rates = params.scheduled_rates;
+
+    Ok(())
+}
@@ -7,7 +7,15 @@ use anchor_spl::{
 
 #[derive(Accounts)]
 pub struct WithdrawExcessParity<'info> {
-    #[account(mut, seeds = [b"pool-manager"], bump)]
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
     pub pool_manager: Account<'info, PoolManager>,
 
     #[account(
@@ -45,17 +53,27 @@ pub fn handler(ctx: Context<WithdrawExcessParity>) -> Result<()> {
 
     //Signing
     let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
-    let signer_seeds: &[&[&[u8]]] = &[&[b"pool-manager", &[bump]]];
+    let base_mint_key = pool_manager.base_mint;
+    let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"pool-manager",
+        base_mint_key.as_ref(),
+        &pool_id_bytes,
+        &[bump],
+    ]];
 
-    // Calculate the excess tokens
+    // Calculate the excess tokens, counting the base held by the strategies
     let vault_amount = vault.amount;
+    let pool_assets = pool_manager.calculate_pool_assets(vault_amount)?;
     let base_balance = pool_manager.base_balance;
 
     // Check if there is an excess
-    if vault_amount > base_balance {
-        let excess_amount = (vault_amount as u128)
+    if pool_assets > base_balance {
+        // Only the part sitting in the vault can be withdrawn
+        let excess_amount = (pool_assets as u128)
             .checked_sub(base_balance as u128)
-            .ok_or(ParityStakingError::CalculationOverflow)?;
+            .ok_or(ParityStakingError::CalculationOverflow)?
+            .min(vault_amount as u128);
 
         // Transfer the excess tokens back to the admin
         transfer_checked(
@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
+
+use crate::{
+    error::ParityStakingError, strategy_interface::StrategyContext, PoolManager, Strategy,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawFromStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"pool-manager",
+            pool_manager.base_mint.as_ref(),
+            &pool_manager.pool_id.to_le_bytes(),
+        ],
+        bump = pool_manager.bump,
+    )]
+    pub pool_manager: Account<'info, PoolManager>,
+    #[account(
+        mut,
+        seeds = [
+            b"strategy",
+            pool_manager.key().as_ref(),
+            strategy_program.key().as_ref(),
+        ],
+        bump = strategy.bump,
+    )]
+    pub strategy: Account<'info, Strategy>,
+    #[account(
+        mint::decimals = pool_manager.base_mint_decimals,
+        address = pool_manager.base_mint,
+    )]
+    pub base_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = pool_manager,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = pool_manager.treasury @ ParityStakingError::InvalidTreasury,
+        token::mint = base_mint,
+    )]
+    pub treasury: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Strategy program whitelisted by the strategy account
+    #[account(executable)]
+    pub strategy_program: UncheckedAccount<'info>,
+    /// CHECK: Signs for the pool in the strategy program, with no authority over the vault or the mints
+    #[account(
+        seeds = [b"strategy-authority", strategy.key().as_ref()],
+        bump,
+    )]
+    pub strategy_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = strategy_authority,
+    )]
+    pub strategy_token_account: Account<'info, TokenAccount>,
+    #[account(address = pool_manager.owner @ ParityStakingError::InvalidOwner)]
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Moves up to `quantity` base tokens from the strategy back into the vault.
+/// The strategy's own accounts are passed as remaining accounts.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawFromStrategy<'info>>,
+    quantity: u64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    // Check if the quantity to withdraw is greater than zero
+    if quantity == 0 {
+        return err!(ParityStakingError::InvalidQuantity);
+    }
+
+    let vault_amount = accounts.vault.amount;
+
+    StrategyContext {
+        strategy_program: accounts.strategy_program.to_account_info(),
+        strategy: accounts.strategy.key(),
+        strategy_authority: accounts.strategy_authority.to_account_info(),
+        strategy_authority_bump: ctx.bumps.strategy_authority,
+        strategy_token_account: accounts.strategy_token_account.to_account_info(),
+        vault: accounts.vault.to_account_info(),
+        base_mint: accounts.base_mint.to_account_info(),
+        token_program: accounts.token_program.to_account_info(),
+        remaining_accounts: ctx.remaining_accounts,
+    }
+    .withdraw(quantity)?;
+
+    accounts.vault.reload()?;
+    let withdrawn_amount = accounts
+        .vault
+        .amount
+        .checked_sub(vault_amount)
+        .ok_or(ParityStakingError::InvalidStrategy)?;
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let performance_fee = record_strategy_withdrawal(
+        &mut accounts.pool_manager,
+        &mut accounts.strategy,
+        withdrawn_amount,
+        current_timestamp,
+    )?;
+    pay_performance_fee(
+        &mut accounts.pool_manager,
+        &accounts.vault,
+        accounts.treasury.as_ref(),
+        &accounts.base_mint,
+        &accounts.token_program,
+        performance_fee,
+    )?;
+
+    msg!("Withdrawn from strategy: {}", withdrawn_amount);
+
+    Ok(())
+}
+
+/// Records `withdrawn_amount` returned to the vault by a strategy. Any part of
+/// it above the reported value is a gain the strategy had not reported yet.
+/// Returns the performance fee on that gain.
+pub fn record_strategy_withdrawal(
+    pool_manager: &mut PoolManager,
+    strategy: &mut Strategy,
+    withdrawn_amount: u64,
+    current_timestamp: i64,
+) -> Result<u64> {
+    let mut performance_fee = 0;
+
+    if withdrawn_amount > strategy.reported_value {
+        performance_fee = pool_manager.record_strategy_value(
+            strategy.reported_value,
+            withdrawn_amount,
+            current_timestamp,
+        )?;
+        strategy.reported_value = withdrawn_amount;
+    }
+
+    strategy.record_withdrawal(withdrawn_amount)?;
+    pool_manager.record_strategy_withdrawal(withdrawn_amount)?;
+
+    Ok(performance_fee)
+}
+
+/// Pays the `performance_fee` taken on a strategy gain from the vault to the
+/// treasury. A reported gain may still sit in the strategy, so the vault pays
+/// the fee in advance of it.
+pub fn pay_performance_fee<'info>(
+    pool_manager: &mut Account<'info, PoolManager>,
+    vault: &Account<'info, TokenAccount>,
+    treasury: Option<&Account<'info, TokenAccount>>,
+    base_mint: &Account<'info, Mint>,
+    token_program: &Program<'info, Token>,
+    performance_fee: u64,
+) -> Result<()> {
+    if performance_fee == 0 {
+        return Ok(());
+    }
+
+    let treasury = treasury.ok_or(ParityStakingError::InvalidTreasury)?;
+
+    let bump = pool_manager.bump; // Corrected to be a slice of a slice of a byte slice
+    let base_mint_key = pool_manager.base_mint;
+    let pool_id_bytes = pool_manager.pool_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"pool-manager",
+        base_mint_key.as_ref(),
+        &pool_id_bytes,
+        &[bump],
+    ]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: vault.to_account_info(),
+                to: treasury.to_account_info(),
+                mint: base_mint.to_account_info(),
+                authority: pool_manager.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        performance_fee,
+        pool_manager.base_mint_decimals,
+    )?;
+
+    pool_manager.record_performance_fee(performance_fee)
+}
@@ -1,12 +1,15 @@
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
-pub mod u64x64_math;
+pub mod strategy_interface;
 
 use anchor_lang::prelude::*;
 
 pub use error::*;
+pub use events::*;
 pub use instructions::*;
+pub use parity_math::{u64x64_math, DEAD_SHARES, SECONDS_PER_YEAR};
 pub use state::*;
 pub use u64x64_math::*;
 
@@ -27,10 +30,67 @@ pub mod parity_staking {
         stake::handler(ctx, quantity)
     }
 
-    pub fn unstake(ctx: Context<Unstake>, quantity: u64) -> Result<()> {
+    pub fn unstake(ctx: Context<Unstake>, quantity: u64) -> Result<UnstakePath> {
         unstake::handler(ctx, quantity)
     }
 
+    pub fn preview_stake(ctx: Context<PreviewStake>, quantity: u64) -> Result<StakePreview> {
+        preview_stake::handler(ctx, quantity)
+    }
+
+    pub fn preview_unstake(
+        ctx: Context<PreviewUnstake>,
+        quantity: u64,
+    ) -> Result<UnstakePreview> {
+        preview_unstake::handler(ctx, quantity)
+    }
+
+    pub fn request_unstake(
+        ctx: Context<RequestUnstake>,
+        quantity: u64,
+        ticket_id: u64,
+    ) -> Result<()> {
+        request_unstake::handler(ctx, quantity, ticket_id)
+    }
+
+    pub fn claim_unstake(ctx: Context<ClaimUnstake>) -> Result<()> {
+        claim_unstake::handler(ctx)
+    }
+
+    pub fn mint_and_stake(ctx: Context<MintAndStake>, params: MintAndStakeParams) -> Result<()> {
+        mint_and_stake::handler(ctx, params)
+    }
+
+    pub fn unstake_and_redeem(
+        ctx: Context<UnstakeAndRedeem>,
+        params: UnstakeAndRedeemParams,
+    ) -> Result<()> {
+        unstake_and_redeem::handler(ctx, params)
+    }
+
+    pub fn open_term_position(
+        ctx: Context<OpenTermPosition>,
+        quantity: u64,
+        position_id: u64,
+    ) -> Result<()> {
+        open_term_position::handler(ctx, quantity, position_id)
+    }
+
+    pub fn close_term_position(ctx: Context<CloseTermPosition>) -> Result<()> {
+        close_term_position::handler(ctx)
+    }
+
+    pub fn initialize_tranche(
+        ctx: Context<InitializeTranche>,
+        params: InitializeTrancheParams,
+    ) -> Result<()> {
+        initialize_tranche::handler(ctx, params)
+    }
+
+    pub fn update_tranche(ctx: Context<UpdateTranche>, params: UpdateTrancheParams) -> Result<()> {
+        update_tranche::handler(ctx, params)
+    }
+
     pub fn update_annual_yield(
         ctx: Context<UpdateAnnualYield>,
         params: UpdateYieldParams,
@@ -38,6 +98,104 @@ pub mod parity_staking {
         update_annual_yield::handler(ctx, params)
     }
 
+    pub fn update_yield_schedule(
+        ctx: Context<UpdateYieldSchedule>,
+        params: UpdateYieldScheduleParams,
+    ) -> Result<()> {
+        update_yield_schedule::handler(ctx, params)
+    }
+
+    pub fn deposit_rewards(ctx: Context<DepositRewards>, quantity: u64) -> Result<()> {
+        deposit_rewards::handler(ctx, quantity)
+    }
+
+    pub fn accrue_yield(ctx: Context<AccrueYield>) -> Result<()> {
+        accrue_yield::handler(ctx)
+    }
+
+    pub fn get_effective_apy(ctx: Context<GetEffectiveApy>) -> Result<u64> {
+        get_effective_apy::handler(ctx)
+    }
+
+    pub fn get_trailing_apy(ctx: Context<GetTrailingApy>, window: i64) -> Result<u64> {
+        get_trailing_apy::handler(ctx, window)
+    }
+
+    pub fn get_accrued_yield(ctx: Context<GetAccruedYield>) -> Result<AccruedYield> {
+        get_accrued_yield::handler(ctx)
+    }
+
+    pub fn refresh_rate_feed(ctx: Context<RefreshRateFeed>) -> Result<()> {
+        refresh_rate_feed::handler(ctx)
+    }
+
+    pub fn add_guardian(ctx: Context<AddGuardian>, new_guardian: Pubkey) -> Result<()> {
+        add_guardian::handler(ctx, new_guardian)
+    }
+
+    pub fn remove_guardian(_ctx: Context<RemoveGuardian>) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn toggle_active(
+        ctx: Context<ToggleActive>,
+        stake_active: bool,
+        unstake_active: bool,
+    ) -> Result<()> {
+        toggle_active::handler(ctx, stake_active, unstake_active)
+    }
+
+    pub fn add_staker_entry(
+        ctx: Context<AddStakerEntry>,
+        params: AddStakerEntryParams,
+    ) -> Result<()> {
+        add_staker_entry::handler(ctx, params)
+    }
+
+    pub fn update_staker_entry(
+        ctx: Context<UpdateStakerEntry>,
+        params: UpdateStakerEntryParams,
+    ) -> Result<()> {
+        update_staker_entry::handler(ctx, params)
+    }
+
+    pub fn add_strategy(ctx: Context<AddStrategy>, cap: u64) -> Result<()> {
+        add_strategy::handler(ctx, cap)
+    }
+
+    pub fn update_strategy(
+        ctx: Context<UpdateStrategy>,
+        params: UpdateStrategyParams,
+    ) -> Result<()> {
+        update_strategy::handler(ctx, params)
+    }
+
+    pub fn allocate_to_strategy<'info>(
+        ctx: Context<'_, '_, '_, 'info, AllocateToStrategy<'info>>,
+        quantity: u64,
+    ) -> Result<()> {
+        allocate_to_strategy::handler(ctx, quantity)
+    }
+
+    pub fn withdraw_from_strategy<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawFromStrategy<'info>>,
+        quantity: u64,
+    ) -> Result<()> {
+        withdraw_from_strategy::handler(ctx, quantity)
+    }
+
+    pub fn report_strategy_value<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReportStrategyValue<'info>>,
+    ) -> Result<()> {
+        report_strategy_value::handler(ctx)
+    }
+
+    pub fn emergency_withdraw_all<'info>(
+        ctx: Context<'_, '_, '_, 'info, EmergencyWithdrawAll<'info>>,
+    ) -> Result<()> {
+        emergency_withdraw_all::handler(ctx)
+    }
+
     pub fn update_pool_manager(
         ctx: Context<UpdatePoolManager>,
         params: UpdatePoolManagerParams,
@@ -65,7 +223,19 @@ pub mod parity_staking {
         initiate_update_pool_owner::handler(ctx, new_owner)
     }
 
+    pub fn initiate_update_issuance(ctx: Context<InitiateUpdateIssuance>) -> Result<()> {
+        initiate_update_issuance::handler(ctx)
+    }
+
+    pub fn update_issuance(ctx: Context<UpdateIssuance>) -> Result<()> {
+        update_issuance::handler(ctx)
+    }
+
     pub fn withdraw_excess_parity(ctx: Context<WithdrawExcessParity>) -> Result<()> {
         withdraw_excess_parity::handler(ctx)
     }
+
+    pub fn migrate_pool_manager(ctx: Context<MigratePoolManager>, pool_id: u64) -> Result<()> {
+        migrate_pool_manager::handler(ctx, pool_id)
+    }
 }
@@ -1,14 +1,66 @@
 use anchor_lang::prelude::*;
+use anchor_spl::{token, token_2022};
+use parity_math::{compounding, conversion, decimals, fees, twap, vesting};
 
-use crate::{from_decimal, pow, to_decimal, ParityStakingError, PRECISION};
+use crate::{u64x64_math, ParityStakingError, DEAD_SHARES, SECONDS_PER_YEAR};
 
-pub const POOL_MANAGER_LENGTH: usize = 8 + 1 + (32 * 5) + 1 + 1 + (8 * 8) + 4;
+pub const POOL_MANAGER_LENGTH: usize =
+    8 + 1 + (32 * 12) + 1 + 1 + (8 * 33) + 4 + (2 * 5) + 1 + 1 + 1 + 1 + 1 + 1 + 1;
+
+// Single pool manager at `[b"pool-manager"]`, before pools were keyed by base mint and pool id.
+// It was allocated 8 bytes more than its fields take
+pub const LEGACY_POOL_MANAGER_LENGTH: usize = 8 + 1 + (32 * 5) + 1 + 1 + (8 * 8) + 4;
+
+pub const UNSTAKE_TICKET_LENGTH: usize = 8 + 1 + (32 * 2) + (8 * 5);
+
+pub const GUARDIAN_LENGTH: usize = 8 + 32 + 32;
+
+pub const TRANCHE_LENGTH: usize = 8 + 1 + 32 + (8 * 6) + 1;
+
+pub const TERM_POSITION_LENGTH: usize = 8 + 1 + (32 * 3) + (8 * 6);
 
 pub const MAX_INTERVAL_APR_RATE: u64 = 40006660682680;
 
+pub const MAX_SCHEDULED_RATES: usize = 16;
+
+pub const YIELD_SCHEDULE_LENGTH: usize = 8 + 1 + 32 + 4 + (MAX_SCHEDULED_RATES * (8 * 2));
+
+pub const RATE_FEED_LENGTH: usize =
+    8 + 1 + 1 + (32 * 3) + 1 + (8 * 4) + 16 + 4 + 4 + (RATE_FEED_OBSERVATIONS * ((8 * 2) + 16));
+
+// Bumped whenever fields are appended to the rate feed
+pub const RATE_FEED_VERSION: u8 = 1;
+
+// Window of the rate feed TWAP
+pub const RATE_FEED_TWAP_WINDOW: i64 = 24 * 60 * 60;
+
+// Rate feed observations kept, so that the oldest is at least a TWAP window old
+pub const RATE_FEED_OBSERVATIONS: usize = 25;
+
+// Minimum seconds between rate feed observations
+pub const RATE_FEED_OBSERVATION_INTERVAL: i64 =
+    RATE_FEED_TWAP_WINDOW / (RATE_FEED_OBSERVATIONS as i64 - 1);
+
+pub const MAX_RATE_CHECKPOINTS: usize = 90;
+
+pub const RATE_HISTORY_LENGTH: usize = 8 + 1 + 32 + 4 + 4 + (MAX_RATE_CHECKPOINTS * (8 * 2));
+
+// Rate checkpoint spacing of new pools, 90 days of history at 90 checkpoints
+pub const DEFAULT_CHECKPOINT_INTERVAL: i64 = 24 * 60 * 60;
+
+pub const STRATEGY_LENGTH: usize = 8 + 1 + (32 * 2) + 1 + (8 * 4);
+
+pub const STAKER_ENTRY_LENGTH: usize = 8 + 1 + (32 * 2) + 1 + (8 * 2);
+
+pub const MAX_PERFORMANCE_FEE_BPS: u16 = 5000;
+
+// Delay before a re-pointed issuance program and token manager take effect
+pub const ISSUANCE_UPDATE_TIMELOCK: i64 = 2 * 24 * 60 * 60;
+
 #[account]
 pub struct PoolManager {
-    pub bump: u8, // 1 byte
+    pub bump: u8,     // 1 byte
+    pub pool_id: u64, // 8 bytes - distinguishes pools sharing a base mint
 
     // Authorities
     pub owner: Pubkey,         // 32 bytes
@@ -22,7 +74,8 @@ pub struct PoolManager {
     pub x_mint_decimals: u8,    // 1 byte
 
     // Yield
-    pub interval_apr_rate: u64, // 8 bytes - Stored as basis points, e.g., 2000 for 20%
+    pub annual_yield_bps: u64, // 8 bytes - Stored as basis points, e.g., 2000 for 20%
+    pub interval_apr_rate: u64, // 8 bytes - Per interval compounding factor derived from annual_yield_bps
     pub seconds_per_interval: i32, // 4 bytes
     pub initial_exchange_rate: u64, // 8 bytes
     pub last_yield_change_exchange_rate: u64, // 8 bytes
@@ -30,317 +83,2399 @@ pub struct PoolManager {
     pub last_yield_change_timestamp: i64, // 8 bytes
 
     // Other
-    pub base_balance: u64, // 8 bytes
+    pub base_balance: u64, // 8 bytes - principal_amount + realized_yield_amount
     // Deposit cap
     pub deposit_cap: u64, // 8 bytes
+
+    // Unstake cooldown
+    pub unstake_cooldown: i64,       // 8 bytes
+    pub pending_unstake_amount: u64, // 8 bytes - base amount locked in unstake tickets
+
+    // Instant unstake
+    pub treasury: Pubkey,             // 32 bytes - base mint token account
+    pub instant_unstake_fee_bps: u16, // 2 bytes
+    pub instant_unstake_fee_destination: FeeDestination, // 1 byte
+    pub liquidity_buffer_target: u64, // 8 bytes
+    pub liquidity_buffer_amount: u64, // 8 bytes - part of the vault reserved for instant unstakes
+
+    // Minimum amounts
+    pub min_stake_amount: u64,   // 8 bytes - base mint
+    pub min_unstake_amount: u64, // 8 bytes - x mint
+
+    // Issuance binding
+    pub issuance_program: Pubkey, // 32 bytes - program minting the yield
+    pub token_manager: Pubkey,    // 32 bytes - token manager of the base mint
+    pub pending_issuance_program: Pubkey, // 32 bytes
+    pub pending_token_manager: Pubkey, // 32 bytes
+    pub issuance_update_timestamp: i64, // 8 bytes - when the pending binding can be applied
+
+    // Yield accounting
+    pub principal_amount: u64, // 8 bytes - base staked, net of the principal paid out
+    pub realized_yield_amount: u64, // 8 bytes - yield minted or deposited into the vault, net of the yield paid out
+    pub total_yield_minted: u64,    // 8 bytes - cumulative yield minted into the vault
+    pub total_boost_minted: u64, // 8 bytes - cumulative term boost minted and paid out, part of total_yield_minted
+
+    // Yield accrual crank
+    pub min_accrual_interval: i64, // 8 bytes - seconds between accrue_yield calls
+    pub last_accrual_timestamp: i64, // 8 bytes
+    pub keeper_reward: u64,        // 8 bytes - base mint, paid to the accrue_yield caller
+    pub total_keeper_rewards: u64, // 8 bytes - cumulative keeper rewards minted and paid out
+
+    // Reward streaming
+    pub yield_mode: YieldMode,        // 1 byte
+    pub x_supply: u64, // 8 bytes - x tokens minted by the pool, mirrors the x mint supply
+    pub rewarder: Pubkey, // 32 bytes - deposits the rewards in RewardStreaming mode
+    pub vesting_period: i64, // 8 bytes
+    pub vesting_amount: u64, // 8 bytes - rewards vesting since vesting_start_timestamp
+    pub vesting_start_timestamp: i64, // 8 bytes
+    pub total_rewards_deposited: u64, // 8 bytes - cumulative rewards deposited into the vault
+
+    // Performance fee
+    pub performance_fee_bps: u16, // 2 bytes - share of the yield sent to the treasury
+    pub total_performance_fees: u64, // 8 bytes - cumulative performance fees sent to the treasury
+
+    // Entry and exit fees
+    pub entry_fee_bps: u16,                    // 2 bytes - charged on stakes
+    pub entry_fee_destination: FeeDestination, // 1 byte
+    pub exit_fee_bps: u16, // 2 bytes - charged on unstakes, on top of the instant unstake fee
+    pub exit_fee_destination: FeeDestination, // 1 byte
+
+    // Emergency controls
+    pub stake_active: bool,   // 1 byte - paused by the admin or a guardian
+    pub unstake_active: bool, // 1 byte - covers unstake requests and claims
+
+    // Interest-bearing x mint
+    pub x_token_program: Pubkey, // 32 bytes - Token-2022 when the x mint is interest-bearing
+
+    // Rate history
+    pub checkpoint_interval: i64, // 8 bytes - minimum seconds between rate checkpoints
+
+    // Yield strategies
+    pub max_strategy_allocation_bps: u16, // 2 bytes - share of the pool assets strategies may hold
+    pub strategy_value: u64,              // 8 bytes - base held by the strategies, as last reported
+
+    // Staking access
+    pub allowlist_enabled: bool, // 1 byte - only wallets with an allowed staker entry can stake
+    pub wallet_deposit_cap: u64, // 8 bytes - base each wallet can stake, 0 for no cap
 }
 
-impl PoolManager {
-    pub fn calculate_exchange_rate(&mut self, current_timestamp: i64) -> Option<u64> {
-        if current_timestamp == self.last_yield_change_timestamp {
-            return Some(self.last_yield_change_exchange_rate);
-        }
+/// Layout of the pool manager before pools were keyed by base mint and pool
+/// id, read by `migrate_pool_manager`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyPoolManager {
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub admin: Pubkey,
+    pub base_mint: Pubkey,
+    pub x_mint: Pubkey,
+    pub base_mint_decimals: u8,
+    pub x_mint_decimals: u8,
+    pub interval_apr_rate: u64,
+    pub seconds_per_interval: i32,
+    pub initial_exchange_rate: u64,
+    pub last_yield_change_exchange_rate: u64,
+    pub inception_timestamp: i64,
+    pub last_yield_change_timestamp: i64,
+    pub base_balance: u64,
+    pub deposit_cap: u64,
+}
 
-        let elapsed_time = current_timestamp.checked_sub(self.last_yield_change_timestamp)?;
-        msg!("Elapsed time: {}", elapsed_time);
+#[account]
+pub struct Guardian {
+    pub pool_manager: Pubkey, // 32 bytes
+    pub wallet: Pubkey,       // 32 bytes - can pause staking and unstaking
+}
+
+/// Strategy program whitelisted to hold part of the vault, at
+/// `[b"strategy", pool_manager, strategy_program]`.
+#[account]
+pub struct Strategy {
+    pub bump: u8,                   // 1 byte
+    pub pool_manager: Pubkey,       // 32 bytes
+    pub strategy_program: Pubkey,   // 32 bytes - implements the strategy interface
+    pub active: bool,               // 1 byte - open to new allocations
+    pub cap: u64,                   // 8 bytes - most base the strategy may hold
+    pub allocated_amount: u64,      // 8 bytes - base deposited, net of the base withdrawn
+    pub reported_value: u64,        // 8 bytes - base held, as last reported
+    pub last_report_timestamp: i64, // 8 bytes
+}
+
+/// Staking allowance of a wallet, at `[b"staker-entry", pool_manager, wallet]`.
+#[account]
+pub struct StakerEntry {
+    pub bump: u8,             // 1 byte
+    pub pool_manager: Pubkey, // 32 bytes
+    pub wallet: Pubkey,       // 32 bytes
+    pub allowed: bool,        // 1 byte - admitted by the admin while the allowlist is enabled
+    pub deposit_cap: u64,     // 8 bytes - overrides the pool wallet deposit cap, 0 to keep it
+    pub staked_amount: u64,   // 8 bytes - base staked by the wallet, net of the entry fee
+}
 
-        let interval_amounts = elapsed_time.checked_div(self.seconds_per_interval as i64)?;
-        let remaining_seconds = elapsed_time.checked_rem(self.seconds_per_interval as i64)?;
-        msg!("intervals: {}", interval_amounts);
-        msg!("Remaining seconds: {}", remaining_seconds);
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeDestination {
+    Stakers,
+    Treasury,
+}
 
-        let interval_rate = self.interval_apr_rate as u128;
-        msg!("Interval Rate: {}", interval_rate);
+/// How the exchange rate grows.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum YieldMode {
+    /// Compounds the interval APR rate, minting the yield through the issuance program
+    Compounding,
+    /// Vault assets over x supply, grown by rewards vesting linearly after a deposit
+    RewardStreaming,
+}
 
-        let compounded_rate = to_decimal(
-            pow(
-                from_decimal(interval_rate).unwrap(),
-                interval_amounts as i32,
-            )
-            .unwrap(),
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnstakePath {
+    Standard,
+    Instant,
+}
+
+#[account]
+pub struct UnstakeTicket {
+    pub bump: u8,                 // 1 byte
+    pub owner: Pubkey,            // 32 bytes
+    pub pool_manager: Pubkey,     // 32 bytes
+    pub ticket_id: u64,           // 8 bytes
+    pub x_amount: u64,            // 8 bytes - spUSD burnt on request
+    pub base_amount: u64,         // 8 bytes - pUSD fixed at the request exchange rate
+    pub request_timestamp: i64,   // 8 bytes
+    pub claimable_timestamp: i64, // 8 bytes
+}
+
+#[account]
+pub struct Tranche {
+    pub bump: u8,                    // 1 byte
+    pub pool_manager: Pubkey,        // 32 bytes
+    pub tranche_id: u64,             // 8 bytes
+    pub term_duration: i64,          // 8 bytes - seconds from opening a position to maturity
+    pub annual_yield_bps: u64,       // 8 bytes - boosted rate for new positions
+    pub interval_apr_rate: u64,      // 8 bytes - derived from annual_yield_bps
+    pub active: bool,                // 1 byte - open to new positions
+    pub total_principal_amount: u64, // 8 bytes - base staked by the open positions
+    pub total_boost_paid: u64,       // 8 bytes - cumulative boost minted at maturity
+}
+
+#[account]
+pub struct TermPosition {
+    pub bump: u8,                // 1 byte
+    pub owner: Pubkey,           // 32 bytes
+    pub pool_manager: Pubkey,    // 32 bytes
+    pub tranche: Pubkey,         // 32 bytes
+    pub position_id: u64,        // 8 bytes
+    pub principal_amount: u64,   // 8 bytes - base staked, net of the entry fee
+    pub x_amount: u64,           // 8 bytes - spUSD locked in the tranche vault
+    pub interval_apr_rate: u64,  // 8 bytes - tranche rate locked in at opening
+    pub start_timestamp: i64,    // 8 bytes
+    pub maturity_timestamp: i64, // 8 bytes
+}
+
+#[account]
+pub struct YieldSchedule {
+    pub bump: u8,                            // 1 byte
+    pub pool_manager: Pubkey,                // 32 bytes
+    pub scheduled_rates: Vec<ScheduledRate>, // 4 + (16 * MAX_SCHEDULED_RATES) bytes - sorted by effective timestamp
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScheduledRate {
+    pub effective_timestamp: i64, // 8 bytes
+    pub annual_yield_bps: u64,    // 8 bytes
+}
+
+/// Exchange rate of a pool published for other programs, at
+/// `[b"rate-feed", pool_manager]`. The layout is stable: fields are only ever
+/// appended, with `version` bumped, so readers can rely on these offsets after
+/// the 8 byte discriminator:
+///
+/// | offset | field                      | type                 |
+/// |--------|----------------------------|----------------------|
+/// | 0      | `version`                  | u8                   |
+/// | 1      | `bump`                     | u8                   |
+/// | 2      | `pool_manager`             | Pubkey               |
+/// | 34     | `x_mint`                   | Pubkey               |
+/// | 66     | `base_mint`                | Pubkey               |
+/// | 98     | `base_mint_decimals`       | u8                   |
+/// | 99     | `exchange_rate`            | u64                  |
+/// | 107    | `last_update_timestamp`    | i64                  |
+/// | 115    | `twap_exchange_rate`       | u64                  |
+/// | 123    | `realized_apy_bps`         | u64                  |
+/// | 131    | `cumulative_exchange_rate` | u128                 |
+/// | 147    | `next_observation_index`   | u32                  |
+/// | 151    | `observations`             | Vec<RateObservation> |
+///
+/// Integers are little-endian. Rates are base per x, in base mint decimals.
+///
+/// `twap_exchange_rate` is the average rate over the last
+/// `RATE_FEED_TWAP_WINDOW`, taken from the cumulative rate against the
+/// observations around the start of the window.
+#[account]
+pub struct RateFeed {
+    pub version: u8,                        // 1 byte
+    pub bump: u8,                           // 1 byte
+    pub pool_manager: Pubkey,               // 32 bytes
+    pub x_mint: Pubkey,                     // 32 bytes - spUSD token mint
+    pub base_mint: Pubkey,                  // 32 bytes - pUSD token mint
+    pub base_mint_decimals: u8,             // 1 byte
+    pub exchange_rate: u64,                 // 8 bytes
+    pub last_update_timestamp: i64,         // 8 bytes
+    pub twap_exchange_rate: u64,            // 8 bytes - average over RATE_FEED_TWAP_WINDOW
+    pub realized_apy_bps: u64,              // 8 bytes - compounded since inception
+    pub cumulative_exchange_rate: u128, // 16 bytes - exchange rate times seconds since the feed started
+    pub next_observation_index: u32,    // 4 bytes - observation overwritten next once full
+    pub observations: Vec<RateObservation>, // 4 + (32 * RATE_FEED_OBSERVATIONS) bytes - oldest at next_observation_index once full
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateObservation {
+    pub timestamp: i64,                 // 8 bytes
+    pub exchange_rate: u64,             // 8 bytes
+    pub cumulative_exchange_rate: u128, // 16 bytes
+}
+
+/// Ring buffer of exchange rate checkpoints, at `[b"rate-history", pool_manager]`.
+#[account]
+pub struct RateHistory {
+    pub bump: u8,                         // 1 byte
+    pub pool_manager: Pubkey,             // 32 bytes
+    pub next_index: u32,                  // 4 bytes - checkpoint overwritten next once full
+    pub checkpoints: Vec<RateCheckpoint>, // 4 + (16 * MAX_RATE_CHECKPOINTS) bytes - oldest at next_index once full
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateCheckpoint {
+    pub timestamp: i64,     // 8 bytes
+    pub exchange_rate: u64, // 8 bytes
+}
+
+/// Yield accounting returned by `get_accrued_yield`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AccruedYield {
+    pub accrued_unminted_yield: u64, // base owed to stakers that is not minted yet
+    pub principal_amount: u64,
+    pub realized_yield_amount: u64,
+    pub total_yield_minted: u64,
+    pub total_boost_minted: u64,
+    pub total_keeper_rewards: u64,
+    pub exchange_rate: u64, // base per x, in base mint decimals
+}
+
+/// Quote returned by `preview_stake`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StakePreview {
+    pub x_amount: u64,         // x tokens minted for the quantity
+    pub fee_amount: u64,       // base tokens withheld as fees
+    pub exchange_rate: u64,    // base per x, in base mint decimals
+    pub min_stake_amount: u64, // base mint
+    pub max_stake_amount: u64, // base mint - room left under the deposit cap
+}
+
+/// Quote returned by `preview_unstake`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnstakePreview {
+    pub base_amount: u64,   // base tokens paid out, net of fees
+    pub fee_amount: u64,    // base tokens withheld as fees
+    pub exchange_rate: u64, // base per x, in base mint decimals
+    pub unstake_path: UnstakePath,
+    pub min_unstake_amount: u64, // x mint
+    pub max_base_amount: u64,    // base mint - liquidity available to the path
+}
+
+impl YieldSchedule {
+    /// Compounds the pool up to the schedule boundaries that have passed and
+    /// drops the scheduled rates that are now in effect.
+    pub fn apply(&mut self, pool_manager: &mut PoolManager, current_timestamp: i64) -> Result<()> {
+        let applied_rates =
+            pool_manager.apply_scheduled_rates(&self.scheduled_rates, current_timestamp)?;
+        self.scheduled_rates.drain(..applied_rates);
+
+        Ok(())
+    }
+}
+
+impl RateFeed {
+    /// Records the pool exchange rate at `current_timestamp` and sets
+    /// `twap_exchange_rate` to its average over the last
+    /// `RATE_FEED_TWAP_WINDOW`, or since the feed started while it is younger.
+    pub fn refresh(&mut self, pool_manager: &PoolManager, current_timestamp: i64) -> Result<()> {
+        let exchange_rate = pool_manager.calculate_exchange_rate(current_timestamp)?;
+
+        // Several refreshes in the same slot leave the average where it is
+        let elapsed_time = current_timestamp
+            .checked_sub(self.last_update_timestamp)
+            .ok_or(ParityStakingError::CalculationOverflow)?
+            .max(0);
+        self.cumulative_exchange_rate = twap::accumulate(
+            self.cumulative_exchange_rate,
+            self.exchange_rate,
+            exchange_rate,
+            elapsed_time,
         )
-        .unwrap();
-        msg!("Compounded rate: {}", compounded_rate);
+        .ok_or(ParityStakingError::CalculationOverflow)?;
 
-        // Calculate the linear yield for the remaining seconds
-        let linear_yield = (interval_rate as u128)
-            .checked_sub(PRECISION)
-            .unwrap()
-            .checked_mul(remaining_seconds as u128)
-            .unwrap()
-            .checked_div(self.seconds_per_interval as u128)
-            .unwrap();
-        msg!("Linear yield: {}", linear_yield);
+        // Annualizing less than a window of history is too noisy to publish
+        let pool_age = current_timestamp
+            .checked_sub(pool_manager.inception_timestamp)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.realized_apy_bps = if pool_age < RATE_FEED_TWAP_WINDOW {
+            0
+        } else {
+            compounding::annualized_yield_bps(
+                pool_manager.initial_exchange_rate,
+                exchange_rate,
+                pool_age,
+            )
+            .ok_or(ParityStakingError::CalculationOverflow)?
+        };
 
-        // Add the linear yield to the compounded rate
-        let total_rate = compounded_rate.checked_add(linear_yield).unwrap();
-        msg!("Total rate: {}", total_rate);
+        self.exchange_rate = exchange_rate;
+        self.last_update_timestamp = current_timestamp;
+        self.twap_exchange_rate = self.calculate_twap_exchange_rate()?;
+        self.record_observation()?;
 
-        // Multiply the current exchange rate with the total rate
-        let new_exchange_rate = (self.last_yield_change_exchange_rate as u128)
-            .checked_mul(total_rate)
-            .unwrap()
-            .checked_div(PRECISION as u128)
-            .unwrap() as u64;
+        Ok(())
+    }
 
-        msg!("New exchange rate: {}", new_exchange_rate);
+    /// Average exchange rate over the last `RATE_FEED_TWAP_WINDOW`, or since
+    /// the oldest observation while the feed is younger than a window.
+    pub fn calculate_twap_exchange_rate(&self) -> Result<u64> {
+        let window_start_timestamp = self
+            .last_update_timestamp
+            .checked_sub(RATE_FEED_TWAP_WINDOW)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        let older_observation = self
+            .observations
+            .iter()
+            .filter(|observation| observation.timestamp <= window_start_timestamp)
+            .max_by_key(|observation| observation.timestamp);
+
+        let (start_timestamp, start_cumulative_exchange_rate) = match older_observation {
+            // Interpolate towards the next observation, or the latest rate
+            Some(older_observation) => {
+                let (newer_timestamp, newer_exchange_rate) = self
+                    .observations
+                    .iter()
+                    .filter(|observation| observation.timestamp > window_start_timestamp)
+                    .min_by_key(|observation| observation.timestamp)
+                    .map(|observation| (observation.timestamp, observation.exchange_rate))
+                    .unwrap_or((self.last_update_timestamp, self.exchange_rate));
+
+                let cumulative_exchange_rate = twap::interpolate(
+                    older_observation.cumulative_exchange_rate,
+                    older_observation.exchange_rate,
+                    newer_exchange_rate,
+                    window_start_timestamp - older_observation.timestamp,
+                    newer_timestamp - older_observation.timestamp,
+                )
+                .ok_or(ParityStakingError::CalculationOverflow)?;
 
-        Some(new_exchange_rate as u64)
+                (window_start_timestamp, cumulative_exchange_rate)
+            }
+            None => match self
+                .observations
+                .iter()
+                .min_by_key(|observation| observation.timestamp)
+            {
+                Some(oldest_observation) => (
+                    oldest_observation.timestamp,
+                    oldest_observation.cumulative_exchange_rate,
+                ),
+                None => return Ok(self.exchange_rate),
+            },
+        };
+
+        if start_timestamp >= self.last_update_timestamp {
+            return Ok(self.exchange_rate);
+        }
+
+        twap::time_weighted_average(
+            start_cumulative_exchange_rate,
+            self.cumulative_exchange_rate,
+            self.last_update_timestamp - start_timestamp,
+        )
+        .ok_or(ParityStakingError::CalculationOverflow.into())
     }
 
-    pub fn calculate_normalized_quantity(
-        &self,
-        quantity: u64,
-        from_decimals: u8,
-        to_decimals: u8,
-    ) -> Result<u64> {
-        let decimal_difference = (from_decimals as i32 - to_decimals as i32).abs() as u32;
-
-        if from_decimals > to_decimals {
-            (quantity as u128)
-                .checked_div(10u128.pow(decimal_difference))
-                .ok_or(ParityStakingError::CalculationOverflow.into())
-                .map(|result| result as u64)
-        } else if from_decimals < to_decimals {
-            (quantity as u128)
-                .checked_mul(10u128.pow(decimal_difference))
-                .ok_or(ParityStakingError::CalculationOverflow.into())
-                .map(|result| result as u64)
+    /// Records the latest rate unless the latest observation is less than
+    /// `RATE_FEED_OBSERVATION_INTERVAL` old, overwriting the oldest
+    /// observation once the buffer is full. Returns whether one was written.
+    pub fn record_observation(&mut self) -> Result<bool> {
+        let len = self.observations.len();
+        if len > 0 {
+            let latest_observation =
+                &self.observations[(self.next_observation_index as usize + len - 1) % len];
+            let next_observation_timestamp = latest_observation
+                .timestamp
+                .checked_add(RATE_FEED_OBSERVATION_INTERVAL)
+                .ok_or(ParityStakingError::CalculationOverflow)?;
+
+            if self.last_update_timestamp < next_observation_timestamp {
+                return Ok(false);
+            }
+        }
+
+        let observation = RateObservation {
+            timestamp: self.last_update_timestamp,
+            exchange_rate: self.exchange_rate,
+            cumulative_exchange_rate: self.cumulative_exchange_rate,
+        };
+
+        let index = self.next_observation_index as usize;
+        if index < len {
+            self.observations[index] = observation;
         } else {
-            Ok(quantity)
+            self.observations.push(observation);
         }
+        self.next_observation_index = ((index + 1) % RATE_FEED_OBSERVATIONS) as u32;
+
+        Ok(true)
     }
+}
 
-    pub fn calculate_amount_to_mint(
-        &mut self,
-        x_mint_supply: u64,
-        current_timestamp: i64,
-        vault_balance: u64,
-    ) -> Result<u64> {
-        // Normalize the x_mint_supply to the base_mint decimals
-        let base_decimals = 10u64.pow(self.base_mint_decimals.into());
-        let normalized_x_mint_supply = self.calculate_normalized_quantity(
-            x_mint_supply,
-            self.x_mint_decimals,
-            self.base_mint_decimals,
-        )?;
+impl RateHistory {
+    /// Most recent checkpoint.
+    pub fn latest_checkpoint(&self) -> Option<&RateCheckpoint> {
+        let len = self.checkpoints.len();
+        if len == 0 {
+            return None;
+        }
 
-        let exchange_rate = self
-            .calculate_exchange_rate(current_timestamp)
-            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.checkpoints
+            .get((self.next_index as usize + len - 1) % len)
+    }
 
-        // Calculate the x_supply_value
-        let x_supply_value = (normalized_x_mint_supply as u128)
-            .checked_mul(exchange_rate as u128)
-            .ok_or(ParityStakingError::CalculationOverflow)?
-            .checked_div(base_decimals as u128)
-            .ok_or(ParityStakingError::CalculationOverflow)?;
+    /// Newest checkpoint taken at or before `timestamp`.
+    pub fn checkpoint_at(&self, timestamp: i64) -> Option<&RateCheckpoint> {
+        self.checkpoints
+            .iter()
+            .filter(|checkpoint| checkpoint.timestamp <= timestamp)
+            .max_by_key(|checkpoint| checkpoint.timestamp)
+    }
 
-        // let base_balance = self.base_balance as u128;
-        let vault_balance = vault_balance as u128;
+    /// Records `exchange_rate` unless the latest checkpoint is less than
+    /// `checkpoint_interval` old, overwriting the oldest checkpoint once the
+    /// buffer is full. Returns whether a checkpoint was written.
+    pub fn record(
+        &mut self,
+        exchange_rate: u64,
+        current_timestamp: i64,
+        checkpoint_interval: i64,
+    ) -> Result<bool> {
+        if let Some(latest_checkpoint) = self.latest_checkpoint() {
+            let next_checkpoint_timestamp = latest_checkpoint
+                .timestamp
+                .checked_add(checkpoint_interval)
+                .ok_or(ParityStakingError::CalculationOverflow)?;
 
-        msg!("X Supply Value: {}", x_supply_value);
-        // msg!("Base Balance: {}", base_balance);
-        msg!("Vault Balance: {}", vault_balance);
+            if current_timestamp < next_checkpoint_timestamp {
+                return Ok(false);
+            }
+        }
 
-        // Calculate the amount to mint
-        let amount_to_mint = if x_supply_value > vault_balance {
-            x_supply_value
-                .checked_sub(vault_balance)
-                .ok_or(ParityStakingError::CalculationOverflow)? as u64
-        } else {
-            0
+        let checkpoint = RateCheckpoint {
+            timestamp: current_timestamp,
+            exchange_rate,
         };
 
-        msg!("Amount to mint: {}", amount_to_mint);
+        let index = self.next_index as usize;
+        if index < self.checkpoints.len() {
+            self.checkpoints[index] = checkpoint;
+        } else {
+            self.checkpoints.push(checkpoint);
+        }
+        self.next_index = ((index + 1) % MAX_RATE_CHECKPOINTS) as u32;
 
-        Ok(amount_to_mint)
+        Ok(true)
     }
 
-    pub fn calculate_output_amount(
-        &mut self,
-        quantity: u64,
+    /// Annual yield in basis points realized from the newest checkpoint at
+    /// least `window` seconds old up to `exchange_rate`.
+    pub fn calculate_trailing_apy_bps(
+        &self,
+        exchange_rate: u64,
+        window: i64,
         current_timestamp: i64,
-        is_base_to_x: bool, // true if converting from baseMint to xMint, false otherwise
     ) -> Result<u64> {
-        let (from_decimals, to_decimals) = if is_base_to_x {
-            (self.base_mint_decimals, self.x_mint_decimals)
-        } else {
-            (self.x_mint_decimals, self.base_mint_decimals)
-        };
+        if window <= 0 {
+            return err!(ParityStakingError::InvalidParam);
+        }
 
-        let exchange_rate = self
-            .calculate_exchange_rate(current_timestamp)
+        let window_start_timestamp = current_timestamp
+            .checked_sub(window)
             .ok_or(ParityStakingError::CalculationOverflow)?;
+        let checkpoint = self
+            .checkpoint_at(window_start_timestamp)
+            .ok_or(ParityStakingError::InsufficientRateHistory)?;
 
-        // Normalize the input quantity
-        let normalized_quantity =
-            self.calculate_normalized_quantity(quantity, from_decimals, to_decimals)?;
+        let elapsed_time = current_timestamp
+            .checked_sub(checkpoint.timestamp)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
 
-        msg!("Normalized quantity: {}", normalized_quantity);
+        compounding::annualized_yield_bps(checkpoint.exchange_rate, exchange_rate, elapsed_time)
+            .ok_or(ParityStakingError::CalculationOverflow.into())
+    }
+}
 
-        // Calculate the output amount based on the direction of conversion
-        let output_amount = if is_base_to_x {
-            // Converting from baseMint to xMint
-            (normalized_quantity as u128)
-                .checked_mul(10u128.pow(to_decimals.into()))
-                .ok_or(ParityStakingError::CalculationOverflow)?
-                .checked_div(exchange_rate as u128)
-                .ok_or(ParityStakingError::CalculationOverflow)?
-        } else {
-            // Converting from xMint to baseMint
-            (normalized_quantity as u128)
-                .checked_mul(exchange_rate as u128)
-                .ok_or(ParityStakingError::CalculationOverflow)?
-                .checked_div(10u128.pow(to_decimals.into()))
-                .ok_or(ParityStakingError::CalculationOverflow)?
-        } as u64;
+impl Strategy {
+    /// Checks that the strategy takes new allocations and stays under its cap
+    /// once it holds `base_amount` more.
+    pub fn check_allocation(&self, base_amount: u64) -> Result<()> {
+        if !self.active {
+            return err!(ParityStakingError::StrategyInactive);
+        }
 
-        msg!("Output amount: {}", output_amount);
+        let new_value = self
+            .reported_value
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        if new_value > self.cap {
+            return err!(ParityStakingError::StrategyCapExceeded);
+        }
 
-        Ok(output_amount)
+        Ok(())
     }
 
-    pub fn check_excessive_deposit(&self, quote_amount: u64, vault_amount: u64) -> Result<()> {
-        let new_vault_amount = (vault_amount as u128)
-            .checked_add(quote_amount as u128)
+    pub fn record_deposit(&mut self, base_amount: u64) -> Result<()> {
+        self.allocated_amount = self
+            .allocated_amount
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.reported_value = self
+            .reported_value
+            .checked_add(base_amount)
             .ok_or(ParityStakingError::CalculationOverflow)?;
 
-        msg!("quote_amount: {}", quote_amount);
-        msg!("vault_amount: {}", vault_amount);
-        msg!("deposit_cap: {}", self.deposit_cap);
+        Ok(())
+    }
 
-        if new_vault_amount > self.deposit_cap as u128 {
-            return err!(ParityStakingError::DepositCapExceeded);
-        }
+    /// Records `base_amount` returned to the vault, which the reported value
+    /// must already cover.
+    pub fn record_withdrawal(&mut self, base_amount: u64) -> Result<()> {
+        self.reported_value = self
+            .reported_value
+            .checked_sub(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        // Gains paid out reduce the allocation past zero
+        self.allocated_amount = self.allocated_amount.saturating_sub(base_amount);
 
         Ok(())
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    fn default_pool_manager() -> PoolManager {
-        PoolManager {
-            bump: 0,
-            owner: Pubkey::default(),
-            pending_owner: Pubkey::default(),
-            admin: Pubkey::default(),
-            base_mint: Pubkey::default(),
-            x_mint: Pubkey::default(),
-            base_mint_decimals: 6,
-            x_mint_decimals: 6,
-            interval_apr_rate: 1000166517567, // Interval APR rate without considering zeros
-            seconds_per_interval: 8 * 60 * 60, // 8 hours / Can be changed
-            initial_exchange_rate: 1000000,
-            last_yield_change_exchange_rate: 1000000,
-            inception_timestamp: 0,
-            last_yield_change_timestamp: 0,
-            base_balance: 0,
-            deposit_cap: 500000,
+impl StakerEntry {
+    /// Most base the wallet can stake in total, none when zero.
+    pub fn effective_deposit_cap(&self, pool_manager: &PoolManager) -> u64 {
+        match self.deposit_cap {
+            0 => pool_manager.wallet_deposit_cap,
+            deposit_cap => deposit_cap,
         }
     }
 
-    #[test]
-    fn test_calculate_exchange_rate() {
-        let mut pool_manager = default_pool_manager();
+    /// Records `base_amount` staked by the wallet, once it is allowed to stake
+    /// and stays under its deposit cap.
+    pub fn record_stake(&mut self, base_amount: u64, pool_manager: &PoolManager) -> Result<()> {
+        if pool_manager.allowlist_enabled && !self.allowed {
+            return err!(ParityStakingError::StakerNotAllowed);
+        }
 
-        // Test case where current_timestamp is equal to last_yield_change_timestamp
-        pool_manager.last_yield_change_timestamp = 1_000_000;
-        pool_manager.last_yield_change_exchange_rate = 1_000_000;
-        let result = pool_manager.calculate_exchange_rate(1_000_000).unwrap();
-        assert_eq!(result, 1_000_000);
+        let staked_amount = self
+            .staked_amount
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
 
-        // Test case where one year has passed
-        pool_manager.last_yield_change_timestamp = 0;
-        let current_timestamp = 31_536_000; // One year in seconds
-        let result = pool_manager
-            .calculate_exchange_rate(current_timestamp)
-            .unwrap();
-        println!("One year has passed: {}", result);
-        assert_eq!(result, 1_199_999); // The exchange rate should have increased
+        let deposit_cap = self.effective_deposit_cap(pool_manager);
+        if deposit_cap > 0 && staked_amount > deposit_cap {
+            return err!(ParityStakingError::WalletDepositCapExceeded);
+        }
 
-        // Test case where half a year has passed
-        pool_manager.last_yield_change_timestamp = 0;
-        let result = pool_manager
-            .calculate_exchange_rate(current_timestamp / 2)
-            .unwrap();
-        assert_eq!(result, 1_095_437); // The exchange rate should have increased but less than 20%
+        self.staked_amount = staked_amount;
+
+        Ok(())
     }
 
-    #[test]
-    fn test_calculate_normalized_quantity() {
-        let pool_manager = default_pool_manager();
+    /// Releases `base_amount` unstaked by the wallet from its staked amount.
+    /// Unstakes include the yield, so it stops at zero.
+    pub fn record_unstake(&mut self, base_amount: u64) {
+        self.staked_amount = self.staked_amount.saturating_sub(base_amount);
+    }
+}
 
-        // Test case where token decimals are equal
-        let result = pool_manager
-            .calculate_normalized_quantity(1000000, 6, 6)
-            .unwrap();
-        assert_eq!(result, 1000000);
+impl TermPosition {
+    /// Principal compounded at the locked-in tranche rate from opening to
+    /// maturity.
+    pub fn calculate_maturity_amount(&self, seconds_per_interval: i32) -> Result<u64> {
+        let term_duration = self
+            .maturity_timestamp
+            .checked_sub(self.start_timestamp)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        // Compounds an amount the same way an exchange rate is
+        compounding::compound_exchange_rate(
+            self.principal_amount,
+            self.interval_apr_rate,
+            seconds_per_interval as i64,
+            term_duration,
+        )
+        .ok_or(ParityStakingError::CalculationOverflow.into())
+    }
+
+    /// Base owed on top of `x_value`, what the locked x tokens are worth at the
+    /// pool rate, to pay out the maturity amount. Positions closed before
+    /// maturity forfeit it.
+    pub fn calculate_boost_amount(
+        &self,
+        x_value: u64,
+        seconds_per_interval: i32,
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        if current_timestamp < self.maturity_timestamp {
+            return Ok(0);
+        }
+
+        Ok(self
+            .calculate_maturity_amount(seconds_per_interval)?
+            .saturating_sub(x_value))
+    }
+}
+
+impl PoolManager {
+    /// Pool manager `pool_id` taking over a legacy pool. The yield curve and
+    /// balances carry over and everything added since starts from the same
+    /// defaults as `initialize_pool_manager`, with an SPL Token x mint.
+    pub fn from_legacy(
+        legacy: &LegacyPoolManager,
+        bump: u8,
+        pool_id: u64,
+        x_supply: u64,
+        current_timestamp: i64,
+    ) -> Result<Self> {
+        let mut pool_manager = Self {
+            bump,
+            pool_id,
+            owner: legacy.owner,
+            pending_owner: legacy.pending_owner,
+            admin: legacy.admin,
+            base_mint: legacy.base_mint,
+            x_mint: legacy.x_mint,
+            base_mint_decimals: legacy.base_mint_decimals,
+            x_mint_decimals: legacy.x_mint_decimals,
+            annual_yield_bps: 0,
+            interval_apr_rate: legacy.interval_apr_rate,
+            seconds_per_interval: legacy.seconds_per_interval,
+            initial_exchange_rate: legacy.initial_exchange_rate,
+            last_yield_change_exchange_rate: legacy.last_yield_change_exchange_rate,
+            inception_timestamp: legacy.inception_timestamp,
+            last_yield_change_timestamp: legacy.last_yield_change_timestamp,
+            base_balance: legacy.base_balance,
+            deposit_cap: legacy.deposit_cap,
+            unstake_cooldown: 0,
+            pending_unstake_amount: 0,
+            treasury: Pubkey::default(),
+            instant_unstake_fee_bps: 0,
+            instant_unstake_fee_destination: FeeDestination::Stakers,
+            liquidity_buffer_target: 0,
+            liquidity_buffer_amount: 0,
+            min_stake_amount: 0,
+            min_unstake_amount: 0,
+            issuance_program: Pubkey::default(),
+            token_manager: Pubkey::default(),
+            pending_issuance_program: Pubkey::default(),
+            pending_token_manager: Pubkey::default(),
+            issuance_update_timestamp: 0,
+            // Legacy pools minted the yield on unstake, so the vault only holds principal
+            principal_amount: legacy.base_balance,
+            realized_yield_amount: 0,
+            total_yield_minted: 0,
+            total_boost_minted: 0,
+            min_accrual_interval: 0,
+            last_accrual_timestamp: current_timestamp,
+            keeper_reward: 0,
+            total_keeper_rewards: 0,
+            yield_mode: YieldMode::Compounding,
+            x_supply,
+            rewarder: Pubkey::default(),
+            vesting_period: 0,
+            vesting_amount: 0,
+            vesting_start_timestamp: current_timestamp,
+            total_rewards_deposited: 0,
+            performance_fee_bps: 0,
+            total_performance_fees: 0,
+            entry_fee_bps: 0,
+            entry_fee_destination: FeeDestination::Stakers,
+            exit_fee_bps: 0,
+            exit_fee_destination: FeeDestination::Stakers,
+            stake_active: true,
+            unstake_active: true,
+            x_token_program: token::ID,
+            checkpoint_interval: DEFAULT_CHECKPOINT_INTERVAL,
+            max_strategy_allocation_bps: 0,
+            strategy_value: 0,
+            allowlist_enabled: false,
+            wallet_deposit_cap: 0,
+        };
+
+        // Legacy pools only stored the interval APR rate
+        pool_manager.annual_yield_bps = pool_manager.calculate_effective_apy_bps()?;
+
+        Ok(pool_manager)
+    }
+
+    /// Compounds the exchange rate up to each scheduled rate whose effective
+    /// timestamp has passed and switches to it from that point on. Returns the
+    /// number of scheduled rates applied.
+    pub fn apply_scheduled_rates(
+        &mut self,
+        scheduled_rates: &[ScheduledRate],
+        current_timestamp: i64,
+    ) -> Result<usize> {
+        let mut applied_rates = 0;
+
+        for scheduled_rate in scheduled_rates {
+            if scheduled_rate.effective_timestamp > current_timestamp {
+                break;
+            }
+
+            // A manual yield change after the boundary already compounded past it
+            let boundary_timestamp = scheduled_rate
+                .effective_timestamp
+                .max(self.last_yield_change_timestamp);
+
+            self.last_yield_change_exchange_rate =
+                self.calculate_exchange_rate(boundary_timestamp)?;
+            self.last_yield_change_timestamp = boundary_timestamp;
+            self.set_annual_yield(scheduled_rate.annual_yield_bps)?;
+
+            applied_rates += 1;
+        }
+
+        Ok(applied_rates)
+    }
+
+    /// Per interval compounding factor that compounds to `annual_yield_bps`
+    /// over a year.
+    pub fn calculate_interval_apr_rate(&self, annual_yield_bps: u64) -> Result<u64> {
+        let interval_apr_rate = u64x64_math::calculate_interval_apr_rate(
+            annual_yield_bps,
+            self.seconds_per_interval as u128,
+            SECONDS_PER_YEAR,
+        )
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        if interval_apr_rate > MAX_INTERVAL_APR_RATE as u128 {
+            return err!(ParityStakingError::MaxIntervalAprRateExceeded);
+        }
+
+        Ok(interval_apr_rate as u64)
+    }
+
+    pub fn set_annual_yield(&mut self, annual_yield_bps: u64) -> Result<()> {
+        self.interval_apr_rate = self.calculate_interval_apr_rate(annual_yield_bps)?;
+        self.annual_yield_bps = annual_yield_bps;
+
+        Ok(())
+    }
+
+    /// Annual yield in basis points the stored interval APR rate compounds to.
+    pub fn calculate_effective_apy_bps(&self) -> Result<u64> {
+        u64x64_math::calculate_annual_yield_bps(
+            self.interval_apr_rate as u128,
+            self.seconds_per_interval as u128,
+            SECONDS_PER_YEAR,
+        )
+        .ok_or(ParityStakingError::CalculationOverflow.into())
+    }
+
+    /// Exchange rate at `current_timestamp`, compounding piecewise across every
+    /// scheduled rate change in between.
+    pub fn calculate_scheduled_exchange_rate(
+        &self,
+        scheduled_rates: &[ScheduledRate],
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        if self.yield_mode == YieldMode::RewardStreaming {
+            return self.calculate_streaming_exchange_rate(current_timestamp);
+        }
+
+        compounding::compound_scheduled_exchange_rate(
+            self.last_yield_change_exchange_rate,
+            self.interval_apr_rate,
+            self.seconds_per_interval as i64,
+            self.last_yield_change_timestamp,
+            scheduled_rates
+                .iter()
+                .map(|rate| (rate.effective_timestamp, rate.annual_yield_bps)),
+            current_timestamp,
+        )
+        .ok_or(ParityStakingError::CalculationOverflow.into())
+    }
+
+    /// Exchange rate at `current_timestamp`, compounding the interval APR rate
+    /// over every full interval since the last yield change and accruing
+    /// linearly over the remaining partial interval. Streaming pools derive it
+    /// from their assets instead.
+    pub fn calculate_exchange_rate(&self, current_timestamp: i64) -> Result<u64> {
+        if self.yield_mode == YieldMode::RewardStreaming {
+            return self.calculate_streaming_exchange_rate(current_timestamp);
+        }
+
+        if current_timestamp <= self.last_yield_change_timestamp {
+            return Ok(self.last_yield_change_exchange_rate);
+        }
+
+        let seconds_per_interval = self.seconds_per_interval as i64;
+        if seconds_per_interval <= 0 {
+            return err!(ParityStakingError::InvalidParam);
+        }
+
+        let elapsed_time = current_timestamp
+            .checked_sub(self.last_yield_change_timestamp)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        msg!("Elapsed time: {}", elapsed_time);
+
+        let new_exchange_rate = compounding::compound_exchange_rate(
+            self.last_yield_change_exchange_rate,
+            self.interval_apr_rate,
+            seconds_per_interval,
+            elapsed_time,
+        )
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+        msg!("New exchange rate: {}", new_exchange_rate);
+
+        Ok(new_exchange_rate)
+    }
+
+    /// Exchange rate of a streaming pool: the base tokens backing the x supply,
+    /// less the rewards that have not vested yet, over the x supply.
+    pub fn calculate_streaming_exchange_rate(&self, current_timestamp: i64) -> Result<u64> {
+        let normalized_x_supply = self.calculate_normalized_quantity(
+            self.x_supply,
+            self.x_mint_decimals,
+            self.base_mint_decimals,
+        )?;
+
+        // Base tokens locked in unstake tickets no longer back the x supply
+        vesting::streaming_exchange_rate(
+            self.base_balance
+                .saturating_sub(self.pending_unstake_amount),
+            self.calculate_unvested_amount(current_timestamp)?,
+            normalized_x_supply,
+            self.base_mint_decimals,
+            self.initial_exchange_rate,
+        )
+        .ok_or(ParityStakingError::CalculationOverflow.into())
+    }
+
+    /// Rewards deposited into a streaming pool that have not vested yet.
+    pub fn calculate_unvested_amount(&self, current_timestamp: i64) -> Result<u64> {
+        vesting::unvested_amount(
+            self.vesting_amount,
+            self.vesting_start_timestamp,
+            self.vesting_period,
+            current_timestamp,
+        )
+        .ok_or(ParityStakingError::CalculationOverflow.into())
+    }
+
+    /// Starts vesting `base_amount` rewards, together with the rewards still
+    /// unvested, over a new vesting period.
+    pub fn deposit_rewards(&mut self, base_amount: u64, current_timestamp: i64) -> Result<()> {
+        if self.yield_mode != YieldMode::RewardStreaming {
+            return err!(ParityStakingError::InvalidYieldMode);
+        }
+
+        self.vesting_amount = self
+            .calculate_unvested_amount(current_timestamp)?
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.vesting_start_timestamp = current_timestamp;
+
+        self.base_balance = self
+            .base_balance
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.realized_yield_amount = self
+            .realized_yield_amount
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.total_rewards_deposited = self
+            .total_rewards_deposited
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn calculate_normalized_quantity(
+        &self,
+        quantity: u64,
+        from_decimals: u8,
+        to_decimals: u8,
+    ) -> Result<u64> {
+        decimals::normalize_quantity(quantity, from_decimals, to_decimals)
+            .ok_or(ParityStakingError::CalculationOverflow.into())
+    }
+
+    pub fn calculate_amount_to_mint(
+        &mut self,
+        x_mint_supply: u64,
+        current_timestamp: i64,
+        vault_balance: u64,
+    ) -> Result<u64> {
+        // Streaming pools are only funded by deposited rewards
+        if self.yield_mode == YieldMode::RewardStreaming {
+            return Ok(0);
+        }
+
+        // Normalize the x_mint_supply to the base_mint decimals
+        let normalized_x_mint_supply = self.calculate_normalized_quantity(
+            x_mint_supply,
+            self.x_mint_decimals,
+            self.base_mint_decimals,
+        )?;
+
+        let exchange_rate = self.calculate_exchange_rate(current_timestamp)?;
+
+        // Calculate the x_supply_value
+        let x_supply_value = conversion::x_to_base(
+            normalized_x_mint_supply,
+            exchange_rate,
+            self.base_mint_decimals,
+        )
+        .ok_or(ParityStakingError::CalculationOverflow)? as u128;
+
+        // Base tokens locked in unstake tickets no longer back the x supply
+        // let base_balance = self.base_balance as u128;
+        let vault_balance = vault_balance.saturating_sub(self.pending_unstake_amount) as u128;
+
+        msg!("X Supply Value: {}", x_supply_value);
+        // msg!("Base Balance: {}", base_balance);
+        msg!("Vault Balance: {}", vault_balance);
+
+        // Calculate the amount to mint
+        let amount_to_mint = if x_supply_value > vault_balance {
+            x_supply_value
+                .checked_sub(vault_balance)
+                .ok_or(ParityStakingError::CalculationOverflow)? as u64
+        } else {
+            0
+        };
+
+        msg!("Amount to mint: {}", amount_to_mint);
+
+        Ok(amount_to_mint)
+    }
+
+    /// Yield the x supply has accrued at `current_timestamp` beyond the base
+    /// tokens backing it, i.e. staked x times the exchange rate minus the base
+    /// locked in the vault for it.
+    pub fn calculate_accrued_unminted_yield(
+        &self,
+        x_mint_supply: u64,
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        let x_supply_value =
+            self.calculate_output_amount(x_mint_supply, current_timestamp, false)?;
+
+        // Base tokens locked in unstake tickets no longer back the x supply
+        let backing_amount = self
+            .base_balance
+            .saturating_sub(self.pending_unstake_amount);
+
+        Ok(x_supply_value.saturating_sub(backing_amount))
+    }
+
+    pub fn check_accrual_interval(&self, current_timestamp: i64) -> Result<()> {
+        let next_accrual_timestamp = self
+            .last_accrual_timestamp
+            .checked_add(self.min_accrual_interval)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        if current_timestamp < next_accrual_timestamp {
+            return err!(ParityStakingError::AccrualIntervalNotElapsed);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that a keeper reward can only be collected once per accrual
+    /// interval, so that it is not minted on every slot.
+    pub fn check_keeper_reward(&self) -> Result<()> {
+        if self.keeper_reward > 0 && self.min_accrual_interval == 0 {
+            return err!(ParityStakingError::KeeperRewardWithoutAccrualInterval);
+        }
+
+        Ok(())
+    }
+
+    /// Reward for the keeper minting `amount_to_mint`, never more than the
+    /// yield it brings into the vault.
+    pub fn calculate_keeper_reward(&self, amount_to_mint: u64) -> u64 {
+        self.keeper_reward.min(amount_to_mint)
+    }
+
+    /// Performance fee on `base_amount` of deposited rewards. Rounds down.
+    pub fn calculate_performance_fee(&self, base_amount: u64) -> Result<u64> {
+        fees::fee_rounded_down(base_amount, self.performance_fee_bps)
+            .ok_or(ParityStakingError::CalculationOverflow.into())
+    }
+
+    /// Performance fee minted on top of `yield_amount` owed to stakers, so that
+    /// it is the fee share of the total yield minted and the exchange rate stays
+    /// net of fees. Rounds down.
+    pub fn calculate_minted_performance_fee(&self, yield_amount: u64) -> Result<u64> {
+        let performance_fee_bps = self.performance_fee_bps.min(MAX_PERFORMANCE_FEE_BPS);
+
+        (yield_amount as u128)
+            .checked_mul(performance_fee_bps as u128)
+            .ok_or(ParityStakingError::CalculationOverflow)?
+            .checked_div(10000 - performance_fee_bps as u128)
+            .ok_or(ParityStakingError::CalculationOverflow.into())
+            .map(|result| result as u64)
+    }
+
+    pub fn record_performance_fee(&mut self, fee_amount: u64) -> Result<()> {
+        self.total_performance_fees = self
+            .total_performance_fees
+            .checked_add(fee_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    /// Records a keeper reward minted on top of the yield and paid out.
+    pub fn record_keeper_reward(&mut self, keeper_reward: u64) -> Result<()> {
+        self.total_keeper_rewards = self
+            .total_keeper_rewards
+            .checked_add(keeper_reward)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    /// Mirrors x tokens minted by the pool.
+    pub fn record_x_minted(&mut self, x_amount: u64) -> Result<()> {
+        self.x_supply = self
+            .x_supply
+            .checked_add(x_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    /// Mirrors x tokens burnt by the pool.
+    pub fn record_x_burnt(&mut self, x_amount: u64) -> Result<()> {
+        self.x_supply = self
+            .x_supply
+            .checked_sub(x_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    /// Records base tokens staked into the vault as principal.
+    pub fn record_deposit(&mut self, base_amount: u64) -> Result<()> {
+        self.base_balance = self
+            .base_balance
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.principal_amount = self
+            .principal_amount
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    /// Records yield minted into the vault.
+    pub fn record_yield_minted(&mut self, base_amount: u64) -> Result<()> {
+        self.base_balance = self
+            .base_balance
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.realized_yield_amount = self
+            .realized_yield_amount
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.total_yield_minted = self
+            .total_yield_minted
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    /// Records a term boost minted into the vault and paid out in the same
+    /// instruction. It never joins the tracked balance.
+    pub fn record_boost_minted(&mut self, base_amount: u64) -> Result<()> {
+        self.total_yield_minted = self
+            .total_yield_minted
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.total_boost_minted = self
+            .total_boost_minted
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    /// Records base tokens leaving the tracked balance. They are taken from
+    /// principal and realized yield in proportion to their share of it.
+    pub fn record_withdrawal(&mut self, base_amount: u64) -> Result<()> {
+        if base_amount > self.base_balance {
+            return err!(ParityStakingError::CalculationOverflow);
+        }
+
+        let yield_amount = (base_amount as u128)
+            .checked_mul(self.realized_yield_amount as u128)
+            .ok_or(ParityStakingError::CalculationOverflow)?
+            .checked_div(self.base_balance as u128)
+            .unwrap_or(0) as u64;
+        let principal_amount = base_amount - yield_amount;
+
+        self.base_balance -= base_amount;
+        self.realized_yield_amount = self
+            .realized_yield_amount
+            .checked_sub(yield_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.principal_amount = self
+            .principal_amount
+            .checked_sub(principal_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    /// Converts between base and x amounts at the current exchange rate.
+    ///
+    /// Rounding always favours the pool: both the x minted for a stake and the
+    /// base paid out for an unstake are rounded down, so a stake followed by an
+    /// unstake can never return more than was deposited.
+    pub fn calculate_output_amount(
+        &self,
+        quantity: u64,
+        current_timestamp: i64,
+        is_base_to_x: bool, // true if converting from baseMint to xMint, false otherwise
+    ) -> Result<u64> {
+        let (from_decimals, to_decimals) = if is_base_to_x {
+            (self.base_mint_decimals, self.x_mint_decimals)
+        } else {
+            (self.x_mint_decimals, self.base_mint_decimals)
+        };
+
+        let exchange_rate = self.calculate_exchange_rate(current_timestamp)?;
+
+        // Normalize the input quantity
+        let normalized_quantity =
+            self.calculate_normalized_quantity(quantity, from_decimals, to_decimals)?;
+
+        msg!("Normalized quantity: {}", normalized_quantity);
+
+        // Calculate the output amount based on the direction of conversion
+        let output_amount = if is_base_to_x {
+            conversion::base_to_x(normalized_quantity, exchange_rate, self.base_mint_decimals)
+        } else {
+            conversion::x_to_base(normalized_quantity, exchange_rate, self.base_mint_decimals)
+        }
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        msg!("Output amount: {}", output_amount);
+
+        Ok(output_amount)
+    }
+
+    /// Base amount backing `x_amount` x tokens, rounded up as it is taken from
+    /// the payer.
+    pub fn calculate_required_base_amount(
+        &self,
+        x_amount: u64,
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        let exchange_rate = self.calculate_exchange_rate(current_timestamp)?;
+
+        conversion::x_to_base_rounded_up(x_amount, exchange_rate, self.x_mint_decimals)
+            .ok_or(ParityStakingError::CalculationOverflow.into())
+    }
+
+    /// Rounds up, in favour of the pool.
+    pub fn calculate_instant_unstake_fee(&self, base_amount: u64) -> Result<u64> {
+        calculate_fee_rounded_up(base_amount, self.instant_unstake_fee_bps)
+    }
+
+    /// Entry fee on staking `quantity` base tokens. Rounds up, in favour of the pool.
+    pub fn calculate_entry_fee(&self, quantity: u64) -> Result<u64> {
+        calculate_fee_rounded_up(quantity, self.entry_fee_bps)
+    }
+
+    /// Exit fee on unstaking for `base_amount` base tokens. Rounds up, in favour
+    /// of the pool.
+    pub fn calculate_exit_fee(&self, base_amount: u64) -> Result<u64> {
+        calculate_fee_rounded_up(base_amount, self.exit_fee_bps)
+    }
+
+    /// Path an unstake takes and the fee it pays on `base_amount`.
+    pub fn calculate_unstake_fee(&self, base_amount: u64) -> Result<(UnstakePath, u64)> {
+        if self.unstake_cooldown > 0 {
+            Ok((
+                UnstakePath::Instant,
+                self.calculate_instant_unstake_fee(base_amount)?,
+            ))
+        } else {
+            Ok((UnstakePath::Standard, 0))
+        }
+    }
+
+    pub fn top_up_liquidity_buffer(&mut self, base_amount: u64) {
+        let missing_amount = self
+            .liquidity_buffer_target
+            .saturating_sub(self.liquidity_buffer_amount);
+
+        self.liquidity_buffer_amount += base_amount.min(missing_amount);
+    }
+
+    pub fn use_liquidity_buffer(&mut self, base_amount: u64) -> Result<()> {
+        self.liquidity_buffer_amount = self
+            .liquidity_buffer_amount
+            .checked_sub(base_amount)
+            .ok_or(ParityStakingError::InsufficientLiquidityBuffer)?;
+
+        Ok(())
+    }
+
+    /// Keeps an entry fee paid into the vault for the current stakers, before
+    /// the stake paying it mints its x tokens.
+    pub fn distribute_entry_fee(&mut self, fee_amount: u64, current_timestamp: i64) -> Result<()> {
+        // Only the dead shares are left, so nobody can receive it and it stays in the vault as excess
+        if fee_amount == 0 || self.x_supply <= DEAD_SHARES {
+            return Ok(());
+        }
+
+        self.distribute_to_stakers(fee_amount, self.x_supply, current_timestamp)?;
+
+        self.base_balance = self
+            .base_balance
+            .checked_add(fee_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.realized_yield_amount = self
+            .realized_yield_amount
+            .checked_add(fee_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn distribute_to_stakers(
+        &mut self,
+        base_amount: u64,
+        x_mint_supply: u64,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        // The base amount stays in the vault, which already raises a streaming pool's rate
+        if self.yield_mode == YieldMode::RewardStreaming {
+            return Ok(());
+        }
+
+        let exchange_rate = self.calculate_exchange_rate(current_timestamp)?;
+
+        let normalized_x_mint_supply = self.calculate_normalized_quantity(
+            x_mint_supply,
+            self.x_mint_decimals,
+            self.base_mint_decimals,
+        )?;
+        if normalized_x_mint_supply == 0 {
+            return err!(ParityStakingError::InvalidQuantity);
+        }
+
+        // Raise the exchange rate so that the x supply absorbs the base amount.
+        // Compounding continues from the raised exchange rate
+        self.last_yield_change_exchange_rate = conversion::raise_exchange_rate(
+            exchange_rate,
+            base_amount,
+            normalized_x_mint_supply,
+            self.base_mint_decimals,
+        )
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+        self.last_yield_change_timestamp = current_timestamp;
+
+        Ok(())
+    }
+
+    /// Whether the x mint carries the Token-2022 interest-bearing extension,
+    /// so that wallets display the accrued base value of x balances.
+    pub fn is_x_mint_interest_bearing(&self) -> bool {
+        self.x_token_program == token_2022::ID
+    }
+
+    /// Continuously compounded rate in basis points matching the interval APR
+    /// rate, as the Token-2022 interest-bearing extension expects it.
+    pub fn calculate_x_mint_interest_rate(&self) -> Result<i16> {
+        let rate_bps = u64x64_math::calculate_continuous_rate_bps(
+            self.interval_apr_rate as u128,
+            self.seconds_per_interval as u128,
+            SECONDS_PER_YEAR,
+        )
+        .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        i16::try_from(rate_bps).map_err(|_| ParityStakingError::InvalidInterestRate.into())
+    }
+
+    /// Checks that only the interval APR rate moves the exchange rate of a
+    /// pool with an interest-bearing x mint, as the extension compounds that
+    /// rate alone from the pool's start. Otherwise wallets would display x
+    /// balances at a value holders cannot redeem. Strategy gains and losses
+    /// only change the yield left to mint in compounding pools, so they keep
+    /// to the rate.
+    pub fn check_interest_bearing_x_mint(&self) -> Result<()> {
+        if !self.is_x_mint_interest_bearing() {
+            return Ok(());
+        }
+
+        let unit_exchange_rate = 10u64
+            .checked_pow(self.base_mint_decimals as u32)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        let fee_to_stakers = |fee_bps: u16, fee_destination: FeeDestination| {
+            fee_bps > 0 && fee_destination == FeeDestination::Stakers
+        };
+
+        if self.yield_mode != YieldMode::Compounding
+            || self.initial_exchange_rate != unit_exchange_rate
+            || fee_to_stakers(self.entry_fee_bps, self.entry_fee_destination)
+            || fee_to_stakers(self.exit_fee_bps, self.exit_fee_destination)
+            || fee_to_stakers(
+                self.instant_unstake_fee_bps,
+                self.instant_unstake_fee_destination,
+            )
+        {
+            return err!(ParityStakingError::InterestBearingXMintUnsupported);
+        }
+
+        Ok(())
+    }
+
+    pub fn check_stake_active(&self) -> Result<()> {
+        if !self.stake_active {
+            return err!(ParityStakingError::StakingPaused);
+        }
+
+        Ok(())
+    }
+
+    pub fn check_unstake_active(&self) -> Result<()> {
+        if !self.unstake_active {
+            return err!(ParityStakingError::UnstakingPaused);
+        }
+
+        Ok(())
+    }
+
+    /// Sets the pause flags. The admin or a guardian can pause, but only the
+    /// admin can unpause.
+    pub fn update_pool_status(
+        &mut self,
+        stake_active: bool,
+        unstake_active: bool,
+        is_admin: bool,
+        is_guardian: bool,
+    ) -> Result<()> {
+        if self.stake_active == stake_active && self.unstake_active == unstake_active {
+            return err!(ParityStakingError::PoolStatusUnchanged);
+        }
+
+        let unpausing =
+            (stake_active && !self.stake_active) || (unstake_active && !self.unstake_active);
+
+        if unpausing {
+            // If unpausing, authority must be the admin
+            require!(is_admin, ParityStakingError::InvalidToggleActiveAuthority);
+        } else {
+            // If only pausing, authority can be either the admin or a guardian
+            require!(
+                is_admin || is_guardian,
+                ParityStakingError::InvalidToggleActiveAuthority
+            );
+        }
+
+        self.stake_active = stake_active;
+        self.unstake_active = unstake_active;
+
+        Ok(())
+    }
+
+    pub fn check_minimum_stake(&self, quantity: u64) -> Result<()> {
+        if quantity < self.min_stake_amount {
+            return err!(ParityStakingError::AmountBelowMinimum);
+        }
+
+        Ok(())
+    }
+
+    pub fn check_minimum_unstake(&self, quantity: u64) -> Result<()> {
+        if quantity < self.min_unstake_amount {
+            return err!(ParityStakingError::AmountBelowMinimum);
+        }
+
+        Ok(())
+    }
+
+    /// Base tokens backing the pool: the vault balance plus the value held by
+    /// the strategies.
+    pub fn calculate_pool_assets(&self, vault_amount: u64) -> Result<u64> {
+        vault_amount
+            .checked_add(self.strategy_value)
+            .ok_or(ParityStakingError::CalculationOverflow.into())
+    }
+
+    /// Checks that moving `base_amount` from the vault into a strategy keeps
+    /// the strategies within their share of the pool assets, and leaves the
+    /// vault enough to pay the unstake tickets and the liquidity buffer.
+    pub fn check_strategy_allocation(&self, base_amount: u64, vault_amount: u64) -> Result<()> {
+        let max_strategy_value = (self.calculate_pool_assets(vault_amount)? as u128)
+            .checked_mul(self.max_strategy_allocation_bps as u128)
+            .ok_or(ParityStakingError::CalculationOverflow)?
+            / 10000;
+        let new_strategy_value = (self.strategy_value as u128)
+            .checked_add(base_amount as u128)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        if new_strategy_value > max_strategy_value {
+            return err!(ParityStakingError::StrategyAllocationExceeded);
+        }
+
+        let reserved_amount = (self.pending_unstake_amount as u128)
+            .checked_add(self.liquidity_buffer_amount as u128)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        if (vault_amount as u128) < reserved_amount + base_amount as u128 {
+            return err!(ParityStakingError::InsufficientVaultLiquidity);
+        }
+
+        Ok(())
+    }
+
+    pub fn record_strategy_deposit(&mut self, base_amount: u64) -> Result<()> {
+        self.strategy_value = self
+            .strategy_value
+            .checked_add(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn record_strategy_withdrawal(&mut self, base_amount: u64) -> Result<()> {
+        self.strategy_value = self
+            .strategy_value
+            .checked_sub(base_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(())
+    }
+
+    /// Records a strategy valued at `new_value` after last reporting
+    /// `reported_value`. Gains net of the performance fee are realized yield,
+    /// which streaming pools vest like deposited rewards, and losses leave the
+    /// pool like a withdrawal. Returns the performance fee on the gain, for the
+    /// caller to pay to the treasury.
+    pub fn record_strategy_value(
+        &mut self,
+        reported_value: u64,
+        new_value: u64,
+        current_timestamp: i64,
+    ) -> Result<u64> {
+        let mut performance_fee = 0;
+
+        if new_value > reported_value {
+            let gain = new_value - reported_value;
+            performance_fee = self.calculate_performance_fee(gain)?;
+            let yield_amount = gain
+                .checked_sub(performance_fee)
+                .ok_or(ParityStakingError::CalculationOverflow)?;
+
+            match self.yield_mode {
+                YieldMode::RewardStreaming => {
+                    self.deposit_rewards(yield_amount, current_timestamp)?
+                }
+                // Backs the x supply, so less yield is left to mint
+                YieldMode::Compounding => {
+                    self.base_balance = self
+                        .base_balance
+                        .checked_add(yield_amount)
+                        .ok_or(ParityStakingError::CalculationOverflow)?;
+                    self.realized_yield_amount = self
+                        .realized_yield_amount
+                        .checked_add(yield_amount)
+                        .ok_or(ParityStakingError::CalculationOverflow)?;
+                }
+            }
+        } else {
+            self.record_withdrawal(reported_value - new_value)?;
+        }
+
+        self.strategy_value = self
+            .strategy_value
+            .checked_sub(reported_value)
+            .and_then(|strategy_value| strategy_value.checked_add(new_value))
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        Ok(performance_fee)
+    }
+
+    /// Whether stakes must come with the payer's staker entry.
+    pub fn is_staker_entry_required(&self) -> bool {
+        self.allowlist_enabled || self.wallet_deposit_cap > 0
+    }
+
+    /// Checks the deposit cap against the principal, so that yield accruing
+    /// in the vault does not use it up.
+    pub fn check_excessive_deposit(&self, quote_amount: u64) -> Result<()> {
+        let new_principal_amount = (self.principal_amount as u128)
+            .checked_add(quote_amount as u128)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        msg!("quote_amount: {}", quote_amount);
+        msg!("principal_amount: {}", self.principal_amount);
+        msg!("deposit_cap: {}", self.deposit_cap);
+
+        if new_principal_amount > self.deposit_cap as u128 {
+            return err!(ParityStakingError::DepositCapExceeded);
+        }
+
+        Ok(())
+    }
+
+    /// Outcome of staking `quantity` base tokens at `current_timestamp`, with
+    /// the scheduled rates already applied. Limits are reported, not enforced.
+    pub fn preview_stake(&self, quantity: u64, current_timestamp: i64) -> Result<StakePreview> {
+        let fee_amount = self.calculate_entry_fee(quantity)?;
+        let base_amount = quantity
+            .checked_sub(fee_amount)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        // The stake mints its x tokens after the fee reached the stakers
+        let mut pool_manager = self.clone();
+        if pool_manager.entry_fee_destination == FeeDestination::Stakers {
+            pool_manager.distribute_entry_fee(fee_amount, current_timestamp)?;
+        }
+
+        Ok(StakePreview {
+            x_amount: pool_manager.calculate_output_amount(base_amount, current_timestamp, true)?,
+            fee_amount,
+            exchange_rate: pool_manager.calculate_exchange_rate(current_timestamp)?,
+            min_stake_amount: self.min_stake_amount,
+            max_stake_amount: self.deposit_cap.saturating_sub(self.principal_amount),
+        })
+    }
+
+    /// Outcome of unstaking `quantity` x tokens at `current_timestamp`, with
+    /// the scheduled rates already applied. Limits are reported, not enforced.
+    pub fn preview_unstake(&self, quantity: u64, current_timestamp: i64) -> Result<UnstakePreview> {
+        let base_amount = self.calculate_output_amount(quantity, current_timestamp, false)?;
+        let exit_fee = self.calculate_exit_fee(base_amount)?;
+        let base_amount = base_amount
+            .checked_sub(exit_fee)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+        let (unstake_path, instant_unstake_fee) = self.calculate_unstake_fee(base_amount)?;
+        let fee_amount = exit_fee
+            .checked_add(instant_unstake_fee)
+            .ok_or(ParityStakingError::CalculationOverflow)?;
+
+        let max_base_amount = match unstake_path {
+            UnstakePath::Instant => self.liquidity_buffer_amount,
+            UnstakePath::Standard => u64::MAX,
+        };
+
+        Ok(UnstakePreview {
+            base_amount: base_amount
+                .checked_sub(instant_unstake_fee)
+                .ok_or(ParityStakingError::CalculationOverflow)?,
+            fee_amount,
+            exchange_rate: self.calculate_exchange_rate(current_timestamp)?,
+            unstake_path,
+            min_unstake_amount: self.min_unstake_amount,
+            max_base_amount,
+        })
+    }
+}
+
+fn calculate_fee_rounded_up(amount: u64, fee_bps: u16) -> Result<u64> {
+    fees::fee_rounded_up(amount, fee_bps).ok_or(ParityStakingError::CalculationOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MAX_EXPONENTIAL, PRECISION};
+    use anchor_lang::Discriminator;
+    use ruint::aliases::U512;
+
+    fn default_pool_manager() -> PoolManager {
+        PoolManager {
+            bump: 0,
+            pool_id: 0,
+            owner: Pubkey::default(),
+            pending_owner: Pubkey::default(),
+            admin: Pubkey::default(),
+            base_mint: Pubkey::default(),
+            x_mint: Pubkey::default(),
+            base_mint_decimals: 6,
+            x_mint_decimals: 6,
+            annual_yield_bps: 2000,
+            interval_apr_rate: 1000166517567, // Interval APR rate without considering zeros
+            seconds_per_interval: 8 * 60 * 60, // 8 hours / Can be changed
+            initial_exchange_rate: 1000000,
+            last_yield_change_exchange_rate: 1000000,
+            inception_timestamp: 0,
+            last_yield_change_timestamp: 0,
+            base_balance: 0,
+            deposit_cap: 500000,
+            unstake_cooldown: 0,
+            pending_unstake_amount: 0,
+            treasury: Pubkey::default(),
+            instant_unstake_fee_bps: 0,
+            instant_unstake_fee_destination: FeeDestination::Stakers,
+            liquidity_buffer_target: 0,
+            liquidity_buffer_amount: 0,
+            min_stake_amount: 0,
+            min_unstake_amount: 0,
+            issuance_program: Pubkey::default(),
+            token_manager: Pubkey::default(),
+            pending_issuance_program: Pubkey::default(),
+            pending_token_manager: Pubkey::default(),
+            issuance_update_timestamp: 0,
+            principal_amount: 0,
+            realized_yield_amount: 0,
+            total_yield_minted: 0,
+            total_boost_minted: 0,
+            min_accrual_interval: 0,
+            last_accrual_timestamp: 0,
+            keeper_reward: 0,
+            total_keeper_rewards: 0,
+            yield_mode: YieldMode::Compounding,
+            x_supply: 0,
+            rewarder: Pubkey::default(),
+            vesting_period: 0,
+            vesting_amount: 0,
+            vesting_start_timestamp: 0,
+            total_rewards_deposited: 0,
+            performance_fee_bps: 0,
+            total_performance_fees: 0,
+            entry_fee_bps: 0,
+            entry_fee_destination: FeeDestination::Stakers,
+            exit_fee_bps: 0,
+            exit_fee_destination: FeeDestination::Stakers,
+            stake_active: true,
+            unstake_active: true,
+            x_token_program: Pubkey::default(),
+            checkpoint_interval: DEFAULT_CHECKPOINT_INTERVAL,
+            max_strategy_allocation_bps: 0,
+            strategy_value: 0,
+            allowlist_enabled: false,
+            wallet_deposit_cap: 0,
+        }
+    }
+
+    #[test]
+    fn test_calculate_exchange_rate() {
+        let mut pool_manager = default_pool_manager();
+
+        // Test case where current_timestamp is equal to last_yield_change_timestamp
+        pool_manager.last_yield_change_timestamp = 1_000_000;
+        pool_manager.last_yield_change_exchange_rate = 1_000_000;
+        let result = pool_manager.calculate_exchange_rate(1_000_000).unwrap();
+        assert_eq!(result, 1_000_000);
+
+        // Test case where one year has passed
+        pool_manager.last_yield_change_timestamp = 0;
+        let current_timestamp = 31_536_000; // One year in seconds
+        let result = pool_manager
+            .calculate_exchange_rate(current_timestamp)
+            .unwrap();
+        println!("One year has passed: {}", result);
+        assert_eq!(result, 1_199_999); // The exchange rate should have increased
+
+        // Test case where half a year has passed
+        pool_manager.last_yield_change_timestamp = 0;
+        let result = pool_manager
+            .calculate_exchange_rate(current_timestamp / 2)
+            .unwrap();
+        assert_eq!(result, 1_095_445); // The exchange rate should have increased but less than 20%
+    }
+
+    // High precision reference of the exchange rate: exact compounding over
+    // full intervals and linear accrual over the rest, with 10^36 precision
+    fn reference_exchange_rate(pool_manager: &PoolManager, current_timestamp: i64) -> U512 {
+        let scale = U512::from(10u128.pow(36));
+        let interval_rate = U512::from(pool_manager.interval_apr_rate) * U512::from(10u128.pow(24));
+
+        let elapsed_time = current_timestamp - pool_manager.last_yield_change_timestamp;
+        let seconds_per_interval = pool_manager.seconds_per_interval as i64;
+        let mut remaining_intervals = (elapsed_time / seconds_per_interval) as u64;
+        let remaining_seconds = (elapsed_time % seconds_per_interval) as u64;
+
+        let mut growth = scale;
+        let mut squared_rate = interval_rate;
+        while remaining_intervals > 0 {
+            if remaining_intervals & 1 == 1 {
+                growth = growth * squared_rate / scale;
+            }
+            remaining_intervals >>= 1;
+            if remaining_intervals > 0 {
+                squared_rate = squared_rate * squared_rate / scale;
+            }
+        }
+
+        let linear_growth = scale
+            + (interval_rate - scale) * U512::from(remaining_seconds)
+                / U512::from(seconds_per_interval as u64);
+
+        U512::from(pool_manager.last_yield_change_exchange_rate) * growth * linear_growth
+            / (scale * scale)
+    }
+
+    #[test]
+    fn test_calculate_exchange_rate_against_reference() {
+        let one_year = SECONDS_PER_YEAR as i64;
+        let elapsed_times = [
+            1,
+            12_345,
+            one_year / 2,
+            one_year,
+            one_year * 5 + 12_345,
+            one_year * 10,
+            one_year * 25 + 1,
+            one_year * 50 + 28_799,
+        ];
+
+        for annual_yield_bps in [0, 100, 500, 2000, 10000] {
+            for seconds_per_interval in [60, 3600, 8 * 60 * 60, 24 * 60 * 60] {
+                let mut pool_manager = default_pool_manager();
+                pool_manager.seconds_per_interval = seconds_per_interval;
+                pool_manager.set_annual_yield(annual_yield_bps).unwrap();
+
+                for elapsed_time in elapsed_times {
+                    let reference = reference_exchange_rate(&pool_manager, elapsed_time);
+                    let result = pool_manager.calculate_exchange_rate(elapsed_time);
+
+                    // Rates beyond u64 have to fail with an error rather than panic
+                    if reference > U512::from(u64::MAX) {
+                        assert!(result.is_err());
+                        continue;
+                    }
+
+                    let result = U512::from(result.unwrap());
+                    let difference = if result > reference {
+                        result - reference
+                    } else {
+                        reference - result
+                    };
+                    // Within a unit or 10^-12 relative, the precision of the interval APR rate
+                    let tolerance = U512::from(1) + reference / U512::from(PRECISION);
+                    assert!(
+                        difference <= tolerance,
+                        "{} bps every {}s after {}s: {} vs reference {}",
+                        annual_yield_bps,
+                        seconds_per_interval,
+                        elapsed_time,
+                        result,
+                        reference
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_calculate_exchange_rate_long_elapsed_time() {
+        let mut pool_manager = default_pool_manager();
+
+        // Far more intervals than a single pow call supports
+        pool_manager.seconds_per_interval = 60;
+        pool_manager.set_annual_yield(100).unwrap();
+        let current_timestamp = SECONDS_PER_YEAR as i64 * 100;
+        assert!(current_timestamp / 60 > MAX_EXPONENTIAL as i64);
+
+        let result = pool_manager
+            .calculate_exchange_rate(current_timestamp)
+            .unwrap();
+        assert_eq!(
+            U512::from(result),
+            reference_exchange_rate(&pool_manager, current_timestamp)
+        );
+
+        // Overflowing the exchange rate returns an error
+        pool_manager.set_annual_yield(2000).unwrap();
+        let result = pool_manager.calculate_exchange_rate(current_timestamp * 10);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_calculate_normalized_quantity() {
+        let pool_manager = default_pool_manager();
+
+        // Test case where token decimals are equal
+        let result = pool_manager
+            .calculate_normalized_quantity(1000000, 6, 6)
+            .unwrap();
+        assert_eq!(result, 1000000);
 
         // Test case where base mint decimals are less than x mint decimals
         let result = pool_manager
             .calculate_normalized_quantity(1000000, 6, 9)
             .unwrap();
-        assert_eq!(result, 1000000000);
+        assert_eq!(result, 1000000000);
+
+        // Test case where base mint decimals are more than x mint decimals
+        let result = pool_manager
+            .calculate_normalized_quantity(1000000000, 9, 6)
+            .unwrap();
+        assert_eq!(result, 1000000);
+    }
+
+    #[test]
+    fn test_calculate_amount_to_mint() {
+        let mut pool_manager = default_pool_manager();
+
+        // Set up the conditions
+        pool_manager.base_balance = 1000_000_000; // 500 base tokens
+        let vault_balance = pool_manager.base_balance;
+        let x_mint_supply = 1000_000_000; // 1,000 xMint tokens
+        let current_timestamp = 31_536_000; // One year in seconds
+
+        // Calculate the amount to mint for one year
+        let amount_to_mint = pool_manager
+            .calculate_amount_to_mint(x_mint_supply, current_timestamp, vault_balance)
+            .unwrap();
+
+        // Verify the result for one year
+        assert_eq!(amount_to_mint, 199999000); // Needs to mint 200 base tokens
+
+        // Calculate the amount to mint for half a year
+        let half_year_timestamp = current_timestamp / 2; // Half a year in seconds
+        let amount_to_mint_half_year = pool_manager
+            .calculate_amount_to_mint(x_mint_supply, half_year_timestamp, vault_balance)
+            .unwrap();
+
+        // Verify the result for half a year
+        assert_eq!(amount_to_mint_half_year, 95445000); // Needs to mint 100 base tokens
+    }
+
+    #[test]
+    fn test_calculate_amount_to_mint_with_pending_unstakes() {
+        let mut pool_manager = default_pool_manager();
+
+        // Base tokens locked in unstake tickets are not counted as backing
+        let x_mint_supply = 1_000_000_000;
+        let vault_balance = 1_200_000_000;
+        pool_manager.pending_unstake_amount = 200_000_000;
+
+        let amount_to_mint = pool_manager
+            .calculate_amount_to_mint(x_mint_supply, 31_536_000, vault_balance)
+            .unwrap();
+        assert_eq!(amount_to_mint, 199_999_000);
+    }
+
+    #[test]
+    fn test_yield_accounting() {
+        let mut pool_manager = default_pool_manager();
+
+        pool_manager.record_deposit(1_000_000_000).unwrap();
+        assert_eq!(pool_manager.base_balance, 1_000_000_000);
+        assert_eq!(pool_manager.principal_amount, 1_000_000_000);
+
+        // A year in, the x supply is worth the principal plus the accrued yield
+        let x_mint_supply = 1_000_000_000;
+        let current_timestamp = 31_536_000;
+        let accrued_yield = pool_manager
+            .calculate_accrued_unminted_yield(x_mint_supply, current_timestamp)
+            .unwrap();
+        assert_eq!(
+            accrued_yield,
+            pool_manager
+                .calculate_amount_to_mint(x_mint_supply, current_timestamp, 1_000_000_000)
+                .unwrap()
+        );
+
+        // Minting it realizes the yield and leaves nothing accrued
+        pool_manager.record_yield_minted(accrued_yield).unwrap();
+        assert_eq!(pool_manager.realized_yield_amount, accrued_yield);
+        assert_eq!(pool_manager.total_yield_minted, accrued_yield);
+        assert_eq!(
+            pool_manager
+                .calculate_accrued_unminted_yield(x_mint_supply, current_timestamp)
+                .unwrap(),
+            0
+        );
+
+        // Withdrawals take principal and yield in proportion
+        let base_balance = pool_manager.base_balance;
+        pool_manager.record_withdrawal(base_balance / 2).unwrap();
+        assert_eq!(pool_manager.principal_amount, 500_000_000);
+        assert_eq!(pool_manager.realized_yield_amount, accrued_yield / 2);
+        assert_eq!(
+            pool_manager.base_balance,
+            pool_manager.principal_amount + pool_manager.realized_yield_amount
+        );
+        // The cumulative counter is unaffected
+        assert_eq!(pool_manager.total_yield_minted, accrued_yield);
+
+        // Withdrawing everything clears both
+        pool_manager
+            .record_withdrawal(pool_manager.base_balance)
+            .unwrap();
+        assert_eq!(pool_manager.principal_amount, 0);
+        assert_eq!(pool_manager.realized_yield_amount, 0);
+        assert!(pool_manager.record_withdrawal(1).is_err());
+
+        // Term boosts count as minted yield but leave the vault right away
+        pool_manager.record_boost_minted(50_000).unwrap();
+        assert_eq!(pool_manager.total_yield_minted, accrued_yield + 50_000);
+        assert_eq!(pool_manager.total_boost_minted, 50_000);
+        assert_eq!(pool_manager.base_balance, 0);
+        assert_eq!(pool_manager.realized_yield_amount, 0);
+    }
+
+    #[test]
+    fn test_accrual_interval_and_keeper_reward() {
+        let mut pool_manager = default_pool_manager();
+        pool_manager.min_accrual_interval = 3600;
+        pool_manager.last_accrual_timestamp = 1_000_000;
+
+        assert!(pool_manager.check_accrual_interval(1_003_599).is_err());
+        assert!(pool_manager.check_accrual_interval(1_003_600).is_ok());
+
+        // A second accrual within the interval fails
+        pool_manager.last_accrual_timestamp = 1_003_600;
+        assert!(pool_manager.check_accrual_interval(1_003_600).is_err());
+        assert!(pool_manager.check_accrual_interval(1_007_199).is_err());
+        assert!(pool_manager.check_accrual_interval(1_007_200).is_ok());
+
+        // The reward is capped by the yield minted
+        pool_manager.keeper_reward = 1_000;
+        assert_eq!(pool_manager.calculate_keeper_reward(50_000), 1_000);
+        assert_eq!(pool_manager.calculate_keeper_reward(400), 400);
+        assert_eq!(pool_manager.calculate_keeper_reward(0), 0);
+
+        // and only paid once an accrual interval is set
+        pool_manager.check_keeper_reward().unwrap();
+        pool_manager.min_accrual_interval = 0;
+        assert!(pool_manager.check_keeper_reward().is_err());
+        pool_manager.keeper_reward = 0;
+        pool_manager.check_keeper_reward().unwrap();
+
+        pool_manager.record_keeper_reward(1_000).unwrap();
+        pool_manager.record_keeper_reward(400).unwrap();
+        assert_eq!(pool_manager.total_keeper_rewards, 1_400);
+        assert_eq!(pool_manager.total_yield_minted, 0);
+    }
+
+    fn streaming_pool_manager() -> PoolManager {
+        let mut pool_manager = default_pool_manager();
+        pool_manager.yield_mode = YieldMode::RewardStreaming;
+        pool_manager.vesting_period = 1_000;
+        pool_manager.record_deposit(1_000_000_000).unwrap();
+        pool_manager.record_x_minted(1_000_000_000).unwrap();
+        pool_manager
+    }
+
+    #[test]
+    fn test_streaming_exchange_rate_vests_rewards() {
+        let mut pool_manager = streaming_pool_manager();
+        assert_eq!(pool_manager.calculate_exchange_rate(0).unwrap(), 1_000_000);
+
+        // Rewards vest linearly into the exchange rate
+        pool_manager.deposit_rewards(100_000_000, 0).unwrap();
+        assert_eq!(pool_manager.calculate_exchange_rate(0).unwrap(), 1_000_000);
+        assert_eq!(
+            pool_manager.calculate_exchange_rate(500).unwrap(),
+            1_050_000
+        );
+        assert_eq!(
+            pool_manager.calculate_exchange_rate(1_000).unwrap(),
+            1_100_000
+        );
+        assert_eq!(
+            pool_manager.calculate_exchange_rate(5_000).unwrap(),
+            1_100_000
+        );
+        assert_eq!(pool_manager.total_rewards_deposited, 100_000_000);
+        assert_eq!(pool_manager.realized_yield_amount, 100_000_000);
+
+        // A new deposit rolls the unvested rewards into a new vesting period
+        pool_manager.deposit_rewards(100_000_000, 500).unwrap();
+        assert_eq!(pool_manager.vesting_amount, 150_000_000);
+        assert_eq!(
+            pool_manager.calculate_exchange_rate(500).unwrap(),
+            1_050_000
+        );
+        assert_eq!(
+            pool_manager.calculate_exchange_rate(1_500).unwrap(),
+            1_200_000
+        );
+
+        // Nothing is ever minted
+        assert_eq!(
+            pool_manager
+                .calculate_amount_to_mint(1_000_000_000, 1_500, 1_200_000_000)
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_streaming_stake_and_unstake_keep_the_exchange_rate() {
+        let mut pool_manager = streaming_pool_manager();
+        pool_manager.deposit_rewards(100_000_000, 0).unwrap();
+        let exchange_rate = pool_manager.calculate_exchange_rate(500).unwrap();
+
+        // Staking mints x at the current rate, rounded in favour of the pool
+        let x_amount = pool_manager
+            .calculate_output_amount(333_333_333, 500, true)
+            .unwrap();
+        pool_manager.record_deposit(333_333_333).unwrap();
+        pool_manager.record_x_minted(x_amount).unwrap();
+        assert!(pool_manager.calculate_exchange_rate(500).unwrap() >= exchange_rate);
+
+        // Requesting an unstake locks the base amount and burns the x tokens
+        let base_amount = pool_manager
+            .calculate_output_amount(x_amount, 500, false)
+            .unwrap();
+        assert!(base_amount <= 333_333_333);
+        pool_manager.record_x_burnt(x_amount).unwrap();
+        pool_manager.pending_unstake_amount = base_amount;
+        assert!(pool_manager.calculate_exchange_rate(500).unwrap() >= exchange_rate);
+
+        // Claiming it leaves the rate untouched
+        pool_manager.record_withdrawal(base_amount).unwrap();
+        pool_manager.pending_unstake_amount = 0;
+        assert!(pool_manager.calculate_exchange_rate(500).unwrap() >= exchange_rate);
+    }
+
+    #[test]
+    fn test_performance_fee() {
+        let mut pool_manager = default_pool_manager();
+        assert_eq!(
+            pool_manager.calculate_performance_fee(1_000_000).unwrap(),
+            0
+        );
+        assert_eq!(
+            pool_manager
+                .calculate_minted_performance_fee(1_000_000)
+                .unwrap(),
+            0
+        );
+
+        // 10% of the yield: a deposit keeps 90% for stakers, minting adds 10% of the total
+        pool_manager.performance_fee_bps = 1000;
+        assert_eq!(
+            pool_manager.calculate_performance_fee(1_000_000).unwrap(),
+            100_000
+        );
+        assert_eq!(
+            pool_manager
+                .calculate_minted_performance_fee(900_000)
+                .unwrap(),
+            100_000
+        );
+
+        pool_manager.record_performance_fee(100_000).unwrap();
+        pool_manager.record_performance_fee(100_000).unwrap();
+        assert_eq!(pool_manager.total_performance_fees, 200_000);
+    }
+
+    #[test]
+    fn test_deposit_rewards_requires_streaming_mode() {
+        let mut pool_manager = default_pool_manager();
+        assert!(pool_manager.deposit_rewards(100_000_000, 0).is_err());
+    }
+
+    #[test]
+    fn test_accrued_unminted_yield_with_pending_unstakes() {
+        let mut pool_manager = default_pool_manager();
+
+        // Base tokens locked in unstake tickets do not back the x supply
+        pool_manager.record_deposit(1_200_000_000).unwrap();
+        pool_manager.pending_unstake_amount = 200_000_000;
+
+        let accrued_yield = pool_manager
+            .calculate_accrued_unminted_yield(1_000_000_000, 31_536_000)
+            .unwrap();
+        assert_eq!(accrued_yield, 199_999_000);
+    }
+
+    #[test]
+    fn test_calculate_output_amount() {
+        let pool_manager = default_pool_manager();
+
+        // Set up the conditions
+        let current_timestamp = 31_536_000; // One year in seconds
+
+        // Test conversion from baseMint to xMint
+        let base_quantity = 1000_000_000; // 1000 baseMint token
+        let x_mint_amount = pool_manager
+            .calculate_output_amount(base_quantity, current_timestamp, true)
+            .unwrap();
+        assert_eq!(x_mint_amount, 833_334_027); // Expected xMint amount after one year
+
+        // Test conversion from xMint to baseMint
+        let x_quantity = 100_000_000; // 1 xMint token
+        let base_mint_amount = pool_manager
+            .calculate_output_amount(x_quantity, current_timestamp, false)
+            .unwrap();
+        assert_eq!(base_mint_amount, 119_999_900); // Expected baseMint amount after one year
+    }
+
+    #[test]
+    fn test_set_annual_yield() {
+        let mut pool_manager = default_pool_manager();
+
+        pool_manager.set_annual_yield(1000).unwrap();
+        assert_eq!(pool_manager.annual_yield_bps, 1000);
+        assert_eq!(pool_manager.interval_apr_rate, 1000087045048);
+        assert_eq!(pool_manager.calculate_effective_apy_bps().unwrap(), 1000);
+
+        // Zero yield keeps the exchange rate flat
+        pool_manager.set_annual_yield(0).unwrap();
+        assert_eq!(pool_manager.interval_apr_rate, PRECISION as u64);
+        assert_eq!(pool_manager.calculate_effective_apy_bps().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_calculate_scheduled_exchange_rate() {
+        let pool_manager = default_pool_manager();
+        let seconds_per_interval = pool_manager.seconds_per_interval as i64;
+        let boundary_timestamp = seconds_per_interval * 10;
+
+        // Without a schedule the rate keeps compounding at the current rate
+        let unscheduled_rate = pool_manager
+            .calculate_scheduled_exchange_rate(&[], boundary_timestamp * 2)
+            .unwrap();
+        assert_eq!(
+            unscheduled_rate,
+            pool_manager
+                .calculate_exchange_rate(boundary_timestamp * 2)
+                .unwrap()
+        );
+
+        // The rate is flat after switching to a zero yield at the boundary
+        let scheduled_rates = [ScheduledRate {
+            effective_timestamp: boundary_timestamp,
+            annual_yield_bps: 0,
+        }];
+        let boundary_rate = pool_manager
+            .calculate_exchange_rate(boundary_timestamp)
+            .unwrap();
+        let scheduled_rate = pool_manager
+            .calculate_scheduled_exchange_rate(&scheduled_rates, boundary_timestamp * 2)
+            .unwrap();
+        assert_eq!(scheduled_rate, boundary_rate);
+        assert!(scheduled_rate < unscheduled_rate);
+
+        // Boundaries in the future do not change the rate yet
+        let rate_before_boundary = pool_manager
+            .calculate_scheduled_exchange_rate(&scheduled_rates, boundary_timestamp - 1)
+            .unwrap();
+        assert_eq!(
+            rate_before_boundary,
+            pool_manager
+                .calculate_exchange_rate(boundary_timestamp - 1)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_apply_yield_schedule() {
+        let mut pool_manager = default_pool_manager();
+        let seconds_per_interval = pool_manager.seconds_per_interval as i64;
+        let mut yield_schedule = YieldSchedule {
+            bump: 0,
+            pool_manager: Pubkey::default(),
+            scheduled_rates: vec![
+                ScheduledRate {
+                    effective_timestamp: seconds_per_interval,
+                    annual_yield_bps: 1000,
+                },
+                ScheduledRate {
+                    effective_timestamp: seconds_per_interval * 3,
+                    annual_yield_bps: 500,
+                },
+            ],
+        };
+
+        let expected_rate = pool_manager
+            .calculate_scheduled_exchange_rate(
+                &yield_schedule.scheduled_rates,
+                seconds_per_interval * 2,
+            )
+            .unwrap();
 
-        // Test case where base mint decimals are more than x mint decimals
+        yield_schedule
+            .apply(&mut pool_manager, seconds_per_interval * 2)
+            .unwrap();
+
+        // Only the first boundary has been crossed
+        assert_eq!(yield_schedule.scheduled_rates.len(), 1);
+        assert_eq!(pool_manager.annual_yield_bps, 1000);
+        assert_eq!(pool_manager.interval_apr_rate, 1000087045048);
+        assert_eq!(
+            pool_manager.last_yield_change_timestamp,
+            seconds_per_interval
+        );
+        assert_eq!(
+            pool_manager
+                .calculate_exchange_rate(seconds_per_interval * 2)
+                .unwrap(),
+            expected_rate
+        );
+    }
+
+    #[test]
+    fn test_stake_unstake_round_trip_never_profits() {
+        let quantities = [
+            1,
+            2,
+            3,
+            7,
+            999,
+            1_000,
+            1_001,
+            123_457,
+            999_999_999,
+            10u64.pow(15) + 1,
+        ];
+        let elapsed_times = [0, 1, 28_799, 12_345_678, SECONDS_PER_YEAR as i64 * 7 + 3];
+
+        for (base_mint_decimals, x_mint_decimals) in [(6, 6), (6, 9), (9, 6), (0, 6)] {
+            for elapsed_time in elapsed_times {
+                let mut pool_manager = default_pool_manager();
+                pool_manager.base_mint_decimals = base_mint_decimals;
+                pool_manager.x_mint_decimals = x_mint_decimals;
+                pool_manager.last_yield_change_exchange_rate =
+                    1_000_003 * 10u64.pow(base_mint_decimals.into()) / 1_000_000;
+
+                for quantity in quantities {
+                    let x_amount =
+                        match pool_manager.calculate_output_amount(quantity, elapsed_time, true) {
+                            Ok(x_amount) => x_amount,
+                            // Rescaling past u64 is rejected rather than truncated
+                            Err(_) => {
+                                assert!(decimals::normalize_quantity(
+                                    quantity,
+                                    base_mint_decimals,
+                                    x_mint_decimals
+                                )
+                                .is_none());
+                                continue;
+                            }
+                        };
+                    let base_amount = pool_manager
+                        .calculate_output_amount(x_amount, elapsed_time, false)
+                        .unwrap();
+                    assert!(
+                        base_amount <= quantity,
+                        "staking {} returned {}",
+                        quantity,
+                        base_amount
+                    );
+
+                    // Unstaking later can only add the accrued yield
+                    let later_base_amount = pool_manager
+                        .calculate_output_amount(x_amount, elapsed_time + 28_800, false)
+                        .unwrap();
+                    assert!(later_base_amount >= base_amount);
+
+                    // Whatever the x amount is worth never costs less than it pays out
+                    let required_base_amount = pool_manager
+                        .calculate_required_base_amount(x_amount, elapsed_time)
+                        .unwrap();
+                    assert!(required_base_amount >= base_amount);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_calculate_required_base_amount() {
+        let mut pool_manager = default_pool_manager();
+        pool_manager.last_yield_change_exchange_rate = 1_500_000;
+
+        // 1,000 x units at 1.5 are worth exactly 1,500 base units
         let result = pool_manager
-            .calculate_normalized_quantity(1000000000, 9, 6)
+            .calculate_required_base_amount(DEAD_SHARES, 0)
             .unwrap();
-        assert_eq!(result, 1000000);
+        assert_eq!(result, 1_500);
+
+        // A single x unit is worth 1.5 base units, rounded up
+        let result = pool_manager.calculate_required_base_amount(1, 0).unwrap();
+        assert_eq!(result, 2);
+        let result = pool_manager.calculate_output_amount(1, 0, false).unwrap();
+        assert_eq!(result, 1);
     }
 
     #[test]
-    fn test_calculate_amount_to_mint() {
+    fn test_check_minimum_amounts() {
         let mut pool_manager = default_pool_manager();
+        pool_manager.min_stake_amount = 1_000;
+        pool_manager.min_unstake_amount = 500;
 
-        // Set up the conditions
-        pool_manager.base_balance = 1000_000_000; // 500 base tokens
-        let vault_balance = pool_manager.base_balance;
-        let x_mint_supply = 1000_000_000; // 1,000 xMint tokens
-        let current_timestamp = 31_536_000; // One year in seconds
+        assert!(pool_manager.check_minimum_stake(999).is_err());
+        assert!(pool_manager.check_minimum_stake(1_000).is_ok());
+        assert!(pool_manager.check_minimum_unstake(499).is_err());
+        assert!(pool_manager.check_minimum_unstake(500).is_ok());
+    }
 
-        // Calculate the amount to mint for one year
-        let amount_to_mint = pool_manager
-            .calculate_amount_to_mint(x_mint_supply, current_timestamp, vault_balance)
+    #[test]
+    fn test_calculate_instant_unstake_fee() {
+        let mut pool_manager = default_pool_manager();
+        pool_manager.instant_unstake_fee_bps = 50; // 0.5%
+
+        let fee = pool_manager
+            .calculate_instant_unstake_fee(1_000_000)
             .unwrap();
+        assert_eq!(fee, 5_000);
 
-        // Verify the result for one year
-        assert_eq!(amount_to_mint, 199999000); // Needs to mint 200 base tokens
+        let fee = pool_manager.calculate_instant_unstake_fee(0).unwrap();
+        assert_eq!(fee, 0);
 
-        // Calculate the amount to mint for half a year
-        let half_year_timestamp = current_timestamp / 2; // Half a year in seconds
-        let amount_to_mint_half_year = pool_manager
-            .calculate_amount_to_mint(x_mint_supply, half_year_timestamp, vault_balance)
+        // Partial units of fee are rounded up
+        let fee = pool_manager
+            .calculate_instant_unstake_fee(1_000_001)
             .unwrap();
-
-        // Verify the result for half a year
-        assert_eq!(amount_to_mint_half_year, 95437000); // Needs to mint 100 base tokens
+        assert_eq!(fee, 5_001);
     }
 
     #[test]
-    fn test_calculate_output_amount() {
+    fn test_liquidity_buffer() {
         let mut pool_manager = default_pool_manager();
+        pool_manager.liquidity_buffer_target = 1_000_000;
 
-        // Set up the conditions
-        let current_timestamp = 31_536_000; // One year in seconds
+        // Stakes top up the buffer up to the target
+        pool_manager.top_up_liquidity_buffer(600_000);
+        assert_eq!(pool_manager.liquidity_buffer_amount, 600_000);
+        pool_manager.top_up_liquidity_buffer(600_000);
+        assert_eq!(pool_manager.liquidity_buffer_amount, 1_000_000);
 
-        // Test conversion from baseMint to xMint
-        let base_quantity = 1000_000_000; // 1000 baseMint token
-        let x_mint_amount = pool_manager
-            .calculate_output_amount(base_quantity, current_timestamp, true)
-            .unwrap();
-        assert_eq!(x_mint_amount, 833_334_027); // Expected xMint amount after one year
+        // Instant unstakes can not exceed the buffer
+        assert!(pool_manager.use_liquidity_buffer(400_000).is_ok());
+        assert_eq!(pool_manager.liquidity_buffer_amount, 600_000);
+        assert!(pool_manager.use_liquidity_buffer(600_001).is_err());
+    }
 
-        // Test conversion from xMint to baseMint
-        let x_quantity = 100_000_000; // 1 xMint token
-        let base_mint_amount = pool_manager
-            .calculate_output_amount(x_quantity, current_timestamp, false)
+    #[test]
+    fn test_distribute_to_stakers() {
+        let mut pool_manager = default_pool_manager();
+        let current_timestamp = 0;
+
+        // 10 base tokens distributed over 1,000 x tokens raise the rate by 1%
+        pool_manager
+            .distribute_to_stakers(10_000_000, 1_000_000_000, current_timestamp)
             .unwrap();
-        assert_eq!(base_mint_amount, 119_999_900); // Expected baseMint amount after one year
+        assert_eq!(pool_manager.last_yield_change_exchange_rate, 1_010_000);
+        assert_eq!(pool_manager.last_yield_change_timestamp, current_timestamp);
+
+        // Nothing can be distributed without stakers
+        let result = pool_manager.distribute_to_stakers(10_000_000, 0, current_timestamp);
+        assert!(result.is_err());
     }
 
     #[test]
@@ -348,12 +2483,718 @@ mod tests {
         let mut pool_manager = default_pool_manager();
         pool_manager.deposit_cap = 1000000;
 
+        pool_manager.record_deposit(500000).unwrap();
+
         // Test case where deposit is within limit
-        let result = pool_manager.check_excessive_deposit(500000, 500000);
+        let result = pool_manager.check_excessive_deposit(500000);
         assert!(result.is_ok());
 
         // Test case where deposit exceeds limit
-        let result = pool_manager.check_excessive_deposit(500000, 1000000);
+        let result = pool_manager.check_excessive_deposit(500001);
         assert!(result.is_err());
+
+        // Yield accruing on the principal leaves the cap untouched
+        pool_manager.record_yield_minted(100000).unwrap();
+        let result = pool_manager.check_excessive_deposit(500000);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_preview_stake() {
+        let mut pool_manager = default_pool_manager();
+        pool_manager.min_stake_amount = 1_000;
+        pool_manager.principal_amount = 100_000;
+
+        let preview = pool_manager.preview_stake(200_000, 0).unwrap();
+        assert_eq!(
+            preview,
+            StakePreview {
+                x_amount: 200_000,
+                fee_amount: 0,
+                exchange_rate: 1_000_000,
+                min_stake_amount: 1_000,
+                max_stake_amount: 400_000,
+            }
+        );
+
+        // Matches the amount a stake mints later on
+        let current_timestamp = 365 * 24 * 60 * 60;
+        let preview = pool_manager
+            .preview_stake(200_000, current_timestamp)
+            .unwrap();
+        assert_eq!(
+            preview.x_amount,
+            pool_manager
+                .calculate_output_amount(200_000, current_timestamp, true)
+                .unwrap()
+        );
+        assert!(preview.exchange_rate > 1_000_000);
+    }
+
+    #[test]
+    fn test_preview_unstake() {
+        let mut pool_manager = default_pool_manager();
+
+        let preview = pool_manager.preview_unstake(1_000_000, 0).unwrap();
+        assert_eq!(preview.base_amount, 1_000_000);
+        assert_eq!(preview.fee_amount, 0);
+        assert_eq!(preview.unstake_path, UnstakePath::Standard);
+        assert_eq!(preview.max_base_amount, u64::MAX);
+
+        // A cooldown routes unstakes through the fee charging instant path
+        pool_manager.unstake_cooldown = 24 * 60 * 60;
+        pool_manager.instant_unstake_fee_bps = 50; // 0.5%
+        pool_manager.liquidity_buffer_amount = 250_000;
+
+        let preview = pool_manager.preview_unstake(1_000_000, 0).unwrap();
+        assert_eq!(preview.base_amount, 995_000);
+        assert_eq!(preview.fee_amount, 5_000);
+        assert_eq!(preview.unstake_path, UnstakePath::Instant);
+        assert_eq!(preview.max_base_amount, 250_000);
+    }
+
+    #[test]
+    fn test_preview_stake_with_entry_fee() {
+        let mut pool_manager = default_pool_manager();
+        pool_manager.record_deposit(1_000_000_000).unwrap();
+        pool_manager.record_x_minted(1_000_000_000).unwrap();
+        pool_manager.entry_fee_bps = 100; // 1%
+
+        // Sent to the treasury, the fee only reduces the amount staked
+        pool_manager.entry_fee_destination = FeeDestination::Treasury;
+        let preview = pool_manager.preview_stake(1_000_000, 0).unwrap();
+        assert_eq!(preview.fee_amount, 10_000);
+        assert_eq!(preview.x_amount, 990_000);
+        assert_eq!(preview.exchange_rate, 1_000_000);
+
+        // Left to the stakers, it raises the rate before the stake mints
+        pool_manager.entry_fee_destination = FeeDestination::Stakers;
+        let preview = pool_manager.preview_stake(1_000_000, 0).unwrap();
+        assert_eq!(preview.fee_amount, 10_000);
+        assert_eq!(preview.exchange_rate, 1_000_010);
+        assert_eq!(preview.x_amount, 989_990);
+
+        pool_manager.distribute_entry_fee(10_000, 0).unwrap();
+        assert_eq!(pool_manager.calculate_exchange_rate(0).unwrap(), 1_000_010);
+        assert_eq!(pool_manager.base_balance, 1_000_010_000);
+        assert_eq!(pool_manager.realized_yield_amount, 10_000);
+    }
+
+    #[test]
+    fn test_distribute_entry_fee_without_stakers() {
+        let mut pool_manager = default_pool_manager();
+        pool_manager.record_deposit(1_000).unwrap();
+        pool_manager.record_x_minted(DEAD_SHARES).unwrap();
+
+        // Nobody but the dead shares to receive it, so it is left as excess
+        pool_manager.distribute_entry_fee(10_000, 0).unwrap();
+        assert_eq!(pool_manager.base_balance, 1_000);
+        assert_eq!(pool_manager.calculate_exchange_rate(0).unwrap(), 1_000_000);
+    }
+
+    #[test]
+    fn test_preview_unstake_with_exit_fee() {
+        let mut pool_manager = default_pool_manager();
+        pool_manager.exit_fee_bps = 100; // 1%
+
+        let preview = pool_manager.preview_unstake(1_000_000, 0).unwrap();
+        assert_eq!(preview.base_amount, 990_000);
+        assert_eq!(preview.fee_amount, 10_000);
+        assert_eq!(preview.unstake_path, UnstakePath::Standard);
+
+        // The instant unstake fee applies to what is left after the exit fee
+        pool_manager.unstake_cooldown = 24 * 60 * 60;
+        pool_manager.instant_unstake_fee_bps = 50; // 0.5%
+        let preview = pool_manager.preview_unstake(1_000_000, 0).unwrap();
+        assert_eq!(preview.base_amount, 985_050);
+        assert_eq!(preview.fee_amount, 14_950);
+    }
+
+    #[test]
+    fn test_update_pool_status() {
+        let mut pool_manager = default_pool_manager();
+
+        // A guardian can pause
+        pool_manager
+            .update_pool_status(false, true, false, true)
+            .unwrap();
+        assert!(pool_manager.check_stake_active().is_err());
+        assert!(pool_manager.check_unstake_active().is_ok());
+
+        // But cannot unpause, even while pausing the other flag
+        assert!(pool_manager
+            .update_pool_status(true, false, false, true)
+            .is_err());
+        assert!(pool_manager
+            .update_pool_status(false, false, false, false)
+            .is_err());
+        pool_manager
+            .update_pool_status(false, false, false, true)
+            .unwrap();
+        assert!(pool_manager.check_unstake_active().is_err());
+
+        // Only the admin can unpause
+        assert!(pool_manager
+            .update_pool_status(false, false, true, false)
+            .is_err());
+        pool_manager
+            .update_pool_status(true, true, true, false)
+            .unwrap();
+        assert!(pool_manager.check_stake_active().is_ok());
+        assert!(pool_manager.check_unstake_active().is_ok());
+    }
+
+    #[test]
+    fn test_calculate_boost_amount() {
+        let pool_manager = default_pool_manager();
+        let term_position = TermPosition {
+            bump: 0,
+            owner: Pubkey::default(),
+            pool_manager: Pubkey::default(),
+            tranche: Pubkey::default(),
+            position_id: 0,
+            principal_amount: 1_000_000,
+            x_amount: 1_000_000,
+            // 30% instead of the pool's 20%
+            interval_apr_rate: pool_manager.calculate_interval_apr_rate(3000).unwrap(),
+            start_timestamp: 0,
+            maturity_timestamp: 31_536_000,
+        };
+
+        let maturity_amount = term_position
+            .calculate_maturity_amount(pool_manager.seconds_per_interval)
+            .unwrap();
+        assert!((1_299_990..=1_300_000).contains(&maturity_amount));
+
+        // The locked x tokens earned the pool rate over the term
+        let x_value = pool_manager
+            .calculate_output_amount(term_position.x_amount, 31_536_000, false)
+            .unwrap();
+        assert_eq!(x_value, 1_199_999);
+
+        // Forfeited before maturity
+        let boost_amount = term_position
+            .calculate_boost_amount(x_value, pool_manager.seconds_per_interval, 31_535_999)
+            .unwrap();
+        assert_eq!(boost_amount, 0);
+
+        let boost_amount = term_position
+            .calculate_boost_amount(x_value, pool_manager.seconds_per_interval, 31_536_000)
+            .unwrap();
+        assert_eq!(boost_amount, maturity_amount - x_value);
+
+        // Nothing owed once the pool rate caught up with the tranche rate
+        let boost_amount = term_position
+            .calculate_boost_amount(1_400_000, pool_manager.seconds_per_interval, 40_000_000)
+            .unwrap();
+        assert_eq!(boost_amount, 0);
+    }
+
+    #[test]
+    fn test_calculate_x_mint_interest_rate() {
+        let mut pool_manager = default_pool_manager();
+        assert!(!pool_manager.is_x_mint_interest_bearing());
+
+        pool_manager.x_token_program = token_2022::ID;
+        assert!(pool_manager.is_x_mint_interest_bearing());
+
+        // 20% APY
+        assert_eq!(pool_manager.calculate_x_mint_interest_rate().unwrap(), 1823);
+
+        pool_manager.set_annual_yield(0).unwrap();
+        assert_eq!(pool_manager.calculate_x_mint_interest_rate().unwrap(), 0);
+
+        // Beyond what the extension can represent
+        pool_manager.set_annual_yield(400_000).unwrap();
+        assert!(pool_manager.calculate_x_mint_interest_rate().is_err());
+    }
+
+    #[test]
+    fn test_check_interest_bearing_x_mint() {
+        let mut pool_manager = default_pool_manager();
+        pool_manager.initial_exchange_rate = 1_050_000;
+        pool_manager.check_interest_bearing_x_mint().unwrap();
+
+        pool_manager.x_token_program = token_2022::ID;
+        assert!(pool_manager.check_interest_bearing_x_mint().is_err());
+        pool_manager.initial_exchange_rate = 1_000_000;
+        pool_manager.check_interest_bearing_x_mint().unwrap();
+
+        // Fees kept by the stakers raise the exchange rate past the mint rate
+        pool_manager.entry_fee_bps = 10;
+        assert!(pool_manager.check_interest_bearing_x_mint().is_err());
+        pool_manager.entry_fee_destination = FeeDestination::Treasury;
+        pool_manager.check_interest_bearing_x_mint().unwrap();
+
+        pool_manager.exit_fee_bps = 10;
+        assert!(pool_manager.check_interest_bearing_x_mint().is_err());
+        pool_manager.exit_fee_destination = FeeDestination::Treasury;
+        pool_manager.check_interest_bearing_x_mint().unwrap();
+
+        pool_manager.instant_unstake_fee_bps = 10;
+        pool_manager.instant_unstake_fee_destination = FeeDestination::Stakers;
+        assert!(pool_manager.check_interest_bearing_x_mint().is_err());
+        pool_manager.instant_unstake_fee_destination = FeeDestination::Treasury;
+        pool_manager.check_interest_bearing_x_mint().unwrap();
+
+        pool_manager.yield_mode = YieldMode::RewardStreaming;
+        assert!(pool_manager.check_interest_bearing_x_mint().is_err());
+    }
+
+    fn default_rate_feed() -> RateFeed {
+        let mut rate_feed = RateFeed {
+            version: RATE_FEED_VERSION,
+            bump: 0,
+            pool_manager: Pubkey::default(),
+            x_mint: Pubkey::default(),
+            base_mint: Pubkey::default(),
+            base_mint_decimals: 6,
+            exchange_rate: 1000000,
+            last_update_timestamp: 0,
+            twap_exchange_rate: 1000000,
+            realized_apy_bps: 0,
+            cumulative_exchange_rate: 0,
+            next_observation_index: 0,
+            observations: Vec::new(),
+        };
+        rate_feed.record_observation().unwrap();
+        rate_feed
+    }
+
+    #[test]
+    fn test_refresh_rate_feed() {
+        let pool_manager = default_pool_manager();
+        let mut rate_feed = default_rate_feed();
+
+        // Half a window in, the average covers the feed so far
+        let half_window = RATE_FEED_TWAP_WINDOW / 2;
+        rate_feed.refresh(&pool_manager, half_window).unwrap();
+        let exchange_rate = pool_manager.calculate_exchange_rate(half_window).unwrap();
+        assert_eq!(rate_feed.exchange_rate, exchange_rate);
+        assert_eq!(rate_feed.last_update_timestamp, half_window);
+        assert_eq!(rate_feed.twap_exchange_rate, (1000000 + exchange_rate) / 2);
+        assert_eq!(rate_feed.observations.len(), 2);
+        // Too young to annualize
+        assert_eq!(rate_feed.realized_apy_bps, 0);
+
+        // Refreshing again in the same slot changes nothing
+        let twap_exchange_rate = rate_feed.twap_exchange_rate;
+        rate_feed.refresh(&pool_manager, half_window).unwrap();
+        assert_eq!(rate_feed.twap_exchange_rate, twap_exchange_rate);
+        assert_eq!(rate_feed.observations.len(), 2);
+
+        // A gap longer than the window averages the last window of it only
+        let year = SECONDS_PER_YEAR as i64;
+        rate_feed.refresh(&pool_manager, year).unwrap();
+        let year_exchange_rate = pool_manager.calculate_exchange_rate(year).unwrap();
+        assert_eq!(rate_feed.exchange_rate, year_exchange_rate);
+        assert!(rate_feed.twap_exchange_rate < year_exchange_rate);
+        assert!(rate_feed.twap_exchange_rate > year_exchange_rate - 1_000);
+        assert_eq!(rate_feed.realized_apy_bps, 2000);
+    }
+
+    #[test]
+    fn test_rate_feed_twap_window() {
+        let pool_manager = default_pool_manager();
+        let mut hourly_rate_feed = default_rate_feed();
+        let mut sparse_rate_feed = default_rate_feed();
+
+        // Refreshed every hour for three days, the buffer wraps around
+        let hour = 60 * 60;
+        let mut cumulative_exchange_rates = vec![0];
+        for hours in 1..=72 {
+            hourly_rate_feed
+                .refresh(&pool_manager, hours * hour)
+                .unwrap();
+            cumulative_exchange_rates.push(hourly_rate_feed.cumulative_exchange_rate);
+        }
+        assert_eq!(hourly_rate_feed.observations.len(), RATE_FEED_OBSERVATIONS);
+
+        // The average is taken over the last window exactly
+        let window_start = (72 * hour - RATE_FEED_TWAP_WINDOW) / hour;
+        assert_eq!(
+            hourly_rate_feed.twap_exchange_rate,
+            twap::time_weighted_average(
+                cumulative_exchange_rates[window_start as usize],
+                hourly_rate_feed.cumulative_exchange_rate,
+                RATE_FEED_TWAP_WINDOW,
+            )
+            .unwrap()
+        );
+        let window_start_exchange_rate = pool_manager
+            .calculate_exchange_rate(72 * hour - RATE_FEED_TWAP_WINDOW)
+            .unwrap();
+        assert!(hourly_rate_feed.twap_exchange_rate > window_start_exchange_rate);
+        assert!(hourly_rate_feed.twap_exchange_rate < hourly_rate_feed.exchange_rate);
+
+        // Refreshing rarely lands on about the same average
+        for hours in [12, 60, 72] {
+            sparse_rate_feed
+                .refresh(&pool_manager, hours * hour)
+                .unwrap();
+        }
+        assert_eq!(
+            sparse_rate_feed.exchange_rate,
+            hourly_rate_feed.exchange_rate
+        );
+        assert!(
+            sparse_rate_feed
+                .twap_exchange_rate
+                .abs_diff(hourly_rate_feed.twap_exchange_rate)
+                <= 2
+        );
+    }
+
+    #[test]
+    fn test_rate_feed_layout() {
+        let mut rate_feed = default_rate_feed();
+        rate_feed.pool_manager = Pubkey::new_unique();
+        rate_feed.exchange_rate = 1_050_000;
+        rate_feed.last_update_timestamp = 1_700_000_000;
+        rate_feed.twap_exchange_rate = 1_049_000;
+        rate_feed.realized_apy_bps = 2000;
+        rate_feed.cumulative_exchange_rate = 1_000_000 * 86_400;
+        rate_feed.next_observation_index = 1;
+        rate_feed.observations = vec![rate_feed.observations[0]; RATE_FEED_OBSERVATIONS];
+
+        let mut data = Vec::new();
+        rate_feed.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), RATE_FEED_LENGTH);
+
+        // Offsets documented on RateFeed, after the discriminator
+        let data = &data[8..];
+        let read_u64 =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        let read_u32 =
+            |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        assert_eq!(data[0], RATE_FEED_VERSION);
+        assert_eq!(&data[2..34], rate_feed.pool_manager.as_ref());
+        assert_eq!(data[98], 6);
+        assert_eq!(read_u64(99), 1_050_000);
+        assert_eq!(read_u64(107), 1_700_000_000);
+        assert_eq!(read_u64(115), 1_049_000);
+        assert_eq!(read_u64(123), 2000);
+        assert_eq!(
+            u128::from_le_bytes(data[131..147].try_into().unwrap()),
+            1_000_000 * 86_400
+        );
+        assert_eq!(read_u32(147), 1);
+        assert_eq!(read_u32(151), RATE_FEED_OBSERVATIONS as u32);
+    }
+
+    fn default_rate_history() -> RateHistory {
+        RateHistory {
+            bump: 0,
+            pool_manager: Pubkey::default(),
+            next_index: 0,
+            checkpoints: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_record_rate_checkpoint() {
+        let mut rate_history = default_rate_history();
+        assert_eq!(rate_history.latest_checkpoint(), None);
+
+        assert!(rate_history.record(1_000_000, 0, 100).unwrap());
+        // Too soon after the latest checkpoint
+        assert!(!rate_history.record(1_000_001, 99, 100).unwrap());
+        assert!(rate_history.record(1_000_001, 100, 100).unwrap());
+        assert_eq!(rate_history.checkpoints.len(), 2);
+        assert_eq!(
+            rate_history.latest_checkpoint(),
+            Some(&RateCheckpoint {
+                timestamp: 100,
+                exchange_rate: 1_000_001,
+            })
+        );
+
+        // Fill the buffer and wrap around over the oldest checkpoints
+        for i in 2..(MAX_RATE_CHECKPOINTS as i64 + 3) {
+            assert!(rate_history
+                .record(1_000_000 + i as u64, i * 100, 100)
+                .unwrap());
+        }
+        assert_eq!(rate_history.checkpoints.len(), MAX_RATE_CHECKPOINTS);
+        assert_eq!(rate_history.next_index, 3);
+
+        let latest_timestamp = (MAX_RATE_CHECKPOINTS as i64 + 2) * 100;
+        assert_eq!(
+            rate_history.latest_checkpoint().unwrap().timestamp,
+            latest_timestamp
+        );
+        // The first three checkpoints were overwritten
+        assert_eq!(rate_history.checkpoint_at(250), None);
+        assert_eq!(rate_history.checkpoint_at(350).unwrap().timestamp, 300);
+        assert_eq!(
+            rate_history.checkpoint_at(i64::MAX).unwrap().timestamp,
+            latest_timestamp
+        );
+    }
+
+    #[test]
+    fn test_calculate_trailing_apy_bps() {
+        let pool_manager = default_pool_manager();
+        let mut rate_history = default_rate_history();
+        let day = 24 * 60 * 60;
+
+        // Daily checkpoints over 40 days at 20% APY
+        for i in 0..=40 {
+            let exchange_rate = pool_manager.calculate_exchange_rate(i * day).unwrap();
+            rate_history
+                .record(exchange_rate, i * day, pool_manager.checkpoint_interval)
+                .unwrap();
+        }
+
+        let current_timestamp = 40 * day;
+        let exchange_rate = pool_manager
+            .calculate_exchange_rate(current_timestamp)
+            .unwrap();
+
+        for window in [7 * day, 30 * day] {
+            let trailing_apy_bps = rate_history
+                .calculate_trailing_apy_bps(exchange_rate, window, current_timestamp)
+                .unwrap();
+            assert!((1999..=2001).contains(&trailing_apy_bps));
+        }
+
+        // Older than the first checkpoint
+        assert!(rate_history
+            .calculate_trailing_apy_bps(exchange_rate, 41 * day, current_timestamp)
+            .is_err());
+        assert!(rate_history
+            .calculate_trailing_apy_bps(exchange_rate, 0, current_timestamp)
+            .is_err());
+    }
+
+    #[test]
+    fn test_check_strategy_allocation() {
+        let mut pool_manager = default_pool_manager();
+        pool_manager.max_strategy_allocation_bps = 5000;
+        pool_manager.pending_unstake_amount = 100_000;
+        pool_manager.liquidity_buffer_amount = 100_000;
+
+        // Half of the 1_000_000 assets, leaving the reserved 200_000 in the vault
+        assert!(pool_manager
+            .check_strategy_allocation(500_000, 1_000_000)
+            .is_ok());
+        assert!(pool_manager
+            .check_strategy_allocation(500_001, 1_000_000)
+            .is_err());
+
+        // Base already held by strategies counts toward both the assets and the share
+        pool_manager.strategy_value = 400_000;
+        assert!(pool_manager
+            .check_strategy_allocation(100_000, 600_000)
+            .is_ok());
+        assert!(pool_manager
+            .check_strategy_allocation(100_001, 600_000)
+            .is_err());
+
+        // Unstakes come first
+        pool_manager.pending_unstake_amount = 500_000;
+        assert!(pool_manager
+            .check_strategy_allocation(100_000, 600_000)
+            .is_err());
+
+        // Nothing can be allocated until a share is set
+        let pool_manager = default_pool_manager();
+        assert!(pool_manager
+            .check_strategy_allocation(1, 1_000_000)
+            .is_err());
+    }
+
+    #[test]
+    fn test_record_strategy_value() {
+        let mut pool_manager = default_pool_manager();
+        pool_manager.record_deposit(1_000_000).unwrap();
+        pool_manager.record_strategy_deposit(400_000).unwrap();
+        assert_eq!(
+            pool_manager.calculate_pool_assets(600_000).unwrap(),
+            1_000_000
+        );
+
+        // A gain is realized yield
+        assert_eq!(
+            pool_manager
+                .record_strategy_value(400_000, 410_000, 0)
+                .unwrap(),
+            0
+        );
+        assert_eq!(pool_manager.strategy_value, 410_000);
+        assert_eq!(pool_manager.base_balance, 1_010_000);
+        assert_eq!(pool_manager.realized_yield_amount, 10_000);
+        assert_eq!(pool_manager.principal_amount, 1_000_000);
+
+        // A loss leaves the pool like a withdrawal
+        pool_manager
+            .record_strategy_value(410_000, 400_000, 0)
+            .unwrap();
+        assert_eq!(pool_manager.strategy_value, 400_000);
+        assert_eq!(pool_manager.base_balance, 1_000_000);
+
+        // The performance fee comes out of the gain
+        pool_manager.performance_fee_bps = 1000;
+        let realized_yield_amount = pool_manager.realized_yield_amount;
+        assert_eq!(
+            pool_manager
+                .record_strategy_value(400_000, 410_000, 0)
+                .unwrap(),
+            1_000
+        );
+        assert_eq!(pool_manager.strategy_value, 410_000);
+        assert_eq!(pool_manager.base_balance, 1_009_000);
+        assert_eq!(
+            pool_manager.realized_yield_amount,
+            realized_yield_amount + 9_000
+        );
+
+        // Losses pay no fee
+        assert_eq!(
+            pool_manager
+                .record_strategy_value(410_000, 400_000, 0)
+                .unwrap(),
+            0
+        );
+
+        // Streaming pools vest the gain
+        let mut pool_manager = default_pool_manager();
+        pool_manager.yield_mode = YieldMode::RewardStreaming;
+        pool_manager.vesting_period = 1000;
+        pool_manager.record_strategy_deposit(400_000).unwrap();
+        pool_manager
+            .record_strategy_value(400_000, 410_000, 0)
+            .unwrap();
+        assert_eq!(pool_manager.vesting_amount, 10_000);
+        assert_eq!(pool_manager.calculate_unvested_amount(500).unwrap(), 5_000);
+    }
+
+    #[test]
+    fn test_strategy_allocation() {
+        let mut strategy = Strategy {
+            bump: 0,
+            pool_manager: Pubkey::default(),
+            strategy_program: Pubkey::default(),
+            active: true,
+            cap: 500_000,
+            allocated_amount: 0,
+            reported_value: 0,
+            last_report_timestamp: 0,
+        };
+
+        assert!(strategy.check_allocation(500_000).is_ok());
+        strategy.record_deposit(400_000).unwrap();
+        assert!(strategy.check_allocation(100_001).is_err());
+
+        // The cap applies to the reported value, gains included
+        strategy.reported_value = 450_000;
+        assert!(strategy.check_allocation(50_001).is_err());
+
+        // Withdrawing gains runs the allocation down to zero
+        strategy.record_withdrawal(450_000).unwrap();
+        assert_eq!(strategy.reported_value, 0);
+        assert_eq!(strategy.allocated_amount, 0);
+        assert!(strategy.record_withdrawal(1).is_err());
+
+        strategy.active = false;
+        assert!(strategy.check_allocation(1).is_err());
+    }
+
+    #[test]
+    fn test_staker_entry_record_stake() {
+        let mut pool_manager = default_pool_manager();
+        let mut staker_entry = StakerEntry {
+            bump: 0,
+            pool_manager: Pubkey::default(),
+            wallet: Pubkey::default(),
+            allowed: false,
+            deposit_cap: 0,
+            staked_amount: 0,
+        };
+        assert!(!pool_manager.is_staker_entry_required());
+
+        // Without a cap every stake is tracked
+        staker_entry.record_stake(1_000_000, &pool_manager).unwrap();
+        assert_eq!(staker_entry.staked_amount, 1_000_000);
+
+        // The pool cap counts the stakes made so far
+        pool_manager.wallet_deposit_cap = 1_500_000;
+        assert!(pool_manager.is_staker_entry_required());
+        staker_entry.record_stake(500_000, &pool_manager).unwrap();
+        assert!(staker_entry.record_stake(1, &pool_manager).is_err());
+        assert_eq!(staker_entry.staked_amount, 1_500_000);
+
+        // The entry cap overrides the pool cap
+        staker_entry.deposit_cap = 2_000_000;
+        staker_entry.record_stake(500_000, &pool_manager).unwrap();
+        assert!(staker_entry.record_stake(1, &pool_manager).is_err());
+
+        // Only allowed wallets stake while the allowlist is enabled
+        staker_entry.deposit_cap = 0;
+        pool_manager.wallet_deposit_cap = 0;
+        pool_manager.allowlist_enabled = true;
+        assert!(pool_manager.is_staker_entry_required());
+        assert!(staker_entry.record_stake(1, &pool_manager).is_err());
+        staker_entry.allowed = true;
+        staker_entry.record_stake(1, &pool_manager).unwrap();
+        assert_eq!(staker_entry.staked_amount, 2_000_001);
+
+        // Unstakes free up the cap, and the yield they include stops at zero
+        staker_entry.record_unstake(1_000_000);
+        assert_eq!(staker_entry.staked_amount, 1_000_001);
+        staker_entry.record_unstake(1_500_000);
+        assert_eq!(staker_entry.staked_amount, 0);
+    }
+
+    #[test]
+    fn test_pool_manager_length() {
+        let mut data = Vec::new();
+        default_pool_manager().try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), POOL_MANAGER_LENGTH);
+    }
+
+    #[test]
+    fn test_pool_manager_from_legacy() {
+        let legacy = LegacyPoolManager {
+            bump: 255,
+            owner: Pubkey::new_unique(),
+            pending_owner: Pubkey::default(),
+            admin: Pubkey::new_unique(),
+            base_mint: Pubkey::new_unique(),
+            x_mint: Pubkey::new_unique(),
+            base_mint_decimals: 6,
+            x_mint_decimals: 6,
+            interval_apr_rate: 1000166517567,
+            seconds_per_interval: 8 * 60 * 60,
+            initial_exchange_rate: 1_000_000,
+            last_yield_change_exchange_rate: 1_050_000,
+            inception_timestamp: 0,
+            last_yield_change_timestamp: 1_000_000,
+            base_balance: 5_000_000,
+            deposit_cap: 1_000_000_000,
+        };
+        let mut data = PoolManager::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        assert_eq!(data.len() + 8, LEGACY_POOL_MANAGER_LENGTH);
+
+        let pool_manager = PoolManager::from_legacy(&legacy, 254, 0, 4_800_000, 2_000_000).unwrap();
+        assert_eq!(pool_manager.bump, 254);
+        assert_eq!(pool_manager.owner, legacy.owner);
+        assert_eq!(pool_manager.x_mint, legacy.x_mint);
+        assert_eq!(pool_manager.x_supply, 4_800_000);
+        assert_eq!(pool_manager.principal_amount, 5_000_000);
+        assert!(pool_manager.stake_active && pool_manager.unstake_active);
+        assert_eq!(pool_manager.x_token_program, token::ID);
+        assert_eq!(
+            pool_manager.checkpoint_interval,
+            DEFAULT_CHECKPOINT_INTERVAL
+        );
+
+        // The yield curve carries over unchanged
+        assert_eq!(pool_manager.annual_yield_bps, 2000);
+        assert_eq!(
+            pool_manager.calculate_exchange_rate(1_000_000).unwrap(),
+            1_050_000
+        );
     }
 }
@@ -0,0 +1,121 @@
+//! Interface between the pool and the yield strategy programs it allocates to.
+//!
+//! A strategy program exposes three instructions, each identified by its Anchor
+//! discriminator (`sha256("global:<name>")[..8]`) followed by its borsh
+//! arguments:
+//!
+//! - `deposit(amount: u64)` takes `amount` base tokens that the pool just moved
+//!   into the strategy token account.
+//! - `withdraw(amount: u64)` moves up to `amount` base tokens back into the
+//!   vault, everything it holds for the pool for `u64::MAX`.
+//! - `report_value() -> u64` returns, as borsh return data, the base value it
+//!   holds for the pool, the strategy token account included.
+//!
+//! Every instruction receives the strategy authority, the strategy token
+//! account and the vault (both writable), the base mint and the token program,
+//! followed by the strategy's own accounts, forwarded from the remaining
+//! accounts of the pool instruction without their signer flag.
+//!
+//! The strategy authority is a PDA of the pool at
+//! `[b"strategy-authority", strategy]` that owns the strategy token account, an
+//! associated token account of the base mint, and nothing else. It signs
+//! `deposit` and `withdraw` only. The pool manager, which holds the vault and
+//! mints the base and x tokens, never signs for a strategy.
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        hash::hash,
+        instruction::Instruction,
+        program::{get_return_data, invoke_signed},
+    },
+};
+
+use crate::error::ParityStakingError;
+
+pub struct StrategyContext<'a, 'info> {
+    pub strategy_program: AccountInfo<'info>,
+    pub strategy: Pubkey,
+    pub strategy_authority: AccountInfo<'info>,
+    pub strategy_authority_bump: u8,
+    pub strategy_token_account: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub base_mint: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> StrategyContext<'a, 'info> {
+    pub fn deposit(&self, amount: u64) -> Result<()> {
+        self.invoke("deposit", &amount.to_le_bytes(), true)
+    }
+
+    pub fn withdraw(&self, amount: u64) -> Result<()> {
+        self.invoke("withdraw", &amount.to_le_bytes(), true)
+    }
+
+    pub fn report_value(&self) -> Result<u64> {
+        self.invoke("report_value", &[], false)?;
+
+        // Only trust return data set by the strategy program itself
+        let (program_id, return_data) =
+            get_return_data().ok_or(ParityStakingError::InvalidStrategy)?;
+        if program_id != self.strategy_program.key() {
+            return err!(ParityStakingError::InvalidStrategy);
+        }
+
+        u64::try_from_slice(&return_data).map_err(|_| ParityStakingError::InvalidStrategy.into())
+    }
+
+    fn invoke(&self, instruction_name: &str, args: &[u8], signed: bool) -> Result<()> {
+        let mut data =
+            hash(format!("global:{}", instruction_name).as_bytes()).to_bytes()[..8].to_vec();
+        data.extend_from_slice(args);
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(self.strategy_authority.key(), signed),
+            AccountMeta::new(self.strategy_token_account.key(), false),
+            AccountMeta::new(self.vault.key(), false),
+            AccountMeta::new_readonly(self.base_mint.key(), false),
+            AccountMeta::new_readonly(self.token_program.key(), false),
+        ];
+        // Strategies only ever act with the signatures of the strategy authority
+        accounts.extend(self.remaining_accounts.iter().map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: false,
+            is_writable: account.is_writable,
+        }));
+
+        let mut account_infos = vec![
+            self.strategy_authority.clone(),
+            self.strategy_token_account.clone(),
+            self.vault.clone(),
+            self.base_mint.clone(),
+            self.token_program.clone(),
+        ];
+        account_infos.extend_from_slice(self.remaining_accounts);
+
+        let strategy_authority_seeds: &[&[u8]] = &[
+            b"strategy-authority",
+            self.strategy.as_ref(),
+            &[self.strategy_authority_bump],
+        ];
+        let signer_seeds: &[&[&[u8]]] = if signed {
+            &[strategy_authority_seeds]
+        } else {
+            &[]
+        };
+
+        invoke_signed(
+            &Instruction {
+                program_id: self.strategy_program.key(),
+                accounts,
+                data,
+            },
+            &account_infos,
+            signer_seeds,
+        )?;
+
+        Ok(())
+    }
+}
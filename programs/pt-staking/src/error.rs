@@ -26,4 +26,14 @@ pub enum PtStakingError {
     NotInitialized,
     #[msg("An Invalid Parameter was passed")]
     InvalidParam,
+    #[msg("Staking is paused")]
+    StakingPaused,
+    #[msg("Unstaking is paused")]
+    UnstakingPaused,
+    #[msg("Status unchanged")]
+    StatusUnchanged,
+    #[msg("Invalid toggle active authority")]
+    InvalidToggleActiveAuthority,
+    #[msg("Invalid legacy global config")]
+    InvalidLegacyGlobalConfig,
 }
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{GlobalConfig, Guardian, PtStakingError, GUARDIAN_SIZE};
+
+#[derive(Accounts)]
+#[instruction(new_guardian: Pubkey)]
+pub struct AddGuardian<'info> {
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = GUARDIAN_SIZE,
+        seeds = [b"guardian", new_guardian.as_ref()],
+        bump
+    )]
+    pub guardian: Account<'info, Guardian>,
+    #[account(mut, address = global_config.admin @ PtStakingError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl AddGuardian<'_> {
+    pub fn handler(ctx: Context<AddGuardian>, new_guardian: Pubkey) -> Result<()> {
+        let guardian = &mut ctx.accounts.guardian;
+        guardian.wallet = new_guardian;
+        Ok(())
+    }
+}
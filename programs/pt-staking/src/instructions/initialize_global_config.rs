@@ -80,6 +80,10 @@ impl InitializeGlobalConfig<'_> {
         global_config.staked_supply = 0;
         global_config.deposit_cap = params.deposit_cap;
 
+        // Emergency controls
+        global_config.stake_active = true;
+        global_config.unstake_active = true;
+
         // Histories
         // Initialize the exchange rate history with the initial exchange rate
         let initial_phase = ExchangeRatePhase {
@@ -0,0 +1,82 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+
+use crate::{GlobalConfig, LegacyGlobalConfig, PtStakingError, EMERGENCY_CONTROLS_SIZE};
+
+#[derive(Accounts)]
+pub struct MigrateGlobalConfig<'info> {
+    /// CHECK: Deserialized in the handler with the legacy layout, then rewritten
+    #[account(
+        mut,
+        seeds = [b"global-config"],
+        bump,
+        owner = crate::ID,
+    )]
+    pub global_config: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateGlobalConfig<'_> {
+    /// Rewrites a global config created before the emergency controls were
+    /// added, so that its histories follow the stake and unstake flags, and
+    /// activates both.
+    pub fn handler(ctx: Context<MigrateGlobalConfig>) -> Result<()> {
+        let legacy_global_config = ctx.accounts.load_legacy_global_config()?;
+        if legacy_global_config.owner != ctx.accounts.owner.key() {
+            return err!(PtStakingError::InvalidOwner);
+        }
+
+        let global_config_info = ctx.accounts.global_config.to_account_info();
+        let required_space = global_config_info
+            .data_len()
+            .checked_add(EMERGENCY_CONTROLS_SIZE)
+            .ok_or(PtStakingError::CalculationOverflow)?;
+
+        let rent = Rent::get()?;
+        let new_minimum_balance = rent.minimum_balance(required_space);
+
+        let lamports_diff = new_minimum_balance.saturating_sub(global_config_info.lamports());
+
+        if lamports_diff > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.owner.to_account_info(),
+                        to: global_config_info.clone(),
+                    },
+                ),
+                lamports_diff,
+            )?;
+        }
+
+        global_config_info.realloc(required_space, false)?;
+
+        let global_config = GlobalConfig::from_legacy(legacy_global_config);
+        let mut data = global_config_info.try_borrow_mut_data()?;
+        global_config.try_serialize(&mut &mut data[..])
+    }
+
+    fn load_legacy_global_config(&self) -> Result<LegacyGlobalConfig> {
+        let data = self.global_config.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != GlobalConfig::DISCRIMINATOR {
+            return err!(PtStakingError::InvalidLegacyGlobalConfig);
+        }
+
+        // Legacy accounts are sized to their exact layout, so a migrated
+        // config never reads back as a legacy one
+        let mut legacy_data = &data[8..];
+        let legacy_global_config = LegacyGlobalConfig::deserialize(&mut legacy_data)
+            .map_err(|_| error!(PtStakingError::InvalidLegacyGlobalConfig))?;
+        if !legacy_data.is_empty() {
+            return err!(PtStakingError::InvalidLegacyGlobalConfig);
+        }
+
+        Ok(legacy_global_config)
+    }
+}
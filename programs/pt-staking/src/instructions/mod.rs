@@ -6,6 +6,10 @@ pub mod update_global_config;
 pub mod initiate_update_global_config_owner;
 pub mod update_global_config_owner;
 pub mod withdraw_excess_pt;
+pub mod add_guardian;
+pub mod remove_guardian;
+pub mod toggle_active;
+pub mod migrate_global_config;
 
 
 pub use initialize_global_config::*;
@@ -15,4 +19,8 @@ pub use pt_unstake::*;
 pub use update_global_config::*;
 pub use initiate_update_global_config_owner::*;
 pub use update_global_config_owner::*;
-pub use withdraw_excess_pt::*;
\ No newline at end of file
+pub use withdraw_excess_pt::*;
+pub use add_guardian::*;
+pub use remove_guardian::*;
+pub use toggle_active::*;
+pub use migrate_global_config::*;
\ No newline at end of file
@@ -67,6 +67,8 @@ impl PtStake<'_> {
             return err!(PtStakingError::InvalidQuantity);
         }
 
+        global_config.check_stake_active()?;
+
         // Check if deposit exceeds the deposit cap or limit
         global_config.check_excessive_deposit(quantity, total_vault_amount)?;
 
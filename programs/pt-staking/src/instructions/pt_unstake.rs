@@ -64,6 +64,8 @@ impl PtUnstake<'_> {
             return err!(PtStakingError::InvalidQuantity);
         }
 
+        global_config.check_unstake_active()?;
+
         //Signing
         let bump = global_config.bump; // Corrected to be a slice of a slice of a byte slice
         let signer_seeds: &[&[&[u8]]] = &[&[b"global-config", &[bump]]];
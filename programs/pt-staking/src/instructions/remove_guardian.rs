@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::{GlobalConfig, Guardian, PtStakingError};
+
+#[derive(Accounts)]
+pub struct RemoveGuardian<'info> {
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"guardian", guardian.wallet.as_ref()],
+        bump
+    )]
+    pub guardian: Account<'info, Guardian>,
+    #[account(mut, address = global_config.admin @ PtStakingError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{GlobalConfig, Guardian};
+
+#[derive(Accounts)]
+pub struct ToggleActive<'info> {
+    #[account(
+        mut,
+        seeds = [b"global-config"],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"guardian", authority.key().as_ref()],
+        bump,
+    )]
+    pub guardian: Option<Account<'info, Guardian>>,
+}
+
+impl ToggleActive<'_> {
+    pub fn handler(
+        ctx: Context<ToggleActive>,
+        stake_active: bool,
+        unstake_active: bool,
+    ) -> Result<()> {
+        let global_config = &mut ctx.accounts.global_config;
+
+        let is_admin = global_config.admin == ctx.accounts.authority.key();
+        let is_guardian = ctx.accounts.guardian.is_some();
+
+        global_config.update_status(stake_active, unstake_active, is_admin, is_guardian)
+    }
+}
@@ -54,4 +54,24 @@ pub mod pt_staking {
     pub fn withdraw_excess_pt(ctx: Context<WithdrawExcessPT>) -> Result<()> {
         withdraw_excess_pt::handler(ctx)
     }
+
+    pub fn add_guardian(ctx: Context<AddGuardian>, new_guardian: Pubkey) -> Result<()> {
+        AddGuardian::handler(ctx, new_guardian)
+    }
+
+    pub fn remove_guardian(_ctx: Context<RemoveGuardian>) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn toggle_active(
+        ctx: Context<ToggleActive>,
+        stake_active: bool,
+        unstake_active: bool,
+    ) -> Result<()> {
+        ToggleActive::handler(ctx, stake_active, unstake_active)
+    }
+
+    pub fn migrate_global_config(ctx: Context<MigrateGlobalConfig>) -> Result<()> {
+        MigrateGlobalConfig::handler(ctx)
+    }
 }
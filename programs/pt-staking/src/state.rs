@@ -1,12 +1,18 @@
 use anchor_lang::prelude::*;
+use parity_math::points;
 
 use crate::PtStakingError;
 
 pub const INITIAL_GLOBAL_CONFIG_SIZE: usize =
-    8 + 32 + 32 + 32 + 32 + 32 + 1 + 8 + 8 + 8 + 4 + 26 + 4 + 18 + 4 + 27;
+    8 + 32 + 32 + 32 + 32 + 32 + 1 + 8 + 8 + 8 + 1 + 1 + 4 + 26 + 4 + 18 + 4 + 27;
 
 pub const INITIAL_USER_STAKE_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 4;
 
+pub const GUARDIAN_SIZE: usize = 8 + 32;
+
+// Bytes the emergency controls add to a global config created without them
+pub const EMERGENCY_CONTROLS_SIZE: usize = 1 + 1;
+
 pub const BASE_YIELD_PHASE_SIZE: usize = 27;
 pub const POINTS_EARNED_PHASE_SIZE: usize = 18;
 pub const EXCHANGE_RATE_PHASE_SIZE: usize = 26;
@@ -30,12 +36,34 @@ pub struct GlobalConfig {
     pub staked_supply: u64, // 8
     pub deposit_cap: u64,   // 8
 
+    // Emergency controls
+    pub stake_active: bool,   // 1
+    pub unstake_active: bool, // 1
+
     // Histories
     pub exchange_rate_history: Vec<ExchangeRatePhase>, // 4 + (26 * X)
     pub points_history: Vec<PointsEarnedPhase>,        // 4 + (18 * X)
     pub base_yield_history: Vec<BaseYieldPhase>,       // 4 + (27 * X)
 }
 
+/// Layout of the global config before the emergency controls were added,
+/// read by `migrate_global_config`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyGlobalConfig {
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub admin: Pubkey,
+    pub base_mint: Pubkey,
+    pub staking_vault: Pubkey,
+    pub base_mint_decimals: u8,
+    pub staked_supply: u64,
+    pub deposit_cap: u64,
+    pub exchange_rate_history: Vec<ExchangeRatePhase>,
+    pub points_history: Vec<PointsEarnedPhase>,
+    pub base_yield_history: Vec<BaseYieldPhase>,
+}
+
 #[account]
 #[derive(Debug)]
 pub struct UserStake {
@@ -46,6 +74,11 @@ pub struct UserStake {
     pub points_history: Vec<PointsEarnedPhase>, // 4 + (18 * X)
 }
 
+#[account]
+pub struct Guardian {
+    pub wallet: Pubkey, // 32
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct ExchangeRatePhase {
     pub exchange_rate: u64,    // 8
@@ -87,6 +120,55 @@ impl GlobalConfig {
         Ok(())
     }
 
+    pub fn check_stake_active(&self) -> Result<()> {
+        if !self.stake_active {
+            return err!(PtStakingError::StakingPaused);
+        }
+
+        Ok(())
+    }
+
+    pub fn check_unstake_active(&self) -> Result<()> {
+        if !self.unstake_active {
+            return err!(PtStakingError::UnstakingPaused);
+        }
+
+        Ok(())
+    }
+
+    /// Sets the pause flags. The admin or a guardian can pause, but only the
+    /// admin can unpause.
+    pub fn update_status(
+        &mut self,
+        stake_active: bool,
+        unstake_active: bool,
+        is_admin: bool,
+        is_guardian: bool,
+    ) -> Result<()> {
+        if self.stake_active == stake_active && self.unstake_active == unstake_active {
+            return err!(PtStakingError::StatusUnchanged);
+        }
+
+        let unpausing =
+            (stake_active && !self.stake_active) || (unstake_active && !self.unstake_active);
+
+        if unpausing {
+            // If unpausing, authority must be the admin
+            require!(is_admin, PtStakingError::InvalidToggleActiveAuthority);
+        } else {
+            // If only pausing, authority can be either the admin or a guardian
+            require!(
+                is_admin || is_guardian,
+                PtStakingError::InvalidToggleActiveAuthority
+            );
+        }
+
+        self.stake_active = stake_active;
+        self.unstake_active = unstake_active;
+
+        Ok(())
+    }
+
     // Method to retrieve the current exchange rate from the global config.
     pub fn get_current_exchange_rate(&self) -> Result<u64> {
         match self.exchange_rate_history.last() {
@@ -101,9 +183,6 @@ impl GlobalConfig {
         staking_timestamp: i64,
         current_timestamp: i64,
     ) -> Result<Vec<PointsEarnedPhase>> {
-        const SECONDS_PER_YEAR: u128 = 31_536_000;
-        const PRECISION: u128 = 1_000_000_000_000;
-
         let mut points_history = Vec::new();
         let mut current_time = staking_timestamp;
         let mut accumulated_points: u128 = 0;
@@ -151,25 +230,14 @@ impl GlobalConfig {
             let phase_end = phase_start.min(current_timestamp);
             let applicable_duration = phase_end - current_time;
 
-            let duration_in_years = (applicable_duration as u128)
-                .checked_mul(PRECISION)
-                .unwrap()
-                .checked_div(SECONDS_PER_YEAR)
-                .unwrap();
-
-            let points = (staked_amount as u128)
-                .checked_mul(current_base_yield.base_yield_bps as u128)
-                .unwrap()
-                .checked_mul(duration_in_years)
-                .unwrap()
-                .checked_mul(current_exchange_rate.exchange_rate as u128)
-                .unwrap()
-                .checked_div(PRECISION)
-                .unwrap()
-                .checked_div(10000)
-                .unwrap()
-                .checked_div(10u128.pow(self.base_mint_decimals as u32))
-                .unwrap();
+            let points = points::calculate_points(
+                staked_amount,
+                current_base_yield.base_yield_bps,
+                current_exchange_rate.exchange_rate,
+                applicable_duration,
+                self.base_mint_decimals,
+            )
+            .ok_or(PtStakingError::CalculationOverflow)?;
 
             accumulated_points += points;
 
@@ -194,25 +262,14 @@ impl GlobalConfig {
         if current_time < current_timestamp {
             let applicable_duration = current_timestamp - current_time;
 
-            let duration_in_years = (applicable_duration as u128)
-                .checked_mul(PRECISION)
-                .unwrap()
-                .checked_div(SECONDS_PER_YEAR)
-                .unwrap();
-
-            let points = (staked_amount as u128)
-                .checked_mul(current_base_yield.base_yield_bps as u128)
-                .unwrap()
-                .checked_mul(duration_in_years)
-                .unwrap()
-                .checked_mul(current_exchange_rate.exchange_rate as u128)
-                .unwrap()
-                .checked_div(PRECISION)
-                .unwrap()
-                .checked_div(10000)
-                .unwrap()
-                .checked_div(10u128.pow(self.base_mint_decimals as u32))
-                .unwrap();
+            let points = points::calculate_points(
+                staked_amount,
+                current_base_yield.base_yield_bps,
+                current_exchange_rate.exchange_rate,
+                applicable_duration,
+                self.base_mint_decimals,
+            )
+            .ok_or(PtStakingError::CalculationOverflow)?;
 
             accumulated_points += points;
         }
@@ -249,6 +306,26 @@ impl GlobalConfig {
         // Log the updated points history for debugging
         // msg!("Updated global points history: {:?}", self.points_history);
     }
+
+    /// Global config of a legacy layout, with staking and unstaking active.
+    pub fn from_legacy(legacy: LegacyGlobalConfig) -> Self {
+        Self {
+            bump: legacy.bump,
+            owner: legacy.owner,
+            pending_owner: legacy.pending_owner,
+            admin: legacy.admin,
+            base_mint: legacy.base_mint,
+            staking_vault: legacy.staking_vault,
+            base_mint_decimals: legacy.base_mint_decimals,
+            staked_supply: legacy.staked_supply,
+            deposit_cap: legacy.deposit_cap,
+            stake_active: true,
+            unstake_active: true,
+            exchange_rate_history: legacy.exchange_rate_history,
+            points_history: legacy.points_history,
+            base_yield_history: legacy.base_yield_history,
+        }
+    }
 }
 
 impl UserStake {
@@ -303,6 +380,8 @@ mod tests {
             }],
             staked_supply: 1_000_000,
             deposit_cap: 10_000_000,
+            stake_active: true,
+            unstake_active: true,
             exchange_rate_history: create_default_exchange_rate_phases(),
             points_history: create_default_points_earned_phases(),
             base_mint: Pubkey::default(),
@@ -860,4 +939,62 @@ mod tests {
         assert_eq!(points[1].points, 38812785); // ~38.81 points (20.55 + 18.26)
         assert_points_within_tolerance(points[2].points, 31315068, 5000);
     }
+
+    #[test]
+    fn test_update_status() {
+        let mut global_config = default_global_config();
+
+        // A guardian can pause
+        global_config
+            .update_status(true, false, false, true)
+            .unwrap();
+        assert!(global_config.check_stake_active().is_ok());
+        assert!(global_config.check_unstake_active().is_err());
+
+        // But cannot unpause
+        assert!(global_config
+            .update_status(true, true, false, true)
+            .is_err());
+        assert!(global_config
+            .update_status(true, false, true, false)
+            .is_err());
+
+        // Only the admin can unpause
+        global_config
+            .update_status(true, true, true, false)
+            .unwrap();
+        assert!(global_config.check_unstake_active().is_ok());
+    }
+
+    #[test]
+    fn test_global_config_from_legacy() {
+        let global_config = default_global_config();
+        let legacy = LegacyGlobalConfig {
+            bump: global_config.bump,
+            owner: global_config.owner,
+            pending_owner: global_config.pending_owner,
+            admin: global_config.admin,
+            base_mint: global_config.base_mint,
+            staking_vault: global_config.staking_vault,
+            base_mint_decimals: global_config.base_mint_decimals,
+            staked_supply: global_config.staked_supply,
+            deposit_cap: global_config.deposit_cap,
+            exchange_rate_history: global_config.exchange_rate_history.clone(),
+            points_history: global_config.points_history.clone(),
+            base_yield_history: global_config.base_yield_history.clone(),
+        };
+        let mut legacy_data = Vec::new();
+        legacy.serialize(&mut legacy_data).unwrap();
+
+        let migrated = GlobalConfig::from_legacy(legacy);
+        assert!(migrated.stake_active && migrated.unstake_active);
+
+        // The migrated config keeps every field and only grows by the flags
+        let mut data = Vec::new();
+        migrated.try_serialize(&mut data).unwrap();
+        let mut expected_data = Vec::new();
+        global_config.try_serialize(&mut expected_data).unwrap();
+        assert_eq!(data, expected_data);
+        assert_eq!(data.len(), 8 + legacy_data.len() + EMERGENCY_CONTROLS_SIZE);
+    }
 }
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    pub strategy_authority: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = strategy_authority,
+    )]
+    pub strategy_token_account: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = base_mint)]
+    pub vault: Account<'info, TokenAccount>,
+    pub base_mint: Account<'info, Mint>,
+    pub token_program: Program<'info, Token>,
+    #[account(
+        mut,
+        seeds = [b"holding", strategy_authority.key().as_ref()],
+        bump,
+        token::mint = base_mint,
+    )]
+    pub holding: Account<'info, TokenAccount>,
+}
+
+impl Deposit<'_> {
+    /// Moves the `amount` base tokens the pool paid into the strategy token
+    /// account over to the holding account.
+    pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.strategy_token_account.to_account_info(),
+                    to: ctx.accounts.holding.to_account_info(),
+                    mint: ctx.accounts.base_mint.to_account_info(),
+                    authority: ctx.accounts.strategy_authority.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.base_mint.decimals,
+        )
+    }
+}
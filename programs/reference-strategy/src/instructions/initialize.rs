@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    /// CHECK: Strategy authority of the pool the holding account is created for
+    pub strategy_authority: UncheckedAccount<'info>,
+    pub base_mint: Account<'info, Mint>,
+    /// Holds the base tokens allocated by the pool, owned by itself
+    #[account(
+        init,
+        seeds = [b"holding", strategy_authority.key().as_ref()],
+        bump,
+        payer = payer,
+        token::mint = base_mint,
+        token::authority = holding,
+    )]
+    pub holding: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl Initialize<'_> {
+    pub fn handler(_ctx: Context<Initialize>) -> Result<()> {
+        Ok(())
+    }
+}
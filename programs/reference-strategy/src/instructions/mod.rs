@@ -0,0 +1,9 @@
+pub mod deposit;
+pub mod initialize;
+pub mod report_value;
+pub mod withdraw;
+
+pub use deposit::*;
+pub use initialize::*;
+pub use report_value::*;
+pub use withdraw::*;
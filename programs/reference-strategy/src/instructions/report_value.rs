@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ReportValue<'info> {
+    /// CHECK: Strategy authority of the pool, which does not sign reports
+    pub strategy_authority: UncheckedAccount<'info>,
+    #[account(
+        token::mint = base_mint,
+        token::authority = strategy_authority,
+    )]
+    pub strategy_token_account: Account<'info, TokenAccount>,
+    #[account(token::mint = base_mint)]
+    pub vault: Account<'info, TokenAccount>,
+    pub base_mint: Account<'info, Mint>,
+    pub token_program: Program<'info, Token>,
+    #[account(
+        seeds = [b"holding", strategy_authority.key().as_ref()],
+        bump,
+        token::mint = base_mint,
+    )]
+    pub holding: Account<'info, TokenAccount>,
+}
+
+impl ReportValue<'_> {
+    /// The holding balance, including any base tokens sent in as simulated
+    /// yield, and whatever is left in the strategy token account.
+    pub fn handler(ctx: Context<ReportValue>) -> Result<u64> {
+        Ok(ctx
+            .accounts
+            .holding
+            .amount
+            .saturating_add(ctx.accounts.strategy_token_account.amount))
+    }
+}
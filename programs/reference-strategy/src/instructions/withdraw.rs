@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    pub strategy_authority: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = strategy_authority,
+    )]
+    pub strategy_token_account: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = base_mint)]
+    pub vault: Account<'info, TokenAccount>,
+    pub base_mint: Account<'info, Mint>,
+    pub token_program: Program<'info, Token>,
+    #[account(
+        mut,
+        seeds = [b"holding", strategy_authority.key().as_ref()],
+        bump,
+        token::mint = base_mint,
+    )]
+    pub holding: Account<'info, TokenAccount>,
+}
+
+impl Withdraw<'_> {
+    /// Returns up to `amount` base tokens to the vault, everything held for
+    /// `u64::MAX`. Tokens left in the strategy token account go first.
+    pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let idle_amount = amount.min(accounts.strategy_token_account.amount);
+        let holding_amount = (amount - idle_amount).min(accounts.holding.amount);
+
+        if idle_amount > 0 {
+            transfer_checked(
+                CpiContext::new(
+                    accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: accounts.strategy_token_account.to_account_info(),
+                        to: accounts.vault.to_account_info(),
+                        mint: accounts.base_mint.to_account_info(),
+                        authority: accounts.strategy_authority.to_account_info(),
+                    },
+                ),
+                idle_amount,
+                accounts.base_mint.decimals,
+            )?;
+        }
+
+        let strategy_authority_key = accounts.strategy_authority.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"holding",
+            strategy_authority_key.as_ref(),
+            &[ctx.bumps.holding],
+        ]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.holding.to_account_info(),
+                    to: accounts.vault.to_account_info(),
+                    mint: accounts.base_mint.to_account_info(),
+                    authority: accounts.holding.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            holding_amount,
+            accounts.base_mint.decimals,
+        )
+    }
+}
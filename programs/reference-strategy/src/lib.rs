@@ -0,0 +1,34 @@
+//! Reference implementation of the parity-staking strategy interface, for
+//! local testing. It keeps the base tokens allocated by a pool's strategy
+//! authority in a holding account and reports the holding balance as their
+//! value, so yield is simulated by transferring base tokens into the holding
+//! account.
+
+pub mod instructions;
+
+use anchor_lang::prelude::*;
+
+pub use instructions::*;
+
+declare_id!("GdEABPbGGuFD9kANYDpMUmm1QADc56THV7617bzLJNBc");
+
+#[program]
+pub mod reference_strategy {
+    use super::*;
+
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+        Initialize::handler(ctx)
+    }
+
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        Deposit::handler(ctx, amount)
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        Withdraw::handler(ctx, amount)
+    }
+
+    pub fn report_value(ctx: Context<ReportValue>) -> Result<u64> {
+        ReportValue::handler(ctx)
+    }
+}
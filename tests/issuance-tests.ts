@@ -1,7 +1,7 @@
 import { Umi, Pda, PublicKey, publicKey, createAmount, keypairIdentity, some, } from "@metaplex-foundation/umi";
 import { Connection, Keypair } from "@solana/web3.js";
 import { TransactionBuilder } from "@metaplex-foundation/umi";
-import { addGatekeeper, depositFunds, findGatekeeperPda, initializeTokenManager, initializeWithdrawFunds, initiateUpdateManagerOwner, mint, mintAdmin, PARITY_ISSUANCE_PROGRAM_ID, redeem, removeGatekeeper, safeFetchGatekeeper, safeFetchTokenManager, setup, SetupOptions, toggleActive, updateManagerOwner, updateMintMetadata, updateTokenManagerAdmin, updateTokenManagerOwner, withdrawExcessIssuance, withdrawFunds } from "../clients/js/src";
+import { addGatekeeper, depositFunds, findGatekeeperPda, getDeadSharesMintQuantity, initializeTokenManager, initializeWithdrawFunds, initiateUpdateManagerOwner, mint, mintAdmin, PARITY_ISSUANCE_PROGRAM_ID, redeem, removeGatekeeper, safeFetchGatekeeper, safeFetchTokenManager, setup, SetupOptions, toggleActive, updateManagerOwner, updateMintMetadata, updateTokenManagerAdmin, updateTokenManagerOwner, withdrawExcessIssuance, withdrawFunds } from "../clients/js/src";
 import { getMerkleProof, getMerkleRoot } from "../clients/js/src/utils";
 import { SPL_ASSOCIATED_TOKEN_PROGRAM_ID, safeFetchToken, safeFetchMint, createAssociatedToken, transferTokens, } from "@metaplex-foundation/mpl-toolbox";
 import {
@@ -79,15 +79,27 @@ export async function runIssuanceTests(getEnv: () => TestEnvironment) {
       true,
       "Token manager should be active"
     );
+
+    // Setup only minted the base tokens backing the staking pool dead shares
+    const deadSharesMintQuantity = BigInt(
+      getDeadSharesMintQuantity(
+        env.initialExchangeRateParityStaking,
+        env.xMintDecimals,
+        tokenManagerAcc.mintFeeBps
+      )
+    );
+    const deadSharesMintFee =
+      (deadSharesMintQuantity * BigInt(tokenManagerAcc.mintFeeBps)) / BigInt(10000);
     assert.equal(
       baseMintAcc.supply,
-      0,
-      "Token manager's total supply should be zero"
+      deadSharesMintQuantity - deadSharesMintFee,
+      "Token manager's total supply should only back the dead shares"
     );
     assert.equal(
       tokenManagerAcc.totalCollateral,
-      0,
-      "Token manager's total collateral should be zero"
+      (deadSharesMintQuantity * BigInt(env.exchangeRate)) /
+        BigInt(10 ** env.exchangeRateDecimals),
+      "Token manager's total collateral should only back the dead shares"
     );
     assert.equal(
       tokenManagerAcc.mintFeeBps,
@@ -1110,6 +1122,9 @@ export async function runIssuanceTests(getEnv: () => TestEnvironment) {
         newMintFeeBps: null,
         newRedeemFeeBps: null,
         newExchangeRate: null,
+        newMaxExchangeRateChangeBps: null,
+        newExchangeRateUpdateInterval: null,
+        newExchangeRateRampDuration: null,
       })
     );
 
@@ -1174,6 +1189,9 @@ export async function runIssuanceTests(getEnv: () => TestEnvironment) {
         newMintFeeBps: 80,
         newRedeemFeeBps: 80,
         newExchangeRate: null,
+        newMaxExchangeRateChangeBps: null,
+        newExchangeRateUpdateInterval: null,
+        newExchangeRateRampDuration: null,
       })
     );
 
@@ -1371,6 +1389,9 @@ export async function runIssuanceTests(getEnv: () => TestEnvironment) {
         newMintFeeBps: 0,
         newRedeemFeeBps: 0,
         newExchangeRate: null,
+        newMaxExchangeRateChangeBps: null,
+        newExchangeRateUpdateInterval: null,
+        newExchangeRateRampDuration: null,
       })
     );
 
@@ -27,8 +27,15 @@ describe("parity-contract", () => {
       allowList: [env.umi.identity.publicKey.toString()],
       withdrawExecutionWindow: 3600,
       withdrawTimeLock: 0,
-      intervalAprRate: 1000166517567, // Approximately 5% APR
+      maxExchangeRateChangeBps: 10000, // No exchange rate guardrail
+      exchangeRateUpdateInterval: 0,
+      exchangeRateRampDuration: 0,
+      poolId: env.poolId,
+      annualYieldBps: 2000, // 20% APY
       secondsPerInterval: 28800, // 8 hours
+      unstakeCooldown: 0, // Unstake without a cooldown
+      minStakeAmount: 0,
+      minUnstakeAmount: 0,
       mintFeeBps: 50,
       redeemFeeBps: 50,
       baselineYieldBps: env.baselineYieldBps,
@@ -39,7 +46,10 @@ describe("parity-contract", () => {
 
     const txBuilder = await setup(env.umi, setupOptions);
 
-    await txBuilder.sendAndConfirm(env.umi)
+    // Setup no longer fits in a single transaction
+    for (const builder of txBuilder.unsafeSplitByTransactionSize(env.umi)) {
+      await builder.sendAndConfirm(env.umi);
+    }
   });
 
   describe("parity-issuance", function () {
@@ -1,5 +1,5 @@
 import { createUmi } from "@metaplex-foundation/umi-bundle-defaults";
-import { keypairIdentity, Pda, PublicKey,createAmount, Umi } from "@metaplex-foundation/umi";
+import { keypairIdentity, Pda, PublicKey, publicKeyBytes, createAmount, Umi } from "@metaplex-foundation/umi";
 import { createSplAssociatedTokenProgram, createSplTokenProgram, findAssociatedTokenPda } from "@metaplex-foundation/mpl-toolbox";
 import {
   createParityIssuanceProgram,
@@ -7,8 +7,11 @@ import {
   createPtStakingProgram,
   findGlobalConfigPda,
   findPoolManagerPda,
+  findRateFeedPda,
+  findRateHistoryPda,
   findTokenManagerPda,
   findUserStakePda,
+  findYieldSchedulePda,
   PARITY_ISSUANCE_PROGRAM_ID,
   PARITY_STAKING_PROGRAM_ID
 } from "../clients/js/src";
@@ -29,7 +32,11 @@ export type TestEnvironment = {
   quoteMint: PublicKey;
   userQuote: PublicKey;
   vaultIssuance: Pda;
+  poolId: number;
   poolManager: PublicKey;
+  yieldSchedule: Pda;
+  rateFeed: Pda;
+  rateHistory: Pda;
   vaultStaking: Pda;
   xMint: PublicKey;
   xMetadata: Pda;
@@ -102,13 +109,21 @@ export const setupTestEnvironment = async () => {
   const withdrawTimeLock = 0;
 
   const tokenManager = findTokenManagerPda(umi);
-  const poolManager = findPoolManagerPda(umi)[0];
+  const poolId = 0;
+  const poolManager = findPoolManagerPda(umi, {
+    baseMint: baseMint[0],
+    poolId,
+  })[0];
+  const yieldSchedule = findYieldSchedulePda(umi, { poolManager });
+  const rateFeed = findRateFeedPda(umi, { poolManager });
+  const rateHistory = findRateHistoryPda(umi, { poolManager });
   const vaultStaking = findAssociatedTokenPda(umi, {
     owner: poolManager,
     mint: baseMint[0],
   });
   const xMint: PublicKey = umi.eddsa.findPda(PARITY_STAKING_PROGRAM_ID, [
     Buffer.from("mint"),
+    publicKeyBytes(poolManager),
   ])[0];
 
   const xMetadata: Pda = findMetadataPda(umi, { mint: xMint });
@@ -224,7 +239,11 @@ export const setupTestEnvironment = async () => {
     withdrawTimeLock,
     allowedWallets,
     keypair,
+    poolId,
     poolManager,
+    yieldSchedule,
+    rateFeed,
+    rateHistory,
     vaultStaking,
     xMint,
     xMetadata,